@@ -0,0 +1,8 @@
+-- Documentation-only down migration for 005_user_deals.sql.
+-- sqlx does not auto-apply this; authoring it forces verification that the
+-- schema is cleanly reversible.
+
+DROP INDEX IF EXISTS idx_user_deals_convention;
+DROP INDEX IF EXISTS idx_user_deals_list;
+DROP TABLE IF EXISTS user_deal_calls;
+DROP TABLE IF EXISTS user_deals;
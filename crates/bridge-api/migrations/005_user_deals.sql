@@ -0,0 +1,43 @@
+-- Per-user deal history: one row per completed board, with the user's
+-- graded calls in user_deal_calls.
+--
+-- deal, auction, contract and play hold the engine's JSON wire shapes
+-- (Deal, Auction, Contract, PlayedCard[]) verbatim; the server stores them
+-- opaquely and never reinterprets them. outcome is derived on insert:
+-- 'correct' when every user call graded correct or acceptable, else
+-- 'incorrect'.
+
+CREATE TABLE user_deals (
+    id                  TEXT PRIMARY KEY,
+    user_id             TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    drill_id            TEXT,
+    convention_id       TEXT NOT NULL,
+    practice_role       TEXT NOT NULL,
+    system_selection_id TEXT NOT NULL,
+    board_id            TEXT,
+    deal                TEXT NOT NULL,
+    auction             TEXT NOT NULL,
+    contract            TEXT,
+    play                TEXT,
+    score               INTEGER,
+    outcome             TEXT NOT NULL,
+    call_count          INTEGER NOT NULL,
+    correct_count       INTEGER NOT NULL,
+    completed_at        TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE user_deal_calls (
+    deal_id       TEXT NOT NULL REFERENCES user_deals(id) ON DELETE CASCADE,
+    position      INTEGER NOT NULL,
+    auction_index INTEGER NOT NULL,
+    call          TEXT NOT NULL,
+    expected_call TEXT,
+    grade         TEXT NOT NULL,
+    PRIMARY KEY (deal_id, position)
+);
+
+CREATE INDEX idx_user_deals_list
+    ON user_deals (user_id, completed_at DESC);
+
+CREATE INDEX idx_user_deals_convention
+    ON user_deals (user_id, convention_id, completed_at DESC);
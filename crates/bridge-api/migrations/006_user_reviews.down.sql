@@ -0,0 +1,6 @@
+-- Documentation-only down migration for 006_user_reviews.sql.
+-- sqlx does not auto-apply this; authoring it forces verification that the
+-- schema is cleanly reversible.
+
+DROP INDEX IF EXISTS idx_user_reviews_due;
+DROP TABLE IF EXISTS user_reviews;
@@ -0,0 +1,24 @@
+-- Per-user spaced-repetition state: one row per convention decision point
+-- (a module surface the user was graded on).
+--
+-- Columns mirror bridge-session's ReviewCard. The scheduling arithmetic
+-- lives there; the server loads a user's cards, applies new grades, and
+-- writes the touched rows back. due_at and last_reviewed_at are unix
+-- seconds. last_grade is a BidGrade wire value.
+
+CREATE TABLE user_reviews (
+    user_id          TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    module_id        TEXT NOT NULL,
+    surface_id       TEXT NOT NULL,
+    ease             REAL NOT NULL,
+    interval_days    REAL NOT NULL,
+    repetitions      INTEGER NOT NULL,
+    lapses           INTEGER NOT NULL,
+    due_at           INTEGER NOT NULL,
+    last_reviewed_at INTEGER NOT NULL,
+    last_grade       TEXT NOT NULL,
+    PRIMARY KEY (user_id, module_id, surface_id)
+);
+
+CREATE INDEX idx_user_reviews_due
+    ON user_reviews (user_id, due_at);
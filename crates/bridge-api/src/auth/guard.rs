@@ -0,0 +1,30 @@
+//! Session-cookie guard shared by the handlers that need a signed-in user.
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::extract::CookieJar;
+use serde_json::json;
+
+use crate::auth::models::User;
+use crate::auth::session;
+use crate::error::AppError;
+use crate::AppState;
+
+/// The user behind the request's `session` cookie, or `None` when the
+/// cookie is missing or its session has expired.
+pub async fn require_user(state: &AppState, jar: &CookieJar) -> Result<Option<User>, AppError> {
+    let Some(token) = jar.get("session").map(|cookie| cookie.value().to_string()) else {
+        return Ok(None);
+    };
+    Ok(session::lookup_session(&state.pool, &token).await?)
+}
+
+/// 401 body returned when `require_user` finds nobody.
+pub fn unauthenticated_response() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(json!({ "error": "unauthenticated" })),
+    )
+        .into_response()
+}
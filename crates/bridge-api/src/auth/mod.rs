@@ -1,3 +1,4 @@
+pub mod guard;
 pub mod handlers;
 pub mod models;
 pub mod oauth;
@@ -14,6 +14,10 @@ pub struct Config {
     pub billing_success_url: String,
     pub billing_cancel_url: String,
     pub conventions_fixtures_dir: PathBuf,
+    /// Directory of extra `ConventionModule` JSON files loaded at startup on
+    /// top of the embedded modules (`CONVENTIONS_MODULES_DIR`). Unset means
+    /// embedded modules only.
+    pub conventions_modules_dir: Option<PathBuf>,
     /// Origins permitted by the CORS layer. Defaults to `[base_url]` when
     /// `CORS_ALLOWED_ORIGINS` is unset. Use a comma-separated list to allow
     /// multiple. Never ship wildcard CORS for a cookie-authenticated API.
@@ -49,6 +53,7 @@ impl Config {
             conventions_fixtures_dir: env::var_os("CONVENTIONS_FIXTURES_DIR")
                 .map(PathBuf::from)
                 .unwrap_or_else(|| PathBuf::from("crates/bridge-conventions/fixtures")),
+            conventions_modules_dir: env::var_os("CONVENTIONS_MODULES_DIR").map(PathBuf::from),
             cors_allowed_origins,
         }
     }
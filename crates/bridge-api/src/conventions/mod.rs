@@ -1,4 +1,5 @@
 pub mod handlers;
+pub mod modules;
 
 use axum::routing::get;
 use axum::Router;
@@ -0,0 +1,62 @@
+//! Convention modules loaded from `CONVENTIONS_MODULES_DIR`.
+//!
+//! Files are validated one by one; a rejected file is logged and skipped, the
+//! rest are installed on top of the embedded modules. With `dev-tools`, the
+//! directory is polled and edits are picked up without a restart.
+
+use std::io;
+use std::path::Path;
+
+use bridge_conventions::registry::{
+    active_registry, install_modules, ModuleDirectory, ModuleLoadReport,
+};
+
+#[cfg(feature = "dev-tools")]
+const HOT_RELOAD_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Load every module file in `dir` and install the result. Fails only when
+/// the directory itself cannot be read.
+pub fn install_module_dir(dir: &Path) -> io::Result<()> {
+    let mut directory = ModuleDirectory::new(dir);
+    reload(&mut directory)?;
+
+    #[cfg(feature = "dev-tools")]
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HOT_RELOAD_INTERVAL);
+        loop {
+            interval.tick().await;
+            if !directory.has_changed() {
+                continue;
+            }
+            if let Err(error) = reload(&mut directory) {
+                tracing::warn!(
+                    dir = %directory.path().display(),
+                    %error,
+                    "convention module reload failed"
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
+fn reload(directory: &mut ModuleDirectory) -> io::Result<()> {
+    let (registry, report) = directory.reload(active_registry())?;
+    install_modules(registry);
+    log_report(directory.path(), &report);
+    Ok(())
+}
+
+fn log_report(dir: &Path, report: &ModuleLoadReport) {
+    for error in &report.errors {
+        tracing::warn!(dir = %dir.display(), %error, "convention module rejected");
+    }
+    tracing::info!(
+        dir = %dir.display(),
+        loaded = ?report.loaded,
+        replaced = ?report.replaced,
+        kept = ?report.kept,
+        "convention modules installed"
+    );
+}
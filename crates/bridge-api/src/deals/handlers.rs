@@ -0,0 +1,465 @@
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path as AxumPath, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::extract::CookieJar;
+use std::collections::HashSet;
+
+use bridge_engine::types::{Auction, Call, Contract, Deal, PlayedCard};
+use bridge_engine::SEATS;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::auth::guard::{require_user, unauthenticated_response};
+use crate::drills::entitlement::unknown_modules;
+use crate::error::AppError;
+use crate::AppState;
+
+use super::models::{is_passing_grade, DealCallRow, DealRow, BID_GRADES, OUTCOMES};
+use super::repository::{self, DealFilter, InsertDeal, InsertDealCall};
+
+const DEAL_CALLS_MAX: usize = 64;
+const DEAL_LABEL_MAX: usize = 64;
+const LIST_LIMIT_DEFAULT: i64 = 50;
+const LIST_LIMIT_MAX: i64 = 200;
+
+/// One graded user call as stored. `call` and `expectedCall` are engine
+/// `Call` JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealCallPayload {
+    /// Index of the call within `auction.entries`.
+    pub auction_index: i64,
+    pub call: Value,
+    pub expected_call: Option<Value>,
+    /// A `BidGrade` wire value: correct, acceptable, near-miss or incorrect.
+    pub grade: String,
+}
+
+/// List row for the review table — everything but the hands, auction and
+/// play. Wire shape is camelCase.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealSummary {
+    pub id: String,
+    pub drill_id: Option<String>,
+    pub convention_id: String,
+    pub practice_role: String,
+    pub system_selection_id: String,
+    pub board_id: Option<String>,
+    pub contract: Option<Value>,
+    pub score: Option<i64>,
+    pub outcome: String,
+    pub call_count: i64,
+    pub correct_count: i64,
+    pub completed_at: String,
+}
+
+/// Full board record. Never returned as a `DealRow` directly — always
+/// converted via `From<(DealRow, Vec<DealCallRow>)>`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealPayload {
+    #[serde(flatten)]
+    pub summary: DealSummary,
+    pub deal: Value,
+    pub auction: Value,
+    pub play: Option<Value>,
+    pub calls: Vec<DealCallPayload>,
+}
+
+/// One graded user call in a `DealRequest`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealCallRequest {
+    /// Index of the call within `auction.entries`.
+    pub auction_index: i64,
+    pub call: Call,
+    pub expected_call: Option<Call>,
+    /// A `BidGrade` wire value: correct, acceptable, near-miss or incorrect.
+    pub grade: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealRequest {
+    pub id: Option<String>,
+    pub drill_id: Option<String>,
+    pub convention_id: String,
+    pub practice_role: String,
+    pub system_selection_id: String,
+    pub board_id: Option<String>,
+    pub deal: Deal,
+    pub auction: Auction,
+    pub contract: Option<Contract>,
+    pub play: Option<Vec<PlayedCard>>,
+    pub score: Option<i64>,
+    pub calls: Vec<DealCallRequest>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DealListQuery {
+    pub convention: Option<String>,
+    pub role: Option<String>,
+    pub system: Option<String>,
+    pub outcome: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct DealResponse {
+    deal: DealPayload,
+}
+
+#[derive(Serialize)]
+struct DealsResponse {
+    deals: Vec<DealSummary>,
+}
+
+/// Stored JSON columns are written by `record_deal`; a row that no longer
+/// parses comes back as `null` rather than failing the whole listing.
+fn parse_stored(json: &str) -> Value {
+    serde_json::from_str(json).unwrap_or(Value::Null)
+}
+
+impl From<DealRow> for DealSummary {
+    fn from(row: DealRow) -> Self {
+        DealSummary {
+            id: row.id,
+            drill_id: row.drill_id,
+            convention_id: row.convention_id,
+            practice_role: row.practice_role,
+            system_selection_id: row.system_selection_id,
+            board_id: row.board_id,
+            contract: row.contract.as_deref().map(parse_stored),
+            score: row.score,
+            outcome: row.outcome,
+            call_count: row.call_count,
+            correct_count: row.correct_count,
+            completed_at: row.completed_at,
+        }
+    }
+}
+
+impl From<(DealRow, Vec<DealCallRow>)> for DealPayload {
+    fn from((row, calls): (DealRow, Vec<DealCallRow>)) -> Self {
+        let deal = parse_stored(&row.deal);
+        let auction = parse_stored(&row.auction);
+        let play = row.play.as_deref().map(parse_stored);
+        let calls = calls
+            .into_iter()
+            .map(|call| DealCallPayload {
+                auction_index: call.auction_index,
+                call: parse_stored(&call.call),
+                expected_call: call.expected_call.as_deref().map(parse_stored),
+                grade: call.grade,
+            })
+            .collect();
+
+        DealPayload {
+            summary: DealSummary::from(row),
+            deal,
+            auction,
+            play,
+            calls,
+        }
+    }
+}
+
+// ─── Handlers ──────────────────────────────────────────────
+
+pub async fn list_deals(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Query(query): Query<DealListQuery>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    if let Some(outcome) = query.outcome.as_deref() {
+        if !OUTCOMES.contains(&outcome) {
+            return Ok(validation_response(
+                "outcome",
+                "outcome must be correct or incorrect",
+            ));
+        }
+    }
+    for (field, date) in [("from", &query.from), ("to", &query.to)] {
+        if let Some(date) = date {
+            if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                return Ok(validation_response(field, "date must be YYYY-MM-DD"));
+            }
+        }
+    }
+    let limit = query.limit.unwrap_or(LIST_LIMIT_DEFAULT);
+    if !(1..=LIST_LIMIT_MAX).contains(&limit) {
+        return Ok(validation_response("limit", "limit must be 1-200"));
+    }
+    let offset = query.offset.unwrap_or(0);
+    if offset < 0 {
+        return Ok(validation_response("offset", "offset must be non-negative"));
+    }
+
+    let filter = DealFilter {
+        convention_id: query.convention.as_deref(),
+        practice_role: query.role.as_deref(),
+        system_selection_id: query.system.as_deref(),
+        outcome: query.outcome.as_deref(),
+        from: query.from.as_deref(),
+        to: query.to.as_deref(),
+        limit,
+        offset,
+    };
+    let rows = repository::list_deals(&state.pool, &user.id, &filter).await?;
+    let deals: Vec<DealSummary> = rows.into_iter().map(DealSummary::from).collect();
+    Ok(Json(DealsResponse { deals }).into_response())
+}
+
+pub async fn get_deal(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(id): AxumPath<String>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    if let Err(resp) = validate_deal_id(&id) {
+        return Ok(resp);
+    }
+
+    match repository::get_deal(&state.pool, &user.id, &id).await? {
+        Some(saved) => Ok(Json(DealResponse {
+            deal: DealPayload::from(saved),
+        })
+        .into_response()),
+        None => Ok(not_found_response()),
+    }
+}
+
+pub async fn record_deal(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    req: Result<Json<DealRequest>, JsonRejection>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+    let Json(req) = match req {
+        Ok(req) => req,
+        Err(rejection) => return Ok(validation_response("body", &rejection.body_text())),
+    };
+
+    let id =
+        req.id.as_deref().map(str::to_string).unwrap_or_else(|| {
+            format!("deal:{}", &uuid::Uuid::new_v4().simple().to_string()[..12])
+        });
+
+    if let Err(resp) = validate_deal_id(&id) {
+        return Ok(resp);
+    }
+    if let Err(resp) = validate_request(&req) {
+        return Ok(resp);
+    }
+    // Derived rather than trusted, so a board id always names the deal stored.
+    let board_id = bridge_engine::board_id(&req.deal);
+    if !unknown_modules(std::slice::from_ref(&req.convention_id)).is_empty() {
+        return Ok(validation_response("conventionId", "unknown convention"));
+    }
+    let deal_json = to_json(&req.deal)?;
+    let auction_json = to_json(&req.auction)?;
+    let contract_json = req.contract.as_ref().map(to_json).transpose()?;
+    let play_json = req.play.as_ref().map(to_json).transpose()?;
+    let call_jsons = req
+        .calls
+        .iter()
+        .map(|call| {
+            Ok((
+                to_json(&call.call)?,
+                call.expected_call.as_ref().map(to_json).transpose()?,
+            ))
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let correct_count = req
+        .calls
+        .iter()
+        .filter(|call| is_passing_grade(&call.grade))
+        .count() as i64;
+    let outcome = if correct_count == req.calls.len() as i64 {
+        "correct"
+    } else {
+        "incorrect"
+    };
+
+    let inserted = repository::insert_deal(
+        &state.pool,
+        InsertDeal {
+            id: &id,
+            user_id: &user.id,
+            drill_id: req.drill_id.as_deref(),
+            convention_id: &req.convention_id,
+            practice_role: &req.practice_role,
+            system_selection_id: &req.system_selection_id,
+            board_id: Some(&board_id),
+            deal_json: &deal_json,
+            auction_json: &auction_json,
+            contract_json: contract_json.as_deref(),
+            play_json: play_json.as_deref(),
+            score: req.score,
+            outcome,
+            correct_count,
+            calls: req
+                .calls
+                .iter()
+                .zip(&call_jsons)
+                .map(|(call, (call_json, expected_json))| InsertDealCall {
+                    auction_index: call.auction_index,
+                    call_json,
+                    expected_call_json: expected_json.as_deref(),
+                    grade: &call.grade,
+                })
+                .collect(),
+        },
+    )
+    .await;
+    match inserted {
+        Ok(()) => {}
+        // Records are immutable; a retried POST with the same id is a conflict.
+        Err(sqlx::Error::Database(err)) if err.is_unique_violation() => {
+            return Ok(conflict_response());
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    let saved = repository::get_deal(&state.pool, &user.id, &id).await?;
+    let deal = saved
+        .map(DealPayload::from)
+        .ok_or_else(|| AppError::Internal("deal should exist after insert".into()))?;
+
+    Ok((StatusCode::CREATED, Json(DealResponse { deal })).into_response())
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, AppError> {
+    serde_json::to_string(value).map_err(|err| AppError::Internal(format!("deal serialize: {err}")))
+}
+
+// ─── Validation ──────────────────────────────────────────
+
+fn validate_deal_id(id: &str) -> Result<(), Response> {
+    if !id.starts_with("deal:") || id.len() <= "deal:".len() || id.len() > 70 {
+        return Err(validation_response("id", "invalid deal id"));
+    }
+    let suffix = &id["deal:".len()..];
+    if !suffix
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    {
+        return Err(validation_response("id", "invalid deal id"));
+    }
+    Ok(())
+}
+
+fn validate_request(req: &DealRequest) -> Result<(), Response> {
+    for (field, value) in [
+        ("conventionId", &req.convention_id),
+        ("practiceRole", &req.practice_role),
+        ("systemSelectionId", &req.system_selection_id),
+    ] {
+        if value.trim().is_empty() {
+            return Err(validation_response(field, "value is required"));
+        }
+        if value.chars().count() > DEAL_LABEL_MAX {
+            return Err(validation_response(field, "value exceeds maximum length"));
+        }
+    }
+    if !is_full_deal(&req.deal) {
+        return Err(validation_response(
+            "deal",
+            "deal must hold all 52 cards, 13 to a seat",
+        ));
+    }
+    if let Some(board_id) = &req.board_id {
+        if board_id.chars().count() > DEAL_LABEL_MAX {
+            return Err(validation_response(
+                "boardId",
+                "value exceeds maximum length",
+            ));
+        }
+        if *board_id != bridge_engine::board_id(&req.deal) {
+            return Err(validation_response(
+                "boardId",
+                "board id does not match the deal",
+            ));
+        }
+    }
+    // A board with no graded calls has nothing to be correct about.
+    if req.calls.is_empty() {
+        return Err(validation_response(
+            "calls",
+            "at least one call is required",
+        ));
+    }
+    let auction_len = req.auction.entries.len();
+    if req.calls.len() > DEAL_CALLS_MAX || req.calls.len() > auction_len {
+        return Err(validation_response("calls", "too many calls"));
+    }
+    for call in &req.calls {
+        if !BID_GRADES.contains(&call.grade.as_str()) {
+            return Err(validation_response("calls", "unknown grade"));
+        }
+        if usize::try_from(call.auction_index).map_or(true, |index| index >= auction_len) {
+            return Err(validation_response(
+                "calls",
+                "auction index is outside the auction",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether every seat holds 13 cards and the 52 are all distinct.
+fn is_full_deal(deal: &Deal) -> bool {
+    let mut seen = HashSet::with_capacity(52);
+    SEATS.iter().all(|seat| {
+        deal.hands.get(seat).is_some_and(|hand| {
+            hand.cards.len() == 13
+                && hand
+                    .cards
+                    .iter()
+                    .all(|card| seen.insert((card.suit, card.rank)))
+        })
+    })
+}
+
+// ─── Error responses ──────────────────────────────────────
+
+fn validation_response(field: &str, message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "error": "validation",
+            "field": field,
+            "message": message,
+        })),
+    )
+        .into_response()
+}
+
+fn conflict_response() -> Response {
+    (StatusCode::CONFLICT, Json(json!({ "error": "conflict" }))).into_response()
+}
+
+fn not_found_response() -> Response {
+    (StatusCode::NOT_FOUND, Json(json!({ "error": "not_found" }))).into_response()
+}
@@ -0,0 +1,17 @@
+pub mod handlers;
+pub mod models;
+pub mod repository;
+
+use axum::routing::get;
+use axum::Router;
+
+use crate::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/deals",
+            get(handlers::list_deals).post(handlers::record_deal),
+        )
+        .route("/api/deals/{id}", get(handlers::get_deal))
+}
@@ -0,0 +1,45 @@
+use sqlx::FromRow;
+
+/// Storage shape for `user_deals` rows. Never returned directly from a
+/// handler — convert to `DealSummary` or `DealPayload` in `handlers.rs`.
+#[derive(Debug, Clone, FromRow)]
+pub struct DealRow {
+    pub id: String,
+    #[allow(dead_code)]
+    pub user_id: String,
+    pub drill_id: Option<String>,
+    pub convention_id: String,
+    pub practice_role: String,
+    pub system_selection_id: String,
+    pub board_id: Option<String>,
+    pub deal: String,
+    pub auction: String,
+    pub contract: Option<String>,
+    pub play: Option<String>,
+    pub score: Option<i64>,
+    pub outcome: String,
+    pub call_count: i64,
+    pub correct_count: i64,
+    pub completed_at: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DealCallRow {
+    #[allow(dead_code)]
+    pub deal_id: String,
+    pub position: i64,
+    pub auction_index: i64,
+    pub call: String,
+    pub expected_call: Option<String>,
+    pub grade: String,
+}
+
+/// Grades a user call can carry. Mirrors `BidGrade` in bridge-session.
+pub const BID_GRADES: &[&str] = &["correct", "acceptable", "near-miss", "incorrect"];
+
+/// A board is `correct` when every user call graded correct or acceptable.
+pub fn is_passing_grade(grade: &str) -> bool {
+    matches!(grade, "correct" | "acceptable")
+}
+
+pub const OUTCOMES: &[&str] = &["correct", "incorrect"];
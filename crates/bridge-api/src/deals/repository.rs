@@ -0,0 +1,160 @@
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+
+use super::models::{DealCallRow, DealRow};
+
+const DEAL_COLUMNS: &str = "id, user_id, drill_id, convention_id, practice_role, \
+     system_selection_id, board_id, deal, auction, contract, play, score, outcome, \
+     call_count, correct_count, completed_at";
+
+/// Listing filters. Dates are `YYYY-MM-DD`, both ends inclusive.
+#[derive(Debug, Default)]
+pub struct DealFilter<'a> {
+    pub convention_id: Option<&'a str>,
+    pub practice_role: Option<&'a str>,
+    pub system_selection_id: Option<&'a str>,
+    pub outcome: Option<&'a str>,
+    pub from: Option<&'a str>,
+    pub to: Option<&'a str>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+pub async fn list_deals(
+    pool: &SqlitePool,
+    user_id: &str,
+    filter: &DealFilter<'_>,
+) -> Result<Vec<DealRow>, sqlx::Error> {
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+        "SELECT {DEAL_COLUMNS} FROM user_deals WHERE user_id = "
+    ));
+    query.push_bind(user_id);
+    if let Some(convention_id) = filter.convention_id {
+        query.push(" AND convention_id = ").push_bind(convention_id);
+    }
+    if let Some(practice_role) = filter.practice_role {
+        query.push(" AND practice_role = ").push_bind(practice_role);
+    }
+    if let Some(system_selection_id) = filter.system_selection_id {
+        query
+            .push(" AND system_selection_id = ")
+            .push_bind(system_selection_id);
+    }
+    if let Some(outcome) = filter.outcome {
+        query.push(" AND outcome = ").push_bind(outcome);
+    }
+    if let Some(from) = filter.from {
+        query.push(" AND completed_at >= date(").push_bind(from);
+        query.push(")");
+    }
+    if let Some(to) = filter.to {
+        query.push(" AND completed_at < date(").push_bind(to);
+        query.push(", '+1 day')");
+    }
+    query.push(" ORDER BY completed_at DESC, rowid DESC LIMIT ");
+    query.push_bind(filter.limit);
+    query.push(" OFFSET ").push_bind(filter.offset);
+
+    query.build_query_as().fetch_all(pool).await
+}
+
+pub async fn get_deal(
+    pool: &SqlitePool,
+    user_id: &str,
+    deal_id: &str,
+) -> Result<Option<(DealRow, Vec<DealCallRow>)>, sqlx::Error> {
+    let row: Option<DealRow> = sqlx::query_as(&format!(
+        "SELECT {DEAL_COLUMNS} FROM user_deals WHERE user_id = ? AND id = ?"
+    ))
+    .bind(user_id)
+    .bind(deal_id)
+    .fetch_optional(pool)
+    .await?;
+
+    match row {
+        None => Ok(None),
+        Some(row) => {
+            let calls = sqlx::query_as(
+                "SELECT deal_id, position, auction_index, call, expected_call, grade \
+                 FROM user_deal_calls \
+                 WHERE deal_id = ? \
+                 ORDER BY position ASC",
+            )
+            .bind(&row.id)
+            .fetch_all(pool)
+            .await?;
+            Ok(Some((row, calls)))
+        }
+    }
+}
+
+pub struct InsertDealCall<'a> {
+    pub auction_index: i64,
+    pub call_json: &'a str,
+    pub expected_call_json: Option<&'a str>,
+    pub grade: &'a str,
+}
+
+pub struct InsertDeal<'a> {
+    pub id: &'a str,
+    pub user_id: &'a str,
+    pub drill_id: Option<&'a str>,
+    pub convention_id: &'a str,
+    pub practice_role: &'a str,
+    pub system_selection_id: &'a str,
+    pub board_id: Option<&'a str>,
+    pub deal_json: &'a str,
+    pub auction_json: &'a str,
+    pub contract_json: Option<&'a str>,
+    pub play_json: Option<&'a str>,
+    pub score: Option<i64>,
+    pub outcome: &'a str,
+    pub correct_count: i64,
+    pub calls: Vec<InsertDealCall<'a>>,
+}
+
+pub async fn insert_deal(pool: &SqlitePool, deal: InsertDeal<'_>) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO user_deals (\
+            id, user_id, drill_id, convention_id, practice_role, system_selection_id, \
+            board_id, deal, auction, contract, play, score, outcome, call_count, correct_count\
+         ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(deal.id)
+    .bind(deal.user_id)
+    .bind(deal.drill_id)
+    .bind(deal.convention_id)
+    .bind(deal.practice_role)
+    .bind(deal.system_selection_id)
+    .bind(deal.board_id)
+    .bind(deal.deal_json)
+    .bind(deal.auction_json)
+    .bind(deal.contract_json)
+    .bind(deal.play_json)
+    .bind(deal.score)
+    .bind(deal.outcome)
+    .bind(deal.calls.len() as i64)
+    .bind(deal.correct_count)
+    .execute(&mut *tx)
+    .await?;
+
+    for (position, call) in deal.calls.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO user_deal_calls (\
+                deal_id, position, auction_index, call, expected_call, grade\
+             ) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(deal.id)
+        .bind(position as i64)
+        .bind(call.auction_index)
+        .bind(call.call_json)
+        .bind(call.expected_call_json)
+        .bind(call.grade)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
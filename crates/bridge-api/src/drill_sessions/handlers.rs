@@ -0,0 +1,319 @@
+use axum::extract::{Path as AxumPath, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::extract::CookieJar;
+use bridge_conventions::registry::bundle_registry::get_bundle_input;
+use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
+use bridge_conventions::types::rule_types::TargetSelector;
+use bridge_engine::types::{Call, Card, Seat};
+use bridge_service::{ServiceError, ServicePort, SessionConfig};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::auth::guard::{require_user, unauthenticated_response};
+use crate::billing::entitlements::tier_for;
+use crate::drills::entitlement::{blocked_modules, unknown_modules};
+use crate::error::AppError;
+use crate::reviews;
+use crate::AppState;
+
+#[derive(Serialize)]
+struct CreatedResponse {
+    handle: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BidRequest {
+    pub call: Call,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnterPlayRequest {
+    pub seat_override: Option<Seat>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlayCardRequest {
+    pub card: Card,
+    pub seat: Seat,
+}
+
+// ─── Handlers ──────────────────────────────────────────────
+
+pub async fn create_session(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Json(mut config): Json<SessionConfig>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    let gated = gated_module_ids(&config);
+    let unknown = unknown_modules(&gated);
+    if !unknown.is_empty() {
+        return Ok(unknown_module_response(unknown));
+    }
+    let tier = tier_for(
+        user.subscription_status.as_deref(),
+        user.subscription_current_period_end,
+        Utc::now().timestamp(),
+    );
+    if !blocked_modules(tier, &gated).is_empty() {
+        return Ok(subscription_required_response());
+    }
+
+    // Lean the drill toward what the user keeps missing, unless the caller
+    // chose its own weights.
+    if config.module_weights.is_none() {
+        let schedule = reviews::repository::load_schedule(&state.pool, &user.id).await?;
+        if !schedule.cards().is_empty() {
+            config.module_weights = Some(schedule.module_weights(Utc::now().timestamp()));
+        }
+    }
+
+    match state.drill_sessions.create(user.id, config).await? {
+        Ok(handle) => Ok((StatusCode::CREATED, Json(CreatedResponse { handle })).into_response()),
+        Err(err) => Ok(service_error_response(err)),
+    }
+}
+
+/// IDs in `config` the user must be entitled to: the convention being
+/// drilled, every base module outside the standard set (which rides along
+/// with every system and is never paid content), and a drill target that is
+/// neither a member of the convention nor a base module.
+fn gated_module_ids(config: &SessionConfig) -> Vec<String> {
+    let is_standard = |id: &str| BASE_MODULE_IDS.contains(&id);
+    let mut ids = vec![config.convention_id.clone()];
+    ids.extend(
+        config
+            .base_module_ids
+            .iter()
+            .filter(|id| !is_standard(id))
+            .cloned(),
+    );
+    if let Some(target) = config.target.as_ref().and_then(TargetSelector::module_id) {
+        let is_member = get_bundle_input(&config.convention_id)
+            .is_some_and(|bundle| bundle.member_ids.iter().any(|id| id == target));
+        if !is_member && !is_standard(target) && !ids.iter().any(|id| id == target) {
+            ids.push(target.to_string());
+        }
+    }
+    ids
+}
+
+pub async fn destroy_session(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(handle): AxumPath<String>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    match state.drill_sessions.destroy(user.id, handle).await? {
+        Ok(()) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(err) => Ok(service_error_response(err)),
+    }
+}
+
+pub async fn start_drill(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(handle): AxumPath<String>,
+) -> Result<Response, AppError> {
+    run(&state, &jar, handle, |service, handle| {
+        service.start_drill(handle)
+    })
+    .await
+}
+
+pub async fn submit_bid(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(handle): AxumPath<String>,
+    Json(req): Json<BidRequest>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    let result = match state
+        .drill_sessions
+        .with_session(user.id.clone(), handle, move |service, handle| {
+            service.submit_bid(handle, req.call)
+        })
+        .await?
+    {
+        Ok(result) => result,
+        Err(err) => return Ok(service_error_response(err)),
+    };
+
+    // The server graded this call, so it goes straight into the review
+    // schedule; clients of hosted drills never post their own outcomes.
+    let decision = result
+        .feedback
+        .as_ref()
+        .and_then(|feedback| feedback.decision_point.clone());
+    if let (Some(grade), Some(decision)) = (result.grade, decision) {
+        reviews::repository::record_outcomes(
+            &state.pool,
+            &user.id,
+            vec![(decision, grade)],
+            Utc::now().timestamp(),
+        )
+        .await?;
+    }
+
+    Ok(Json(result).into_response())
+}
+
+pub async fn enter_play(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(handle): AxumPath<String>,
+    req: Option<Json<EnterPlayRequest>>,
+) -> Result<Response, AppError> {
+    let seat_override = req.and_then(|Json(req)| req.seat_override);
+    run(&state, &jar, handle, move |service, handle| {
+        service.enter_play(handle, seat_override)
+    })
+    .await
+}
+
+pub async fn play_card(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath(handle): AxumPath<String>,
+    Json(req): Json<PlayCardRequest>,
+) -> Result<Response, AppError> {
+    run(&state, &jar, handle, move |service, handle| {
+        service.play_card(handle, req.card, req.seat)
+    })
+    .await
+}
+
+/// Viewport getters. Each returns the viewport, or `null` when the session
+/// is not in a phase that has one.
+pub async fn get_viewport(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    AxumPath((handle, kind)): AxumPath<(String, String)>,
+) -> Result<Response, AppError> {
+    let getter: fn(&dyn ServicePort, &str) -> Result<serde_json::Value, ServiceError> =
+        match kind.as_str() {
+            "bidding" => |service, handle| to_value(service.get_bidding_viewport(handle)?),
+            "declarer-prompt" => {
+                |service, handle| to_value(service.get_declarer_prompt_viewport(handle)?)
+            }
+            "playing" => |service, handle| to_value(service.get_playing_viewport(handle)?),
+            "explanation" => |service, handle| to_value(service.get_explanation_viewport(handle)?),
+            "replay" => |service, handle| to_value(service.get_auction_replay_viewport(handle)?),
+            _ => return Ok(not_found_response("unknown_viewport")),
+        };
+
+    run(&state, &jar, handle, move |service, handle| {
+        getter(service, handle)
+    })
+    .await
+}
+
+// ─── Helpers ──────────────────────────────────────────────
+
+/// Authenticate, then run `f` against one of the caller's sessions and
+/// serialize whatever it returns.
+async fn run<T, F>(
+    state: &AppState,
+    jar: &CookieJar,
+    handle: String,
+    f: F,
+) -> Result<Response, AppError>
+where
+    T: Serialize + Send + 'static,
+    F: FnOnce(&mut bridge_service::ServicePortImpl, &str) -> Result<T, ServiceError>
+        + Send
+        + 'static,
+{
+    let user = match require_user(state, jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    match state
+        .drill_sessions
+        .with_session(user.id, handle, f)
+        .await?
+    {
+        Ok(body) => Ok(Json(body).into_response()),
+        Err(err) => Ok(service_error_response(err)),
+    }
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<serde_json::Value, ServiceError> {
+    serde_json::to_value(value).map_err(|err| ServiceError::Internal(err.to_string()))
+}
+
+// ─── Error responses ──────────────────────────────────────
+
+fn service_error_response(err: ServiceError) -> Response {
+    let (status, error) = match &err {
+        ServiceError::NoSession | ServiceError::InvalidHandle(_) => {
+            return not_found_response("session_not_found");
+        }
+        ServiceError::WrongPhase | ServiceError::Undo(_) => (StatusCode::CONFLICT, "wrong_phase"),
+        ServiceError::BundleNotFound(_)
+        | ServiceError::ModuleNotFound(_)
+        | ServiceError::InvalidConfig(_)
+        | ServiceError::InvalidHandRecord(_)
+        | ServiceError::Snapshot(_) => (StatusCode::BAD_REQUEST, "invalid_request"),
+        ServiceError::DealGenerationExhausted { .. } => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "deal_generation_exhausted",
+        ),
+        ServiceError::DdsNotAvailable | ServiceError::Internal(_) => {
+            tracing::error!("drill session error: {err}");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "internal" })),
+            )
+                .into_response();
+        }
+    };
+    (
+        status,
+        Json(json!({
+            "error": error,
+            "message": err.to_string(),
+        })),
+    )
+        .into_response()
+}
+
+fn unknown_module_response(module_ids: Vec<String>) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "error": "unknown_module",
+            "module_ids": module_ids,
+        })),
+    )
+        .into_response()
+}
+
+fn subscription_required_response() -> Response {
+    (
+        StatusCode::PAYMENT_REQUIRED,
+        Json(json!({ "error": "subscription_required" })),
+    )
+        .into_response()
+}
+
+fn not_found_response(error: &'static str) -> Response {
+    (StatusCode::NOT_FOUND, Json(json!({ "error": error }))).into_response()
+}
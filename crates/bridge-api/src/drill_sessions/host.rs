@@ -0,0 +1,176 @@
+//! Server-side `ServicePort` sessions, each owned by the user who created it.
+//!
+//! All hosted sessions live in one multi-session `ServicePortImpl`, whose
+//! `SessionManager` enforces the global cap (`HOSTED_SESSIONS_MAX`, least
+//! recently used evicted first) and the idle expiry (`HOSTED_SESSION_TTL`).
+//! The host adds a per-user index on top: creating a session drops that
+//! user's oldest at `SESSIONS_PER_USER_MAX`, and handles owned by someone
+//! else are reported as unknown. Every access sweeps expired sessions and
+//! prunes the index of anything the manager has evicted. Service calls are
+//! CPU-bound (deal generation, card play search), so they run on the
+//! blocking pool.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use bridge_service::{
+    DrillHandle, ServiceError, ServicePort, ServicePortImpl, SessionConfig, SessionLimits,
+};
+
+use crate::error::AppError;
+
+/// Idle time after which a hosted session expires.
+const HOSTED_SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+/// Sessions hosted across all users. Creating another drops the least
+/// recently used.
+pub const HOSTED_SESSIONS_MAX: usize = 1024;
+/// Sessions one user may hold. Creating another drops their oldest.
+pub const SESSIONS_PER_USER_MAX: usize = 4;
+
+pub struct DrillSessionHost {
+    state: Mutex<HostState>,
+}
+
+struct HostState {
+    service: ServicePortImpl,
+    /// Each user's live handles, oldest first.
+    by_user: HashMap<String, Vec<DrillHandle>>,
+}
+
+impl DrillSessionHost {
+    pub fn new() -> Self {
+        Self::with_limits(SessionLimits {
+            capacity: HOSTED_SESSIONS_MAX,
+            ttl: Some(HOSTED_SESSION_TTL),
+        })
+    }
+
+    /// A host with its own global cap and idle expiry. The per-user cap
+    /// still applies.
+    pub fn with_limits(limits: SessionLimits) -> Self {
+        Self {
+            state: Mutex::new(HostState {
+                service: ServicePortImpl::with_session_limits(limits),
+                by_user: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Create a session for `user_id`, dropping their oldest one if they
+    /// are at `SESSIONS_PER_USER_MAX`.
+    pub async fn create(
+        self: &Arc<Self>,
+        user_id: String,
+        config: SessionConfig,
+    ) -> Result<Result<DrillHandle, ServiceError>, AppError> {
+        let host = Arc::clone(self);
+        blocking(move || {
+            let mut state = host.lock_state()?;
+            state.sweep();
+            let handle = match state.service.create_drill_session(config) {
+                Ok(handle) => handle,
+                Err(err) => return Ok(Err(err)),
+            };
+
+            let HostState { service, by_user } = &mut *state;
+            let owned = by_user.entry(user_id).or_default();
+            let excess = (owned.len() + 1).saturating_sub(SESSIONS_PER_USER_MAX);
+            for oldest in owned.drain(..excess) {
+                // Already gone if the manager evicted it to make room.
+                let _ = service.destroy_drill_session(&oldest);
+            }
+            owned.push(handle.clone());
+            Ok(Ok(handle))
+        })
+        .await
+    }
+
+    /// Run `f` against one of `user_id`'s sessions. Handles owned by
+    /// someone else are reported exactly like unknown ones.
+    pub async fn with_session<T, F>(
+        self: &Arc<Self>,
+        user_id: String,
+        handle: String,
+        f: F,
+    ) -> Result<Result<T, ServiceError>, AppError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ServicePortImpl, &str) -> Result<T, ServiceError> + Send + 'static,
+    {
+        let host = Arc::clone(self);
+        blocking(move || {
+            let mut state = host.lock_state()?;
+            state.sweep();
+            if !state.owns(&user_id, &handle) {
+                return Ok(Err(ServiceError::InvalidHandle(handle)));
+            }
+            Ok(f(&mut state.service, &handle))
+        })
+        .await
+    }
+
+    /// Destroy one of `user_id`'s sessions.
+    pub async fn destroy(
+        self: &Arc<Self>,
+        user_id: String,
+        handle: String,
+    ) -> Result<Result<(), ServiceError>, AppError> {
+        let host = Arc::clone(self);
+        // The lock can be held through a slow service call, so wait for it
+        // off the async workers.
+        blocking(move || {
+            let mut state = host.lock_state()?;
+            state.sweep();
+            if !state.owns(&user_id, &handle) {
+                return Ok(Err(ServiceError::InvalidHandle(handle)));
+            }
+            if let Some(owned) = state.by_user.get_mut(&user_id) {
+                owned.retain(|h| *h != handle);
+            }
+            Ok(state.service.destroy_drill_session(&handle))
+        })
+        .await
+    }
+
+    fn lock_state(&self) -> Result<MutexGuard<'_, HostState>, AppError> {
+        self.state
+            .lock()
+            .map_err(|_| AppError::Internal("drill session host poisoned".to_string()))
+    }
+}
+
+impl HostState {
+    /// Drop expired sessions, then forget every handle the manager no
+    /// longer holds (expired or evicted at capacity).
+    fn sweep(&mut self) {
+        self.service.evict_expired_sessions();
+        let service = &self.service;
+        self.by_user.retain(|_, owned| {
+            owned.retain(|handle| service.has_drill_session(handle));
+            !owned.is_empty()
+        });
+    }
+
+    fn owns(&self, user_id: &str, handle: &str) -> bool {
+        self.by_user
+            .get(user_id)
+            .is_some_and(|owned| owned.iter().any(|h| h == handle))
+    }
+}
+
+impl Default for DrillSessionHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn blocking<T, F>(f: F) -> Result<T, AppError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, AppError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| AppError::Internal(format!("drill session task failed: {err}")))?
+}
@@ -0,0 +1,45 @@
+//! Drill sessions hosted on the server.
+//!
+//! The same `ServicePort` the browser drives through WASM, exposed over
+//! HTTP. Paid conventions are graded here without their definitions ever
+//! reaching the client, and non-browser clients get the engine without
+//! embedding it.
+
+pub mod handlers;
+pub mod host;
+
+use axum::routing::{delete, get, post};
+use axum::Router;
+
+use crate::AppState;
+
+pub use host::DrillSessionHost;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/drill-sessions", post(handlers::create_session))
+        .route(
+            "/api/drill-sessions/{handle}",
+            delete(handlers::destroy_session),
+        )
+        .route(
+            "/api/drill-sessions/{handle}/start",
+            post(handlers::start_drill),
+        )
+        .route(
+            "/api/drill-sessions/{handle}/bids",
+            post(handlers::submit_bid),
+        )
+        .route(
+            "/api/drill-sessions/{handle}/play",
+            post(handlers::enter_play),
+        )
+        .route(
+            "/api/drill-sessions/{handle}/cards",
+            post(handlers::play_card),
+        )
+        .route(
+            "/api/drill-sessions/{handle}/viewport/{kind}",
+            get(handlers::get_viewport),
+        )
+}
@@ -1,3 +1,5 @@
+use bridge_conventions::registry::active_registry;
+
 use crate::billing::entitlements::{SubscriptionTier, FREE_BUNDLE_IDS};
 
 /// Canonical bundle IDs known to the server. Mirrors the IDs the client
@@ -19,12 +21,20 @@ const KNOWN_BUNDLE_IDS: &[&str] = &[
     "nmf-bundle",
     "jacoby-4way-bundle",
     "stayman-garbage-bundle",
+    "lebensohl-bundle",
+    "drury-bundle",
+    "fourth-suit-forcing-bundle",
 ];
 
 /// Bare module IDs accepted as drill members. Mirrors the fixture file
 /// names under `crates/bridge-conventions/fixtures/modules/`.
 const KNOWN_MODULE_IDS: &[&str] = &[
     "natural-bids",
+    "suit-responses",
+    "opener-rebids",
+    "responder-rebids",
+    "notrump-openings",
+    "preempts",
     "natural-competitive",
     "stayman",
     "stayman-garbage",
@@ -39,11 +49,16 @@ const KNOWN_MODULE_IDS: &[&str] = &[
     "michaels-unusual",
     "negative-doubles",
     "new-minor-forcing",
+    "lebensohl",
+    "drury",
+    "fourth-suit-forcing",
 ];
 
 /// Returns the subset of `module_ids` that the server does not recognize.
 /// `user:*` IDs are accepted as opaque — they refer to per-user
-/// device-local modules that the server cannot validate.
+/// device-local modules that the server cannot validate. Modules loaded
+/// from `CONVENTIONS_MODULES_DIR`, and their single-module bundles, are
+/// known too.
 pub fn unknown_modules(module_ids: &[String]) -> Vec<String> {
     module_ids
         .iter()
@@ -58,6 +73,13 @@ fn is_known_module(id: &str) -> bool {
     }
     KNOWN_BUNDLE_IDS.iter().any(|known| *known == id)
         || KNOWN_MODULE_IDS.iter().any(|known| *known == id)
+        || is_runtime_module(id)
+}
+
+fn is_runtime_module(id: &str) -> bool {
+    let registry = active_registry();
+    let module_id = id.strip_suffix("-bundle").unwrap_or(id);
+    registry.origin(module_id).is_some()
 }
 
 /// Returns the subset of `module_ids` that the given tier may NOT practice.
@@ -9,8 +9,7 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::auth::models::User;
-use crate::auth::session;
+use crate::auth::guard::{require_user, unauthenticated_response};
 use crate::billing::entitlements::tier_for;
 use crate::error::AppError;
 use crate::AppState;
@@ -413,20 +412,3 @@ fn convention_locked_response(blocked_module_ids: Vec<String>) -> Response {
 fn not_found_response() -> Response {
     (StatusCode::NOT_FOUND, Json(json!({ "error": "not_found" }))).into_response()
 }
-
-fn unauthenticated_response() -> Response {
-    (
-        StatusCode::UNAUTHORIZED,
-        Json(json!({ "error": "unauthenticated" })),
-    )
-        .into_response()
-}
-
-// ─── Auth ──────────────────────────────────────
-
-async fn require_user(state: &AppState, jar: &CookieJar) -> Result<Option<User>, AppError> {
-    let Some(token) = jar.get("session").map(|cookie| cookie.value().to_string()) else {
-        return Ok(None);
-    };
-    Ok(session::lookup_session(&state.pool, &token).await?)
-}
@@ -3,10 +3,13 @@ pub mod billing;
 pub mod config;
 pub mod conventions;
 pub mod db;
+pub mod deals;
 #[cfg(feature = "dev-tools")]
 pub mod dev;
+pub mod drill_sessions;
 pub mod drills;
 pub mod error;
+pub mod reviews;
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
 pub mod user;
@@ -23,12 +26,14 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 use billing::stripe_client::LiveStripeOps;
 use billing::stripe_client::StripeOps;
 use config::Config;
+use drill_sessions::DrillSessionHost;
 
 #[derive(Clone)]
 pub struct AppState {
     pub pool: SqlitePool,
     pub config: Config,
     pub stripe: Arc<dyn StripeOps>,
+    pub drill_sessions: Arc<DrillSessionHost>,
 }
 
 impl AppState {
@@ -41,6 +46,7 @@ impl AppState {
             pool,
             config,
             stripe,
+            drill_sessions: Arc::new(DrillSessionHost::new()),
         }
     }
 
@@ -49,6 +55,7 @@ impl AppState {
             pool,
             config,
             stripe,
+            drill_sessions: Arc::new(DrillSessionHost::new()),
         }
     }
 }
@@ -78,7 +85,10 @@ pub fn app(state: AppState) -> Router {
         .merge(auth::auth_routes())
         .merge(billing::billing_routes())
         .merge(conventions::conventions_routes())
-        .merge(drills::routes());
+        .merge(drills::routes())
+        .merge(drill_sessions::routes())
+        .merge(deals::routes())
+        .merge(reviews::routes());
 
     #[cfg(feature = "dev-tools")]
     let router = router.merge(dev::dev_routes());
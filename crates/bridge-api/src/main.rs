@@ -1,4 +1,4 @@
-use bridge_api::{app, config::Config, db, AppState};
+use bridge_api::{app, config::Config, conventions, db, AppState};
 
 #[tokio::main]
 async fn main() {
@@ -15,6 +15,14 @@ async fn main() {
     tracing::warn!("dev-tools feature is enabled; this binary must not be used in production");
 
     let config = Config::from_env();
+    if let Some(dir) = &config.conventions_modules_dir {
+        conventions::modules::install_module_dir(dir).unwrap_or_else(|e| {
+            panic!(
+                "failed to read CONVENTIONS_MODULES_DIR {}: {e}",
+                dir.display()
+            )
+        });
+    }
     let pool = db::init_db(&config.database_url).await;
     let state = AppState::new(pool, config);
     let app = app(state);
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::extract::CookieJar;
+use bridge_conventions::types::rule_types::TargetSelector;
+use bridge_session::session::{DecisionPoint, ReviewCard};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::auth::guard::{require_user, unauthenticated_response};
+use crate::drills::entitlement::unknown_modules;
+use crate::error::AppError;
+use crate::AppState;
+
+use super::models::parse_grade;
+use super::repository;
+
+const OUTCOMES_MAX: usize = 64;
+const SURFACE_ID_MAX: usize = 120;
+
+/// One graded call to fold into the schedule. `grade` is a `BidGrade` wire
+/// value: correct, acceptable, near-miss or incorrect.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutcomePayload {
+    pub module_id: String,
+    pub surface_id: String,
+    pub grade: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordOutcomesRequest {
+    pub outcomes: Vec<OutcomePayload>,
+}
+
+/// The user's whole schedule plus what it implies for the next drill.
+/// `moduleWeights` is ready to pass as `SessionConfig.moduleWeights`;
+/// `nextTarget` as `SessionConfig.target`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScheduleResponse {
+    cards: Vec<ReviewCard>,
+    due_count: usize,
+    module_weights: HashMap<String, f64>,
+    next_target: Option<TargetSelector>,
+}
+
+#[derive(Serialize)]
+struct RecordedResponse {
+    cards: Vec<ReviewCard>,
+}
+
+// ─── Handlers ──────────────────────────────────────────────
+
+pub async fn get_schedule(
+    State(state): State<AppState>,
+    jar: CookieJar,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    let now = Utc::now().timestamp();
+    let schedule = repository::load_schedule(&state.pool, &user.id).await?;
+    let response = ScheduleResponse {
+        due_count: schedule.due(now).len(),
+        module_weights: schedule.module_weights(now),
+        next_target: schedule.next_target(now),
+        cards: schedule.into_cards(),
+    };
+    Ok(Json(response).into_response())
+}
+
+pub async fn record_outcomes(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    Json(req): Json<RecordOutcomesRequest>,
+) -> Result<Response, AppError> {
+    let user = match require_user(&state, &jar).await? {
+        Some(user) => user,
+        None => return Ok(unauthenticated_response()),
+    };
+
+    let outcomes = match validate_request(&req) {
+        Ok(outcomes) => outcomes,
+        Err(resp) => return Ok(*resp),
+    };
+    let module_ids: Vec<String> = outcomes
+        .iter()
+        .map(|(decision, _)| decision.module_id.clone())
+        .collect();
+    let unknown = unknown_modules(&module_ids);
+    if !unknown.is_empty() {
+        return Ok(unknown_module_response(unknown));
+    }
+
+    let now = Utc::now().timestamp();
+    let cards = repository::record_outcomes(&state.pool, &user.id, outcomes, now).await?;
+
+    Ok(Json(RecordedResponse { cards }).into_response())
+}
+
+// ─── Validation ──────────────────────────────────────────
+
+type Outcome = (DecisionPoint, bridge_session::session::BidGrade);
+
+fn validate_request(req: &RecordOutcomesRequest) -> Result<Vec<Outcome>, Box<Response>> {
+    if req.outcomes.is_empty() {
+        return Err(Box::new(validation_response(
+            "outcomes",
+            "at least one outcome required",
+        )));
+    }
+    if req.outcomes.len() > OUTCOMES_MAX {
+        return Err(Box::new(validation_response(
+            "outcomes",
+            "too many outcomes",
+        )));
+    }
+    req.outcomes
+        .iter()
+        .map(|outcome| {
+            if outcome.module_id.is_empty() {
+                return Err(Box::new(validation_response("outcomes", "empty module id")));
+            }
+            if outcome.surface_id.is_empty() || outcome.surface_id.len() > SURFACE_ID_MAX {
+                return Err(Box::new(validation_response(
+                    "outcomes",
+                    "invalid surface id",
+                )));
+            }
+            let grade = parse_grade(&outcome.grade)
+                .ok_or_else(|| Box::new(validation_response("outcomes", "unknown grade")))?;
+            Ok((
+                DecisionPoint {
+                    module_id: outcome.module_id.clone(),
+                    surface_id: outcome.surface_id.clone(),
+                },
+                grade,
+            ))
+        })
+        .collect()
+}
+
+// ─── Error responses ──────────────────────────────────────
+
+fn validation_response(field: &str, message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "error": "validation",
+            "field": field,
+            "message": message,
+        })),
+    )
+        .into_response()
+}
+
+fn unknown_module_response(module_ids: Vec<String>) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "error": "unknown_module",
+            "module_ids": module_ids,
+        })),
+    )
+        .into_response()
+}
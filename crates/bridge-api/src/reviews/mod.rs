@@ -0,0 +1,15 @@
+pub mod handlers;
+pub mod models;
+pub mod repository;
+
+use axum::routing::get;
+use axum::Router;
+
+use crate::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route(
+        "/api/reviews",
+        get(handlers::get_schedule).post(handlers::record_outcomes),
+    )
+}
@@ -0,0 +1,54 @@
+use bridge_session::session::{BidGrade, DecisionPoint, ReviewCard};
+use sqlx::FromRow;
+
+/// Storage shape for `user_reviews` rows. Converted to and from
+/// bridge-session's `ReviewCard`, which owns the scheduling rules.
+#[derive(Debug, Clone, FromRow)]
+pub struct ReviewRow {
+    #[allow(dead_code)]
+    pub user_id: String,
+    pub module_id: String,
+    pub surface_id: String,
+    pub ease: f64,
+    pub interval_days: f64,
+    pub repetitions: i64,
+    pub lapses: i64,
+    pub due_at: i64,
+    pub last_reviewed_at: i64,
+    pub last_grade: String,
+}
+
+impl ReviewRow {
+    /// `None` when `last_grade` is not a known grade.
+    pub fn into_card(self) -> Option<ReviewCard> {
+        let last_grade = parse_grade(&self.last_grade)?;
+        Some(ReviewCard {
+            decision: DecisionPoint {
+                module_id: self.module_id,
+                surface_id: self.surface_id,
+            },
+            ease: self.ease,
+            interval_days: self.interval_days,
+            repetitions: self.repetitions.max(0) as u32,
+            lapses: self.lapses.max(0) as u32,
+            due_at: self.due_at,
+            last_reviewed_at: self.last_reviewed_at,
+            last_grade,
+        })
+    }
+}
+
+/// Parse a `BidGrade` wire value: correct, acceptable, near-miss or incorrect.
+pub fn parse_grade(grade: &str) -> Option<BidGrade> {
+    serde_json::from_value(serde_json::Value::String(grade.to_string())).ok()
+}
+
+/// The `BidGrade` wire value, as stored in `last_grade`.
+pub fn grade_str(grade: BidGrade) -> &'static str {
+    match grade {
+        BidGrade::Correct => "correct",
+        BidGrade::Acceptable => "acceptable",
+        BidGrade::NearMiss => "near-miss",
+        BidGrade::Incorrect => "incorrect",
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use bridge_session::session::{BidGrade, DecisionPoint, ReviewCard, ReviewSchedule};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::models::{grade_str, ReviewRow};
+
+/// Load every review card the user has. Rows with an unknown grade are
+/// skipped rather than failing the whole schedule.
+pub async fn load_schedule(
+    pool: &SqlitePool,
+    user_id: &str,
+) -> Result<ReviewSchedule, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    fetch_schedule(&mut conn, user_id).await
+}
+
+/// Fold graded decisions into the user's schedule and return the cards they
+/// touched. Read, update and write share one `BEGIN IMMEDIATE` transaction,
+/// so concurrent recordings for the same user serialize instead of
+/// overwriting each other's cards.
+pub async fn record_outcomes(
+    pool: &SqlitePool,
+    user_id: &str,
+    outcomes: Vec<(DecisionPoint, BidGrade)>,
+    now: i64,
+) -> Result<Vec<ReviewCard>, sqlx::Error> {
+    let mut tx = pool.begin_with("BEGIN IMMEDIATE").await?;
+    let mut schedule = fetch_schedule(&mut tx, user_id).await?;
+    let mut touched = HashSet::new();
+    for (decision, grade) in outcomes {
+        schedule.record(decision.clone(), grade, now);
+        touched.insert(decision);
+    }
+    let cards: Vec<ReviewCard> = schedule
+        .into_cards()
+        .into_iter()
+        .filter(|card| touched.contains(&card.decision))
+        .collect();
+    upsert_cards(&mut tx, user_id, &cards).await?;
+    tx.commit().await?;
+    Ok(cards)
+}
+
+async fn fetch_schedule(
+    conn: &mut SqliteConnection,
+    user_id: &str,
+) -> Result<ReviewSchedule, sqlx::Error> {
+    let rows: Vec<ReviewRow> = sqlx::query_as(
+        "SELECT user_id, module_id, surface_id, ease, interval_days, repetitions, lapses, \
+                due_at, last_reviewed_at, last_grade \
+         FROM user_reviews \
+         WHERE user_id = ? \
+         ORDER BY module_id ASC, surface_id ASC",
+    )
+    .bind(user_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(ReviewSchedule::new(
+        rows.into_iter().filter_map(ReviewRow::into_card).collect(),
+    ))
+}
+
+/// Insert or replace the given cards.
+async fn upsert_cards(
+    conn: &mut SqliteConnection,
+    user_id: &str,
+    cards: &[ReviewCard],
+) -> Result<(), sqlx::Error> {
+    for card in cards {
+        sqlx::query(
+            "INSERT INTO user_reviews (\
+                user_id, module_id, surface_id, ease, interval_days, repetitions, lapses, \
+                due_at, last_reviewed_at, last_grade\
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT (user_id, module_id, surface_id) DO UPDATE SET \
+                ease = excluded.ease, \
+                interval_days = excluded.interval_days, \
+                repetitions = excluded.repetitions, \
+                lapses = excluded.lapses, \
+                due_at = excluded.due_at, \
+                last_reviewed_at = excluded.last_reviewed_at, \
+                last_grade = excluded.last_grade",
+        )
+        .bind(user_id)
+        .bind(&card.decision.module_id)
+        .bind(&card.decision.surface_id)
+        .bind(card.ease)
+        .bind(card.interval_days)
+        .bind(card.repetitions as i64)
+        .bind(card.lapses as i64)
+        .bind(card.due_at)
+        .bind(card.last_reviewed_at)
+        .bind(grade_str(card.last_grade))
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,323 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use bridge_api::test_support::{session_cookie_header, TestHarness, UserSeed};
+use bridge_engine::parse_board_id;
+use serde_json::{json, Value};
+
+const USER: &str = "reviewer";
+const OTHER_USER: &str = "someone-else";
+const BOARD_ID: &str = "N0-000000000000000000000001";
+
+#[tokio::test]
+async fn deal_history_requires_session() {
+    let harness = TestHarness::new().await;
+    let listed = harness.send(get("/api/deals", None)).await;
+    assert_eq!(listed.status(), StatusCode::UNAUTHORIZED);
+
+    let recorded = harness
+        .send(post_json(
+            "/api/deals",
+            None,
+            &deal_request("nt-bundle", &["correct"]),
+        ))
+        .await;
+    assert_eq!(recorded.status(), StatusCode::UNAUTHORIZED);
+    let body = body_json(recorded).await;
+    assert_eq!(body["error"], "unauthenticated");
+}
+
+#[tokio::test]
+async fn recorded_deal_round_trips_with_graded_calls() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let request = deal_request("nt-bundle", &["correct", "near-miss"]);
+    let created = harness
+        .send(post_json("/api/deals", Some(&session), &request))
+        .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created = body_json(created).await;
+    let deal_id = created["deal"]["id"].as_str().unwrap().to_string();
+    assert!(deal_id.starts_with("deal:"));
+    assert_eq!(created["deal"]["outcome"], "incorrect");
+    assert_eq!(created["deal"]["callCount"], 2);
+    assert_eq!(created["deal"]["correctCount"], 1);
+
+    let fetched = harness
+        .send(get(&format!("/api/deals/{deal_id}"), Some(&session)))
+        .await;
+    assert_eq!(fetched.status(), StatusCode::OK);
+    let fetched = body_json(fetched).await;
+    let deal = &fetched["deal"];
+    assert_eq!(deal["deal"], request["deal"]);
+    assert_eq!(deal["auction"], request["auction"]);
+    assert_eq!(deal["contract"], request["contract"]);
+    assert_eq!(deal["play"], request["play"]);
+    assert_eq!(deal["score"], 120);
+    assert_eq!(deal["boardId"], BOARD_ID);
+    let calls = deal["calls"].as_array().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[1]["grade"], "near-miss");
+    assert_eq!(calls[1]["auctionIndex"], 3);
+    assert_eq!(calls[1]["expectedCall"], json!({"type": "pass"}));
+
+    let listed = body_json(harness.send(get("/api/deals", Some(&session))).await).await;
+    let deals = listed["deals"].as_array().unwrap();
+    assert_eq!(deals.len(), 1);
+    assert!(
+        deals[0].get("deal").is_none(),
+        "list rows are summaries without the hands"
+    );
+}
+
+#[tokio::test]
+async fn listing_filters_by_convention_role_system_outcome_and_date() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let mut acol = deal_request("stayman-bundle", &["correct"]);
+    acol["systemSelectionId"] = json!("acol");
+    acol["practiceRole"] = json!("opener");
+    for request in [
+        deal_request("nt-bundle", &["correct", "acceptable"]),
+        deal_request("nt-bundle", &["incorrect"]),
+        acol,
+    ] {
+        let response = harness
+            .send(post_json("/api/deals", Some(&session), &request))
+            .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let count = |uri: &'static str| {
+        let harness = &harness;
+        let session = session.clone();
+        async move {
+            let response = harness.send(get(uri, Some(&session))).await;
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+            body_json(response).await["deals"].as_array().unwrap().len()
+        }
+    };
+    assert_eq!(count("/api/deals").await, 3);
+    assert_eq!(count("/api/deals?convention=nt-bundle").await, 2);
+    assert_eq!(count("/api/deals?role=opener").await, 1);
+    assert_eq!(count("/api/deals?system=acol").await, 1);
+    assert_eq!(count("/api/deals?outcome=correct").await, 2);
+    assert_eq!(
+        count("/api/deals?convention=nt-bundle&outcome=incorrect").await,
+        1
+    );
+    assert_eq!(count("/api/deals?limit=2").await, 2);
+    assert_eq!(count("/api/deals?limit=2&offset=2").await, 1);
+    assert_eq!(count("/api/deals?from=2000-01-01").await, 3);
+    assert_eq!(count("/api/deals?to=2000-01-01").await, 0);
+
+    let bad_date = harness
+        .send(get("/api/deals?from=yesterday", Some(&session)))
+        .await;
+    assert_eq!(bad_date.status(), StatusCode::BAD_REQUEST);
+    let bad_outcome = harness
+        .send(get("/api/deals?outcome=great", Some(&session)))
+        .await;
+    assert_eq!(bad_outcome.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn deals_are_private_to_their_user() {
+    let harness = TestHarness::new().await;
+    let owner = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let other = harness
+        .insert_user_and_session(UserSeed::new(OTHER_USER))
+        .await;
+
+    let created = body_json(
+        harness
+            .send(post_json(
+                "/api/deals",
+                Some(&owner),
+                &deal_request("nt-bundle", &["correct"]),
+            ))
+            .await,
+    )
+    .await;
+    let deal_id = created["deal"]["id"].as_str().unwrap().to_string();
+
+    let fetched = harness
+        .send(get(&format!("/api/deals/{deal_id}"), Some(&other)))
+        .await;
+    assert_eq!(fetched.status(), StatusCode::NOT_FOUND);
+    let listed = body_json(harness.send(get("/api/deals", Some(&other))).await).await;
+    assert!(listed["deals"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn invalid_records_are_rejected() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let unknown_grade = deal_request("nt-bundle", &["brilliant"]);
+    let mut unknown_convention = deal_request("nt-stayman", &["correct"]);
+    unknown_convention["id"] = json!("deal:legacy");
+    let mut bad_id = deal_request("nt-bundle", &["correct"]);
+    bad_id["id"] = json!("board:1");
+    let mut no_deal = deal_request("nt-bundle", &["correct"]);
+    no_deal["deal"] = json!("N:AKQ...");
+    let mut bad_call = deal_request("nt-bundle", &["correct"]);
+    bad_call["calls"][0]["call"] = json!({"type": "shout"});
+    let mut bad_play = deal_request("nt-bundle", &["correct"]);
+    bad_play["play"] = json!([{"seat": "E", "card": "S2"}]);
+    let mut short_hand = deal_request("nt-bundle", &["correct"]);
+    short_hand["deal"]["hands"]["N"]["cards"]
+        .as_array_mut()
+        .unwrap()
+        .pop();
+    let mut repeated_card = deal_request("nt-bundle", &["correct"]);
+    repeated_card["deal"]["hands"]["N"]["cards"][0] =
+        repeated_card["deal"]["hands"]["E"]["cards"][0].clone();
+    let mut wrong_board = deal_request("nt-bundle", &["correct"]);
+    wrong_board["boardId"] = json!("N0-000000000000000000000002");
+    let mut long_role = deal_request("nt-bundle", &["correct"]);
+    long_role["practiceRole"] = json!("r".repeat(65));
+    let mut past_the_auction = deal_request("nt-bundle", &["correct"]);
+    past_the_auction["calls"][0]["auctionIndex"] = json!(10);
+    let mut more_calls_than_bids = deal_request("nt-bundle", &["correct"]);
+    more_calls_than_bids["auction"]["entries"] = json!([]);
+
+    for request in [
+        unknown_grade,
+        unknown_convention,
+        bad_id,
+        no_deal,
+        bad_call,
+        bad_play,
+        short_hand,
+        repeated_card,
+        wrong_board,
+        long_role,
+        past_the_auction,
+        more_calls_than_bids,
+    ] {
+        let response = harness
+            .send(post_json("/api/deals", Some(&session), &request))
+            .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{request}");
+        assert_eq!(body_json(response).await["error"], "validation");
+    }
+
+    let mut fixed_id = deal_request("nt-bundle", &["correct"]);
+    fixed_id["id"] = json!("deal:retry");
+    let first = harness
+        .send(post_json("/api/deals", Some(&session), &fixed_id))
+        .await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let retry = harness
+        .send(post_json("/api/deals", Some(&session), &fixed_id))
+        .await;
+    assert_eq!(retry.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn a_deal_without_graded_calls_is_rejected() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let response = harness
+        .send(post_json(
+            "/api/deals",
+            Some(&session),
+            &deal_request("nt-bundle", &[]),
+        ))
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = body_json(response).await;
+    assert_eq!(body["field"], "calls");
+
+    let listed = body_json(harness.send(get("/api/deals", Some(&session))).await).await;
+    assert!(listed["deals"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn the_board_id_is_derived_from_the_deal() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let mut request = deal_request("nt-bundle", &["correct"]);
+    request.as_object_mut().unwrap().remove("boardId");
+    let created = harness
+        .send(post_json("/api/deals", Some(&session), &request))
+        .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    assert_eq!(body_json(created).await["deal"]["boardId"], BOARD_ID);
+}
+
+// ─── Helpers ──────────────────────────────────────────
+
+fn deal_request(convention_id: &str, grades: &[&str]) -> Value {
+    let calls: Vec<Value> = grades
+        .iter()
+        .enumerate()
+        .map(|(i, grade)| {
+            json!({
+                "auctionIndex": 1 + 2 * i,
+                "call": {"type": "bid", "level": 2, "strain": "C"},
+                "expectedCall": {"type": "pass"},
+                "grade": grade,
+            })
+        })
+        .collect();
+    json!({
+        "conventionId": convention_id,
+        "practiceRole": "responder",
+        "systemSelectionId": "sayc",
+        "boardId": BOARD_ID,
+        "deal": parse_board_id(BOARD_ID).expect("board id"),
+        "auction": {
+            "entries": [
+                {"seat": "N", "call": {"type": "bid", "level": 1, "strain": "NT"}},
+                {"seat": "E", "call": {"type": "pass"}},
+                {"seat": "S", "call": {"type": "bid", "level": 2, "strain": "C"}},
+                {"seat": "W", "call": {"type": "pass"}},
+                {"seat": "N", "call": {"type": "bid", "level": 2, "strain": "D"}},
+                {"seat": "E", "call": {"type": "pass"}},
+                {"seat": "S", "call": {"type": "bid", "level": 3, "strain": "NT"}},
+                {"seat": "W", "call": {"type": "pass"}},
+                {"seat": "N", "call": {"type": "pass"}},
+                {"seat": "E", "call": {"type": "pass"}},
+            ],
+            "isComplete": true,
+        },
+        "contract": {"level": 1, "strain": "NT", "doubled": false, "redoubled": false, "declarer": "N"},
+        "play": [{"seat": "E", "card": {"suit": "S", "rank": "2"}}],
+        "score": 120,
+        "calls": calls,
+    })
+}
+
+fn get(uri: &str, session: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method(Method::GET).uri(uri);
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::empty()).expect("request")
+}
+
+fn post_json(uri: &str, session: Option<&str>, body: &Value) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::from(body.to_string())).expect("request")
+}
+
+async fn body_json(response: axum::response::Response) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(&bytes).expect("json body")
+}
@@ -0,0 +1,397 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use bridge_api::drill_sessions::host::SESSIONS_PER_USER_MAX;
+use bridge_api::drill_sessions::DrillSessionHost;
+use bridge_api::test_support::{session_cookie_header, TestHarness, UserSeed};
+use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
+use bridge_conventions::registry::system_configs::get_system_config;
+use bridge_conventions::types::system_config::BaseSystemId;
+use bridge_engine::types::Seat;
+use bridge_service::{ServiceError, ServicePort, SessionConfig, SessionLimits};
+use serde_json::{json, Value};
+
+const USER: &str = "server-driller";
+const OTHER_USER: &str = "someone-else";
+
+#[tokio::test]
+async fn drill_sessions_require_a_session_cookie() {
+    let harness = TestHarness::new().await;
+    let created = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            None,
+            &session_config("nt-bundle", 42),
+        ))
+        .await;
+    assert_eq!(created.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(body_json(created).await["error"], "unauthenticated");
+
+    let viewport = harness
+        .send(get("/api/drill-sessions/session-1/viewport/bidding", None))
+        .await;
+    assert_eq!(viewport.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn paid_conventions_need_a_subscription() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let locked = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            Some(&session),
+            &session_config("bergen-bundle", 42),
+        ))
+        .await;
+    assert_eq!(locked.status(), StatusCode::PAYMENT_REQUIRED);
+    assert_eq!(body_json(locked).await["error"], "subscription_required");
+
+    let unknown = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            Some(&session),
+            &session_config("made-up-bundle", 42),
+        ))
+        .await;
+    assert_eq!(unknown.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(body_json(unknown).await["error"], "unknown_module");
+}
+
+#[tokio::test]
+async fn paid_modules_cannot_ride_along_with_a_free_convention() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let mut extra_base = session_config("nt-bundle", 42);
+    extra_base["baseModuleIds"]
+        .as_array_mut()
+        .expect("base module ids")
+        .push(json!("bergen"));
+    let locked = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            Some(&session),
+            &extra_base,
+        ))
+        .await;
+    assert_eq!(locked.status(), StatusCode::PAYMENT_REQUIRED);
+    assert_eq!(body_json(locked).await["error"], "subscription_required");
+
+    let mut paid_target = session_config("nt-bundle", 42);
+    paid_target["target"] = json!({ "kind": "module", "moduleId": "bergen" });
+    let locked = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            Some(&session),
+            &paid_target,
+        ))
+        .await;
+    assert_eq!(locked.status(), StatusCode::PAYMENT_REQUIRED);
+    assert_eq!(body_json(locked).await["error"], "subscription_required");
+}
+
+#[tokio::test]
+async fn a_hosted_drill_is_bid_through_http() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let handle = create_session(&harness, &session, "nt-bundle").await;
+    let base = format!("/api/drill-sessions/{handle}");
+
+    let started = harness
+        .send(post_json(
+            &format!("{base}/start"),
+            Some(&session),
+            &json!({}),
+        ))
+        .await;
+    assert_eq!(started.status(), StatusCode::OK);
+    let started = body_json(started).await;
+    assert_eq!(started["viewport"]["seat"], "S");
+    assert_eq!(started["viewport"]["isUserTurn"], true);
+
+    let bidding = harness
+        .send(get(&format!("{base}/viewport/bidding"), Some(&session)))
+        .await;
+    assert_eq!(bidding.status(), StatusCode::OK);
+    assert_eq!(body_json(bidding).await["seat"], "S");
+    // No auction to replay yet.
+    let replay = harness
+        .send(get(&format!("{base}/viewport/replay"), Some(&session)))
+        .await;
+    assert_eq!(body_json(replay).await, Value::Null);
+
+    let bid = harness
+        .send(post_json(
+            &format!("{base}/bids"),
+            Some(&session),
+            &json!({ "call": { "type": "pass" } }),
+        ))
+        .await;
+    assert_eq!(bid.status(), StatusCode::OK);
+    // Every call is graded server-side, whether or not it is accepted.
+    let bid = body_json(bid).await;
+    assert!(bid["accepted"].is_boolean());
+    assert!(!bid["grade"].is_null(), "the call is graded: {bid}");
+    // ...and the grade lands in the user's review schedule.
+    let decision = &bid["feedback"]["decisionPoint"];
+    assert!(!decision.is_null(), "the graded decision is named: {bid}");
+    let schedule = body_json(harness.send(get("/api/reviews", Some(&session))).await).await;
+    let cards = schedule["cards"].as_array().unwrap();
+    assert_eq!(cards.len(), 1, "{schedule}");
+    assert_eq!(cards[0]["decision"], *decision);
+    assert_eq!(cards[0]["lastGrade"], bid["grade"]);
+
+    let unknown_kind = harness
+        .send(get(&format!("{base}/viewport/scoreboard"), Some(&session)))
+        .await;
+    assert_eq!(unknown_kind.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn hosted_drills_are_private_to_their_user() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let other = harness
+        .insert_user_and_session(UserSeed::new(OTHER_USER))
+        .await;
+    let handle = create_session(&harness, &session, "nt-bundle").await;
+    let base = format!("/api/drill-sessions/{handle}");
+
+    let started = harness
+        .send(post_json(
+            &format!("{base}/start"),
+            Some(&other),
+            &json!({}),
+        ))
+        .await;
+    assert_eq!(started.status(), StatusCode::NOT_FOUND);
+    assert_eq!(body_json(started).await["error"], "session_not_found");
+    let deleted = harness.send(delete(&base, Some(&other))).await;
+    assert_eq!(deleted.status(), StatusCode::NOT_FOUND);
+
+    // Still there for its owner.
+    let started = harness
+        .send(post_json(
+            &format!("{base}/start"),
+            Some(&session),
+            &json!({}),
+        ))
+        .await;
+    assert_eq!(started.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn session_capacity_is_per_user() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let other = harness
+        .insert_user_and_session(UserSeed::new(OTHER_USER))
+        .await;
+    let mine = create_session(&harness, &session, "nt-bundle").await;
+
+    let mut theirs = Vec::new();
+    for _ in 0..=SESSIONS_PER_USER_MAX {
+        theirs.push(create_session(&harness, &other, "nt-bundle").await);
+    }
+
+    // Their oldest went to make room; mine was never at risk.
+    let evicted = harness
+        .send(get(
+            &format!("/api/drill-sessions/{}/viewport/bidding", theirs[0]),
+            Some(&other),
+        ))
+        .await;
+    assert_eq!(evicted.status(), StatusCode::NOT_FOUND);
+    let kept = harness
+        .send(get(
+            &format!("/api/drill-sessions/{mine}/viewport/bidding"),
+            Some(&session),
+        ))
+        .await;
+    assert_eq!(kept.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn the_global_cap_evicts_across_users() {
+    let host = Arc::new(DrillSessionHost::with_limits(SessionLimits {
+        capacity: 2,
+        ttl: None,
+    }));
+    let first = host_session(&host, USER).await;
+    host_session(&host, OTHER_USER).await;
+    host_session(&host, OTHER_USER).await;
+
+    // The least recently used session went to make room, and its owner can
+    // start another.
+    assert!(matches!(
+        bidding_viewport(&host, USER, &first).await,
+        Err(ServiceError::InvalidHandle(_))
+    ));
+    let replacement = host_session(&host, USER).await;
+    assert!(bidding_viewport(&host, USER, &replacement).await.is_ok());
+}
+
+#[tokio::test]
+async fn idle_sessions_expire_on_access() {
+    let host = Arc::new(DrillSessionHost::with_limits(SessionLimits {
+        capacity: 8,
+        ttl: Some(Duration::from_millis(20)),
+    }));
+    let handle = host_session(&host, USER).await;
+    assert!(bidding_viewport(&host, USER, &handle).await.is_ok());
+
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    assert!(matches!(
+        bidding_viewport(&host, USER, &handle).await,
+        Err(ServiceError::InvalidHandle(_))
+    ));
+}
+
+#[tokio::test]
+async fn destroyed_drills_are_gone() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let handle = create_session(&harness, &session, "nt-bundle").await;
+    let base = format!("/api/drill-sessions/{handle}");
+
+    let deleted = harness.send(delete(&base, Some(&session))).await;
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+
+    let viewport = harness
+        .send(get(&format!("{base}/viewport/bidding"), Some(&session)))
+        .await;
+    assert_eq!(viewport.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn playing_a_card_during_the_auction_is_a_conflict() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let handle = create_session(&harness, &session, "nt-bundle").await;
+    let base = format!("/api/drill-sessions/{handle}");
+    harness
+        .send(post_json(
+            &format!("{base}/start"),
+            Some(&session),
+            &json!({}),
+        ))
+        .await;
+
+    let played = harness
+        .send(post_json(
+            &format!("{base}/cards"),
+            Some(&session),
+            &json!({ "card": { "suit": "S", "rank": "2" }, "seat": "S" }),
+        ))
+        .await;
+    assert_eq!(played.status(), StatusCode::CONFLICT);
+    assert_eq!(body_json(played).await["error"], "wrong_phase");
+}
+
+/// Create a session, retrying seeds the witness sampler cannot satisfy.
+async fn create_session(harness: &TestHarness, session: &str, convention_id: &str) -> String {
+    for seed in 42..74 {
+        let response = harness
+            .send(post_json(
+                "/api/drill-sessions",
+                Some(session),
+                &session_config(convention_id, seed),
+            ))
+            .await;
+        if response.status() == StatusCode::CREATED {
+            let body = body_json(response).await;
+            return body["handle"].as_str().unwrap().to_string();
+        }
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    panic!("no seed produced a {convention_id} drill");
+}
+
+fn session_config(convention_id: &str, seed: u64) -> Value {
+    let config = SessionConfig {
+        convention_id: convention_id.to_string(),
+        user_seat: Some(Seat::South),
+        seed: Some(seed),
+        system_config: get_system_config(BaseSystemId::Sayc),
+        base_module_ids: BASE_MODULE_IDS.iter().map(|s| s.to_string()).collect(),
+        practice_mode: None,
+        target: None,
+        practice_role: None,
+        play_preference: None,
+        opponent_mode: None,
+        vulnerability: None,
+        play_profile_id: None,
+        vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
+    };
+    serde_json::to_value(config).expect("config serializes")
+}
+
+async fn host_session(host: &Arc<DrillSessionHost>, user_id: &str) -> String {
+    let config = serde_json::from_value(session_config("nt-bundle", 42)).expect("config");
+    let Ok(created) = host.create(user_id.to_string(), config).await else {
+        panic!("drill session host failed");
+    };
+    created.expect("session created")
+}
+
+async fn bidding_viewport(
+    host: &Arc<DrillSessionHost>,
+    user_id: &str,
+    handle: &str,
+) -> Result<(), ServiceError> {
+    let viewed = host
+        .with_session(
+            user_id.to_string(),
+            handle.to_string(),
+            |service, handle| service.get_bidding_viewport(handle).map(|_| ()),
+        )
+        .await;
+    let Ok(viewed) = viewed else {
+        panic!("drill session host failed");
+    };
+    viewed
+}
+
+fn get(uri: &str, session: Option<&str>) -> Request<Body> {
+    request(Method::GET, uri, session)
+}
+
+fn delete(uri: &str, session: Option<&str>) -> Request<Body> {
+    request(Method::DELETE, uri, session)
+}
+
+fn request(method: Method, uri: &str, session: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::empty()).expect("request")
+}
+
+fn post_json(uri: &str, session: Option<&str>, body: &Value) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::from(body.to_string())).expect("request")
+}
+
+async fn body_json(response: axum::response::Response) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(&bytes).expect("json body")
+}
@@ -0,0 +1,186 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use bridge_api::test_support::{session_cookie_header, TestHarness, UserSeed};
+use serde_json::{json, Value};
+
+const USER: &str = "reviewer";
+const OTHER_USER: &str = "someone-else";
+
+#[tokio::test]
+async fn reviews_require_session() {
+    let harness = TestHarness::new().await;
+    let listed = harness.send(get("/api/reviews", None)).await;
+    assert_eq!(listed.status(), StatusCode::UNAUTHORIZED);
+
+    let recorded = harness
+        .send(post_json(
+            "/api/reviews",
+            None,
+            &outcomes(&[("stayman", "stayman:ask-major", "correct")]),
+        ))
+        .await;
+    assert_eq!(recorded.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(body_json(recorded).await["error"], "unauthenticated");
+}
+
+#[tokio::test]
+async fn graded_calls_become_review_cards() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    let recorded = harness
+        .send(post_json(
+            "/api/reviews",
+            Some(&session),
+            &outcomes(&[
+                ("stayman", "stayman:ask-major", "correct"),
+                ("smolen", "smolen:3h", "incorrect"),
+            ]),
+        ))
+        .await;
+    assert_eq!(recorded.status(), StatusCode::OK);
+    let recorded = body_json(recorded).await;
+    assert_eq!(recorded["cards"].as_array().unwrap().len(), 2);
+
+    // A second review of the same decision updates its card.
+    let again = harness
+        .send(post_json(
+            "/api/reviews",
+            Some(&session),
+            &outcomes(&[("stayman", "stayman:ask-major", "correct")]),
+        ))
+        .await;
+    let again = body_json(again).await;
+    assert_eq!(again["cards"][0]["repetitions"], 2);
+    assert_eq!(again["cards"][0]["intervalDays"], 6.0);
+
+    let schedule = body_json(harness.send(get("/api/reviews", Some(&session))).await).await;
+    let cards = schedule["cards"].as_array().unwrap();
+    assert_eq!(cards.len(), 2);
+    let smolen = cards
+        .iter()
+        .find(|card| card["decision"]["moduleId"] == "smolen")
+        .unwrap();
+    assert_eq!(smolen["decision"]["surfaceId"], "smolen:3h");
+    assert_eq!(smolen["lastGrade"], "incorrect");
+    assert_eq!(smolen["repetitions"], 0);
+    // The miss comes back later in the sitting, not immediately.
+    assert_eq!(schedule["dueCount"], 0);
+    assert_eq!(schedule["nextTarget"], Value::Null);
+}
+
+#[tokio::test]
+async fn due_misses_steer_the_next_drill() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    harness
+        .send(post_json(
+            "/api/reviews",
+            Some(&session),
+            &outcomes(&[
+                ("stayman", "stayman:ask-major", "correct"),
+                ("smolen", "smolen:3h", "near-miss"),
+            ]),
+        ))
+        .await;
+    sqlx::query("UPDATE user_reviews SET due_at = 0 WHERE module_id = 'smolen'")
+        .execute(&harness.state.pool)
+        .await
+        .unwrap();
+
+    let schedule = body_json(harness.send(get("/api/reviews", Some(&session))).await).await;
+    assert_eq!(schedule["dueCount"], 1);
+    assert_eq!(
+        schedule["nextTarget"],
+        json!({"kind": "surface", "moduleId": "smolen", "surfaceId": "smolen:3h"})
+    );
+    assert_eq!(schedule["moduleWeights"]["stayman"], 1.0);
+    assert!(schedule["moduleWeights"]["smolen"].as_f64().unwrap() > 1.0);
+}
+
+#[tokio::test]
+async fn reviews_are_private_to_their_user() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+    let other = harness
+        .insert_user_and_session(UserSeed::new(OTHER_USER))
+        .await;
+    harness
+        .send(post_json(
+            "/api/reviews",
+            Some(&session),
+            &outcomes(&[("stayman", "stayman:ask-major", "incorrect")]),
+        ))
+        .await;
+
+    let theirs = body_json(harness.send(get("/api/reviews", Some(&other))).await).await;
+    assert_eq!(theirs["cards"], json!([]));
+    assert_eq!(theirs["moduleWeights"], json!({}));
+}
+
+#[tokio::test]
+async fn invalid_outcomes_are_rejected() {
+    let harness = TestHarness::new().await;
+    let session = harness.insert_user_and_session(UserSeed::new(USER)).await;
+
+    for (body, error) in [
+        (outcomes(&[]), "validation"),
+        (
+            outcomes(&[("stayman", "stayman:ask-major", "great")]),
+            "validation",
+        ),
+        (outcomes(&[("stayman", "", "correct")]), "validation"),
+        (
+            outcomes(&[("made-up", "made-up:bid", "correct")]),
+            "unknown_module",
+        ),
+    ] {
+        let response = harness
+            .send(post_json("/api/reviews", Some(&session), &body))
+            .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{body}");
+        assert_eq!(body_json(response).await["error"], error, "{body}");
+    }
+
+    let schedule = body_json(harness.send(get("/api/reviews", Some(&session))).await).await;
+    assert_eq!(schedule["cards"], json!([]));
+}
+
+fn outcomes(entries: &[(&str, &str, &str)]) -> Value {
+    let outcomes: Vec<Value> = entries
+        .iter()
+        .map(|(module_id, surface_id, grade)| {
+            json!({"moduleId": module_id, "surfaceId": surface_id, "grade": grade})
+        })
+        .collect();
+    json!({ "outcomes": outcomes })
+}
+
+fn get(uri: &str, session: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method(Method::GET).uri(uri);
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::empty()).expect("request")
+}
+
+fn post_json(uri: &str, session: Option<&str>, body: &Value) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::from(body.to_string())).expect("request")
+}
+
+async fn body_json(response: axum::response::Response) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(&bytes).expect("json body")
+}
@@ -0,0 +1,141 @@
+//! Modules from `CONVENTIONS_MODULES_DIR` are installed process-wide, so this
+//! binary holds a single test to keep other tests off the shared registry.
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, StatusCode};
+use bridge_api::conventions::modules::install_module_dir;
+use bridge_api::test_support::{session_cookie_header, TestHarness, UserSeed};
+use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
+use bridge_conventions::registry::system_configs::get_system_config;
+use bridge_conventions::registry::{get_module, ModuleRegistry};
+use bridge_conventions::types::system_config::BaseSystemId;
+use bridge_engine::types::Seat;
+use bridge_service::SessionConfig;
+use chrono::Utc;
+use serde_json::Value;
+
+const FREE_USER: &str = "club-member";
+const PAID_USER: &str = "club-subscriber";
+
+#[tokio::test]
+async fn modules_from_the_directory_are_drillable() {
+    let dir = std::env::temp_dir().join(format!("bridge-api-modules-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("club-stayman.json"), club_module("club-stayman")).unwrap();
+    std::fs::write(dir.join("broken.json"), "{ not a module").unwrap();
+
+    install_module_dir(&dir).expect("directory is readable");
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(get_module("club-stayman", BaseSystemId::Sayc).is_some());
+
+    let harness = TestHarness::new().await;
+    let free = harness
+        .insert_user_and_session(UserSeed::new(FREE_USER))
+        .await;
+    let paid_until = Utc::now().timestamp() + 86_400;
+    let paid = harness
+        .insert_user_and_session(UserSeed {
+            subscription_status: Some("active"),
+            subscription_current_period_end: Some(paid_until),
+            ..UserSeed::new(PAID_USER)
+        })
+        .await;
+
+    // Known to entitlement checks, and not on the free list.
+    let locked = harness
+        .send(post_json(
+            "/api/drill-sessions",
+            Some(&free),
+            &session_config("club-stayman-bundle", 42),
+        ))
+        .await;
+    assert_eq!(locked.status(), StatusCode::PAYMENT_REQUIRED);
+
+    let mut created = None;
+    for seed in 42..74 {
+        let response = harness
+            .send(post_json(
+                "/api/drill-sessions",
+                Some(&paid),
+                &session_config("club-stayman-bundle", seed),
+            ))
+            .await;
+        if response.status() == StatusCode::CREATED {
+            created = Some(body_json(response).await);
+            break;
+        }
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let handle = created.expect("a club-stayman drill")["handle"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let started = harness
+        .send(post_json(
+            &format!("/api/drill-sessions/{handle}/start"),
+            Some(&paid),
+            &Value::Object(Default::default()),
+        ))
+        .await;
+    assert_eq!(started.status(), StatusCode::OK);
+}
+
+/// Stayman under a club's own module ID.
+fn club_module(module_id: &str) -> String {
+    let mut module = ModuleRegistry::builtin()
+        .get("stayman")
+        .expect("stayman is embedded")
+        .clone();
+    module.module_id = module_id.to_string();
+    module.display_name = "Club Stayman".to_string();
+    for state in module.states.iter_mut().flatten() {
+        for surface in &mut state.surfaces {
+            surface.module_id = Some(module_id.to_string());
+        }
+    }
+    serde_json::to_string(&module).unwrap()
+}
+
+fn session_config(convention_id: &str, seed: u64) -> Value {
+    let config = SessionConfig {
+        convention_id: convention_id.to_string(),
+        user_seat: Some(Seat::South),
+        seed: Some(seed),
+        system_config: get_system_config(BaseSystemId::Sayc),
+        base_module_ids: BASE_MODULE_IDS.iter().map(|s| s.to_string()).collect(),
+        practice_mode: None,
+        target: None,
+        practice_role: None,
+        play_preference: None,
+        opponent_mode: None,
+        vulnerability: None,
+        play_profile_id: None,
+        vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
+    };
+    serde_json::to_value(config).expect("config serializes")
+}
+
+fn post_json(uri: &str, session: Option<&str>, body: &Value) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = session {
+        builder = builder.header(header::COOKIE, session_cookie_header(token));
+    }
+    builder.body(Body::from(body.to_string())).expect("request")
+}
+
+async fn body_json(response: axum::response::Response) -> Value {
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body");
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(&bytes).expect("json body")
+}
@@ -0,0 +1,275 @@
+//! Argument parsing shared across subcommands: `--key=value` flags,
+//! settings resolution and call parsing. Mirrors `src/cli/shared.ts` so both
+//! CLIs accept the same command lines.
+
+use std::collections::HashMap;
+
+use bridge_conventions::BaseSystemId;
+use bridge_engine::types::{BidSuit, Call, Vulnerability};
+use bridge_service::ServiceError;
+use bridge_session::types::{OpponentMode, PracticeMode, PracticeRole};
+
+/// Parsed `--key=value` and bare `--key` flags.
+#[derive(Debug, Default)]
+pub struct Flags {
+    values: HashMap<String, Option<String>>,
+}
+
+/// Errors that end the CLI with exit code 2.
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    Service(ServiceError),
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(message) => f.write_str(message),
+            CliError::Service(err) => write!(f, "Service error: {err}"),
+        }
+    }
+}
+
+impl From<ServiceError> for CliError {
+    fn from(err: ServiceError) -> Self {
+        CliError::Service(err)
+    }
+}
+
+fn usage<T>(message: String) -> Result<T, CliError> {
+    Err(CliError::Usage(message))
+}
+
+/// Collect `--key=value` and `--key` arguments; anything else is ignored.
+pub fn parse_args(argv: &[String]) -> Flags {
+    let mut values = HashMap::new();
+    for arg in argv {
+        let Some(flag) = arg.strip_prefix("--") else {
+            continue;
+        };
+        match flag.split_once('=') {
+            Some((key, value)) => values.insert(key.to_string(), Some(value.to_string())),
+            None => values.insert(flag.to_string(), None),
+        };
+    }
+    Flags { values }
+}
+
+impl Flags {
+    /// Whether `--name` was given, with or without a value.
+    pub fn has(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// The value of `--name=value`; `None` for a bare `--name` or no flag.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).and_then(|v| v.as_deref())
+    }
+
+    pub fn require(&self, name: &str) -> Result<&str, CliError> {
+        match self.value(name) {
+            Some(value) => Ok(value),
+            None => usage(format!("Missing required argument: --{name}")),
+        }
+    }
+
+    pub fn optional_number(&self, name: &str) -> Result<Option<u64>, CliError> {
+        match self.value(name) {
+            None => Ok(None),
+            Some(value) => match value.parse() {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => usage(format!("Invalid numeric argument: --{name}={value}")),
+            },
+        }
+    }
+
+    /// Look `--name` up in `map` (case-insensitive), or `None` when absent.
+    fn mapped<T: Copy>(
+        &self,
+        name: &str,
+        map: &[(&str, T)],
+        expected: &str,
+    ) -> Result<Option<T>, CliError> {
+        let Some(value) = self.value(name) else {
+            return Ok(None);
+        };
+        let lower = value.to_lowercase();
+        match map.iter().find(|(key, _)| *key == lower) {
+            Some((_, mapped)) => Ok(Some(*mapped)),
+            None => usage(format!(
+                "Invalid --{name} value: \"{value}\" (expected: {expected})"
+            )),
+        }
+    }
+}
+
+// ── Settings parsing ────────────────────────────────────────────────
+
+const SYSTEMS: [(&str, BaseSystemId); 3] = [
+    ("sayc", BaseSystemId::Sayc),
+    ("two-over-one", BaseSystemId::TwoOverOne),
+    ("acol", BaseSystemId::Acol),
+];
+
+pub fn parse_vulnerability(flags: &Flags) -> Result<Vulnerability, CliError> {
+    let map = [
+        ("none", Vulnerability::None),
+        ("ns", Vulnerability::NorthSouth),
+        ("ew", Vulnerability::EastWest),
+        ("both", Vulnerability::Both),
+    ];
+    Ok(flags
+        .mapped("vuln", &map, "none, ns, ew, both")?
+        .unwrap_or(Vulnerability::None))
+}
+
+pub fn parse_base_system(flags: &Flags) -> Result<BaseSystemId, CliError> {
+    Ok(flags
+        .mapped("system", &SYSTEMS, "sayc, two-over-one, acol")?
+        .unwrap_or(BaseSystemId::Sayc))
+}
+
+/// Systems to sweep: `--system=all`, a comma-separated list, or one system
+/// (default: sayc).
+pub fn parse_base_systems(flags: &Flags) -> Result<Vec<BaseSystemId>, CliError> {
+    let Some(value) = flags.value("system") else {
+        return Ok(vec![BaseSystemId::Sayc]);
+    };
+    if value.eq_ignore_ascii_case("all") {
+        return Ok(SYSTEMS.iter().map(|(_, id)| *id).collect());
+    }
+    value
+        .split(',')
+        .map(|name| {
+            let lower = name.trim().to_lowercase();
+            match SYSTEMS.iter().find(|(key, _)| *key == lower) {
+                Some((_, id)) => Ok(*id),
+                None => usage(format!(
+                    "Invalid --system value: \"{name}\" (expected: sayc, two-over-one, acol, all)"
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn parse_opponent_mode(flags: &Flags) -> Result<OpponentMode, CliError> {
+    let map = [
+        ("natural", OpponentMode::Natural),
+        ("none", OpponentMode::None),
+    ];
+    Ok(flags
+        .mapped("opponents", &map, "natural, none")?
+        .unwrap_or(OpponentMode::Natural))
+}
+
+pub fn parse_practice_mode(flags: &Flags) -> Result<Option<PracticeMode>, CliError> {
+    let map = [
+        ("decision-drill", PracticeMode::DecisionDrill),
+        ("full-auction", PracticeMode::FullAuction),
+    ];
+    flags.mapped("mode", &map, "decision-drill, full-auction")
+}
+
+pub fn parse_practice_role(flags: &Flags) -> Result<Option<PracticeRole>, CliError> {
+    let map = [
+        ("opener", PracticeRole::Opener),
+        ("responder", PracticeRole::Responder),
+        ("both", PracticeRole::Both),
+    ];
+    flags.mapped("role", &map, "opener, responder, both")
+}
+
+// ── Call parsing ────────────────────────────────────────────────────
+
+/// Parse a bid string like "2C", "P", "X", "XX" into a Call.
+pub fn parse_call(s: &str) -> Result<Call, CliError> {
+    let upper = s.trim().to_uppercase();
+    match upper.as_str() {
+        "P" | "PASS" => return Ok(Call::Pass),
+        "X" | "DBL" | "DOUBLE" => return Ok(Call::Double),
+        "XX" | "RDBL" | "REDOUBLE" => return Ok(Call::Redouble),
+        _ => {}
+    }
+    let invalid = || {
+        CliError::Usage(format!(
+            "Invalid bid: \"{s}\" (expected: P, X, XX, or 1C..7NT)"
+        ))
+    };
+    let level = upper
+        .get(..1)
+        .and_then(|l| l.parse::<u8>().ok())
+        .filter(|l| (1..=7).contains(l))
+        .ok_or_else(invalid)?;
+    let strain = match &upper[1..] {
+        "C" => BidSuit::Clubs,
+        "D" => BidSuit::Diamonds,
+        "H" => BidSuit::Hearts,
+        "S" => BidSuit::Spades,
+        "NT" | "N" => BidSuit::NoTrump,
+        _ => return Err(invalid()),
+    };
+    Ok(Call::Bid { level, strain })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flags(args: &[&str]) -> Flags {
+        parse_args(&args.iter().map(|a| a.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn parses_valued_and_bare_flags() {
+        let f = flags(&["--bundle=nt-bundle", "--all", "stray", "--bids=2C,3NT"]);
+        assert_eq!(f.value("bundle"), Some("nt-bundle"));
+        assert!(f.has("all"));
+        assert_eq!(f.value("all"), None);
+        assert_eq!(f.value("bids"), Some("2C,3NT"));
+        assert!(!f.has("stray"));
+        assert!(f.require("seed").is_err());
+    }
+
+    #[test]
+    fn parses_calls() {
+        assert_eq!(parse_call("p").unwrap(), Call::Pass);
+        assert_eq!(parse_call("XX").unwrap(), Call::Redouble);
+        assert_eq!(
+            parse_call("3nt").unwrap(),
+            Call::Bid {
+                level: 3,
+                strain: BidSuit::NoTrump
+            }
+        );
+        assert_eq!(
+            parse_call("1N").unwrap(),
+            Call::Bid {
+                level: 1,
+                strain: BidSuit::NoTrump
+            }
+        );
+        for bad in ["8C", "0S", "2Z", "", "NT"] {
+            assert!(parse_call(bad).is_err(), "{bad:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn settings_fall_back_to_defaults_and_reject_unknown_values() {
+        let empty = flags(&[]);
+        assert_eq!(parse_base_system(&empty).unwrap(), BaseSystemId::Sayc);
+        assert_eq!(parse_vulnerability(&empty).unwrap(), Vulnerability::None);
+        assert_eq!(parse_practice_role(&empty).unwrap(), None);
+
+        assert_eq!(
+            parse_base_systems(&flags(&["--system=all"])).unwrap().len(),
+            3
+        );
+        assert_eq!(
+            parse_base_systems(&flags(&["--system=acol,SAYC"])).unwrap(),
+            vec![BaseSystemId::Acol, BaseSystemId::Sayc]
+        );
+        assert!(parse_base_system(&flags(&["--system=precision"])).is_err());
+        assert!(parse_vulnerability(&flags(&["--vuln=NS"])).is_ok());
+    }
+}
@@ -0,0 +1,45 @@
+//! bundles, modules, describe — discovery commands over the service catalog.
+
+use bridge_service::ServicePort;
+use serde_json::json;
+
+use super::print_json;
+use crate::args::{CliError, Flags};
+
+pub fn run_bundles(service: &impl ServicePort) -> Result<bool, CliError> {
+    print_json(&service.list_conventions());
+    Ok(true)
+}
+
+pub fn run_modules(service: &impl ServicePort) -> Result<bool, CliError> {
+    print_json(&service.list_modules());
+    Ok(true)
+}
+
+pub fn run_describe(service: &impl ServicePort, flags: &Flags) -> Result<bool, CliError> {
+    let bundle_id = flags.require("bundle")?;
+
+    let bundles = service.list_conventions();
+    let Some(bundle) = bundles.iter().find(|b| b.id == bundle_id) else {
+        let available: Vec<String> = bundles
+            .iter()
+            .map(|b| format!("  {} — {}", b.id, b.name))
+            .collect();
+        return Err(CliError::Usage(format!(
+            "Unknown bundle: \"{bundle_id}\"\nAvailable bundles:\n{}",
+            available.join("\n")
+        )));
+    };
+
+    let modules: Vec<_> = service
+        .list_modules()
+        .into_iter()
+        .filter(|m| m.bundle_ids.iter().any(|id| id == bundle_id))
+        .collect();
+
+    print_json(&json!({
+        "bundle": bundle,
+        "modules": modules,
+    }));
+    Ok(true)
+}
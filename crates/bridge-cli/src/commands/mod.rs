@@ -0,0 +1,43 @@
+//! Subcommand implementations. Each returns whether the run passed
+//! (exit code 0 vs 1); argument and service errors become exit code 2.
+
+pub mod info;
+pub mod play;
+pub mod selftest;
+
+use std::io::Write;
+
+use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
+use bridge_conventions::registry::system_configs::get_system_config;
+use bridge_conventions::BaseSystemId;
+use bridge_service::SessionConfig;
+use bridge_session::types::PlayPreference;
+
+/// Session config shared by `play` and `selftest`: the chosen system with
+/// the standard base modules, skipping play after the auction.
+fn base_config(convention_id: &str, seed: u64, system: BaseSystemId) -> SessionConfig {
+    SessionConfig {
+        convention_id: convention_id.to_string(),
+        user_seat: None,
+        seed: Some(seed),
+        system_config: get_system_config(system),
+        base_module_ids: BASE_MODULE_IDS.iter().map(|s| s.to_string()).collect(),
+        practice_mode: None,
+        target: None,
+        practice_role: None,
+        play_preference: Some(PlayPreference::Skip),
+        opponent_mode: None,
+        vulnerability: None,
+        play_profile_id: None,
+        vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
+    }
+}
+
+/// Pretty JSON on stdout. Write errors (e.g. a closed pipe) are ignored.
+fn print_json<T: serde::Serialize>(value: &T) {
+    let json = serde_json::to_string_pretty(value).expect("CLI output is serializable");
+    let _ = writeln!(std::io::stdout().lock(), "{json}");
+}
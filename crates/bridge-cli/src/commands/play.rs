@@ -0,0 +1,123 @@
+//! Session-based playthrough. Uses the same session API as the UI:
+//! create_drill_session → start_drill → submit_bid loop. Stateless: each
+//! invocation creates a fresh session (same seed = same deal).
+
+use bridge_conventions::types::rule_types::TargetSelector;
+use bridge_service::ServicePort;
+use bridge_session::session::{BidGrade, BiddingViewport};
+use serde_json::{json, Value};
+
+use super::{base_config, print_json};
+use crate::args::{
+    parse_base_system, parse_call, parse_opponent_mode, parse_practice_mode, parse_practice_role,
+    parse_vulnerability, CliError, Flags,
+};
+
+fn summarize_viewport(viewport: &BiddingViewport) -> Value {
+    json!({
+        "seat": viewport.seat,
+        "hand": viewport.hand,
+        "hcp": viewport.hand_evaluation.hcp,
+        "handSummary": viewport.hand_summary,
+        "auction": viewport.auction_entries,
+        "legalCalls": viewport.legal_calls,
+        "conventionName": viewport.convention_name,
+        "isUserTurn": viewport.is_user_turn,
+        "bidContext": viewport.bid_context,
+    })
+}
+
+pub fn run_play(service: &mut impl ServicePort, flags: &Flags) -> Result<bool, CliError> {
+    let bundle_id = flags.require("bundle")?;
+    let seed = flags.optional_number("seed")?.unwrap_or(42);
+
+    if flags.value("bid").is_some() && flags.value("bids").is_some() {
+        return Err(CliError::Usage("Cannot use both --bid and --bids".into()));
+    }
+
+    // Either a single bid, a replay list, or none (viewport only)
+    let user_bids: Vec<&str> = match (flags.value("bid"), flags.value("bids")) {
+        (Some(bid), _) => vec![bid],
+        (_, Some(bids)) => bids
+            .split(',')
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    };
+    let calls = user_bids
+        .iter()
+        .map(|b| parse_call(b))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut config = base_config(bundle_id, seed, parse_base_system(flags)?);
+    config.vulnerability = Some(parse_vulnerability(flags)?);
+    config.opponent_mode = Some(parse_opponent_mode(flags)?);
+    config.practice_mode = parse_practice_mode(flags)?;
+    config.practice_role = parse_practice_role(flags)?;
+    config.target = flags
+        .value("module")
+        .map(|module_id| TargetSelector::Module {
+            module_id: module_id.to_string(),
+        });
+
+    let handle = service.create_drill_session(config)?;
+    let drill = service.start_drill(&handle)?;
+
+    // No bids requested — return the initial viewport
+    if calls.is_empty() {
+        let mut output = summarize_viewport(&drill.viewport);
+        output["auctionComplete"] = json!(drill.auction_complete);
+        print_json(&output);
+        return Ok(true);
+    }
+
+    for (i, call) in calls.into_iter().enumerate() {
+        let result = service.submit_bid(&handle, call)?;
+
+        if i == user_bids.len() - 1 {
+            let correct = result.grade == Some(BidGrade::Correct);
+            let acceptable = result.grade == Some(BidGrade::Acceptable);
+            let mut output = json!({
+                "yourBid": user_bids[i],
+                "grade": result.grade,
+                "correct": correct,
+                "acceptable": acceptable,
+                "feedback": result.feedback,
+                "teaching": result.teaching,
+                "auctionComplete": result.phase_transition.is_some(),
+            });
+            if let (Some(next), None) = (&result.next_viewport, &result.phase_transition) {
+                output["nextViewport"] = summarize_viewport(next);
+            }
+            if !result.ai_bids.is_empty() {
+                output["aiBids"] = result
+                    .ai_bids
+                    .iter()
+                    .map(|ab| json!({ "seat": ab.seat, "call": ab.call }))
+                    .collect();
+            }
+            print_json(&output);
+            return Ok(correct || acceptable);
+        }
+
+        // Replay bids must be accepted to advance the auction.
+        if !result.accepted {
+            let grade = serde_json::to_string(&result.grade).unwrap_or_default();
+            return Err(CliError::Usage(format!(
+                "Replay failed: bid \"{}\" was not accepted at step {} (graded {grade}). Auction did not advance.",
+                user_bids[i],
+                i + 1
+            )));
+        }
+        if result.phase_transition.is_some() {
+            return Err(CliError::Usage(format!(
+                "Auction completed after bid \"{}\" at step {}, but {} bids remain",
+                user_bids[i],
+                i + 1,
+                user_bids.len() - i - 1
+            )));
+        }
+    }
+    unreachable!("the last bid always returns")
+}
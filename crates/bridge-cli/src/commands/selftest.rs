@@ -0,0 +1,216 @@
+//! Self-consistency check: for each seed, creates a session, gets the
+//! strategy's recommended bid via `get_expected_bid`, submits it, and
+//! verifies it grades as correct. Sweeps bundles × systems × seeds.
+
+use bridge_conventions::BaseSystemId;
+use bridge_engine::types::Vulnerability;
+use bridge_service::{DevServicePort, ServicePort};
+use bridge_session::session::{call_key, BidGrade};
+use bridge_session::types::OpponentMode;
+use serde::Serialize;
+
+use super::{base_config, print_json};
+use crate::args::{parse_base_systems, parse_opponent_mode, parse_vulnerability, CliError, Flags};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeedStatus {
+    Pass,
+    Fail,
+    Skip,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeedResult {
+    pub seed: u64,
+    pub status: SeedStatus,
+    pub steps: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_step: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleReport {
+    bundle: String,
+    system: BaseSystemId,
+    seed_range: [u64; 2],
+    pass: usize,
+    fail: usize,
+    skip: usize,
+    results: Vec<SeedResult>,
+}
+
+pub fn run_selftest<S: ServicePort + DevServicePort>(
+    service: &mut S,
+    flags: &Flags,
+) -> Result<bool, CliError> {
+    let bundle_ids: Vec<String> = if flags.has("all") {
+        service
+            .list_conventions()
+            .into_iter()
+            .map(|c| c.id)
+            .collect()
+    } else {
+        vec![flags.require("bundle")?.to_string()]
+    };
+    let start_seed = flags.optional_number("seed")?.unwrap_or(42);
+    let count = flags.optional_number("count")?.unwrap_or(20);
+    let systems = parse_base_systems(flags)?;
+    let vulnerability = parse_vulnerability(flags)?;
+    let opponents = parse_opponent_mode(flags)?;
+
+    let mut reports = Vec::new();
+    for bundle_id in &bundle_ids {
+        for &system in &systems {
+            let results: Vec<SeedResult> = (start_seed..start_seed + count)
+                .map(|seed| {
+                    run_single_seed(service, bundle_id, seed, system, vulnerability, opponents)
+                })
+                .collect();
+            let tally = |status| results.iter().filter(|r| r.status == status).count();
+            reports.push(BundleReport {
+                bundle: bundle_id.clone(),
+                system,
+                seed_range: [start_seed, start_seed + count.saturating_sub(1)],
+                pass: tally(SeedStatus::Pass),
+                fail: tally(SeedStatus::Fail),
+                skip: tally(SeedStatus::Skip),
+                results,
+            });
+        }
+    }
+
+    let all_pass = reports.iter().all(|r| r.fail == 0);
+    if let [report] = reports.as_slice() {
+        print_json(report);
+    } else {
+        print_json(&reports);
+    }
+    Ok(all_pass)
+}
+
+fn skip(seed: u64, steps: usize, details: String) -> SeedResult {
+    SeedResult {
+        seed,
+        status: SeedStatus::Skip,
+        steps,
+        fail_step: None,
+        expected: None,
+        grade: None,
+        details: Some(details),
+    }
+}
+
+/// Bid the strategy's own recommendation at every user turn of one deal.
+pub fn run_single_seed<S: ServicePort + DevServicePort>(
+    service: &mut S,
+    bundle_id: &str,
+    seed: u64,
+    system: BaseSystemId,
+    vulnerability: Vulnerability,
+    opponents: OpponentMode,
+) -> SeedResult {
+    let mut config = base_config(bundle_id, seed, system);
+    config.vulnerability = Some(vulnerability);
+    config.opponent_mode = Some(opponents);
+
+    // Deal generation can exhaust for a seed; that is not a strategy failure.
+    let handle = match service.create_drill_session(config) {
+        Ok(handle) => handle,
+        Err(err) => return skip(seed, 0, format!("create_drill_session failed: {err}")),
+    };
+    let drill = match service.start_drill(&handle) {
+        Ok(drill) => drill,
+        Err(err) => return skip(seed, 0, format!("start_drill failed: {err}")),
+    };
+    let pass = |steps| SeedResult {
+        seed,
+        status: SeedStatus::Pass,
+        steps,
+        fail_step: None,
+        expected: None,
+        grade: None,
+        details: None,
+    };
+    if drill.auction_complete {
+        return pass(0);
+    }
+
+    let mut steps = 0;
+    loop {
+        steps += 1;
+
+        let expected = match service.get_expected_bid(&handle) {
+            Ok(Some(call)) => call,
+            Ok(None) => {
+                return skip(
+                    seed,
+                    steps,
+                    "get_expected_bid returned None (no strategy recommendation)".into(),
+                )
+            }
+            Err(err) => return skip(seed, steps, format!("get_expected_bid failed: {err}")),
+        };
+        let fail = |grade: String, details: &str| SeedResult {
+            seed,
+            status: SeedStatus::Fail,
+            steps,
+            fail_step: Some(steps),
+            expected: Some(call_key(&expected)),
+            grade: Some(grade),
+            details: Some(details.to_string()),
+        };
+
+        let result = match service.submit_bid(&handle, expected.clone()) {
+            Ok(result) => result,
+            Err(err) => return fail("error".into(), &format!("submit_bid failed: {err}")),
+        };
+        if !result.accepted {
+            return fail(
+                "rejected".into(),
+                "Strategy's recommended bid was not accepted (not legal)",
+            );
+        }
+        if !matches!(result.grade, Some(BidGrade::Correct | BidGrade::Acceptable)) {
+            let grade = match result.grade {
+                Some(grade) => serde_json::to_value(grade)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+                    .unwrap_or_default(),
+                None => "null".into(),
+            };
+            return fail(grade, "Strategy's recommended bid did not grade as correct");
+        }
+        if result.phase_transition.is_some() {
+            return pass(steps);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bridge_service::ServicePortImpl;
+
+    #[test]
+    fn nt_bundle_strategy_grades_its_own_bids_correct() {
+        let mut service = ServicePortImpl::new();
+        let result = run_single_seed(
+            &mut service,
+            "nt-bundle",
+            42,
+            BaseSystemId::Sayc,
+            Vulnerability::None,
+            OpponentMode::None,
+        );
+        assert_ne!(result.status, SeedStatus::Fail, "{result:?}");
+    }
+}
@@ -0,0 +1,111 @@
+//! CLI usage and per-subcommand help, written to stderr so stdout stays JSON.
+
+pub fn print_usage() {
+    eprintln!(
+        "\
+Usage: bridge-cli <subcommand> [options]
+
+Subcommands:
+  bundles                                    List all available bundles (JSON)
+  modules                                    List all available modules (JSON)
+  describe  --bundle=<id>                    Inspect a bundle and its modules
+  play      --bundle=<id> --seed=N [--bid=X] Session-based playthrough
+  selftest  --bundle=<id> | --all [--seed=N] Strategy self-consistency check
+  help                                       Show this help
+
+Global settings:
+  --system=<sayc|two-over-one|acol>  Base bidding system (default: sayc)
+  --vuln=<none|ns|ew|both>           Vulnerability (default: none)
+  --opponents=<natural|none>         Opponent bidding mode (default: natural)
+  --help                             Show help (global or per-subcommand)
+
+Exit codes: 0=correct/pass, 1=wrong/fail, 2=arg error
+
+Tip: Run '<subcommand> --help' for detailed subcommand usage."
+    );
+}
+
+pub fn print_subcommand_help(cmd: &str) {
+    match cmd {
+        "bundles" => eprintln!(
+            "\
+bundles — List all available convention bundles.
+
+Usage: bridge-cli bundles
+
+Returns JSON array of bundles with id, name, description.
+Use this for self-discovery: find valid bundle IDs before calling other commands."
+        ),
+        "modules" => eprintln!(
+            "\
+modules — List all available convention modules.
+
+Usage: bridge-cli modules
+
+Returns JSON array of modules with moduleId, displayName, bundleIds, etc."
+        ),
+        "describe" => eprintln!(
+            "\
+describe — Inspect a single bundle and its modules.
+
+Usage: bridge-cli describe --bundle=<id>
+
+Returns JSON with bundle metadata and its modules."
+        ),
+        "play" => eprintln!(
+            "\
+play — Session-based playthrough evaluation.
+
+Usage:
+  play --bundle=<id> --seed=N
+    Returns BiddingViewport JSON for user's first decision point.
+
+  play --bundle=<id> --seed=N --bid=<bid>
+    Creates session (same seed = same deal), submits bid.
+    Returns grade + teaching feedback + next viewport.
+    Exit code: 0=correct/acceptable, 1=wrong.
+
+  play --bundle=<id> --seed=N --bids=2C,3NT
+    Replays earlier bids silently, grades final bid.
+    --bids contains user-seat bids only (AI bids are automatic).
+
+Options:
+  --bundle=<id>       Convention/bundle ID (required)
+  --seed=N            Deterministic PRNG seed (default: 42)
+  --system=<id>       Base system (default: sayc)
+  --vuln=<v>          Vulnerability (default: none)
+  --opponents=<mode>  Opponent mode (default: natural)
+  --module=<id>       Target module focus
+  --role=<role>       Practice role (opener/responder/both)
+  --mode=<mode>       Practice mode (decision-drill/full-auction)
+  --bid=<bid>         Submit a single bid (P, X, XX, 1C..7NT)
+  --bids=<b1,b2,...>  Submit multiple user-seat bids in sequence
+
+Bid format: P (pass), X (double), XX (redouble), 1C..7NT"
+        ),
+        "selftest" => eprintln!(
+            "\
+selftest — Run strategy against itself (self-consistency check).
+
+Usage:
+  selftest --bundle=<id> [--seed=N] [--count=N]
+  selftest --all [--seed=N] [--count=N] [--system=all]
+
+For each seed: creates session, gets strategy's recommended bid via
+get_expected_bid(), submits it, and verifies it grades as correct.
+
+Options:
+  --bundle=<id>     Convention/bundle ID
+  --all             Test all bundles
+  --seed=N          Starting seed (default: 42)
+  --count=N         Number of seeds to test (default: 20)
+  --system=<ids>    Base system, comma-separated list, or 'all' (default: sayc)
+
+Exit code: 0=all pass, 1=at least one failure."
+        ),
+        _ => {
+            eprintln!("Unknown subcommand: \"{cmd}\"\n");
+            print_usage();
+        }
+    }
+}
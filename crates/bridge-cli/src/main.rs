@@ -0,0 +1,61 @@
+//! Native convention-evaluation CLI — drives ServicePort / DevServicePort
+//! directly, so Rust-only convention changes can be checked without Node.
+//!
+//! Same subcommands, flags, JSON output and exit codes as `src/cli/main.ts`:
+//!   bundles   — list all available bundles
+//!   modules   — list all available modules
+//!   describe  — inspect a bundle and its modules
+//!   play      — session-based playthrough (viewport, bid grading)
+//!   selftest  — strategy self-consistency check
+//!
+//! Thin caller: MUST NOT contain convention logic.
+
+mod args;
+mod commands;
+mod help;
+
+use std::process::ExitCode;
+
+use bridge_service::ServicePortImpl;
+
+use args::parse_args;
+use help::{print_subcommand_help, print_usage};
+
+fn main() -> ExitCode {
+    let raw_args: Vec<String> = std::env::args().skip(1).collect();
+    let subcommand = raw_args.first().map(String::as_str);
+    let flags = parse_args(raw_args.get(1..).unwrap_or_default());
+
+    let Some(subcommand) = subcommand.filter(|s| !matches!(*s, "--help" | "-h" | "help")) else {
+        print_usage();
+        return ExitCode::from(if subcommand.is_some() { 0 } else { 2 });
+    };
+
+    if flags.has("help") || flags.has("h") {
+        print_subcommand_help(subcommand);
+        return ExitCode::SUCCESS;
+    }
+
+    let mut service = ServicePortImpl::new();
+    let outcome = match subcommand {
+        "bundles" => commands::info::run_bundles(&service),
+        "modules" => commands::info::run_modules(&service),
+        "describe" => commands::info::run_describe(&service, &flags),
+        "play" => commands::play::run_play(&mut service, &flags),
+        "selftest" => commands::selftest::run_selftest(&mut service, &flags),
+        _ => {
+            eprintln!("Unknown subcommand: \"{subcommand}\"");
+            print_usage();
+            return ExitCode::from(2);
+        }
+    };
+
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -59,6 +59,7 @@ mod tests {
             constrains_dimensions: vec![],
             composition: None,
             for_teaching_only: false,
+            partnership_measure: None,
         }
     }
 
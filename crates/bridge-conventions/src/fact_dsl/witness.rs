@@ -31,13 +31,16 @@ use bridge_engine::{
 use serde::{Deserialize, Serialize};
 
 use crate::pipeline::evaluation::binding_resolver::resolve_clause;
+use crate::pipeline::observation::attachment::{
+    advance_module_phase, provided_capabilities, surface_attaches, AttachmentStep,
+};
 use crate::pipeline::observation::committed_step::{
     initial_negotiation, ClaimRef, CommittedStep, CommittedStepStatus,
 };
-use crate::pipeline::observation::local_fsm::advance_local_fsm;
 use crate::pipeline::observation::negotiation_extractor::apply_negotiation_actions;
 use crate::pipeline::observation::negotiation_matcher::match_kernel;
 use crate::pipeline::observation::normalize_intent::normalize_intent;
+use crate::pipeline::observation::relay::observed_actions;
 use crate::types::bid_action::{BidAction, BidActionType, BidSuitName};
 use crate::types::meaning::BidMeaning;
 use crate::types::negotiation::{Captain, NegotiationDelta, NegotiationState};
@@ -395,7 +398,12 @@ pub struct Witness {
     /// acceptance predicate needs both to match correctly.
     pub target_surface_module_id: String,
     pub user_seat: Seat,
+    /// Seat that deals; the auction (and `prefix`) starts here.
     pub dealer: Seat,
+    /// Seat in the opener role; turn roles resolve from it. Equals `dealer`
+    /// unless the target's host attachment needs leading passes — Drury's
+    /// `P P 1♥` deals two seats before the opener.
+    pub opener: Seat,
 }
 
 /// Per-seat projected constraint produced from a witness.
@@ -779,7 +787,11 @@ fn fit_relevant_surfaces(modules: &[&ConventionModule]) -> Vec<FitStep> {
                 PhaseRef::Multiple(ps) => ps.join("+"),
             };
             for surface in &se.surfaces {
-                let actions = normalize_intent(&surface.source_intent);
+                let actions = observed_actions(
+                    &surface.encoding,
+                    &surface.source_intent,
+                    &surface.encoding.default_call,
+                );
                 out.push(FitStep {
                     turn,
                     phase: se.phase.clone(),
@@ -829,62 +841,60 @@ pub(crate) fn call_to_short_label(call: &Call) -> String {
     }
 }
 
-/// Check whether the current authored auction prefix satisfies an
-/// `AuctionPattern`. Uses bridge-notation call labels ("1NT" etc.) for
-/// comparison. Passes are stripped from the matched log so
-/// `sequence: ["1NT"]` reads as "the last non-pass bid is 1NT" — the
-/// semantics authored fixtures actually expect (otherwise any opponent
-/// Pass between the opening and the follow-up would break the match).
-fn auction_pattern_matches(
-    pattern: &crate::types::agreement::AuctionPattern,
-    prefix: &[WitnessCall],
-) -> bool {
-    use crate::types::agreement::AuctionPattern;
-    // Walks of `auction_pattern_matches` only fire on internal BFS-built
-    // prefixes (always Concrete). Patterns are skipped defensively rather
-    // than panicking — they would treat as non-Pass labels and break the
-    // sequence match (correct behavior when authoring uses suit_class).
-    let labels: Vec<String> = prefix
-        .iter()
-        .filter_map(|c| c.concrete_call())
-        .filter(|c| !matches!(c, Call::Pass))
-        .map(call_to_short_label)
-        .collect();
-    match pattern {
-        AuctionPattern::Sequence { calls } => {
-            if labels.len() < calls.len() {
-                return false;
-            }
-            let start = labels.len() - calls.len();
-            labels[start..]
-                .iter()
-                .zip(calls.iter())
-                .all(|(a, b)| a == b)
-        }
-        AuctionPattern::Contains { call, .. } => labels.iter().any(|l| l == call),
-        AuctionPattern::ByRole { last_call, .. } => {
-            labels.last().map(|l| l == last_call).unwrap_or(false)
+/// Attachment view of a witness prefix dealt by `dealer`, with the implied
+/// passes between authored steps filled in. Pattern steps (NMF's "any minor
+/// opening") match any call, and no replayed state is carried, so public
+/// guards are left to the live adapter during deal gating.
+fn witness_attachment_steps(prefix: &[WitnessCall], dealer: Seat) -> Vec<AttachmentStep<'static>> {
+    let mut steps = Vec::with_capacity(prefix.len());
+    let mut cursor = dealer;
+    for entry in prefix {
+        while cursor != entry.seat {
+            steps.push(AttachmentStep::call(&Call::Pass, None));
+            cursor = next_seat(cursor);
         }
+        steps.push(AttachmentStep {
+            label: entry.concrete_call().map(call_to_short_label),
+            state_after: None,
+        });
+        cursor = next_seat(entry.seat);
     }
+    steps
 }
 
-/// True if `module`'s authored bundle_metadata.attachments (or top-level
-/// attachments if bundle_metadata is empty) are satisfied by the current
-/// prefix. Modules with no attachments are always considered active.
-fn module_attachments_active(module: &ConventionModule, prefix: &[WitnessCall]) -> bool {
-    let attachments = if !module.bundle_metadata.attachments.is_empty() {
-        &module.bundle_metadata.attachments
-    } else {
-        &module.attachments
-    };
-    if attachments.is_empty() {
-        return true;
-    }
-    attachments.iter().any(|att| {
-        att.when_auction
-            .as_ref()
-            .map(|p| auction_pattern_matches(p, prefix))
-            .unwrap_or(true)
+fn is_bid_step(call: &WitnessCall) -> bool {
+    !matches!(call.concrete_call(), Some(Call::Pass))
+}
+
+/// Deal `prefix` up to three seats before `opener` (leading passes) until
+/// bidding `call` leaves `module` attached. Returns the padded prefix and
+/// its dealer, or `None` if no rotation reaches the host.
+fn attach_with_leading_passes(
+    module: &ConventionModule,
+    prefix: &[WitnessCall],
+    call: &Call,
+    opener: Seat,
+    capabilities: &HashSet<&str>,
+) -> Option<(Vec<WitnessCall>, Seat)> {
+    (0..4).find_map(|leading| {
+        let dealer = step_seat(opener, 4 - leading);
+        let mut padded: Vec<WitnessCall> = (0..leading)
+            .map(|i| {
+                let seat = step_seat(dealer, i);
+                WitnessCall {
+                    seat,
+                    spec: WitnessCallSpec::Concrete(Call::Pass),
+                    role: if is_partnership_seat(seat, opener) {
+                        WitnessRole::Partnership
+                    } else {
+                        WitnessRole::Opponent
+                    },
+                }
+            })
+            .collect();
+        padded.extend_from_slice(prefix);
+        let steps = witness_attachment_steps(&padded, dealer);
+        surface_attaches(module, &steps, call, capabilities).then_some((padded, dealer))
     })
 }
 
@@ -901,9 +911,14 @@ fn initial_module_phases(modules: &[&ConventionModule]) -> ModulePhases {
 }
 
 /// Build a `CommittedStep` from an actor + call + actions. Only the fields
-/// read by `advance_local_fsm` (`call`, `public_actions`) are populated
-/// meaningfully; the rest are placeholders.
-fn synth_committed_step(actor: Seat, call: Call, actions: Vec<BidAction>) -> CommittedStep {
+/// read by `advance_module_phase` (`call`, `public_actions`, `state_after`)
+/// are populated meaningfully; the rest are placeholders.
+fn synth_committed_step(
+    actor: Seat,
+    call: Call,
+    actions: Vec<BidAction>,
+    state_after: NegotiationState,
+) -> CommittedStep {
     CommittedStep {
         actor,
         call,
@@ -918,19 +933,20 @@ fn synth_committed_step(actor: Seat, call: Call, actions: Vec<BidAction>) -> Com
         }),
         public_actions: actions,
         negotiation_delta: NegotiationDelta::default(),
-        state_after: initial_negotiation(),
+        state_after,
         status: CommittedStepStatus::Resolved,
     }
 }
 
 /// Advance every module's FSM phase by one step. `prior_steps` is reused
 /// across modules; FSMs that don't have a matching transition keep their
-/// current phase (idempotent).
+/// current phase (idempotent), and dormant add-ons wait for their host.
 fn advance_all_module_phases(
     modules: &[&ConventionModule],
     phases: &ModulePhases,
     step: &CommittedStep,
     prior_steps: &[CommittedStep],
+    capabilities: &HashSet<&str>,
 ) -> ModulePhases {
     let mut out = phases.clone();
     for m in modules {
@@ -938,7 +954,7 @@ fn advance_all_module_phases(
             .get(&m.module_id)
             .cloned()
             .unwrap_or_else(|| m.local.initial.clone());
-        let next = advance_local_fsm(&current, step, prior_steps, &m.local.transitions);
+        let next = advance_module_phase(m, &current, step, prior_steps, capabilities);
         out.insert(m.module_id.clone(), next);
     }
     out
@@ -1005,6 +1021,7 @@ fn find_kernel_establishing_prefix(
     let steps = fit_relevant_surfaces(modules);
     let initial_state = initial_negotiation();
     let initial_phases = initial_module_phases(modules);
+    let capabilities = provided_capabilities(modules.iter().copied());
 
     // Early-out: empty prefix. Satisfies the kernel iff the initial state
     // matches AND the target seat is the dealer (otherwise padding is
@@ -1120,7 +1137,14 @@ fn find_kernel_establishing_prefix(
                 // without re-checking the activation trigger.
                 if let Some(owner) = find_module(modules, &step.module_id) {
                     let is_at_initial = module_phase == owner.local.initial;
-                    if is_at_initial && !module_attachments_active(owner, &prefix) {
+                    if is_at_initial
+                        && !surface_attaches(
+                            owner,
+                            &witness_attachment_steps(&prefix, dealer),
+                            &step.default_call,
+                            &capabilities,
+                        )
+                    {
                         continue;
                     }
                 }
@@ -1142,9 +1166,15 @@ fn find_kernel_establishing_prefix(
                     current_seat,
                     step.default_call.clone(),
                     step.actions.clone(),
+                    new_state.clone(),
+                );
+                let new_phases = advance_all_module_phases(
+                    modules,
+                    &phases,
+                    &committed,
+                    &steps_log,
+                    &capabilities,
                 );
-                let new_phases =
-                    advance_all_module_phases(modules, &phases, &committed, &steps_log);
                 let mut new_prefix = prefix.clone();
                 new_prefix.push(WitnessCall {
                     seat: current_seat,
@@ -1159,10 +1189,19 @@ fn find_kernel_establishing_prefix(
             // (captain != Undecided), since a captain=Undecided all-pass
             // prefix can never establish a kernel.
             if !require_opening {
-                let pass_step =
-                    synth_committed_step(current_seat, Call::Pass, vec![BidAction::Pass]);
-                let new_phases =
-                    advance_all_module_phases(modules, &phases, &pass_step, &steps_log);
+                let pass_step = synth_committed_step(
+                    current_seat,
+                    Call::Pass,
+                    vec![BidAction::Pass],
+                    state.clone(),
+                );
+                let new_phases = advance_all_module_phases(
+                    modules,
+                    &phases,
+                    &pass_step,
+                    &steps_log,
+                    &capabilities,
+                );
                 let mut pass_prefix = prefix.clone();
                 pass_prefix.push(WitnessCall {
                     seat: current_seat,
@@ -1175,8 +1214,14 @@ fn find_kernel_establishing_prefix(
             }
         } else {
             // Opponent seat: automatic pass.
-            let pass_step = synth_committed_step(current_seat, Call::Pass, vec![BidAction::Pass]);
-            let new_phases = advance_all_module_phases(modules, &phases, &pass_step, &steps_log);
+            let pass_step = synth_committed_step(
+                current_seat,
+                Call::Pass,
+                vec![BidAction::Pass],
+                state.clone(),
+            );
+            let new_phases =
+                advance_all_module_phases(modules, &phases, &pass_step, &steps_log, &capabilities);
             let mut new_prefix = prefix.clone();
             new_prefix.push(WitnessCall {
                 seat: current_seat,
@@ -1250,7 +1295,7 @@ pub fn replay_kernel_from_prefix(
         // Among candidates, prefer fit-setting ones. Then pick the
         // most-specific by invert-score; tie-break by (module_id, meaning_id).
         let chosen = pick_replay_surface(&candidates);
-        let actions = normalize_intent(&chosen.source_intent);
+        let actions = observed_actions(&chosen.encoding, &chosen.source_intent, entry_call);
         state = apply_negotiation_actions(&state, &actions, entry.seat, entry_call);
     }
     state
@@ -1342,7 +1387,11 @@ fn merge_prefixes(mut a: Vec<WitnessCall>, b: Vec<WitnessCall>) -> Vec<WitnessCa
 ///    `loaded_modules` that is *not* the target, find its "responder-context
 ///    path" and fold it in. This is how 1NT-opener context flows into a
 ///    stayman/Jacoby witness whose own path is empty.
-/// 3. Cap output at `max_witnesses`.
+/// 3. **Host attachment:** the target module must be attached (see
+///    `pipeline::observation::attachment`) when the user bids. Prefixes
+///    that miss the host are padded with leading passes, moving the deal
+///    ahead of `dealer`; the witness keeps `dealer` as its `opener`.
+/// 4. Cap output at `max_witnesses`.
 ///
 /// Returns an empty `Vec` (never panics) if the target module/surface is
 /// missing or if no reifiable path exists.
@@ -1364,6 +1413,7 @@ pub fn enumerate_witnesses(
     if target_states.is_empty() {
         return Vec::new();
     }
+    let capabilities = provided_capabilities(loaded_modules.iter().copied());
 
     // Pre-compute base-module responder-context paths once.
     let mut context_paths: Vec<Vec<WitnessCall>> = Vec::new();
@@ -1511,20 +1561,36 @@ pub fn enumerate_witnesses(
         // The surface's authored `module_id` may differ from the containing
         // module's `module_id` for extension modules (e.g. stayman-garbage's
         // surfaces declare moduleId: "stayman"). Grab it for the predicate.
-        let surface_module_id = se
+        let Some(target_surface) = se
             .surfaces
             .iter()
             .find(|s| s.meaning_id == target_surface_id)
-            .and_then(|s| s.module_id.clone())
+        else {
+            continue;
+        };
+        let surface_module_id = target_surface
+            .module_id
+            .clone()
             .unwrap_or_else(|| target_module_id.to_string());
 
+        let Some((prefix, auction_dealer)) = attach_with_leading_passes(
+            target_module,
+            &prefix,
+            &target_surface.encoding.default_call,
+            dealer,
+            &capabilities,
+        ) else {
+            continue;
+        };
+
         witnesses.push(Witness {
             prefix,
             target_surface_id: target_surface_id.to_string(),
             target_module_id: target_module_id.to_string(),
             target_surface_module_id: surface_module_id,
             user_seat,
-            dealer,
+            dealer: auction_dealer,
+            opener: dealer,
         });
         if witnesses.len() >= max_witnesses {
             break;
@@ -1584,9 +1650,12 @@ fn surfaces_emitting_call_with_phase_gate<'m>(
     dealer: Seat,
     prior_prefix: &[WitnessCall],
 ) -> Vec<&'m BidMeaning> {
-    // Compute per-module FSM phases after replaying prior_prefix.
+    // Compute per-module FSM phases after replaying prior_prefix. The folded
+    // negotiation state feeds host-attachment public guards.
     let mut phases = initial_module_phases(loaded_modules);
     let mut steps_log: Vec<CommittedStep> = Vec::new();
+    let mut state = initial_negotiation();
+    let capabilities = provided_capabilities(loaded_modules.iter().copied());
     for entry in prior_prefix {
         // Pattern witnesses are skipped here: project_witness only operates on
         // materialized concrete prefixes (live deal-gating already replays the
@@ -1597,25 +1666,49 @@ fn surfaces_emitting_call_with_phase_gate<'m>(
         let actions = if matches!(entry_call, Call::Pass) {
             vec![BidAction::Pass]
         } else {
-            // Look up candidate surfaces for this historical call (loose
-            // match; used only as a hint for FSM advance). Prefer
-            // fit-setting surfaces to match BFS pick-logic.
-            let candidates =
-                surfaces_emitting_call(loaded_modules, entry_call, entry.seat, dealer);
+            // Look up candidate surfaces for this historical call, preferring
+            // those live in the current phases so e.g. a 2NT opening is not
+            // replayed as some module's 2NT rebid. Falls back to the loose
+            // match; used only as a hint for FSM advance. Prefer fit-setting
+            // surfaces to match BFS pick-logic.
+            let mut candidates = phase_live_surfaces_emitting_call(
+                loaded_modules,
+                &phases,
+                entry_call,
+                entry.seat,
+                dealer,
+            );
+            if candidates.is_empty() {
+                candidates = surfaces_emitting_call(loaded_modules, entry_call, entry.seat, dealer);
+            }
             if candidates.is_empty() {
                 Vec::new()
             } else {
                 // Pick the most-specific fit-setting surface (matches BFS's
                 // `pick_replay_surface` policy).
                 let chosen = pick_replay_surface(&candidates);
-                normalize_intent(&chosen.source_intent)
+                observed_actions(&chosen.encoding, &chosen.source_intent, entry_call)
             }
         };
-        let step = synth_committed_step(entry.seat, entry_call.clone(), actions);
-        phases = advance_all_module_phases(loaded_modules, &phases, &step, &steps_log);
+        state = apply_negotiation_actions(&state, &actions, entry.seat, entry_call);
+        let step = synth_committed_step(entry.seat, entry_call.clone(), actions, state.clone());
+        phases =
+            advance_all_module_phases(loaded_modules, &phases, &step, &steps_log, &capabilities);
         steps_log.push(step);
     }
 
+    phase_live_surfaces_emitting_call(loaded_modules, &phases, call, bidder_seat, dealer)
+}
+
+/// Surfaces that emit `call` at a `turn` matching `bidder_seat` and whose
+/// hosting state phase matches the module's phase in `phases`.
+fn phase_live_surfaces_emitting_call<'m>(
+    loaded_modules: &[&'m ConventionModule],
+    phases: &ModulePhases,
+    call: &Call,
+    bidder_seat: Seat,
+    dealer: Seat,
+) -> Vec<&'m BidMeaning> {
     let mut out = Vec::new();
     for m in loaded_modules {
         let Some(states) = m.states.as_ref() else {
@@ -1723,6 +1816,7 @@ fn inverted_to_seat_constraint(seat: Seat, c: &InvertedConstraint) -> SeatConstr
         min_length: c.min_length.clone(),
         max_length: c.max_length.clone(),
         min_length_any: c.min_length_any.clone(),
+        ..Default::default()
     }
 }
 
@@ -2203,7 +2297,7 @@ fn target_kernel_for_witness(
     let states = target_module.states.as_ref()?;
     for se in states {
         let Some(turn) = se.turn else { continue };
-        if seat_for_turn(turn, witness.dealer) != witness.user_seat {
+        if seat_for_turn(turn, witness.opener) != witness.user_seat {
             continue;
         }
         if se
@@ -2277,7 +2371,7 @@ pub fn project_witness(
     // stayman / jacoby / smolen / bergen targets have no kernel field and
     // skip this branch entirely.
     if let Some(kernel_req) = target_kernel_for_witness(target_module, witness) {
-        let state = replay_kernel_from_prefix(&witness.prefix, loaded_modules, witness.dealer);
+        let state = replay_kernel_from_prefix(&witness.prefix, loaded_modules, witness.opener);
         if !match_kernel(&kernel_req, &state) {
             return Vec::new();
         }
@@ -2299,7 +2393,7 @@ pub fn project_witness(
         target_module,
         &witness.target_surface_id,
         witness.user_seat,
-        witness.dealer,
+        witness.opener,
     );
     if !target_surfaces.is_empty() {
         let best = target_surfaces
@@ -2317,7 +2411,7 @@ pub fn project_witness(
     let mut cursor = witness.dealer;
     for entry in &witness.prefix {
         while cursor != entry.seat {
-            if is_opponent_seat(cursor, witness.dealer) {
+            if is_opponent_seat(cursor, witness.opener) {
                 let synthesized = synthesize_no_interference_constraint(
                     cursor,
                     &position,
@@ -2348,7 +2442,7 @@ pub fn project_witness(
             }
         };
 
-        if entry_call == &Call::Pass && is_opponent_seat(entry.seat, witness.dealer) {
+        if entry_call == &Call::Pass && is_opponent_seat(entry.seat, witness.opener) {
             let synthesized = synthesize_no_interference_constraint(
                 entry.seat,
                 &position,
@@ -2361,11 +2455,20 @@ pub fn project_witness(
             continue;
         }
 
+        // Leading partnership pass (a passed-hand host such as Drury's):
+        // no authored surface describes it, and matching response-level
+        // passes would contradict the target's own constraints.
+        if entry_call == &Call::Pass && position.iter().all(|c| !is_bid_step(c)) {
+            position.push(entry.clone());
+            cursor = step_seat(entry.seat, 1);
+            continue;
+        }
+
         let matches = surfaces_emitting_call_with_phase_gate(
             loaded_modules,
             entry_call,
             entry.seat,
-            witness.dealer,
+            witness.opener,
             &position,
         );
         if matches.is_empty() {
@@ -2386,7 +2489,7 @@ pub fn project_witness(
     }
 
     while cursor != witness.user_seat {
-        if is_opponent_seat(cursor, witness.dealer) {
+        if is_opponent_seat(cursor, witness.opener) {
             let synthesized = synthesize_no_interference_constraint(
                 cursor,
                 &position,
@@ -2418,6 +2521,7 @@ pub fn project_witness(
         vulnerability: None,
         max_attempts: Some(50_000),
         seed: None,
+        ..Default::default()
     }]
 }
 
@@ -24,6 +24,8 @@ pub struct EncodingResolution {
 ///
 /// Tries default_call first, then alternate_encodings.
 /// Returns the first legal call found, or the default if all are illegal.
+/// A relay-encoded default call is traced as `RelayMap`: the call is a
+/// puppet and the meaning lands on the relay's continuation.
 pub fn resolve_encoding(
     encoding: &BidEncoding,
     is_legal: &dyn Fn(&Call) -> bool,
@@ -57,10 +59,12 @@ pub fn resolve_encoding(
                 is_default,
                 all_encodings,
                 trace: EncodingTrace {
-                    encoder_kind: if is_default {
-                        EncoderKind::DefaultCall
-                    } else {
+                    encoder_kind: if !is_default {
                         EncoderKind::AlternateEncoding
+                    } else if encoding.relay.is_some() {
+                        EncoderKind::RelayMap
+                    } else {
+                        EncoderKind::DefaultCall
                     },
                     considered_calls: None,
                     blocked_calls: None,
@@ -302,6 +302,7 @@ mod tests {
                     encoding: BidEncoding {
                         default_call: call.clone(),
                         alternate_encodings: None,
+                        relay: None,
                     },
                 },
                 call: call.clone(),
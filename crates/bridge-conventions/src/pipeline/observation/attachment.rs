@@ -0,0 +1,397 @@
+//! Host attachments — when an add-on module is live.
+//!
+//! A module's host attachments (`bundle_metadata.attachments`, falling back
+//! to the top-level `attachments`) describe the auction it rides on. The
+//! module is *attached* once some prefix of the auction satisfies one of
+//! them and stays attached for the rest of the auction, so an overcall after
+//! partner's 1♣ does not detach Negative Doubles. Until then it is dormant:
+//!
+//! - its FSM holds at `local.initial` (`advance_module_phase`), and
+//! - an initial-phase surface is offered only if its own call attaches the
+//!   module (`surface_attaches`), which keeps host-entry surfaces such as
+//!   `negdbl:partner-opens-1c` available.
+//!
+//! Within one attachment `when_auction`, `when_public` and
+//! `requires_capabilities` are AND-ed; a module with several attachments
+//! attaches when any of them holds.
+
+use bridge_engine::types::Call;
+use std::collections::HashSet;
+
+use crate::fact_dsl::witness::call_to_short_label;
+use crate::pipeline::observation::committed_step::CommittedStep;
+use crate::pipeline::observation::local_fsm::advance_local_fsm;
+use crate::types::agreement::{Attachment, AuctionPattern, GuardOperator, GuardValue, PublicGuard};
+use crate::types::module_types::ConventionModule;
+use crate::types::negotiation::{Competition, NegotiationState};
+
+/// One auction call as seen by attachment matching.
+#[derive(Debug, Clone)]
+pub struct AttachmentStep<'a> {
+    /// Bridge-notation label ("1NT", "P"). `None` is an unresolved witness
+    /// pattern step and matches any call; deal gating re-checks the
+    /// concrete auction through the live adapter.
+    pub label: Option<String>,
+    /// Public negotiation state after the call. `None` when the caller has
+    /// no replayed state, in which case public guards are not evaluated.
+    pub state_after: Option<&'a NegotiationState>,
+}
+
+impl<'a> AttachmentStep<'a> {
+    pub fn call(call: &Call, state_after: Option<&'a NegotiationState>) -> Self {
+        Self {
+            label: Some(call_to_short_label(call)),
+            state_after,
+        }
+    }
+}
+
+/// Attachment steps for an observation log.
+pub fn log_steps(log: &[CommittedStep]) -> Vec<AttachmentStep<'_>> {
+    log.iter()
+        .map(|step| AttachmentStep::call(&step.call, Some(&step.state_after)))
+        .collect()
+}
+
+/// The attachments a module activates on: the authored bundle metadata,
+/// else the top-level field. Empty means always active.
+pub fn host_attachments(module: &ConventionModule) -> &[Attachment] {
+    if !module.bundle_metadata.attachments.is_empty() {
+        &module.bundle_metadata.attachments
+    } else {
+        &module.attachments
+    }
+}
+
+/// Capabilities declared by the loaded module set, checked against
+/// `Attachment::requires_capabilities`.
+pub fn provided_capabilities<'a>(
+    modules: impl IntoIterator<Item = &'a ConventionModule>,
+) -> HashSet<&'a str> {
+    modules
+        .into_iter()
+        .flat_map(|m| m.bundle_metadata.declared_capabilities.keys())
+        .map(String::as_str)
+        .collect()
+}
+
+/// True if some prefix of `steps` attaches `module`.
+pub fn is_attached(
+    module: &ConventionModule,
+    steps: &[AttachmentStep<'_>],
+    capabilities: &HashSet<&str>,
+) -> bool {
+    let attachments = host_attachments(module);
+    attachments.is_empty()
+        || attachments
+            .iter()
+            .any(|att| attachment_holds(att, steps, capabilities))
+}
+
+/// True if bidding `call` after `steps` leaves `module` attached. Gates a
+/// dormant module's initial-phase surfaces: a surface that makes the host
+/// call itself stays available.
+pub fn surface_attaches(
+    module: &ConventionModule,
+    steps: &[AttachmentStep<'_>],
+    call: &Call,
+    capabilities: &HashSet<&str>,
+) -> bool {
+    if is_attached(module, steps, capabilities) {
+        return true;
+    }
+    let state = steps.last().and_then(|s| s.state_after);
+    let mut extended = steps.to_vec();
+    extended.push(AttachmentStep::call(call, state));
+    is_attached(module, &extended, capabilities)
+}
+
+/// Advance `module`'s FSM by one step, holding a dormant module at its
+/// initial phase unless the step (with everything before it) attaches it.
+pub fn advance_module_phase(
+    module: &ConventionModule,
+    current_phase: &str,
+    step: &CommittedStep,
+    prior_log: &[CommittedStep],
+    capabilities: &HashSet<&str>,
+) -> String {
+    let next = advance_local_fsm(current_phase, step, prior_log, &module.local.transitions);
+    if next == current_phase
+        || current_phase != module.local.initial
+        || host_attachments(module).is_empty()
+    {
+        return next;
+    }
+    let mut steps = log_steps(prior_log);
+    steps.push(AttachmentStep::call(&step.call, Some(&step.state_after)));
+    if is_attached(module, &steps, capabilities) {
+        next
+    } else {
+        current_phase.to_string()
+    }
+}
+
+fn attachment_holds(
+    att: &Attachment,
+    steps: &[AttachmentStep<'_>],
+    capabilities: &HashSet<&str>,
+) -> bool {
+    if let Some(required) = &att.requires_capabilities {
+        if !required.iter().all(|c| capabilities.contains(c.as_str())) {
+            return false;
+        }
+    }
+    if att.when_auction.is_none() && att.when_public.is_none() {
+        return true;
+    }
+    (1..=steps.len()).any(|end| {
+        let prefix = &steps[..end];
+        att.when_auction
+            .as_ref()
+            .is_none_or(|pattern| prefix_ends_with(prefix, pattern_calls(pattern)))
+            && att.when_public.as_ref().is_none_or(|guard| {
+                prefix
+                    .last()
+                    .and_then(|s| s.state_after)
+                    .is_none_or(|state| guard_holds(guard, state))
+            })
+    })
+}
+
+/// Calls an auction pattern anchors on. Every prefix of the auction is
+/// tried, so `contains` and `by-role` reduce to a one-call sequence; role
+/// qualifiers are not evaluated.
+fn pattern_calls(pattern: &AuctionPattern) -> &[String] {
+    match pattern {
+        AuctionPattern::Sequence { calls } => calls,
+        AuctionPattern::Contains { call, .. } => std::slice::from_ref(call),
+        AuctionPattern::ByRole { last_call, .. } => std::slice::from_ref(last_call),
+    }
+}
+
+fn prefix_ends_with(prefix: &[AttachmentStep<'_>], calls: &[String]) -> bool {
+    if calls.len() > prefix.len() {
+        return false;
+    }
+    prefix[prefix.len() - calls.len()..]
+        .iter()
+        .zip(calls)
+        .all(|(step, call)| step.label.as_ref().is_none_or(|label| label == call))
+}
+
+/// Evaluate a public guard against a negotiation state. Fields use the
+/// serialized state names ("game", "hearts", "uncontested").
+fn guard_holds(guard: &PublicGuard, state: &NegotiationState) -> bool {
+    let field_value = match guard.field.as_str() {
+        "forcingState" => serde_name(&state.forcing),
+        "fitAgreed" => state
+            .fit_agreed
+            .as_ref()
+            .and_then(|f| serde_name(&f.strain)),
+        "captain" => serde_name(&state.captain),
+        "competition" => match &state.competition {
+            Competition::Simple(simple) => serde_name(simple),
+            Competition::Overcalled(data) => serde_name(&data.kind),
+        },
+        _ => None,
+    };
+
+    match guard.operator {
+        GuardOperator::Exists => field_value.is_some(),
+        GuardOperator::Eq => match (&field_value, &guard.value) {
+            (Some(actual), Some(GuardValue::Scalar(expected))) => actual == expected,
+            _ => false,
+        },
+        GuardOperator::Neq => match (&field_value, &guard.value) {
+            (Some(actual), Some(GuardValue::Scalar(expected))) => actual != expected,
+            _ => true,
+        },
+        GuardOperator::In => match (&field_value, &guard.value) {
+            (Some(actual), Some(GuardValue::List(list))) => list.contains(actual),
+            _ => false,
+        },
+    }
+}
+
+fn serde_name<T: serde::Serialize>(value: &T) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::observation::committed_step::{initial_negotiation, CommittedStepStatus};
+    use crate::registry::module_registry::get_module;
+    use crate::types::bid_action::{BidAction, BidSuitName};
+    use crate::types::negotiation::{NegotiationDelta, OvercalledData, OvercalledKind};
+    use crate::types::system_config::BaseSystemId;
+    use bridge_engine::types::{BidSuit, Seat};
+
+    fn module(id: &str) -> &'static ConventionModule {
+        get_module(id, BaseSystemId::Sayc).expect("module should exist")
+    }
+
+    fn labels(calls: &[&str]) -> Vec<AttachmentStep<'static>> {
+        calls
+            .iter()
+            .map(|c| AttachmentStep {
+                label: Some(c.to_string()),
+                state_after: None,
+            })
+            .collect()
+    }
+
+    fn sequence(calls: &[&str]) -> Attachment {
+        Attachment {
+            when_auction: Some(AuctionPattern::Sequence {
+                calls: calls.iter().map(|c| c.to_string()).collect(),
+            }),
+            when_public: None,
+            requires_capabilities: None,
+            requires_visible_meanings: None,
+        }
+    }
+
+    fn bid(level: u8, strain: BidSuit) -> Call {
+        Call::Bid { level, strain }
+    }
+
+    fn step(actor: Seat, call: Call, public_actions: Vec<BidAction>) -> CommittedStep {
+        CommittedStep {
+            actor,
+            call,
+            resolved_claim: None,
+            public_actions,
+            negotiation_delta: NegotiationDelta::default(),
+            state_after: initial_negotiation(),
+            status: CommittedStepStatus::Resolved,
+        }
+    }
+
+    #[test]
+    fn sequence_matches_bridge_notation() {
+        // Regression: calls were once compared by their Debug output, so
+        // "1NT" never matched and no whenAuction module ever attached.
+        let caps = HashSet::new();
+        let steps = [AttachmentStep::call(&bid(1, BidSuit::NoTrump), None)];
+        assert!(attachment_holds(&sequence(&["1NT"]), &steps, &caps));
+    }
+
+    #[test]
+    fn sequence_2c_matches_only_2c() {
+        let caps = HashSet::new();
+        let steps = [AttachmentStep::call(&bid(2, BidSuit::Clubs), None)];
+        assert!(attachment_holds(&sequence(&["2C"]), &steps, &caps));
+        assert!(!attachment_holds(&sequence(&["3C"]), &steps, &caps));
+    }
+
+    #[test]
+    fn attachment_is_sticky_after_interference() {
+        let caps = HashSet::new();
+        assert!(is_attached(
+            module("negative-doubles"),
+            &labels(&["1C", "1S"]),
+            &caps
+        ));
+        assert!(!is_attached(
+            module("negative-doubles"),
+            &labels(&["1NT"]),
+            &caps
+        ));
+    }
+
+    #[test]
+    fn host_call_attaches_a_dormant_module() {
+        let caps = HashSet::new();
+        let negdbl = module("negative-doubles");
+        assert!(surface_attaches(
+            negdbl,
+            &[],
+            &bid(1, BidSuit::Clubs),
+            &caps
+        ));
+        assert!(!surface_attaches(
+            negdbl,
+            &[],
+            &bid(1, BidSuit::NoTrump),
+            &caps
+        ));
+    }
+
+    #[test]
+    fn missing_capability_keeps_module_dormant() {
+        let stayman = module("stayman");
+        let steps = labels(&["1NT"]);
+        assert!(!is_attached(stayman, &steps, &HashSet::new()));
+        assert!(is_attached(
+            stayman,
+            &steps,
+            &HashSet::from(["opening.1nt"])
+        ));
+    }
+
+    #[test]
+    fn public_guard_reads_the_state_at_the_host_call() {
+        let caps = HashSet::new();
+        let fsf = module("fourth-suit-forcing");
+        let quiet = initial_negotiation();
+        let contested = NegotiationState {
+            competition: Competition::Overcalled(OvercalledData {
+                kind: OvercalledKind::Overcalled,
+                strain: BidSuitName::Diamonds,
+                level: 1,
+            }),
+            ..initial_negotiation()
+        };
+        let host = |state| -> Vec<AttachmentStep<'_>> {
+            ["1C", "P", "1H", "P", "1S"]
+                .iter()
+                .map(|c| AttachmentStep {
+                    label: Some(c.to_string()),
+                    state_after: Some(state),
+                })
+                .collect()
+        };
+        assert!(is_attached(fsf, &host(&quiet), &caps));
+        assert!(!is_attached(fsf, &host(&contested), &caps));
+    }
+
+    #[test]
+    fn drury_needs_two_passes_before_the_opening() {
+        let caps = HashSet::new();
+        let drury = module("drury");
+        assert!(!is_attached(drury, &labels(&["1H"]), &caps));
+        assert!(!is_attached(drury, &labels(&["P", "1H"]), &caps));
+        assert!(is_attached(drury, &labels(&["P", "P", "1H"]), &caps));
+        assert!(is_attached(drury, &labels(&["P", "P", "P", "1S"]), &caps));
+    }
+
+    #[test]
+    fn dormant_fsm_holds_its_initial_phase() {
+        let caps = HashSet::new();
+        let drury = module("drury");
+        let open_1h = step(
+            Seat::North,
+            bid(1, BidSuit::Hearts),
+            vec![BidAction::Open {
+                strain: BidSuitName::Hearts,
+                strength: None,
+            }],
+        );
+        assert_eq!(
+            advance_module_phase(drury, "idle", &open_1h, &[], &caps),
+            "idle"
+        );
+
+        let passes = [
+            step(Seat::South, Call::Pass, vec![BidAction::Pass]),
+            step(Seat::West, Call::Pass, vec![BidAction::Pass]),
+        ];
+        assert_eq!(
+            advance_module_phase(drury, "idle", &open_1h, &passes, &caps),
+            "opened-1h"
+        );
+    }
+}
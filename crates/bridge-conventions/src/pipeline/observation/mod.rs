@@ -3,11 +3,13 @@
 //! Replays local FSMs, matches routes and negotiation predicates,
 //! and builds the observation log from auction history.
 
+pub mod attachment;
 pub mod committed_step;
 pub mod local_fsm;
 pub mod negotiation_extractor;
 pub mod negotiation_matcher;
 pub mod normalize_intent;
 pub mod public_commitments;
+pub mod relay;
 pub mod route_matcher;
 pub mod rule_interpreter;
@@ -107,7 +107,7 @@ pub fn apply_negotiation_actions(
             BidAction::Redouble { .. } => {
                 state.competition = Competition::Simple(CompetitionSimple::Redoubled);
             }
-            // Show, Deny, Inquire, Decline, Relay, Pass — no negotiation state change
+            // Show, Deny, Inquire, Decline, Relay, Puppet, Pass — no negotiation state change
             _ => {}
         }
     }
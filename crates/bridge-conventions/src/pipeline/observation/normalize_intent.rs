@@ -211,6 +211,14 @@ pub fn normalize_intent(intent: &SourceIntent) -> Vec<BidAction> {
             strain: param_strain(p).unwrap_or(BidSuitName::Spades),
             strength: None,
         }],
+        "JacobyTwoNotrump" => vec![
+            BidAction::Agree {
+                strain: param_strain(p).unwrap_or(BidSuitName::Notrump),
+            },
+            BidAction::Force {
+                level: HandStrength::Game,
+            },
+        ],
 
         // ── Bergen ───────────────────────────────────────────────────
         "Splinter" => vec![
@@ -463,7 +471,7 @@ pub fn normalize_intent(intent: &SourceIntent) -> Vec<BidAction> {
         }],
 
         // ── Weak Twos ────────────────────────────────────────────────
-        "WeakTwoOpen" => vec![BidAction::Open {
+        "WeakTwoOpen" | "PreemptOpen" => vec![BidAction::Open {
             strain: param_strain(p).unwrap_or(BidSuitName::Diamonds),
             strength: Some(HandStrength::Weak),
         }],
@@ -942,6 +950,102 @@ pub fn normalize_intent(intent: &SourceIntent) -> Vec<BidAction> {
         }],
         "NegDblOpenerPass" => vec![BidAction::Pass],
 
+        // ── Lebensohl ────────────────────────────────────────────
+        "LebensohlOpponentOvercall" => vec![BidAction::Overcall {
+            feature: HandFeature::HeldSuit,
+            suit: param_suit(p),
+        }],
+        "LebensohlCompete" => vec![BidAction::Show {
+            feature: HandFeature::HeldSuit,
+            suit: param_suit(p),
+            quality: None,
+            strength: None,
+        }],
+        "LebensohlGameForce" => vec![
+            BidAction::Show {
+                feature: HandFeature::HeldSuit,
+                suit: param_suit(p),
+                quality: None,
+                strength: None,
+            },
+            BidAction::Force {
+                level: HandStrength::Game,
+            },
+        ],
+        "LebensohlSignoff" => vec![BidAction::Signoff {
+            strain: param_strain(p),
+        }],
+        "LebensohlFastNT" => vec![
+            BidAction::Place {
+                strain: BidSuitName::Notrump,
+            },
+            BidAction::Deny {
+                feature: HandFeature::Stopper,
+                suit: param_suit(p),
+            },
+        ],
+        "LebensohlSlowNT" => vec![
+            BidAction::Place {
+                strain: BidSuitName::Notrump,
+            },
+            BidAction::Show {
+                feature: HandFeature::Stopper,
+                suit: param_suit(p),
+                quality: None,
+                strength: None,
+            },
+        ],
+        "LebensohlPass" => vec![BidAction::Pass],
+
+        // ── Drury ────────────────────────────────────────────────
+        "DruryAsk" => vec![
+            BidAction::Show {
+                feature: HandFeature::Fit,
+                suit: param_suit(p),
+                quality: None,
+                strength: Some(HandStrength::Limit),
+            },
+            BidAction::Inquire {
+                feature: HandFeature::Strength,
+                suit: None,
+            },
+        ],
+        "DrurySignoff" => vec![BidAction::Signoff {
+            strain: param_strain(p),
+        }],
+        "DruryGame" => vec![BidAction::Raise {
+            strain: param_strain(p).unwrap_or(BidSuitName::Notrump),
+            strength: HandStrength::Game,
+        }],
+
+        // ── Fourth Suit Forcing ──────────────────────────────────
+        "FourthSuitForcing" => vec![
+            BidAction::Inquire {
+                feature: HandFeature::Stopper,
+                suit: param_suit(p),
+            },
+            BidAction::Force {
+                level: HandStrength::Game,
+            },
+        ],
+        "FSFOpenerSupport" => vec![BidAction::Show {
+            feature: HandFeature::Fit,
+            suit: param_suit(p),
+            quality: None,
+            strength: None,
+        }],
+        "FSFOpenerNotrump" => vec![
+            BidAction::Place {
+                strain: BidSuitName::Notrump,
+            },
+            BidAction::Show {
+                feature: HandFeature::Stopper,
+                suit: param_suit(p),
+                quality: None,
+                strength: None,
+            },
+        ],
+
         // ── New Minor Forcing ────────────────────────────────────
         "NMFAsk" => vec![BidAction::Inquire {
             feature: HandFeature::MajorSuit,
@@ -1046,6 +1150,10 @@ pub fn normalize_intent(intent: &SourceIntent) -> Vec<BidAction> {
             strain: Some(BidSuitName::Notrump),
         }],
 
+        // ── Relay completion ─────────────────────────────────────
+        // Synthesized by `relay::relay_turn_claims` for the forced reply.
+        "RelayForcedReply" => vec![BidAction::Relay { forced: true }],
+
         // Unknown intent — graceful degradation
         _ => Vec::new(),
     }
@@ -1101,6 +1209,16 @@ mod tests {
         assert_eq!(result[0].suit(), Some(&ObsSuit::Spades));
     }
 
+    #[test]
+    fn jacoby_two_notrump_agrees_and_forces_game() {
+        let result = normalize_intent(&intent_with_suit("JacobyTwoNotrump", "hearts"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(*result[0].act(), BidActionType::Agree);
+        assert_eq!(result[0].strain(), Some(&BidSuitName::Hearts));
+        assert_eq!(*result[1].act(), BidActionType::Force);
+        assert_eq!(result[1].strength(), Some(&HandStrength::Game));
+    }
+
     #[test]
     fn unknown_intent() {
         let result = normalize_intent(&intent("UnknownIntent"));
@@ -1151,4 +1269,40 @@ mod tests {
         assert_eq!(result[0].strain(), Some(&BidSuitName::Notrump));
         assert_eq!(result[0].strength(), Some(&HandStrength::Invitational));
     }
+
+    #[test]
+    fn lebensohl_slow_nt_places_notrump_and_shows_stopper() {
+        let result = normalize_intent(&intent_with_suit("LebensohlSlowNT", "hearts"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(*result[0].act(), BidActionType::Place);
+        assert_eq!(*result[1].act(), BidActionType::Show);
+        assert_eq!(result[1].feature(), Some(&HandFeature::Stopper));
+        assert_eq!(result[1].suit(), Some(&ObsSuit::Hearts));
+    }
+
+    #[test]
+    fn drury_ask_shows_fit_and_asks_strength() {
+        let result = normalize_intent(&intent_with_suit("DruryAsk", "spades"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(*result[0].act(), BidActionType::Show);
+        assert_eq!(result[0].feature(), Some(&HandFeature::Fit));
+        assert_eq!(result[0].suit(), Some(&ObsSuit::Spades));
+        assert_eq!(*result[1].act(), BidActionType::Inquire);
+    }
+
+    #[test]
+    fn fourth_suit_forcing_asks_stopper_and_forces_game() {
+        let result = normalize_intent(&intent_with_suit("FourthSuitForcing", "diamonds"));
+        assert_eq!(result.len(), 2);
+        assert_eq!(*result[0].act(), BidActionType::Inquire);
+        assert_eq!(result[0].suit(), Some(&ObsSuit::Diamonds));
+        assert_eq!(*result[1].act(), BidActionType::Force);
+        assert_eq!(result[1].strength(), Some(&HandStrength::Game));
+    }
+
+    #[test]
+    fn relay_forced_reply_is_forced_relay() {
+        let result = normalize_intent(&intent("RelayForcedReply"));
+        assert_eq!(result, vec![BidAction::Relay { forced: true }]);
+    }
 }
@@ -0,0 +1,354 @@
+//! Relay (puppet) encodings — observation and follow-up turns.
+//!
+//! A surface whose `BidEncoding.relay` is set is bid with `default_call`,
+//! forces partner to `relay.forced_reply`, and carries its meaning on the
+//! relay bidder's `relay.continuation`. Neither follow-up turn is authored
+//! in the module's FSM: `relay_turn_claims` synthesizes partner's forced
+//! reply, then re-encodes the relay surfaces onto their continuations for
+//! the relay bidder's next turn.
+
+use bridge_engine::partner_seat;
+use bridge_engine::types::{BidSuit, Call, Seat};
+use std::collections::HashMap;
+
+use crate::fact_dsl::witness::call_to_short_label;
+use crate::pipeline::observation::committed_step::CommittedStep;
+use crate::pipeline::observation::normalize_intent::normalize_intent;
+use crate::pipeline::observation::rule_interpreter::ModuleSurfaceResult;
+use crate::types::authored_text::{BidName, BidSummary, TeachingLabel};
+use crate::types::bid_action::{BidAction, BidSuitName};
+use crate::types::meaning::{
+    AuthoredRankingMetadata, BidEncoding, BidMeaning, Disclosure, RecommendationBand,
+    RelayEncoding, SourceIntent,
+};
+use crate::types::module_types::ConventionModule;
+use crate::types::rule_types::{ResolvedSurface, StateEntry};
+
+/// Source intent of the synthesized forced reply.
+pub const RELAY_FORCED_REPLY_INTENT: &str = "RelayForcedReply";
+
+/// Public actions observed when `call` is made under `encoding`.
+///
+/// The relay call itself is a puppet: the authored intent describes the
+/// continuation and is only observed once the continuation is bid.
+pub fn observed_actions(
+    encoding: &BidEncoding,
+    source_intent: &SourceIntent,
+    call: &Call,
+) -> Vec<BidAction> {
+    if let Some(relay) = encoding.relay.as_ref() {
+        if &encoding.default_call == call {
+            if let Call::Bid { strain, .. } = relay.forced_reply {
+                return vec![BidAction::Puppet {
+                    strain: bid_suit_name(strain),
+                }];
+            }
+        }
+    }
+    normalize_intent(source_intent)
+}
+
+/// Claims for a relay follow-up turn, or `None` when `next_seat` is not
+/// completing or continuing a relay.
+///
+/// - **Forced reply:** partner's last bid was a relay call → the only claim
+///   is the synthesized `forced_reply` surface.
+/// - **Continuation:** partner's last bid was the forced reply to our own
+///   relay → the relay surfaces sharing that relay, re-encoded onto their
+///   continuations.
+///
+/// Passes are skipped when locating the last bids, so an opponent's pass
+/// keeps the relay on while any other intervening call switches it off.
+pub fn relay_turn_claims(
+    modules: &[ConventionModule],
+    log: &[CommittedStep],
+    next_seat: Seat,
+) -> Option<ModuleSurfaceResult> {
+    let mut bids = log.iter().rev().filter(|step| step.call != Call::Pass);
+    let last = bids.next()?;
+    if last.actor != partner_seat(next_seat) {
+        return None;
+    }
+
+    if let Some((module_id, _, surface)) = find_relay_surface(modules, last) {
+        let relay = surface.encoding.relay.as_ref()?;
+        return Some(ModuleSurfaceResult {
+            module_id: module_id.to_string(),
+            resolved: vec![ResolvedSurface {
+                surface: forced_reply_surface(module_id, relay),
+                negotiation_delta: None,
+            }],
+        });
+    }
+
+    let own = bids.next()?;
+    if own.actor != next_seat {
+        return None;
+    }
+    let (module_id, entry, surface) = find_relay_surface(modules, own)?;
+    let relay = surface.encoding.relay.as_ref()?;
+    if relay.forced_reply != last.call {
+        return None;
+    }
+
+    let resolved: Vec<ResolvedSurface> = entry
+        .surfaces
+        .iter()
+        .filter_map(|sibling| {
+            let sibling_relay = sibling.encoding.relay.as_ref()?;
+            if sibling.encoding.default_call != own.call
+                || sibling_relay.forced_reply != relay.forced_reply
+            {
+                return None;
+            }
+            let mut continued = sibling.clone();
+            continued.encoding = BidEncoding {
+                default_call: sibling_relay.continuation.clone(),
+                alternate_encodings: None,
+                relay: None,
+            };
+            Some(ResolvedSurface {
+                surface: continued,
+                negotiation_delta: entry.negotiation_delta.clone(),
+            })
+        })
+        .collect();
+
+    Some(ModuleSurfaceResult {
+        module_id: module_id.to_string(),
+        resolved,
+    })
+}
+
+/// Locate the relay-encoded surface behind a committed step: the claimed
+/// meaning must carry a relay and the step's call must be its relay call.
+fn find_relay_surface<'m>(
+    modules: &'m [ConventionModule],
+    step: &CommittedStep,
+) -> Option<(&'m str, &'m StateEntry, &'m BidMeaning)> {
+    let claim = step.resolved_claim.as_ref()?;
+    let module = modules.iter().find(|m| m.module_id == claim.module_id)?;
+    module.states.as_ref()?.iter().find_map(|entry| {
+        entry
+            .surfaces
+            .iter()
+            .find(|s| {
+                s.meaning_id == claim.meaning_id
+                    && s.encoding.relay.is_some()
+                    && s.encoding.default_call == step.call
+            })
+            .map(|s| (module.module_id.as_str(), entry, s))
+    })
+}
+
+/// The forced reply to a relay: a must-bid with no hand requirements.
+fn forced_reply_surface(module_id: &str, relay: &RelayEncoding) -> BidMeaning {
+    let label = call_to_short_label(&relay.forced_reply);
+    BidMeaning {
+        meaning_id: format!("{module_id}:forced-relay-{}", label.to_lowercase()),
+        semantic_class_id: format!("{module_id}:forced-relay"),
+        module_id: Some(module_id.to_string()),
+        encoding: BidEncoding {
+            default_call: relay.forced_reply.clone(),
+            alternate_encodings: None,
+            relay: None,
+        },
+        clauses: Vec::new(),
+        ranking: AuthoredRankingMetadata {
+            recommendation_band: RecommendationBand::Must,
+            module_precedence: Some(0),
+            declaration_order: 0,
+        },
+        source_intent: SourceIntent {
+            intent_type: RELAY_FORCED_REPLY_INTENT.to_string(),
+            params: HashMap::new(),
+        },
+        disclosure: Disclosure::Alert,
+        teaching_label: TeachingLabel {
+            name: BidName::new(format!("{label} \u{2014} forced relay")),
+            summary: BidSummary::new(
+                "Complete partner's relay; partner's next call describes the hand",
+            ),
+        },
+        surface_bindings: None,
+    }
+}
+
+fn bid_suit_name(strain: BidSuit) -> BidSuitName {
+    match strain {
+        BidSuit::Clubs => BidSuitName::Clubs,
+        BidSuit::Diamonds => BidSuitName::Diamonds,
+        BidSuit::Hearts => BidSuitName::Hearts,
+        BidSuit::Spades => BidSuitName::Spades,
+        BidSuit::NoTrump => BidSuitName::Notrump,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::observation::committed_step::{
+        initial_negotiation, ClaimRef, CommittedStepStatus,
+    };
+    use crate::registry::module_registry::get_module;
+    use crate::types::negotiation::NegotiationDelta;
+    use crate::types::system_config::BaseSystemId;
+
+    fn lebensohl() -> Vec<ConventionModule> {
+        vec![get_module("lebensohl", BaseSystemId::Sayc)
+            .expect("lebensohl module should exist")
+            .clone()]
+    }
+
+    fn bid(level: u8, strain: BidSuit) -> Call {
+        Call::Bid { level, strain }
+    }
+
+    fn step(actor: Seat, call: Call, meaning_id: Option<&str>) -> CommittedStep {
+        CommittedStep {
+            actor,
+            call,
+            resolved_claim: meaning_id.map(|id| ClaimRef {
+                module_id: "lebensohl".to_string(),
+                meaning_id: id.to_string(),
+                semantic_class_id: String::new(),
+                source_intent: SourceIntent {
+                    intent_type: String::new(),
+                    params: HashMap::new(),
+                },
+            }),
+            public_actions: Vec::new(),
+            negotiation_delta: NegotiationDelta::default(),
+            state_after: initial_negotiation(),
+            status: CommittedStepStatus::Resolved,
+        }
+    }
+
+    /// 1NT (N) – 2H (E) – 2NT relay (S) – P (W).
+    fn relayed_log() -> Vec<CommittedStep> {
+        vec![
+            step(Seat::North, bid(1, BidSuit::NoTrump), None),
+            step(Seat::East, bid(2, BidSuit::Hearts), None),
+            step(
+                Seat::South,
+                bid(2, BidSuit::NoTrump),
+                Some("lebensohl:relay-signoff-diamonds-over-2h"),
+            ),
+            step(Seat::West, Call::Pass, None),
+        ]
+    }
+
+    fn calls(result: &ModuleSurfaceResult) -> Vec<(&str, Call)> {
+        result
+            .resolved
+            .iter()
+            .map(|r| {
+                (
+                    r.surface.meaning_id.as_str(),
+                    r.surface.encoding.default_call.clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn relay_call_is_observed_as_puppet() {
+        let modules = lebensohl();
+        let surface = modules[0]
+            .states
+            .iter()
+            .flatten()
+            .flat_map(|entry| &entry.surfaces)
+            .find(|s| s.meaning_id == "lebensohl:slow-3nt-over-2h")
+            .unwrap();
+        let actions = observed_actions(
+            &surface.encoding,
+            &surface.source_intent,
+            &bid(2, BidSuit::NoTrump),
+        );
+        assert_eq!(
+            actions,
+            vec![BidAction::Puppet {
+                strain: BidSuitName::Clubs
+            }]
+        );
+
+        // The continuation carries the authored meaning.
+        let actions = observed_actions(
+            &surface.encoding,
+            &surface.source_intent,
+            &bid(3, BidSuit::NoTrump),
+        );
+        assert_eq!(actions, normalize_intent(&surface.source_intent));
+    }
+
+    #[test]
+    fn partner_of_relay_bidder_must_complete_the_relay() {
+        let result = relay_turn_claims(&lebensohl(), &relayed_log(), Seat::North)
+            .expect("opener owes the forced reply");
+        assert_eq!(
+            calls(&result),
+            vec![("lebensohl:forced-relay-3c", bid(3, BidSuit::Clubs))]
+        );
+        assert_eq!(
+            result.resolved[0].surface.ranking.recommendation_band,
+            RecommendationBand::Must
+        );
+        assert_eq!(
+            normalize_intent(&result.resolved[0].surface.source_intent),
+            vec![BidAction::Relay { forced: true }]
+        );
+    }
+
+    #[test]
+    fn relay_bidder_continues_with_the_relay_surfaces() {
+        let mut log = relayed_log();
+        log.push(step(
+            Seat::North,
+            bid(3, BidSuit::Clubs),
+            Some("lebensohl:forced-relay-3c"),
+        ));
+        log.push(step(Seat::East, Call::Pass, None));
+
+        let result = relay_turn_claims(&lebensohl(), &log, Seat::South)
+            .expect("responder continues after the forced reply");
+        assert_eq!(
+            calls(&result),
+            vec![
+                ("lebensohl:relay-signoff-clubs-over-2h", Call::Pass),
+                (
+                    "lebensohl:relay-signoff-diamonds-over-2h",
+                    bid(3, BidSuit::Diamonds)
+                ),
+                ("lebensohl:slow-3nt-over-2h", bid(3, BidSuit::NoTrump)),
+            ]
+        );
+        assert!(result
+            .resolved
+            .iter()
+            .all(|r| r.surface.encoding.relay.is_none()));
+    }
+
+    #[test]
+    fn opponent_bid_over_the_relay_switches_it_off() {
+        let mut log = relayed_log();
+        log.pop();
+        log.push(step(Seat::West, bid(3, BidSuit::Hearts), None));
+        assert!(relay_turn_claims(&lebensohl(), &log, Seat::North).is_none());
+    }
+
+    #[test]
+    fn non_relay_calls_have_no_follow_up_turn() {
+        let log = vec![
+            step(Seat::North, bid(1, BidSuit::NoTrump), None),
+            step(Seat::East, bid(2, BidSuit::Hearts), None),
+            step(
+                Seat::South,
+                bid(3, BidSuit::NoTrump),
+                Some("lebensohl:fast-3nt-over-2h"),
+            ),
+            step(Seat::West, Call::Pass, None),
+        ];
+        assert!(relay_turn_claims(&lebensohl(), &log, Seat::North).is_none());
+    }
+}
@@ -2,15 +2,20 @@
 //!
 //! Mirrors TS from `pipeline/observation/rule-interpreter.ts`.
 
+use bridge_engine::auction::is_legal_call;
 use bridge_engine::partner_seat;
-use bridge_engine::types::Seat;
-use std::collections::HashMap;
+use bridge_engine::types::{Auction, AuctionEntry, Seat};
+use std::collections::{HashMap, HashSet};
 
+use crate::pipeline::observation::attachment::{
+    advance_module_phase, is_attached, log_steps, provided_capabilities, surface_attaches,
+    AttachmentStep,
+};
 use crate::pipeline::observation::committed_step::{
     AuctionContext, CommittedStep, CommittedStepStatus,
 };
-use crate::pipeline::observation::local_fsm::advance_local_fsm;
 use crate::pipeline::observation::negotiation_matcher::match_kernel;
+use crate::pipeline::observation::relay::relay_turn_claims;
 use crate::pipeline::observation::route_matcher::match_route;
 use crate::types::meaning::BidMeaning;
 use crate::types::module_types::ConventionModule;
@@ -53,24 +58,28 @@ pub fn derive_turn_role(next_seat: Seat, log: &[CommittedStep]) -> TurnRole {
 }
 
 /// Collect all matching claims from convention modules against the current auction context.
+///
+/// A relay follow-up turn (forced reply or continuation) pre-empts the
+/// per-module FSM surfaces; see `relay::relay_turn_claims`.
 pub fn collect_matching_claims(
     modules: &[ConventionModule],
     context: &AuctionContext,
     next_seat: Option<Seat>,
 ) -> Vec<ModuleSurfaceResult> {
+    if let Some(relay) = next_seat.and_then(|s| relay_turn_claims(modules, &context.log, s)) {
+        return vec![relay];
+    }
+
     let current_kernel = get_current_kernel(context);
     let turn_role = next_seat.map(|s| derive_turn_role(s, &context.log));
     let opener_seat = find_opener_seat(&context.log);
+    let capabilities = provided_capabilities(modules);
+    let steps = log_steps(&context.log);
 
     let mut results = Vec::new();
     for module in modules {
-        // Skip modules whose attachment conditions aren't met
-        if !module.attachments.is_empty() && !check_attachments(&module.attachments, context) {
-            continue;
-        }
-
-        let current_phase = replay_local_fsm(module, context);
-        let resolved = collect_module_surfaces(
+        let current_phase = replay_local_fsm(module, context, &capabilities);
+        let mut resolved = collect_module_surfaces(
             module,
             &current_phase,
             &current_kernel,
@@ -78,6 +87,15 @@ pub fn collect_matching_claims(
             turn_role,
             opener_seat,
         );
+        retain_attaching_surfaces(
+            module,
+            &current_phase,
+            context,
+            next_seat,
+            &steps,
+            &capabilities,
+            &mut resolved,
+        );
 
         if !resolved.is_empty() {
             results.push(ModuleSurfaceResult {
@@ -90,134 +108,6 @@ pub fn collect_matching_claims(
     results
 }
 
-/// Check if a module's attachment conditions are satisfied by the current context.
-/// Returns true if ANY attachment is satisfied (OR semantics — any trigger fires the module).
-fn check_attachments(
-    attachments: &[crate::types::agreement::Attachment],
-    context: &AuctionContext,
-) -> bool {
-    attachments
-        .iter()
-        .any(|att| check_single_attachment(att, context))
-}
-
-/// Check a single attachment predicate against the auction context.
-fn check_single_attachment(
-    att: &crate::types::agreement::Attachment,
-    context: &AuctionContext,
-) -> bool {
-    // Check auction pattern if specified
-    if let Some(ref pattern) = att.when_auction {
-        if !check_auction_pattern(pattern, context) {
-            return false;
-        }
-    }
-
-    // Check public guard if specified
-    if let Some(ref guard) = att.when_public {
-        if !check_public_guard(guard, context) {
-            return false;
-        }
-    }
-
-    // All specified conditions passed (conditions are AND-ed within a single attachment)
-    true
-}
-
-/// Check an auction pattern against the log.
-///
-/// Fixture `AuctionPattern` strings use bridge notation ("1NT", "2C", "P"),
-/// so the log calls must be rendered with the same `call_to_short_label`
-/// helper the witness path uses (`fact_dsl::witness`). The previous
-/// implementation compared `format!("{:?}", call)` (e.g.
-/// `"Bid { level: 2, strain: Clubs }"`) to the fixture strings and silently
-/// always returned false.
-fn check_auction_pattern(
-    pattern: &crate::types::agreement::AuctionPattern,
-    context: &AuctionContext,
-) -> bool {
-    use crate::fact_dsl::witness::call_to_short_label;
-    use crate::types::agreement::AuctionPattern;
-
-    match pattern {
-        AuctionPattern::Sequence { calls } => {
-            // Check if the auction log ends with this call sequence
-            let log_calls: Vec<String> = context
-                .log
-                .iter()
-                .map(|step| call_to_short_label(&step.call))
-                .collect();
-            if log_calls.len() < calls.len() {
-                return false;
-            }
-            let start = log_calls.len() - calls.len();
-            log_calls[start..]
-                .iter()
-                .zip(calls.iter())
-                .all(|(a, b)| a == b)
-        }
-        AuctionPattern::Contains { call, by_role: _ } => {
-            // Check if any call in the log matches
-            context
-                .log
-                .iter()
-                .any(|step| call_to_short_label(&step.call) == *call)
-        }
-        AuctionPattern::ByRole { role: _, last_call } => {
-            // Check if the last call in the log matches
-            context
-                .log
-                .last()
-                .map(|step| call_to_short_label(&step.call) == *last_call)
-                .unwrap_or(false)
-        }
-    }
-}
-
-/// Check a public guard against the context's negotiation state.
-fn check_public_guard(
-    guard: &crate::types::agreement::PublicGuard,
-    context: &AuctionContext,
-) -> bool {
-    use crate::types::agreement::GuardOperator;
-
-    // Extract field value from the current kernel/negotiation state
-    let field_value = match guard.field.as_str() {
-        "forcingState" => context
-            .log
-            .last()
-            .map(|step| format!("{:?}", step.state_after.forcing)),
-        "fitAgreed" => context
-            .log
-            .last()
-            .and_then(|step| step.state_after.fit_agreed.as_ref())
-            .map(|f| format!("{:?}", f)),
-        _ => None,
-    };
-
-    match guard.operator {
-        GuardOperator::Exists => field_value.is_some(),
-        GuardOperator::Eq => match (&field_value, &guard.value) {
-            (Some(actual), Some(crate::types::agreement::GuardValue::Scalar(expected))) => {
-                actual == expected
-            }
-            _ => false,
-        },
-        GuardOperator::Neq => match (&field_value, &guard.value) {
-            (Some(actual), Some(crate::types::agreement::GuardValue::Scalar(expected))) => {
-                actual != expected
-            }
-            _ => true,
-        },
-        GuardOperator::In => match (&field_value, &guard.value) {
-            (Some(actual), Some(crate::types::agreement::GuardValue::List(list))) => {
-                list.contains(actual)
-            }
-            _ => false,
-        },
-    }
-}
-
 /// Collect matching claims using pre-computed local phases (no replay).
 ///
 /// Used by buildObservationLogViaRules to avoid O(N²×M) replay cost.
@@ -227,9 +117,15 @@ pub fn collect_matching_claims_with_phases(
     next_seat: Option<Seat>,
     local_phases: &HashMap<String, String>,
 ) -> Vec<ModuleSurfaceResult> {
+    if let Some(relay) = next_seat.and_then(|s| relay_turn_claims(modules, &context.log, s)) {
+        return vec![relay];
+    }
+
     let current_kernel = get_current_kernel(context);
     let turn_role = next_seat.map(|s| derive_turn_role(s, &context.log));
     let opener_seat = find_opener_seat(&context.log);
+    let capabilities = provided_capabilities(modules);
+    let steps = log_steps(&context.log);
 
     let mut results = Vec::new();
     for module in modules {
@@ -237,7 +133,7 @@ pub fn collect_matching_claims_with_phases(
             .get(&module.module_id)
             .cloned()
             .unwrap_or_else(|| module.local.initial.clone());
-        let resolved = collect_module_surfaces(
+        let mut resolved = collect_module_surfaces(
             module,
             &current_phase,
             &current_kernel,
@@ -245,6 +141,15 @@ pub fn collect_matching_claims_with_phases(
             turn_role,
             opener_seat,
         );
+        retain_attaching_surfaces(
+            module,
+            &current_phase,
+            context,
+            next_seat,
+            &steps,
+            &capabilities,
+            &mut resolved,
+        );
 
         if !resolved.is_empty() {
             results.push(ModuleSurfaceResult {
@@ -276,14 +181,54 @@ fn find_opener_seat(log: &[CommittedStep]) -> Option<Seat> {
     None
 }
 
-fn replay_local_fsm(module: &ConventionModule, context: &AuctionContext) -> String {
+fn replay_local_fsm(
+    module: &ConventionModule,
+    context: &AuctionContext,
+    capabilities: &HashSet<&str>,
+) -> String {
     let mut phase = module.local.initial.clone();
     for (i, step) in context.log.iter().enumerate() {
-        phase = advance_local_fsm(&phase, step, &context.log[..i], &module.local.transitions);
+        phase = advance_module_phase(module, &phase, step, &context.log[..i], capabilities);
     }
     phase
 }
 
+/// A dormant module (still at its initial phase, host not yet matched)
+/// keeps only the surfaces whose own call attaches it. The call must also
+/// be legal here: an insufficient 1♣ after 1NT–2♥ opens nothing.
+fn retain_attaching_surfaces(
+    module: &ConventionModule,
+    current_phase: &str,
+    context: &AuctionContext,
+    next_seat: Option<Seat>,
+    steps: &[AttachmentStep<'_>],
+    capabilities: &HashSet<&str>,
+    resolved: &mut Vec<ResolvedSurface>,
+) {
+    if resolved.is_empty()
+        || current_phase != module.local.initial
+        || is_attached(module, steps, capabilities)
+    {
+        return;
+    }
+    let auction = Auction {
+        entries: context
+            .log
+            .iter()
+            .map(|step| AuctionEntry {
+                seat: step.actor,
+                call: step.call.clone(),
+            })
+            .collect(),
+        is_complete: false,
+    };
+    resolved.retain(|r| {
+        let call = &r.surface.encoding.default_call;
+        next_seat.is_none_or(|seat| is_legal_call(&auction, call, seat))
+            && surface_attaches(module, steps, call, capabilities)
+    });
+}
+
 fn collect_module_surfaces(
     module: &ConventionModule,
     current_phase: &str,
@@ -391,63 +336,4 @@ mod tests {
     fn derive_turn_role_no_log() {
         assert_eq!(derive_turn_role(Seat::South, &[]), TurnRole::Opener);
     }
-
-    /// Build a CommittedStep wrapping a single Call. Test helper for
-    /// auction-pattern regression tests below.
-    fn step_for(actor: Seat, call: bridge_engine::types::Call) -> CommittedStep {
-        CommittedStep {
-            actor,
-            call,
-            resolved_claim: None,
-            public_actions: Vec::new(),
-            negotiation_delta: crate::types::negotiation::NegotiationDelta::default(),
-            state_after: initial_negotiation(),
-            status: CommittedStepStatus::Resolved,
-        }
-    }
-
-    #[test]
-    fn auction_pattern_sequence_matches_1nt_notation() {
-        // Regression: previously compared `format!("{:?}", call)` (e.g.
-        // "Bid { level: 1, strain: NoTrump }") to fixture string "1NT" and
-        // silently always rejected — modules with whenAuction filters were
-        // never attached.
-        use crate::types::agreement::AuctionPattern;
-        let context = AuctionContext {
-            log: vec![step_for(
-                Seat::South,
-                bridge_engine::types::Call::Bid {
-                    level: 1,
-                    strain: bridge_engine::types::BidSuit::NoTrump,
-                },
-            )],
-        };
-        let pattern = AuctionPattern::Sequence {
-            calls: vec!["1NT".to_string()],
-        };
-        assert!(check_auction_pattern(&pattern, &context));
-    }
-
-    #[test]
-    fn auction_pattern_sequence_2c_matches_only_2c() {
-        use crate::types::agreement::AuctionPattern;
-        let context = AuctionContext {
-            log: vec![step_for(
-                Seat::South,
-                bridge_engine::types::Call::Bid {
-                    level: 2,
-                    strain: bridge_engine::types::BidSuit::Clubs,
-                },
-            )],
-        };
-        let pattern_2c = AuctionPattern::Sequence {
-            calls: vec!["2C".to_string()],
-        };
-        assert!(check_auction_pattern(&pattern_2c, &context));
-
-        let pattern_3c = AuctionPattern::Sequence {
-            calls: vec!["3C".to_string()],
-        };
-        assert!(!check_auction_pattern(&pattern_3c, &context));
-    }
 }
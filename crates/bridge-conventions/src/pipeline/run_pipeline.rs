@@ -101,6 +101,7 @@ mod tests {
                     strain: bridge_engine::types::BidSuit::Clubs,
                 },
                 alternate_encodings: None,
+                relay: None,
             },
             clauses: vec![BidMeaningClause {
                 fact_id: "hand.hcp".into(),
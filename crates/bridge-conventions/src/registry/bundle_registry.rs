@@ -8,6 +8,9 @@
 //! ID gets a synthesized bundle at cache-init time. Multi-module authored
 //! bundles do not suppress this. See `synthesize_single_module_bundle` for the
 //! derivation rules. The `every_module_has_a_bundle` test enforces coverage.
+//!
+//! Resolved bundles are cached per system, so a bundle resolved for Acol
+//! carries the Acol variants of its modules.
 
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
@@ -21,8 +24,8 @@ use crate::types::rule_types::{PhaseRef, TurnRole};
 use crate::types::system_config::BaseSystemId;
 use crate::teaching::teaching_types::{SurfaceGroup, SurfaceGroupRelationship};
 
-use super::module_registry::{get_all_modules, get_module};
-use super::system_configs::sayc_system_config;
+use super::module_registry::{active_modules, get_module};
+use super::system_configs::get_system_config;
 
 // Embedded bundle-input manifest (all authored bundles)
 const BUNDLE_MANIFESTS_JSON: &str = include_str!("../../fixtures/bundle-manifests.json");
@@ -121,12 +124,15 @@ fn derive_surface_groups(module: &ConventionModule) -> Vec<SurfaceGroup> {
 /// kernel-gated drills.
 const SLAM_FIT_DEPENDENCIES: &[&str] = &["jacoby-transfers", "stayman"];
 
-/// Synthesize a single-module bundle from a module.
+/// Synthesize a single-module bundle from a module as played in `system`.
 ///
 /// Fields derived from the module:
 /// - name, member_ids, category, description, teaching, bundle metadata
-/// - system_profile: SAYC default + one ModuleEntry using synthesis-only bundle metadata attachments
-fn synthesize_single_module_bundle(module: &ConventionModule) -> ConventionBundle {
+/// - system_profile: `system` defaults + one ModuleEntry using synthesis-only bundle metadata attachments
+fn synthesize_single_module_bundle(
+    module: &ConventionModule,
+    system: BaseSystemId,
+) -> ConventionBundle {
     let bundle_id = synthesized_bundle_id(&module.module_id);
     let bundle_teaching = module.bundle_metadata.teaching.as_ref();
     let teaching = Some(ConventionTeaching {
@@ -171,7 +177,7 @@ fn synthesize_single_module_bundle(module: &ConventionModule) -> ConventionBundl
         options: None,
     }];
     for dep_id in &extra_dep_ids {
-        if let Some(dep_module) = get_module(dep_id, BaseSystemId::Sayc) {
+        if let Some(dep_module) = get_module(dep_id, system) {
             profile_modules.push(ModuleEntry {
                 module_id: dep_id.clone(),
                 kind: ModuleKind::BaseSystem,
@@ -187,14 +193,14 @@ fn synthesize_single_module_bundle(module: &ConventionModule) -> ConventionBundl
 
     let system_profile = Some(SystemProfile {
         profile_id: format!("{}-synth", module.module_id),
-        base_system: BaseSystemId::Sayc,
-        system_config: Some(sayc_system_config()),
+        base_system: system,
+        system_config: Some(get_system_config(system)),
         modules: profile_modules,
     });
 
     let mut bundle_modules = vec![module.clone()];
     for dep_id in &extra_dep_ids {
-        if let Some(dep_module) = get_module(dep_id, BaseSystemId::Sayc) {
+        if let Some(dep_module) = get_module(dep_id, system) {
             bundle_modules.push(dep_module.clone());
         }
     }
@@ -244,10 +250,36 @@ fn bundle_input_from(bundle: &ConventionBundle) -> BundleInput {
 
 // ── Bundle input manifest cache ────────────────────────────────────
 
-static MANIFEST_CACHE: OnceLock<(Vec<BundleInput>, HashMap<String, usize>)> = OnceLock::new();
+/// Bundle caches derived from one module set. Lives beside the set in
+/// `ActiveModules`, so installing new modules starts from empty caches.
+/// Manifests are system-independent; resolved bundles are cached per system.
+pub(crate) struct BundleCaches {
+    manifest: OnceLock<(Vec<BundleInput>, HashMap<String, usize>)>,
+    bundles: HashMap<BaseSystemId, OnceLock<HashMap<String, ConventionBundle>>>,
+}
+
+impl Default for BundleCaches {
+    fn default() -> Self {
+        Self {
+            manifest: OnceLock::new(),
+            bundles: BUNDLE_SYSTEMS
+                .iter()
+                .map(|&system| (system, OnceLock::new()))
+                .collect(),
+        }
+    }
+}
+
+/// Systems with their own resolved bundles. `Custom` resolves like SAYC.
+const BUNDLE_SYSTEMS: &[BaseSystemId] = &[
+    BaseSystemId::Sayc,
+    BaseSystemId::TwoOverOne,
+    BaseSystemId::Acol,
+];
 
 fn manifest_cache() -> &'static (Vec<BundleInput>, HashMap<String, usize>) {
-    MANIFEST_CACHE.get_or_init(|| {
+    let active = active_modules();
+    active.bundles.manifest.get_or_init(|| {
         let mut inputs: Vec<BundleInput> = serde_json::from_str(BUNDLE_MANIFESTS_JSON)
             .expect("Failed to deserialize bundle manifests");
 
@@ -255,9 +287,12 @@ fn manifest_cache() -> &'static (Vec<BundleInput>, HashMap<String, usize>) {
         // bundles (for example nt-bundle) do not suppress canonical
         // single-module bundle IDs such as stayman-bundle.
         let authored_ids: HashSet<String> = inputs.iter().map(|b| b.id.clone()).collect();
-        for module in get_all_modules(BaseSystemId::Sayc) {
+        for module in active.registry.modules() {
             if !authored_ids.contains(&synthesized_bundle_id(&module.module_id)) {
-                inputs.push(bundle_input_from(&synthesize_single_module_bundle(module)));
+                inputs.push(bundle_input_from(&synthesize_single_module_bundle(
+                    module,
+                    BaseSystemId::Sayc,
+                )));
             }
         }
 
@@ -283,30 +318,34 @@ pub fn list_bundle_inputs() -> &'static [BundleInput] {
 
 // ── Resolved bundle cache ──────────────────────────────────────────
 
-static BUNDLE_CACHE: OnceLock<HashMap<String, ConventionBundle>> = OnceLock::new();
-
-fn bundle_cache() -> &'static HashMap<String, ConventionBundle> {
-    BUNDLE_CACHE.get_or_init(|| {
+fn bundle_cache(system: BaseSystemId) -> &'static HashMap<String, ConventionBundle> {
+    let active = active_modules();
+    let system = match system {
+        BaseSystemId::Custom => BaseSystemId::Sayc,
+        other => other,
+    };
+    active.bundles.bundles[&system].get_or_init(|| {
         let mut map = HashMap::new();
         for &id in AUTHORED_BUNDLE_IDS {
             if let Some(json) = json_for_bundle(id) {
                 match serde_json::from_str::<ConventionBundle>(json) {
                     Ok(mut bundle) => {
                         // Populate modules from module registry (single source of truth).
-                        // Uses Sayc — multi-system support is deferred (get_module ignores system).
                         if bundle.modules.is_empty() {
                             bundle.modules = bundle
                                 .member_ids
                                 .iter()
                                 .map(|mid| {
-                                    get_module(mid, BaseSystemId::Sayc).cloned().unwrap_or_else(
-                                        || {
+                                    active
+                                        .registry
+                                        .get_for(mid, system)
+                                        .cloned()
+                                        .unwrap_or_else(|| {
                                             panic!(
                                                 "Bundle '{}' references unknown module '{}'",
                                                 id, mid
                                             )
-                                        },
-                                    )
+                                        })
                                 })
                                 .collect();
                         }
@@ -321,10 +360,10 @@ fn bundle_cache() -> &'static HashMap<String, ConventionBundle> {
 
         // Synthesize canonical single-module bundles for every module that
         // does not already have an authored bundle at its canonical ID.
-        for module in get_all_modules(BaseSystemId::Sayc) {
+        for module in active.registry.modules_for(system) {
             let synthesized_id = synthesized_bundle_id(&module.module_id);
             if !map.contains_key(&synthesized_id) {
-                let synthesized = synthesize_single_module_bundle(module);
+                let synthesized = synthesize_single_module_bundle(module, system);
                 map.insert(synthesized.id.clone(), synthesized);
             }
         }
@@ -333,15 +372,16 @@ fn bundle_cache() -> &'static HashMap<String, ConventionBundle> {
     })
 }
 
-/// Resolve a bundle by ID for a given system.
-/// Currently returns SAYC-resolved bundles (multi-system deferred).
-pub fn resolve_bundle(id: &str, _system: BaseSystemId) -> Option<&'static ConventionBundle> {
-    bundle_cache().get(id)
+/// Resolve a bundle by ID for a given system, with that system's module
+/// variants.
+pub fn resolve_bundle(id: &str, system: BaseSystemId) -> Option<&'static ConventionBundle> {
+    bundle_cache(system).get(id)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::registry::module_registry::get_all_modules;
     use crate::types::bundle_types::ConventionCategory;
 
     #[test]
@@ -514,6 +554,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bundles_resolve_with_the_system_variants() {
+        let acol = resolve_bundle("nt-bundle", BaseSystemId::Acol).unwrap();
+        let sayc = resolve_bundle("nt-bundle", BaseSystemId::Sayc).unwrap();
+        assert_eq!(acol.member_ids, sayc.member_ids);
+        assert_ne!(acol.modules[0], sayc.modules[0]);
+        assert_eq!(
+            &acol.modules[0],
+            get_module("stayman", BaseSystemId::Acol).unwrap()
+        );
+
+        let synthesized = resolve_bundle("strong-2c-bundle", BaseSystemId::Acol).unwrap();
+        let profile = synthesized.system_profile.as_ref().unwrap();
+        assert_eq!(profile.base_system, BaseSystemId::Acol);
+        assert_eq!(
+            profile.system_config.as_ref().unwrap().system_id,
+            BaseSystemId::Acol
+        );
+        assert!(synthesized
+            .derived_teaching
+            .surface_groups
+            .iter()
+            .any(|group| group.id.starts_with("strong-2c/opened-acol-two-hearts")));
+
+        assert!(std::ptr::eq(
+            resolve_bundle("bergen-bundle", BaseSystemId::Custom).unwrap(),
+            resolve_bundle("bergen-bundle", BaseSystemId::Sayc).unwrap()
+        ));
+    }
+
     #[test]
     fn synthesized_bundle_preserves_disabled_role_selection() {
         let bundle = resolve_bundle("dont-bundle", BaseSystemId::Sayc)
@@ -1,15 +1,28 @@
 //! Convention registry — runtime lookup for modules, bundles, and specs.
 //!
 //! Pre-baked JSON is embedded via `include_str!()` and deserialized on first access.
-//! Module factories only vary by SystemConfig (SAYC/2/1/Acol), so pre-baked JSON
-//! per system variant is sufficient.
+//! `module_loader` builds module sets from JSON at runtime; `install_modules`
+//! swaps one in without a rebuild.
+//! Modules are authored once for SAYC; `system_variants` overlays give 2/1 and
+//! Acol their own definitions where they play a convention differently.
 
 pub mod bundle_registry;
+pub mod module_loader;
 pub mod module_registry;
+pub mod module_validation;
 pub mod spec_builder;
 pub mod system_configs;
+pub mod system_variants;
 
 pub use bundle_registry::{get_bundle_input, list_bundle_inputs, resolve_bundle};
-pub use module_registry::{get_all_modules, get_base_module_ids, get_module};
+pub use module_loader::{
+    ModuleDirectory, ModuleLoadError, ModuleLoadReport, ModuleRegistry, ModuleSource,
+};
+pub use module_registry::{
+    active_registry, get_all_modules, get_base_module_ids, get_base_system_module_ids, get_module,
+    install_modules,
+};
+pub use module_validation::{validate_module, ModuleIssue};
 pub use spec_builder::spec_from_bundle;
 pub use system_configs::*;
+pub use system_variants::{apply_overlay, ModuleOverlay, TextReplacement};
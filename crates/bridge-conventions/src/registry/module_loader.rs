@@ -0,0 +1,607 @@
+//! Runtime module loading — build a module set from JSON at runtime.
+//!
+//! `ModuleRegistry` is an owned module set: the embedded SAYC modules plus
+//! any `ConventionModule` JSON loaded from caller-supplied strings or a
+//! directory. Every source is parsed and validated on its own, so one bad
+//! file is reported in the `ModuleLoadReport` without blocking the rest.
+//!
+//! ID rules: two runtime sources may not define the same module ID (the
+//! later one is rejected as a conflict). A runtime module may replace an
+//! embedded one — that is how a deployment edits a shipped convention — and
+//! the report lists every replacement. A replaced module drops its embedded
+//! system variants, so every system sees the deployment's edit.
+//!
+//! A registry does nothing until `install_modules` makes it the set that
+//! `get_module` / `get_all_modules` resolve against.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::module_types::ConventionModule;
+use crate::types::system_config::BaseSystemId;
+
+use super::module_registry::{embedded_registry, prepare_module};
+use super::module_validation::{validate_module, ModuleIssue};
+use super::system_variants::{apply_overlay, ModuleOverlay};
+
+/// One module document to load. `name` identifies it in reports — a file
+/// name for directory loads, any caller-chosen label otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleSource {
+    pub name: String,
+    pub json: String,
+}
+
+impl ModuleSource {
+    pub fn new(name: impl Into<String>, json: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            json: json.into(),
+        }
+    }
+}
+
+/// Why one source was not registered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ModuleLoadError {
+    /// The source could not be read.
+    Io { source: String, message: String },
+    /// The source is not a `ConventionModule` document.
+    Parse { source: String, message: String },
+    /// The module parsed but failed validation.
+    #[serde(rename_all = "camelCase")]
+    Invalid {
+        source: String,
+        module_id: String,
+        issues: Vec<ModuleIssue>,
+    },
+    /// Another runtime source already defines this module ID.
+    #[serde(rename_all = "camelCase")]
+    DuplicateId {
+        source: String,
+        module_id: String,
+        defined_in: String,
+    },
+}
+
+impl ModuleLoadError {
+    /// Name of the source that failed.
+    pub fn source_name(&self) -> &str {
+        match self {
+            ModuleLoadError::Io { source, .. }
+            | ModuleLoadError::Parse { source, .. }
+            | ModuleLoadError::Invalid { source, .. }
+            | ModuleLoadError::DuplicateId { source, .. } => source,
+        }
+    }
+}
+
+impl fmt::Display for ModuleLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModuleLoadError::Io { source, message } => {
+                write!(f, "{}: could not read: {}", source, message)
+            }
+            ModuleLoadError::Parse { source, message } => {
+                write!(f, "{}: not a convention module: {}", source, message)
+            }
+            ModuleLoadError::Invalid {
+                source,
+                module_id,
+                issues,
+            } => {
+                write!(f, "{}: module '{}' is invalid:", source, module_id)?;
+                for issue in issues {
+                    write!(f, " {}: {};", issue.field, issue.message)?;
+                }
+                Ok(())
+            }
+            ModuleLoadError::DuplicateId {
+                source,
+                module_id,
+                defined_in,
+            } => write!(
+                f,
+                "{}: module '{}' is already defined in {}",
+                source, module_id, defined_in
+            ),
+        }
+    }
+}
+
+/// Outcome of loading a batch of sources.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleLoadReport {
+    /// Module IDs registered from the batch, in source order.
+    pub loaded: Vec<String>,
+    /// Embedded module IDs now replaced by a runtime module, system variants
+    /// included.
+    pub replaced: Vec<String>,
+    /// Runtime modules whose source failed on reload; the previous good
+    /// version stays registered.
+    pub kept: Vec<String>,
+    pub errors: Vec<ModuleLoadError>,
+}
+
+impl ModuleLoadReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// An owned, ordered set of convention modules keyed by module ID, with
+/// per-system variants of some of them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleRegistry {
+    modules: Vec<ConventionModule>,
+    index: HashMap<String, usize>,
+    /// Source name of every runtime-loaded module. Embedded modules are absent.
+    origins: HashMap<String, String>,
+    /// System-specific copies of shared modules, built from overlays.
+    variants: HashMap<(BaseSystemId, String), ConventionModule>,
+}
+
+impl ModuleRegistry {
+    /// A registry of embedded modules, in the given order, with `overlays`
+    /// applied as system variants. An overlay that does not apply is an
+    /// authoring error in the embedded fixtures.
+    pub(crate) fn from_embedded(
+        modules: Vec<ConventionModule>,
+        overlays: Vec<ModuleOverlay>,
+    ) -> Self {
+        let mut registry = Self::default();
+        for module in modules {
+            registry.put(module);
+        }
+        for overlay in overlays {
+            let shared = registry.get(&overlay.module_id).unwrap_or_else(|| {
+                panic!(
+                    "Overlay for {:?} targets unknown module '{}'",
+                    overlay.system, overlay.module_id
+                )
+            });
+            let variant = apply_overlay(shared, &overlay).unwrap_or_else(|issues| {
+                panic!(
+                    "Overlay for {:?} does not apply to '{}': {:?}",
+                    overlay.system, overlay.module_id, issues
+                )
+            });
+            registry
+                .variants
+                .insert((overlay.system, overlay.module_id), variant);
+        }
+        registry
+    }
+
+    /// The embedded modules only.
+    pub fn builtin() -> Self {
+        embedded_registry().clone()
+    }
+
+    /// The embedded modules plus `sources`.
+    pub fn with_sources(sources: &[ModuleSource]) -> (Self, ModuleLoadReport) {
+        let mut registry = Self::builtin();
+        let report = registry.load(sources);
+        (registry, report)
+    }
+
+    /// The shared definition of a module, as written for SAYC.
+    pub fn get(&self, module_id: &str) -> Option<&ConventionModule> {
+        self.index.get(module_id).map(|&i| &self.modules[i])
+    }
+
+    /// A module as played in `system`: its variant for that system, or the
+    /// shared definition when there is none.
+    pub fn get_for(&self, module_id: &str, system: BaseSystemId) -> Option<&ConventionModule> {
+        self.variants
+            .get(&(system, module_id.to_string()))
+            .or_else(|| self.get(module_id))
+    }
+
+    /// Modules in registration order: embedded modules first, then runtime
+    /// modules in the order they were loaded.
+    pub fn modules(&self) -> &[ConventionModule] {
+        &self.modules
+    }
+
+    /// `modules()` as played in `system`, in the same order.
+    pub fn modules_for(&self, system: BaseSystemId) -> Vec<&ConventionModule> {
+        self.modules
+            .iter()
+            .map(|module| {
+                self.variants
+                    .get(&(system, module.module_id.clone()))
+                    .unwrap_or(module)
+            })
+            .collect()
+    }
+
+    /// Source a runtime module was loaded from; `None` for embedded modules
+    /// and unknown IDs.
+    pub fn origin(&self, module_id: &str) -> Option<&str> {
+        self.origins.get(module_id).map(String::as_str)
+    }
+
+    /// Parse, validate and register `sources`. Sources that fail are left
+    /// out and reported; the rest are registered.
+    pub fn load(&mut self, sources: &[ModuleSource]) -> ModuleLoadReport {
+        let mut report = ModuleLoadReport::default();
+        for source in sources {
+            match self.load_one(source) {
+                Ok((module_id, replaced)) => {
+                    if replaced {
+                        report.replaced.push(module_id.clone());
+                    }
+                    report.loaded.push(module_id);
+                }
+                Err(error) => report.errors.push(error),
+            }
+        }
+        report
+    }
+
+    /// Rebuild from the embedded modules and `sources`, as a dev server does
+    /// when module files change. A runtime module whose source now fails keeps
+    /// its previous version, so a half-edited file does not pull a convention
+    /// out from under open drills.
+    pub fn reload(&self, sources: &[ModuleSource]) -> (Self, ModuleLoadReport) {
+        self.reload_with_errors(sources, Vec::new())
+    }
+
+    fn reload_with_errors(
+        &self,
+        sources: &[ModuleSource],
+        read_errors: Vec<ModuleLoadError>,
+    ) -> (Self, ModuleLoadReport) {
+        let (mut next, mut report) = Self::with_sources(sources);
+        report.errors.splice(0..0, read_errors);
+
+        let failed: HashSet<&str> = report
+            .errors
+            .iter()
+            .map(ModuleLoadError::source_name)
+            .collect();
+        for module in &self.modules {
+            let Some(origin) = self.origin(&module.module_id) else {
+                continue;
+            };
+            if !failed.contains(origin) || next.origins.contains_key(&module.module_id) {
+                continue;
+            }
+            next.insert(module.clone(), origin.to_string());
+            report.kept.push(module.module_id.clone());
+        }
+        (next, report)
+    }
+
+    /// Register one source. Returns the module ID and whether it replaced an
+    /// embedded module.
+    fn load_one(&mut self, source: &ModuleSource) -> Result<(String, bool), ModuleLoadError> {
+        let mut module: ConventionModule =
+            serde_json::from_str(&source.json).map_err(|e| ModuleLoadError::Parse {
+                source: source.name.clone(),
+                message: e.to_string(),
+            })?;
+        prepare_module(&mut module);
+
+        let issues = validate_module(&module);
+        if !issues.is_empty() {
+            return Err(ModuleLoadError::Invalid {
+                source: source.name.clone(),
+                module_id: module.module_id,
+                issues,
+            });
+        }
+
+        if let Some(defined_in) = self.origin(&module.module_id) {
+            if defined_in != source.name {
+                return Err(ModuleLoadError::DuplicateId {
+                    source: source.name.clone(),
+                    module_id: module.module_id,
+                    defined_in: defined_in.to_string(),
+                });
+            }
+        }
+
+        let module_id = module.module_id.clone();
+        let replaced = self.index.contains_key(&module_id) && self.origin(&module_id).is_none();
+        self.insert(module, source.name.clone());
+        Ok((module_id, replaced))
+    }
+
+    /// Insert or replace in place, keeping the original position.
+    fn insert(&mut self, module: ConventionModule, origin: String) {
+        self.origins.insert(module.module_id.clone(), origin);
+        self.variants.retain(|(_, id), _| id != &module.module_id);
+        self.put(module);
+    }
+
+    fn put(&mut self, module: ConventionModule) {
+        match self.index.get(&module.module_id) {
+            Some(&i) => self.modules[i] = module,
+            None => {
+                self.index
+                    .insert(module.module_id.clone(), self.modules.len());
+                self.modules.push(module);
+            }
+        }
+    }
+}
+
+/// A directory of `*.json` module files, watched for changes by polling.
+///
+/// `reload` reads every file and rebuilds a registry; `has_changed` tells a
+/// dev server when it is worth calling again.
+#[derive(Debug, Clone)]
+pub struct ModuleDirectory {
+    path: PathBuf,
+    stamps: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl ModuleDirectory {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            stamps: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether files were added, removed or modified since the last `reload`.
+    /// An unreadable directory counts as changed so the error surfaces.
+    pub fn has_changed(&self) -> bool {
+        match self.scan() {
+            Ok(stamps) => stamps != self.stamps,
+            Err(_) => true,
+        }
+    }
+
+    /// Rebuild `current` from the embedded modules and this directory's
+    /// files (see `ModuleRegistry::reload`). Fails only when the directory
+    /// itself cannot be listed; per-file problems land in the report.
+    pub fn reload(
+        &mut self,
+        current: &ModuleRegistry,
+    ) -> io::Result<(ModuleRegistry, ModuleLoadReport)> {
+        let stamps = self.scan()?;
+        let mut sources = Vec::new();
+        let mut read_errors = Vec::new();
+        for (path, _) in &stamps {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            match std::fs::read_to_string(path) {
+                Ok(json) => sources.push(ModuleSource::new(name, json)),
+                Err(e) => read_errors.push(ModuleLoadError::Io {
+                    source: name,
+                    message: e.to_string(),
+                }),
+            }
+        }
+        self.stamps = stamps;
+        Ok(current.reload_with_errors(&sources, read_errors))
+    }
+
+    /// `*.json` files sorted by path, with their modification times.
+    fn scan(&self) -> io::Result<Vec<(PathBuf, Option<SystemTime>)>> {
+        let mut stamps = Vec::new();
+        for entry in std::fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") || !path.is_file() {
+                continue;
+            }
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+            stamps.push((path, modified));
+        }
+        stamps.sort();
+        Ok(stamps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::system_config::BaseSystemId;
+
+    fn club_module(module_id: &str) -> String {
+        let mut module = ModuleRegistry::builtin()
+            .get("stayman")
+            .expect("stayman is embedded")
+            .clone();
+        module.module_id = module_id.to_string();
+        module.display_name = format!("Club {}", module_id);
+        module.variant_of = Some("stayman".to_string());
+        serde_json::to_string(&module).unwrap()
+    }
+
+    #[test]
+    fn builtin_matches_the_embedded_modules() {
+        let registry = ModuleRegistry::builtin();
+        let ids: Vec<&str> = registry
+            .modules()
+            .iter()
+            .map(|m| m.module_id.as_str())
+            .collect();
+        let embedded: Vec<&str> = crate::registry::get_all_modules(BaseSystemId::Sayc)
+            .iter()
+            .map(|m| m.module_id.as_str())
+            .collect();
+        assert_eq!(ids, embedded);
+        assert_eq!(registry.origin("stayman"), None);
+    }
+
+    #[test]
+    fn sources_are_added_after_the_embedded_modules() {
+        let (registry, report) = ModuleRegistry::with_sources(&[ModuleSource::new(
+            "club-stayman.json",
+            club_module("club-stayman"),
+        )]);
+        assert!(report.is_clean(), "{:?}", report.errors);
+        assert_eq!(report.loaded, vec!["club-stayman"]);
+        assert!(report.replaced.is_empty());
+        assert_eq!(registry.modules().last().unwrap().module_id, "club-stayman");
+        assert_eq!(registry.origin("club-stayman"), Some("club-stayman.json"));
+    }
+
+    #[test]
+    fn each_bad_source_is_reported_without_blocking_the_rest() {
+        let mut invalid: serde_json::Value =
+            serde_json::from_str(&club_module("club-broken")).unwrap();
+        invalid["displayName"] = "".into();
+
+        let (registry, report) = ModuleRegistry::with_sources(&[
+            ModuleSource::new("garbage.json", "{ not json"),
+            ModuleSource::new("broken.json", invalid.to_string()),
+            ModuleSource::new("good.json", club_module("club-stayman")),
+        ]);
+        assert_eq!(report.loaded, vec!["club-stayman"]);
+        assert_eq!(report.errors.len(), 2);
+        assert!(matches!(
+            &report.errors[0],
+            ModuleLoadError::Parse { source, .. } if source == "garbage.json"
+        ));
+        match &report.errors[1] {
+            ModuleLoadError::Invalid {
+                module_id, issues, ..
+            } => {
+                assert_eq!(module_id, "club-broken");
+                assert_eq!(issues[0].field, "displayName");
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+        assert!(registry.get("club-broken").is_none());
+    }
+
+    #[test]
+    fn duplicate_ids_across_sources_conflict() {
+        let (registry, report) = ModuleRegistry::with_sources(&[
+            ModuleSource::new("a.json", club_module("club-stayman")),
+            ModuleSource::new("b.json", club_module("club-stayman")),
+        ]);
+        assert_eq!(report.loaded, vec!["club-stayman"]);
+        assert_eq!(
+            report.errors,
+            vec![ModuleLoadError::DuplicateId {
+                source: "b.json".to_string(),
+                module_id: "club-stayman".to_string(),
+                defined_in: "a.json".to_string(),
+            }]
+        );
+        assert_eq!(registry.origin("club-stayman"), Some("a.json"));
+    }
+
+    #[test]
+    fn runtime_modules_may_replace_embedded_ones_in_place() {
+        let mut edited = ModuleRegistry::builtin().get("smolen").unwrap().clone();
+        edited.display_name = "Smolen (club notes)".to_string();
+        let position = ModuleRegistry::builtin()
+            .modules()
+            .iter()
+            .position(|m| m.module_id == "smolen");
+
+        let (registry, report) = ModuleRegistry::with_sources(&[ModuleSource::new(
+            "smolen.json",
+            serde_json::to_string(&edited).unwrap(),
+        )]);
+        assert!(report.is_clean());
+        assert_eq!(report.replaced, vec!["smolen"]);
+        assert_eq!(
+            registry.get("smolen").unwrap().display_name,
+            "Smolen (club notes)"
+        );
+        assert_eq!(
+            registry
+                .modules()
+                .iter()
+                .position(|m| m.module_id == "smolen"),
+            position
+        );
+        assert_eq!(
+            registry.modules().len(),
+            ModuleRegistry::builtin().modules().len()
+        );
+    }
+
+    #[test]
+    fn replacing_a_shared_module_drops_its_system_variants() {
+        let builtin = ModuleRegistry::builtin();
+        assert_ne!(
+            builtin.get_for("stayman", BaseSystemId::Acol),
+            builtin.get("stayman")
+        );
+
+        let mut edited = builtin.get("stayman").unwrap().clone();
+        edited.display_name = "Stayman (club notes)".to_string();
+        let (registry, report) = ModuleRegistry::with_sources(&[ModuleSource::new(
+            "stayman.json",
+            serde_json::to_string(&edited).unwrap(),
+        )]);
+        assert_eq!(report.replaced, vec!["stayman"]);
+        assert_eq!(
+            registry
+                .get_for("stayman", BaseSystemId::Acol)
+                .unwrap()
+                .display_name,
+            "Stayman (club notes)"
+        );
+        // Variants of other modules are kept.
+        assert_eq!(
+            registry.get_for("natural-bids", BaseSystemId::Acol),
+            builtin.get_for("natural-bids", BaseSystemId::Acol)
+        );
+    }
+
+    #[test]
+    fn reload_keeps_the_last_good_version_of_a_failing_source() {
+        let (first, _) = ModuleRegistry::with_sources(&[
+            ModuleSource::new("club.json", club_module("club-stayman")),
+            ModuleSource::new("gone.json", club_module("club-gone")),
+        ]);
+
+        let (second, report) = first.reload(&[ModuleSource::new("club.json", "{ half-edited")]);
+        assert_eq!(report.kept, vec!["club-stayman"]);
+        assert_eq!(report.errors.len(), 1);
+        assert!(second.get("club-stayman").is_some());
+        // A deleted file takes its module with it.
+        assert!(second.get("club-gone").is_none());
+    }
+
+    #[test]
+    fn directory_reload_tracks_file_changes() {
+        let dir = std::env::temp_dir().join(format!(
+            "bridge-modules-{}-{:?}",
+            std::process::id(),
+            std::time::SystemTime::now()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("club.json"), club_module("club-stayman")).unwrap();
+        std::fs::write(dir.join("notes.md"), "ignored").unwrap();
+
+        let mut directory = ModuleDirectory::new(&dir);
+        assert!(directory.has_changed());
+        let (registry, report) = directory.reload(&ModuleRegistry::builtin()).unwrap();
+        assert!(report.is_clean(), "{:?}", report.errors);
+        assert!(registry.get("club-stayman").is_some());
+        assert!(!directory.has_changed());
+
+        std::fs::write(dir.join("second.json"), club_module("club-second")).unwrap();
+        assert!(directory.has_changed());
+        let (registry, report) = directory.reload(&registry).unwrap();
+        assert_eq!(report.loaded, vec!["club-stayman", "club-second"]);
+        assert!(registry.get("club-second").is_some());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,10 +1,14 @@
 //! Module registry — lookup individual convention modules by ID.
 //!
-//! Modules are pre-baked as JSON and embedded via `include_str!()`.
-//! Currently SAYC-only; multi-system support will add per-system variants.
+//! Modules are pre-baked as JSON and embedded via `include_str!()`. A
+//! `ModuleRegistry` built at runtime (see `module_loader`) can replace the
+//! embedded set through `install_modules`.
+//! Modules are authored for SAYC; Two-over-One and Acol variants are overlays
+//! (see `system_variants`) resolved by `get_module` with fallback to the
+//! shared definition.
 
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
 use bridge_engine::types::{BidSuit, Call};
 
@@ -20,9 +24,18 @@ use crate::types::rule_types::{
 };
 use crate::types::system_config::BaseSystemId;
 
+use super::bundle_registry::BundleCaches;
+use super::module_loader::ModuleRegistry;
+use super::system_variants::ModuleOverlay;
+
 /// All registered module IDs in definition order.
 const MODULE_IDS: &[&str] = &[
     "natural-bids",
+    "suit-responses",
+    "opener-rebids",
+    "responder-rebids",
+    "notrump-openings",
+    "preempts",
     "stayman",
     "stayman-garbage",
     "jacoby-transfers",
@@ -37,11 +50,21 @@ const MODULE_IDS: &[&str] = &[
     "negative-doubles",
     "new-minor-forcing",
     "natural-competitive",
+    "lebensohl",
+    "drury",
+    "fourth-suit-forcing",
 ];
 
 /// Base module IDs merged into every spec (strategy layer).
 /// Mirrors TS `DEFAULT_BASE_MODULE_IDS` in system-registry.ts.
 pub const BASE_MODULE_IDS: &[&str] = &[
+    // SAYC base-system modules precede natural-bids so their authored
+    // continuations are preferred over its generic rebids.
+    "suit-responses",
+    "opener-rebids",
+    "responder-rebids",
+    "notrump-openings",
+    "preempts",
     "natural-bids",
     "natural-competitive",
     "stayman",
@@ -49,8 +72,20 @@ pub const BASE_MODULE_IDS: &[&str] = &[
     "blackwood",
 ];
 
+/// SAYC base-system modules (first responses, opener and responder rebids,
+/// 2NT opening, preempts): the entries of `BASE_MODULE_IDS` ahead of
+/// natural-bids. Merged into SAYC specs alongside natural-bids so
+/// full-auction practice has authored continuations beyond the opening; see
+/// `get_base_system_module_ids` for other systems.
+pub const BASE_SYSTEM_MODULE_IDS: &[&str] = BASE_MODULE_IDS.split_at(5).0;
+
 // Embedded module JSON fixtures (SAYC)
 const NATURAL_BIDS_JSON: &str = include_str!("../../fixtures/modules/natural-bids.json");
+const SUIT_RESPONSES_JSON: &str = include_str!("../../fixtures/modules/suit-responses.json");
+const OPENER_REBIDS_JSON: &str = include_str!("../../fixtures/modules/opener-rebids.json");
+const RESPONDER_REBIDS_JSON: &str = include_str!("../../fixtures/modules/responder-rebids.json");
+const NOTRUMP_OPENINGS_JSON: &str = include_str!("../../fixtures/modules/notrump-openings.json");
+const PREEMPTS_JSON: &str = include_str!("../../fixtures/modules/preempts.json");
 const STAYMAN_JSON: &str = include_str!("../../fixtures/modules/stayman.json");
 const JACOBY_TRANSFERS_JSON: &str = include_str!("../../fixtures/modules/jacoby-transfers.json");
 const SMOLEN_JSON: &str = include_str!("../../fixtures/modules/smolen.json");
@@ -65,10 +100,19 @@ const STRONG_2C_JSON: &str = include_str!("../../fixtures/modules/strong-2c.json
 const NEGATIVE_DOUBLES_JSON: &str = include_str!("../../fixtures/modules/negative-doubles.json");
 const NEW_MINOR_FORCING_JSON: &str = include_str!("../../fixtures/modules/new-minor-forcing.json");
 const NATURAL_COMPETITIVE_JSON: &str = include_str!("../../fixtures/modules/natural-competitive.json");
+const LEBENSOHL_JSON: &str = include_str!("../../fixtures/modules/lebensohl.json");
+const DRURY_JSON: &str = include_str!("../../fixtures/modules/drury.json");
+const FOURTH_SUIT_FORCING_JSON: &str =
+    include_str!("../../fixtures/modules/fourth-suit-forcing.json");
 
 fn json_for_module(id: &str) -> Option<&'static str> {
     match id {
         "natural-bids" => Some(NATURAL_BIDS_JSON),
+        "suit-responses" => Some(SUIT_RESPONSES_JSON),
+        "opener-rebids" => Some(OPENER_REBIDS_JSON),
+        "responder-rebids" => Some(RESPONDER_REBIDS_JSON),
+        "notrump-openings" => Some(NOTRUMP_OPENINGS_JSON),
+        "preempts" => Some(PREEMPTS_JSON),
         "stayman" => Some(STAYMAN_JSON),
         "stayman-garbage" => Some(STAYMAN_GARBAGE_JSON),
         "jacoby-transfers" => Some(JACOBY_TRANSFERS_JSON),
@@ -83,10 +127,30 @@ fn json_for_module(id: &str) -> Option<&'static str> {
         "negative-doubles" => Some(NEGATIVE_DOUBLES_JSON),
         "new-minor-forcing" => Some(NEW_MINOR_FORCING_JSON),
         "natural-competitive" => Some(NATURAL_COMPETITIVE_JSON),
+        "lebensohl" => Some(LEBENSOHL_JSON),
+        "drury" => Some(DRURY_JSON),
+        "fourth-suit-forcing" => Some(FOURTH_SUIT_FORCING_JSON),
         _ => None,
     }
 }
 
+// Embedded system overlays (see `system_variants`)
+const ACOL_NATURAL_BIDS_JSON: &str =
+    include_str!("../../fixtures/modules/systems/acol/natural-bids.json");
+const ACOL_STAYMAN_JSON: &str = include_str!("../../fixtures/modules/systems/acol/stayman.json");
+const ACOL_STRONG_2C_JSON: &str =
+    include_str!("../../fixtures/modules/systems/acol/strong-2c.json");
+const TWO_OVER_ONE_NATURAL_BIDS_JSON: &str =
+    include_str!("../../fixtures/modules/systems/two-over-one/natural-bids.json");
+
+/// Overlay fixtures in application order.
+const OVERLAY_JSONS: &[(&str, &str)] = &[
+    ("acol/natural-bids", ACOL_NATURAL_BIDS_JSON),
+    ("acol/stayman", ACOL_STAYMAN_JSON),
+    ("acol/strong-2c", ACOL_STRONG_2C_JSON),
+    ("two-over-one/natural-bids", TWO_OVER_ONE_NATURAL_BIDS_JSON),
+];
+
 #[derive(Clone)]
 struct NegDblShownRoute {
     suit: &'static str,
@@ -242,6 +306,7 @@ fn meaning(
         encoding: BidEncoding {
             default_call: call,
             alternate_encodings: None,
+            relay: None,
         },
         clauses,
         ranking,
@@ -826,43 +891,118 @@ fn patch_negative_doubles_module(module: &mut ConventionModule) {
     states.extend(build_negative_doubles_after_negdbl_states());
 }
 
-/// Lazily deserialized module cache keyed by module ID.
-/// Currently SAYC-only; when multi-system is added, key becomes (id, system).
-static MODULE_CACHE: OnceLock<HashMap<String, ConventionModule>> = OnceLock::new();
+/// Apply load-time fixups keyed by module ID. Runs for embedded and
+/// runtime-loaded modules alike, so an edited copy behaves like the original.
+pub(crate) fn prepare_module(module: &mut ConventionModule) {
+    if module.module_id == "negative-doubles" {
+        patch_negative_doubles_module(module);
+    }
+}
 
-fn module_cache() -> &'static HashMap<String, ConventionModule> {
-    MODULE_CACHE.get_or_init(|| {
-        let mut map = HashMap::new();
-        for &id in MODULE_IDS {
-            if let Some(json) = json_for_module(id) {
-                match serde_json::from_str::<ConventionModule>(json) {
-                    Ok(mut module) => {
-                        if id == "negative-doubles" {
-                            patch_negative_doubles_module(&mut module);
-                        }
-                        map.insert(id.to_string(), module);
-                    }
-                    Err(e) => {
-                        panic!("Failed to deserialize module '{}': {}", id, e);
-                    }
-                }
-            }
-        }
-        map
+/// Embedded modules and their system variants, deserialized once in
+/// definition order.
+static EMBEDDED: OnceLock<ActiveModules> = OnceLock::new();
+
+fn embedded_modules() -> &'static ActiveModules {
+    EMBEDDED.get_or_init(|| {
+        let modules = MODULE_IDS
+            .iter()
+            .filter_map(|&id| json_for_module(id).map(|json| (id, json)))
+            .map(|(id, json)| {
+                let mut module = serde_json::from_str::<ConventionModule>(json)
+                    .unwrap_or_else(|e| panic!("Failed to deserialize module '{}': {}", id, e));
+                prepare_module(&mut module);
+                module
+            })
+            .collect();
+        let overlays = OVERLAY_JSONS
+            .iter()
+            .map(|(name, json)| {
+                serde_json::from_str::<ModuleOverlay>(json)
+                    .unwrap_or_else(|e| panic!("Failed to deserialize overlay '{}': {}", name, e))
+            })
+            .collect();
+        ActiveModules::new(ModuleRegistry::from_embedded(modules, overlays))
     })
 }
 
-/// Look up a module by ID for a given system.
-/// Currently returns SAYC modules for all systems (multi-system deferred).
-pub fn get_module(module_id: &str, _system: BaseSystemId) -> Option<&'static ConventionModule> {
-    module_cache().get(module_id)
+pub(crate) fn embedded_registry() -> &'static ModuleRegistry {
+    &embedded_modules().registry
+}
+
+/// The module set lookups resolve against, with the bundle caches derived
+/// from it. Replaced wholesale by `install_modules`, so derived bundles never
+/// mix two module sets.
+pub(crate) struct ActiveModules {
+    pub(crate) registry: ModuleRegistry,
+    pub(crate) bundles: BundleCaches,
+}
+
+impl ActiveModules {
+    fn new(registry: ModuleRegistry) -> Self {
+        Self {
+            registry,
+            bundles: BundleCaches::default(),
+        }
+    }
+}
+
+/// Module sets installed by `install_modules`.
+struct Installed {
+    /// The set lookups resolve against; `None` means the embedded modules.
+    current: Option<&'static ActiveModules>,
+    /// Every set allocated so far, reused when an equal registry comes back.
+    sets: Vec<&'static ActiveModules>,
 }
 
-/// Get all registered modules for a given system.
-/// Returns modules in definition order.
-pub fn get_all_modules(_system: BaseSystemId) -> Vec<&'static ConventionModule> {
-    let cache = module_cache();
-    MODULE_IDS.iter().filter_map(|&id| cache.get(id)).collect()
+static INSTALLED: RwLock<Installed> = RwLock::new(Installed {
+    current: None,
+    sets: Vec::new(),
+});
+
+pub(crate) fn active_modules() -> &'static ActiveModules {
+    let installed = INSTALLED.read().unwrap_or_else(|e| e.into_inner()).current;
+    installed.unwrap_or_else(embedded_modules)
+}
+
+/// The module set `get_module` and `get_all_modules` currently resolve against.
+pub fn active_registry() -> &'static ModuleRegistry {
+    &active_modules().registry
+}
+
+/// Make `registry` the module set every lookup resolves against, process-wide.
+///
+/// Lookups hand out `&'static` references, so an installed set stays
+/// allocated. A registry equal to the embedded set or to one installed
+/// before reuses that set, so reloading unchanged sources allocates nothing;
+/// only a new module set costs memory.
+pub fn install_modules(registry: ModuleRegistry) {
+    let mut installed = INSTALLED.write().unwrap_or_else(|e| e.into_inner());
+    if registry == embedded_modules().registry {
+        installed.current = None;
+        return;
+    }
+    let active = match installed.sets.iter().find(|set| set.registry == registry) {
+        Some(&set) => set,
+        None => {
+            let set: &'static ActiveModules = Box::leak(Box::new(ActiveModules::new(registry)));
+            installed.sets.push(set);
+            set
+        }
+    };
+    installed.current = Some(active);
+}
+
+/// Look up a module by ID for a given system: the system's variant if it has
+/// one, otherwise the shared definition.
+pub fn get_module(module_id: &str, system: BaseSystemId) -> Option<&'static ConventionModule> {
+    active_registry().get_for(module_id, system)
+}
+
+/// Get all registered modules for a given system, variants substituted.
+/// Returns embedded modules in definition order, then runtime-loaded ones.
+pub fn get_all_modules(system: BaseSystemId) -> Vec<&'static ConventionModule> {
+    active_registry().modules_for(system)
 }
 
 /// Get base module IDs for a system.
@@ -871,6 +1011,17 @@ pub fn get_base_module_ids(_system: BaseSystemId) -> &'static [&'static str] {
     BASE_MODULE_IDS
 }
 
+/// Base-system modules merged into a spec for `system`. They are authored
+/// for SAYC and have no Two-over-One or Acol variants, so those systems get
+/// none rather than SAYC responses and rebids. Custom systems resolve
+/// modules through SAYC and keep them.
+pub fn get_base_system_module_ids(system: BaseSystemId) -> &'static [&'static str] {
+    match system {
+        BaseSystemId::Sayc | BaseSystemId::Custom => BASE_SYSTEM_MODULE_IDS,
+        BaseSystemId::TwoOverOne | BaseSystemId::Acol => &[],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -878,6 +1029,21 @@ mod tests {
     use crate::types::bid_action::BidActionType;
     use crate::types::rule_types::PhaseRef;
 
+    #[test]
+    fn base_system_modules_are_the_ones_ahead_of_natural_bids() {
+        assert_eq!(
+            BASE_MODULE_IDS[BASE_SYSTEM_MODULE_IDS.len()],
+            "natural-bids"
+        );
+    }
+
+    #[test]
+    fn installing_the_embedded_set_reuses_it() {
+        install_modules(embedded_registry().clone());
+        assert!(std::ptr::eq(active_modules(), embedded_modules()));
+        assert!(INSTALLED.read().unwrap().sets.is_empty());
+    }
+
     #[test]
     fn get_module_stayman() {
         let module = get_module("stayman", BaseSystemId::Sayc);
@@ -905,6 +1071,98 @@ mod tests {
     }
 
 
+    fn surface_ids(module: &ConventionModule) -> Vec<&str> {
+        module
+            .states
+            .iter()
+            .flatten()
+            .flat_map(|state| &state.surfaces)
+            .map(|surface| surface.meaning_id.as_str())
+            .collect()
+    }
+
+    fn hcp_bounds(module: &ConventionModule, meaning_id: &str) -> Vec<ConstraintValue> {
+        module
+            .states
+            .iter()
+            .flatten()
+            .flat_map(|state| &state.surfaces)
+            .find(|surface| surface.meaning_id == meaning_id)
+            .unwrap()
+            .clauses
+            .iter()
+            .filter(|clause| clause.fact_id == "hand.hcp")
+            .map(|clause| clause.value.clone())
+            .collect()
+    }
+
+    #[test]
+    fn acol_gets_its_own_weak_nt_opening() {
+        let acol = get_module("natural-bids", BaseSystemId::Acol).unwrap();
+        let sayc = get_module("natural-bids", BaseSystemId::Sayc).unwrap();
+        assert_eq!(
+            hcp_bounds(acol, "bridge:1nt-opening"),
+            vec![ConstraintValue::int(12), ConstraintValue::int(14)]
+        );
+        assert_eq!(
+            hcp_bounds(sayc, "bridge:1nt-opening"),
+            vec![ConstraintValue::int(15), ConstraintValue::int(17)]
+        );
+
+        let stayman =
+            serde_json::to_string(get_module("stayman", BaseSystemId::Acol).unwrap()).unwrap();
+        assert!(stayman.contains("balanced 12-14 HCP"));
+        assert!(!stayman.contains("15-17"));
+    }
+
+    #[test]
+    fn two_over_one_opener_cannot_pass_the_forcing_nt() {
+        let two_over_one = get_module("natural-bids", BaseSystemId::TwoOverOne).unwrap();
+        let ids = surface_ids(two_over_one);
+        assert!(!ids.contains(&"natural:opener-rebid-pass-after-1h-1nt"));
+        assert!(!ids.contains(&"natural:opener-rebid-pass-after-1s-1nt"));
+        assert!(ids.contains(&"natural:opener-rebid-pass-after-1m-1nt"));
+        assert!(
+            surface_ids(get_module("natural-bids", BaseSystemId::Sayc).unwrap())
+                .contains(&"natural:opener-rebid-pass-after-1h-1nt")
+        );
+    }
+
+    #[test]
+    fn acol_strong_twos_extend_the_strong_2c_module() {
+        let acol = get_module("strong-2c", BaseSystemId::Acol).unwrap();
+        let ids = surface_ids(acol);
+        assert!(ids.contains(&"strong-2c:open-2c"));
+        assert!(ids.contains(&"strong-2c:acol-two-h"));
+        assert!(ids.contains(&"strong-2c:acol-two-s"));
+        assert!(
+            !surface_ids(get_module("strong-2c", BaseSystemId::TwoOverOne).unwrap())
+                .contains(&"strong-2c:acol-two-h")
+        );
+    }
+
+    #[test]
+    fn systems_without_a_variant_fall_back_to_the_shared_definition() {
+        let shared = get_module("smolen", BaseSystemId::Sayc).unwrap();
+        for system in [
+            BaseSystemId::TwoOverOne,
+            BaseSystemId::Acol,
+            BaseSystemId::Custom,
+        ] {
+            assert!(std::ptr::eq(get_module("smolen", system).unwrap(), shared));
+        }
+        assert!(std::ptr::eq(
+            get_module("natural-bids", BaseSystemId::Custom).unwrap(),
+            get_module("natural-bids", BaseSystemId::Sayc).unwrap()
+        ));
+
+        let ids: Vec<&str> = get_all_modules(BaseSystemId::Acol)
+            .iter()
+            .map(|m| m.module_id.as_str())
+            .collect();
+        assert_eq!(ids, MODULE_IDS);
+    }
+
     #[test]
     fn base_module_ids() {
         let ids = get_base_module_ids(BaseSystemId::Sayc);
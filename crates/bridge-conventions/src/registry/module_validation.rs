@@ -0,0 +1,274 @@
+//! Module validation — content checks run before a module is registered.
+//!
+//! Covers what deserialization cannot: identity, reachability of authored
+//! states, clause fact namespaces and numeric ranges. The embedded fixtures
+//! are held to stricter invariants by `tests/structural_invariants.rs`; these
+//! are the checks a module loaded at runtime must pass to be drillable.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::meaning::{ConstraintValue, FactOperator};
+use crate::types::module_types::ConventionModule;
+use crate::types::rule_types::PhaseRef;
+
+/// Namespaces a clause `factId` may live in.
+const FACT_NAMESPACES: &[&str] = &["hand.", "system.", "module.", "bridge."];
+
+/// One problem found in a module, located by a JSON-ish field path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleIssue {
+    pub field: String,
+    pub message: String,
+}
+
+impl ModuleIssue {
+    pub(crate) fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Validate a module's content. An empty result means the module is valid.
+pub fn validate_module(module: &ConventionModule) -> Vec<ModuleIssue> {
+    let mut issues = Vec::new();
+
+    if module.module_id.trim().is_empty() {
+        issues.push(ModuleIssue::new("moduleId", "Module ID must not be empty"));
+    } else if module.module_id.chars().any(char::is_whitespace) {
+        issues.push(ModuleIssue::new(
+            "moduleId",
+            format!(
+                "Module ID '{}' must not contain whitespace",
+                module.module_id
+            ),
+        ));
+    }
+
+    if module.display_name.trim().is_empty() {
+        issues.push(ModuleIssue::new(
+            "displayName",
+            "Display name must not be empty",
+        ));
+    }
+
+    let has_surfaces = module
+        .states
+        .as_ref()
+        .is_some_and(|states| states.iter().any(|s| !s.surfaces.is_empty()));
+    if !has_surfaces {
+        issues.push(ModuleIssue::new(
+            "states",
+            "Module must have at least one surface",
+        ));
+    }
+
+    let reachable = reachable_phases(module);
+    for (si, state) in module.states.iter().flatten().enumerate() {
+        for phase in phase_names(&state.phase) {
+            if !reachable.contains(phase) {
+                issues.push(ModuleIssue::new(
+                    format!("states[{}].phase", si),
+                    format!(
+                        "Phase '{}' is not reachable from initial phase '{}'",
+                        phase, module.local.initial
+                    ),
+                ));
+            }
+        }
+
+        let mut seen: HashSet<(&str, i32)> = HashSet::new();
+        for (surf_i, surface) in state.surfaces.iter().enumerate() {
+            let path_prefix = format!("states[{}].surfaces[{}]", si, surf_i);
+
+            if let Some(surface_module_id) = &surface.module_id {
+                let matches_parent = module.variant_of.as_ref() == Some(surface_module_id);
+                if surface_module_id != &module.module_id && !matches_parent {
+                    issues.push(ModuleIssue::new(
+                        format!("{}.moduleId", path_prefix),
+                        format!(
+                            "Surface '{}' belongs to '{}', not '{}'",
+                            surface.meaning_id, surface_module_id, module.module_id
+                        ),
+                    ));
+                }
+            }
+
+            if !seen.insert((
+                surface.meaning_id.as_str(),
+                surface.ranking.declaration_order,
+            )) {
+                issues.push(ModuleIssue::new(
+                    path_prefix.clone(),
+                    format!(
+                        "Duplicate surface '{}' with declarationOrder {}",
+                        surface.meaning_id, surface.ranking.declaration_order
+                    ),
+                ));
+            }
+
+            // Collect gte/lte pairs per fact_id for range consistency
+            let mut gte_values: HashMap<&str, i32> = HashMap::new();
+            let mut lte_values: HashMap<&str, i32> = HashMap::new();
+
+            for (ci, clause) in surface.clauses.iter().enumerate() {
+                let clause_path = format!("{}.clauses[{}]", path_prefix, ci);
+                let fact = clause.fact_id.as_str();
+
+                if !FACT_NAMESPACES.iter().any(|ns| fact.starts_with(ns)) {
+                    issues.push(ModuleIssue::new(
+                        format!("{}.factId", clause_path),
+                        format!("Fact '{}' is not in a known namespace", fact),
+                    ));
+                }
+
+                let Some(val) = constraint_value_as_i32(&clause.value) else {
+                    continue;
+                };
+                if fact.contains("hcp") || fact.contains("points") || fact.contains("tp") {
+                    if !(0..=40).contains(&val) {
+                        issues.push(ModuleIssue::new(
+                            clause_path,
+                            format!("Value {} for '{}' outside valid range 0-40", val, fact),
+                        ));
+                    }
+                } else if (fact.contains("length") || fact.contains("count"))
+                    && !(0..=13).contains(&val)
+                {
+                    issues.push(ModuleIssue::new(
+                        clause_path,
+                        format!("Value {} for '{}' outside valid range 0-13", val, fact),
+                    ));
+                }
+
+                match clause.operator {
+                    FactOperator::Gte => {
+                        gte_values.insert(fact, val);
+                    }
+                    FactOperator::Lte => {
+                        lte_values.insert(fact, val);
+                    }
+                    _ => {}
+                }
+            }
+
+            for (fact_id, gte_val) in &gte_values {
+                if let Some(lte_val) = lte_values.get(fact_id) {
+                    if gte_val > lte_val {
+                        issues.push(ModuleIssue::new(
+                            format!("{}.clauses", path_prefix),
+                            format!(
+                                "Range error for '{}': gte ({}) > lte ({})",
+                                fact_id, gte_val, lte_val
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+fn constraint_value_as_i32(value: &ConstraintValue) -> Option<i32> {
+    match value {
+        ConstraintValue::Number(n) => n.as_i64().map(|v| v as i32),
+        _ => None,
+    }
+}
+
+fn reachable_phases(module: &ConventionModule) -> HashSet<&str> {
+    let mut reachable = HashSet::from([module.local.initial.as_str()]);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for transition in &module.local.transitions {
+            let from_reachable = phase_names(&transition.from)
+                .into_iter()
+                .any(|phase| reachable.contains(phase));
+            if from_reachable && reachable.insert(transition.to.as_str()) {
+                changed = true;
+            }
+        }
+    }
+    reachable
+}
+
+fn phase_names(phase: &PhaseRef) -> Vec<&str> {
+    match phase {
+        PhaseRef::Single(name) => vec![name.as_str()],
+        PhaseRef::Multiple(names) => names.iter().map(String::as_str).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::module_registry::get_all_modules;
+    use crate::types::system_config::BaseSystemId;
+
+    fn stayman() -> ConventionModule {
+        crate::registry::module_registry::get_module("stayman", BaseSystemId::Sayc)
+            .expect("stayman is embedded")
+            .clone()
+    }
+
+    #[test]
+    fn embedded_modules_are_valid() {
+        for module in get_all_modules(BaseSystemId::Sayc) {
+            let issues = validate_module(module);
+            assert!(issues.is_empty(), "{}: {:?}", module.module_id, issues);
+        }
+    }
+
+    #[test]
+    fn unreachable_phases_are_reported() {
+        let mut module = stayman();
+        module.local.transitions.clear();
+        let issues = validate_module(&module);
+        assert!(issues
+            .iter()
+            .any(|issue| issue.field.ends_with(".phase") && issue.message.contains("reachable")));
+    }
+
+    #[test]
+    fn clause_problems_are_located() {
+        let mut module = stayman();
+        let states = module.states.as_mut().unwrap();
+        let (si, state) = states
+            .iter_mut()
+            .enumerate()
+            .find(|(_, state)| !state.surfaces.is_empty())
+            .unwrap();
+        let clause = &mut state.surfaces[0].clauses[0];
+        clause.fact_id = "hcp".to_string();
+        clause.value = ConstraintValue::Number(99.into());
+
+        let issues = validate_module(&module);
+        let prefix = format!("states[{}].surfaces[0].clauses[0]", si);
+        assert!(issues
+            .iter()
+            .any(|issue| issue.field == format!("{}.factId", prefix)));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.field == prefix && issue.message.contains("0-40")));
+    }
+
+    #[test]
+    fn blank_identity_is_reported() {
+        let mut module = stayman();
+        module.module_id = "club stayman".to_string();
+        module.display_name = " ".to_string();
+        let fields: Vec<String> = validate_module(&module)
+            .into_iter()
+            .map(|issue| issue.field)
+            .collect();
+        assert!(fields.contains(&"moduleId".to_string()));
+        assert!(fields.contains(&"displayName".to_string()));
+    }
+}
@@ -10,7 +10,7 @@ use crate::types::spec_types::ConventionSpec;
 use crate::types::system_config::{BaseSystemId, SystemConfig};
 
 use super::bundle_registry::get_bundle_input;
-use super::module_registry::get_module;
+use super::module_registry::{get_base_system_module_ids, get_module};
 
 /// Build a ConventionSpec from a bundle + provided base modules + system config.
 ///
@@ -64,15 +64,19 @@ pub fn spec_from_bundle(
             all_ids.push(effective_id);
         }
     }
-    // Merge natural-bids (observation vocabulary), natural-competitive
+    // Merge natural-bids (observation vocabulary), the rest of the base
+    // system where it is authored for this system (responses, rebids, 2NT
+    // and preempts), natural-competitive
     // (competitive opener-side surfaces over an opponent's one-of-a-suit
     // opening so a partnership playing natural conventions still gets
     // feedback when the opponents open), and user-forked modules. Other base
     // modules (Stayman / Jacoby / Blackwood) deliberately stay out of the
     // dominated set because their attachment context (1NT openings, slam
     // sequences) does not arise in every bundle.
+    let base_system_ids = get_base_system_module_ids(system);
     for id in base_module_ids {
         let dominated = id == "natural-bids"
+            || base_system_ids.contains(&id.as_str())
             || id == "natural-competitive"
             || id.starts_with("user:");
         if dominated && seen.insert(id.as_str()) {
@@ -80,7 +84,7 @@ pub fn spec_from_bundle(
         }
     }
 
-    // Custom systems use SAYC modules (multi-system module variants deferred)
+    // Custom systems have no module variants; they use the shared (SAYC) modules
     let lookup_system = match system {
         BaseSystemId::Custom => BaseSystemId::Sayc,
         other => other,
@@ -113,7 +117,7 @@ pub fn spec_from_bundle(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::registry::module_registry::BASE_MODULE_IDS;
+    use crate::registry::module_registry::{BASE_MODULE_IDS, BASE_SYSTEM_MODULE_IDS};
     use crate::registry::system_configs::get_system_config;
 
     fn default_base_module_ids() -> Vec<String> {
@@ -139,14 +143,15 @@ mod tests {
 
         let module_ids: Vec<&str> = s.modules.iter().map(|m| m.module_id.as_str()).collect();
 
-        // Bundle members + always-merged base modules (natural-bids,
+        // Bundle members + always-merged base modules (base system,
         // natural-competitive)
         assert!(module_ids.contains(&"stayman"));
         assert!(module_ids.contains(&"jacoby-transfers"));
         assert!(module_ids.contains(&"smolen"));
+        assert!(module_ids.contains(&"suit-responses"));
         assert!(module_ids.contains(&"natural-bids"));
         assert!(module_ids.contains(&"natural-competitive"));
-        assert_eq!(module_ids.len(), 5);
+        assert_eq!(module_ids.len(), 10);
     }
 
     #[test]
@@ -161,7 +166,16 @@ mod tests {
         // Bundle member + always-merged base modules
         assert_eq!(
             module_ids,
-            vec!["bergen", "natural-bids", "natural-competitive"]
+            vec![
+                "bergen",
+                "suit-responses",
+                "opener-rebids",
+                "responder-rebids",
+                "notrump-openings",
+                "preempts",
+                "natural-bids",
+                "natural-competitive",
+            ]
         );
     }
 
@@ -209,8 +223,9 @@ mod tests {
         assert_eq!(module_ids[0], "stayman");
         assert_eq!(module_ids[1], "jacoby-transfers");
         assert_eq!(module_ids[2], "smolen");
-        assert_eq!(module_ids[3], "natural-bids");
-        assert_eq!(module_ids[4], "natural-competitive");
+        assert_eq!(module_ids[3], "suit-responses");
+        assert_eq!(module_ids[8], "natural-bids");
+        assert_eq!(module_ids[9], "natural-competitive");
     }
 
     #[test]
@@ -251,4 +266,46 @@ mod tests {
         let sc = spec.system_config.unwrap();
         assert_eq!(sc.system_id, BaseSystemId::Custom);
     }
+
+    #[test]
+    fn spec_from_bundle_uses_system_variants() {
+        let config = get_system_config(BaseSystemId::Acol);
+        let base = default_base_module_ids();
+        let spec = spec_from_bundle("nt-bundle", &config, &base, &no_user_modules()).unwrap();
+
+        for module in &spec.modules {
+            assert_eq!(
+                Some(module),
+                get_module(&module.module_id, BaseSystemId::Acol),
+                "{}",
+                module.module_id
+            );
+        }
+        let natural = spec
+            .modules
+            .iter()
+            .find(|m| m.module_id == "natural-bids")
+            .unwrap();
+        assert_ne!(
+            Some(natural),
+            get_module("natural-bids", BaseSystemId::Sayc)
+        );
+    }
+
+    #[test]
+    fn spec_from_bundle_leaves_sayc_base_system_modules_out_of_acol() {
+        let config = get_system_config(BaseSystemId::Acol);
+        let base = default_base_module_ids();
+        let spec = spec_from_bundle("nt-bundle", &config, &base, &no_user_modules()).unwrap();
+
+        let module_ids: Vec<&str> = spec.modules.iter().map(|m| m.module_id.as_str()).collect();
+        for id in BASE_SYSTEM_MODULE_IDS {
+            assert!(!module_ids.contains(id), "{id} in {module_ids:?}");
+        }
+        assert!(module_ids.contains(&"natural-bids"));
+
+        let sayc =
+            spec_from_bundle("nt-bundle", &sayc_config(), &base, &no_user_modules()).unwrap();
+        assert!(sayc.modules.iter().any(|m| m.module_id == "suit-responses"));
+    }
 }
@@ -0,0 +1,405 @@
+//! System variants — per-system edits layered over the shared modules.
+//!
+//! The embedded modules are authored for SAYC. Where Two-over-One or Acol
+//! play a convention differently, a `ModuleOverlay` records only the
+//! difference: replaced or removed surfaces, added states and transitions,
+//! upserted fact definitions, and prose rewrites. `ModuleRegistry` applies
+//! each overlay to a copy of the shared module; lookups for that system get
+//! the copy, every other system keeps the shared definition.
+//!
+//! Overlay fixtures live in `fixtures/modules/systems/<system>/<module-id>.json`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::fact_types::FactDefinition;
+use crate::types::meaning::BidMeaning;
+use crate::types::module_types::ConventionModule;
+use crate::types::rule_types::{PhaseTransition, StateEntry};
+use crate::types::system_config::BaseSystemId;
+
+use super::module_validation::{validate_module, ModuleIssue};
+
+/// A system-specific edit to one shared module.
+///
+/// Edits apply in field order; `text_replacements` run last so they also
+/// reach surfaces the overlay itself added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleOverlay {
+    pub module_id: String,
+    pub system: BaseSystemId,
+    /// Author's note on why the system differs. Not applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Fact definitions added to the module, replacing any with the same ID.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub facts: Vec<FactDefinition>,
+    /// Surfaces that replace every surface with the same `meaningId`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replace_surfaces: Vec<BidMeaning>,
+    /// `meaningId`s removed from every state.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove_surfaces: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_transitions: Vec<PhaseTransition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_states: Vec<StateEntry>,
+    /// Literal rewrites applied to the module's prose (`PROSE_FIELDS`), e.g.
+    /// a 1NT range quoted in worked-auction rationales. IDs, fact names and
+    /// other machine-read strings are never touched.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub text_replacements: Vec<TextReplacement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextReplacement {
+    pub find: String,
+    pub replace: String,
+}
+
+/// Apply `overlay` to a copy of `module`. Fails when an edit does not match
+/// the module (a stale overlay) or the result does not validate.
+pub fn apply_overlay(
+    module: &ConventionModule,
+    overlay: &ModuleOverlay,
+) -> Result<ConventionModule, Vec<ModuleIssue>> {
+    if overlay.module_id != module.module_id {
+        return Err(vec![ModuleIssue::new(
+            "moduleId",
+            format!(
+                "Overlay for '{}' applied to '{}'",
+                overlay.module_id, module.module_id
+            ),
+        )]);
+    }
+
+    let mut variant = module.clone();
+    let mut issues = Vec::new();
+
+    for fact in &overlay.facts {
+        let definitions = &mut variant.facts.definitions;
+        match definitions.iter_mut().find(|d| d.id == fact.id) {
+            Some(existing) => *existing = fact.clone(),
+            None => definitions.push(fact.clone()),
+        }
+    }
+
+    for (i, replacement) in overlay.replace_surfaces.iter().enumerate() {
+        let mut matched = false;
+        for surface in surfaces_mut(&mut variant) {
+            if surface.meaning_id == replacement.meaning_id {
+                *surface = replacement.clone();
+                matched = true;
+            }
+        }
+        if !matched {
+            issues.push(missing_surface(
+                format!("replaceSurfaces[{}]", i),
+                &replacement.meaning_id,
+                module,
+            ));
+        }
+    }
+
+    for (i, meaning_id) in overlay.remove_surfaces.iter().enumerate() {
+        let mut matched = false;
+        for state in variant.states.iter_mut().flatten() {
+            let before = state.surfaces.len();
+            state.surfaces.retain(|s| &s.meaning_id != meaning_id);
+            matched |= state.surfaces.len() != before;
+        }
+        if !matched {
+            issues.push(missing_surface(
+                format!("removeSurfaces[{}]", i),
+                meaning_id,
+                module,
+            ));
+        }
+    }
+
+    variant
+        .local
+        .transitions
+        .extend(overlay.add_transitions.iter().cloned());
+    if !overlay.add_states.is_empty() {
+        variant
+            .states
+            .get_or_insert_with(Vec::new)
+            .extend(overlay.add_states.iter().cloned());
+    }
+
+    if !overlay.text_replacements.is_empty() {
+        match replace_text(&variant, &overlay.text_replacements) {
+            Ok((rewritten, unmatched)) => {
+                variant = rewritten;
+                for i in unmatched {
+                    issues.push(ModuleIssue::new(
+                        format!("textReplacements[{}]", i),
+                        format!(
+                            "'{}' does not occur in module '{}'",
+                            overlay.text_replacements[i].find, module.module_id
+                        ),
+                    ));
+                }
+            }
+            Err(message) => issues.push(ModuleIssue::new("textReplacements", message)),
+        }
+    }
+
+    issues.extend(validate_module(&variant));
+    if issues.is_empty() {
+        Ok(variant)
+    } else {
+        Err(issues)
+    }
+}
+
+fn surfaces_mut(module: &mut ConventionModule) -> impl Iterator<Item = &mut BidMeaning> {
+    module
+        .states
+        .iter_mut()
+        .flatten()
+        .flat_map(|state| state.surfaces.iter_mut())
+}
+
+fn missing_surface(field: String, meaning_id: &str, module: &ConventionModule) -> ModuleIssue {
+    ModuleIssue::new(
+        field,
+        format!(
+            "No surface '{}' in module '{}'",
+            meaning_id, module.module_id
+        ),
+    )
+}
+
+/// JSON fields holding prose shown to the user — names, descriptions and
+/// teaching text. Text replacements reach only these.
+const PROSE_FIELDS: &[&str] = &[
+    "agreementNote",
+    "commonMistakes",
+    "contrastiveDisplayText",
+    "description",
+    "discriminator",
+    "discriminatorLabel",
+    "displayName",
+    "displayText",
+    "gloss",
+    "name",
+    "note",
+    "opponentAction",
+    "ourAction",
+    "principle",
+    "purpose",
+    "rationale",
+    "reason",
+    "recommendation",
+    "scopeNote",
+    "summary",
+    "tradeoff",
+    "trigger",
+    "whenNotToUse",
+    "whenToUse",
+];
+
+/// Rewrite the prose strings in the module's JSON form. Returns the
+/// rewritten module and the indices of replacements that matched nothing.
+fn replace_text(
+    module: &ConventionModule,
+    replacements: &[TextReplacement],
+) -> Result<(ConventionModule, Vec<usize>), String> {
+    let mut value = serde_json::to_value(module).map_err(|e| e.to_string())?;
+    let mut hits = vec![0usize; replacements.len()];
+    rewrite_prose(&mut value, replacements, &mut hits);
+    let rewritten = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    let unmatched = hits
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count == 0)
+        .map(|(i, _)| i)
+        .collect();
+    Ok((rewritten, unmatched))
+}
+
+/// Walk `value` and rewrite every string under a `PROSE_FIELDS` key.
+fn rewrite_prose(
+    value: &mut serde_json::Value,
+    replacements: &[TextReplacement],
+    hits: &mut [usize],
+) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                rewrite_prose(item, replacements, hits);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if PROSE_FIELDS.contains(&key.as_str()) {
+                    rewrite_strings(field, replacements, hits);
+                } else {
+                    rewrite_prose(field, replacements, hits);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_strings(
+    value: &mut serde_json::Value,
+    replacements: &[TextReplacement],
+    hits: &mut [usize],
+) {
+    match value {
+        serde_json::Value::String(text) => {
+            for (replacement, count) in replacements.iter().zip(hits.iter_mut()) {
+                let found = text.matches(replacement.find.as_str()).count();
+                if found > 0 {
+                    *count += found;
+                    *text = text.replace(&replacement.find, &replacement.replace);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                rewrite_strings(item, replacements, hits);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for field in fields.values_mut() {
+                rewrite_strings(field, replacements, hits);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::module_loader::ModuleRegistry;
+
+    fn overlay(module_id: &str) -> ModuleOverlay {
+        ModuleOverlay {
+            module_id: module_id.to_string(),
+            system: BaseSystemId::Acol,
+            note: None,
+            facts: Vec::new(),
+            replace_surfaces: Vec::new(),
+            remove_surfaces: Vec::new(),
+            add_transitions: Vec::new(),
+            add_states: Vec::new(),
+            text_replacements: Vec::new(),
+        }
+    }
+
+    fn shared(module_id: &str) -> ConventionModule {
+        ModuleRegistry::builtin().get(module_id).unwrap().clone()
+    }
+
+    #[test]
+    fn replacements_and_removals_match_by_meaning_id() {
+        let module = shared("natural-bids");
+        let mut opening = module.states.as_ref().unwrap()[0].surfaces[0].clone();
+        opening.teaching_label.name = crate::types::authored_text::BidName::new("Edited");
+        let mut edit = overlay("natural-bids");
+        edit.replace_surfaces.push(opening.clone());
+        edit.remove_surfaces
+            .push("natural:opener-rebid-pass-after-1h-1nt".to_string());
+
+        let variant = apply_overlay(&module, &edit).unwrap();
+        let surfaces: Vec<&BidMeaning> = variant
+            .states
+            .iter()
+            .flatten()
+            .flat_map(|state| &state.surfaces)
+            .collect();
+        assert!(surfaces.contains(&&opening));
+        assert!(!surfaces
+            .iter()
+            .any(|s| s.meaning_id == "natural:opener-rebid-pass-after-1h-1nt"));
+        // The shared definition is untouched.
+        assert_ne!(module, variant);
+        assert_eq!(module, shared("natural-bids"));
+    }
+
+    #[test]
+    fn text_replacements_reach_the_prose() {
+        let mut edit = overlay("stayman");
+        edit.text_replacements.push(TextReplacement {
+            find: "15-17".to_string(),
+            replace: "12-14".to_string(),
+        });
+        let variant = apply_overlay(&shared("stayman"), &edit).unwrap();
+        let json = serde_json::to_string(&variant).unwrap();
+        assert!(!json.contains("15-17"));
+        assert!(json.contains("balanced 12-14 HCP"));
+    }
+
+    #[test]
+    fn text_replacements_leave_ids_alone() {
+        let module = shared("stayman");
+        let mut edit = overlay("stayman");
+        edit.text_replacements.push(TextReplacement {
+            find: "stayman".to_string(),
+            replace: "STAYMAN".to_string(),
+        });
+        let variant = apply_overlay(&module, &edit).unwrap();
+
+        assert_eq!(variant.module_id, module.module_id);
+        let ids = |m: &ConventionModule| -> Vec<(String, String)> {
+            m.states
+                .iter()
+                .flatten()
+                .flat_map(|state| &state.surfaces)
+                .map(|s| (s.meaning_id.clone(), s.semantic_class_id.clone()))
+                .collect()
+        };
+        assert_eq!(ids(&variant), ids(&module));
+        let fact_ids = |m: &ConventionModule| -> Vec<String> {
+            m.facts.definitions.iter().map(|d| d.id.clone()).collect()
+        };
+        assert_eq!(fact_ids(&variant), fact_ids(&module));
+        // Only prose changed.
+        let json = serde_json::to_string(&variant).unwrap();
+        assert!(json.contains("STAYMAN"));
+        assert_eq!(
+            json.replace("STAYMAN", "stayman"),
+            serde_json::to_string(&module).unwrap()
+        );
+    }
+
+    #[test]
+    fn stale_edits_are_reported() {
+        let mut edit = overlay("stayman");
+        edit.remove_surfaces
+            .push("stayman:no-such-surface".to_string());
+        edit.text_replacements.push(TextReplacement {
+            find: "no such prose".to_string(),
+            replace: "anything".to_string(),
+        });
+        let fields: Vec<String> = apply_overlay(&shared("stayman"), &edit)
+            .unwrap_err()
+            .into_iter()
+            .map(|issue| issue.field)
+            .collect();
+        assert_eq!(fields, vec!["removeSurfaces[0]", "textReplacements[0]"]);
+
+        let wrong_module = apply_overlay(&shared("smolen"), &overlay("stayman")).unwrap_err();
+        assert_eq!(wrong_module[0].field, "moduleId");
+    }
+
+    #[test]
+    fn overlaid_modules_are_validated() {
+        let module = shared("stayman");
+        let mut state = module.states.as_ref().unwrap()[0].clone();
+        state.phase = crate::types::rule_types::PhaseRef::Single("nowhere".to_string());
+        let mut edit = overlay("stayman");
+        edit.add_states.push(state);
+        let issues = apply_overlay(&module, &edit).unwrap_err();
+        assert!(issues
+            .iter()
+            .any(|issue| issue.message.contains("'nowhere' is not reachable")));
+    }
+}
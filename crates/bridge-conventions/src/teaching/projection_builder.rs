@@ -388,6 +388,7 @@ mod tests {
             encoding: BidEncoding {
                 default_call: make_call_2c(),
                 alternate_encodings: None,
+                relay: None,
             },
         };
         let carrier = PipelineCarrier {
@@ -497,6 +498,7 @@ mod tests {
                 encoding: BidEncoding {
                     default_call: call_3nt.clone(),
                     alternate_encodings: None,
+                    relay: None,
                 },
             };
             PipelineCarrier {
@@ -87,6 +87,7 @@ pub enum BidActionType {
     Force,
     Agree,
     Relay,
+    Puppet,
     Overcall,
     Double,
     Pass,
@@ -164,6 +165,10 @@ pub enum BidAction {
     Agree { strain: BidSuitName },
     #[serde(rename = "relay")]
     Relay { forced: bool },
+    /// Puppet to a forced reply in `strain`; the meaning rides on the
+    /// puppeteer's next call.
+    #[serde(rename = "puppet")]
+    Puppet { strain: BidSuitName },
     #[serde(rename = "overcall")]
     Overcall {
         feature: HandFeature,
@@ -195,6 +200,7 @@ impl BidAction {
             BidAction::Force { .. } => &BidActionType::Force,
             BidAction::Agree { .. } => &BidActionType::Agree,
             BidAction::Relay { .. } => &BidActionType::Relay,
+            BidAction::Puppet { .. } => &BidActionType::Puppet,
             BidAction::Overcall { .. } => &BidActionType::Overcall,
             BidAction::Double { .. } => &BidActionType::Double,
             BidAction::Pass => &BidActionType::Pass,
@@ -244,7 +250,8 @@ impl BidAction {
             BidAction::Open { strain, .. }
             | BidAction::Raise { strain, .. }
             | BidAction::Place { strain }
-            | BidAction::Agree { strain } => Some(strain),
+            | BidAction::Agree { strain }
+            | BidAction::Puppet { strain } => Some(strain),
             BidAction::Signoff { strain } => strain.as_ref(),
             _ => None,
         }
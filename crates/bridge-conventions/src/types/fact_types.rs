@@ -245,6 +245,22 @@ pub enum FactComposition {
     Compute { expr: ComputeExpr },
 }
 
+/// What a partnership-wide fact totals across both partners' hands.
+///
+/// Declared on the fact so deal generation can turn surface clauses on it
+/// into partnership constraints without knowing which module owns it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PartnershipMeasure {
+    Hcp,
+    /// Combined length in one suit.
+    Fit {
+        suit: Suit,
+    },
+    Aces,
+    Kings,
+}
+
 /// A fact definition in the catalog.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -261,6 +277,9 @@ pub struct FactDefinition {
     pub composition: Option<FactComposition>,
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub for_teaching_only: bool,
+    /// Set when the fact measures the partnership rather than one hand.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partnership_measure: Option<PartnershipMeasure>,
 }
 
 /// Data-only subset of TS `FactCatalogExtension`.
@@ -347,9 +366,18 @@ mod tests {
             constrains_dimensions: vec![ConstraintDimension::SuitLength],
             composition: None,
             for_teaching_only: false,
+            partnership_measure: None,
         };
         let json = serde_json::to_string(&def).unwrap();
         let back: FactDefinition = serde_json::from_str(&json).unwrap();
         assert_eq!(back, def);
     }
+
+    #[test]
+    fn partnership_measure_serde() {
+        let json = r#"{"kind":"fit","suit":"H"}"#;
+        let measure: PartnershipMeasure = serde_json::from_str(json).unwrap();
+        assert_eq!(measure, PartnershipMeasure::Fit { suit: Suit::Hearts });
+        assert_eq!(serde_json::to_string(&measure).unwrap(), json);
+    }
 }
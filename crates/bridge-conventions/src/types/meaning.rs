@@ -151,6 +151,18 @@ pub struct AlternateEncoding {
     pub condition: Option<String>,
 }
 
+/// Relay (puppet) encoding: `default_call` forces partner to bid
+/// `forced_reply`, and the meaning is carried by `continuation`.
+///
+/// E.g. Lebensohl 2NT → 3C → 3D: the surface's clauses describe the
+/// diamond signoff, not the 2NT call itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayEncoding {
+    pub forced_reply: Call,
+    pub continuation: Call,
+}
+
 /// Encoding specification for a bid meaning.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -158,6 +170,8 @@ pub struct BidEncoding {
     pub default_call: Call,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alternate_encodings: Option<Vec<AlternateEncoding>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay: Option<RelayEncoding>,
 }
 
 /// A bid meaning surface — the complete specification of what a bid means.
@@ -267,6 +281,7 @@ mod tests {
                     strain: BidSuit::Clubs,
                 },
                 alternate_encodings: None,
+                relay: None,
             },
             clauses: vec![BidMeaningClause {
                 fact_id: "hand.hcp".to_string(),
@@ -299,4 +314,36 @@ mod tests {
         let back: BidMeaning = serde_json::from_str(&json).unwrap();
         assert_eq!(back, meaning);
     }
+
+    #[test]
+    fn bid_encoding_relay_serde() {
+        let json = r#"{
+            "defaultCall": {"type": "bid", "level": 2, "strain": "NT"},
+            "relay": {
+                "forcedReply": {"type": "bid", "level": 3, "strain": "C"},
+                "continuation": {"type": "bid", "level": 3, "strain": "D"}
+            }
+        }"#;
+        let encoding: BidEncoding = serde_json::from_str(json).unwrap();
+        let relay = encoding.relay.as_ref().expect("relay should deserialize");
+        assert_eq!(
+            relay.forced_reply,
+            Call::Bid {
+                level: 3,
+                strain: BidSuit::Clubs
+            }
+        );
+        assert_eq!(
+            relay.continuation,
+            Call::Bid {
+                level: 3,
+                strain: BidSuit::Diamonds
+            }
+        );
+
+        let plain: BidEncoding =
+            serde_json::from_str(r#"{"defaultCall": {"type": "pass"}}"#).unwrap();
+        assert!(plain.relay.is_none());
+        assert!(!serde_json::to_string(&plain).unwrap().contains("relay"));
+    }
 }
@@ -22,31 +22,56 @@ use bridge_engine::types::{BidSuit, Call};
 /// Each entry pins the expected authored `biddingContext`; drift causes a
 /// clear failure.
 fn allowlist() -> Vec<(&'static str, BiddingContext)> {
-    vec![(
-        "negative-doubles",
-        BiddingContext {
-            opener_bids: vec![
-                Call::Bid {
-                    level: 1,
-                    strain: BidSuit::Clubs,
-                },
-                Call::Bid {
-                    level: 1,
-                    strain: BidSuit::Diamonds,
-                },
-                Call::Bid {
-                    level: 1,
-                    strain: BidSuit::Hearts,
-                },
-                Call::Bid {
-                    level: 1,
-                    strain: BidSuit::Spades,
-                },
-            ],
-            opener_role: OpenerRole::Partner,
-            competitive: true,
-        },
-    )]
+    vec![
+        (
+            "negative-doubles",
+            BiddingContext {
+                opener_bids: vec![
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Clubs,
+                    },
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Diamonds,
+                    },
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Hearts,
+                    },
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Spades,
+                    },
+                ],
+                opener_role: OpenerRole::Partner,
+                competitive: true,
+            },
+        ),
+        // The idle exit is opener's second suit; the opening itself lives in
+        // the host attachments.
+        (
+            "fourth-suit-forcing",
+            BiddingContext {
+                opener_bids: vec![
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Clubs,
+                    },
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Diamonds,
+                    },
+                    Call::Bid {
+                        level: 1,
+                        strain: BidSuit::Hearts,
+                    },
+                ],
+                opener_role: OpenerRole::Partner,
+                competitive: false,
+            },
+        ),
+    ]
 }
 
 fn fixtures_dir() -> PathBuf {
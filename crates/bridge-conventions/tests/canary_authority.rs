@@ -613,6 +613,63 @@ const BLACKWOOD_ASK_KINGS_AFTER_FOUR_ACES: Canary = Canary {
     note: "verify-blackwood.md:25",
 };
 
+const LEBENSOHL_RELAY_WEAK_DIAMONDS: Canary = Canary {
+    module: "lebensohl",
+    target: Target::Bundle("lebensohl-bundle"),
+    auction: &["1NT", "2H"],
+    auction_meaning_ids: &["bridge:1nt-opening", "lebensohl:opp-overcall-2h"],
+    auction_seats: &[Seat::North, Seat::East],
+    hand_pbn: "74.832.QJ9864.52",
+    user_seat: Seat::South,
+    expected_call: "2NT",
+    authority: "BridgeBum Lebensohl: 2NT relays to 3C so responder can sign off below the overcall",
+    note: "Lebensohl relay sign-off",
+};
+
+const LEBENSOHL_FORCED_RELAY_REPLY: Canary = Canary {
+    module: "lebensohl",
+    target: Target::Bundle("lebensohl-bundle"),
+    auction: &["1NT", "2H", "2NT"],
+    auction_meaning_ids: &[
+        "bridge:1nt-opening",
+        "lebensohl:opp-overcall-2h",
+        "lebensohl:relay-signoff-diamonds-over-2h",
+    ],
+    auction_seats: &[Seat::North, Seat::East, Seat::South],
+    hand_pbn: "AK4.KQ3.A74.J852",
+    user_seat: Seat::North,
+    expected_call: "3C",
+    authority: "BridgeBum Lebensohl: opener must complete the 2NT relay with 3C",
+    note: "Lebensohl forced reply",
+};
+
+const LEBENSOHL_FAST_3NT_DENIES_STOPPER: Canary = Canary {
+    module: "lebensohl",
+    target: Target::Bundle("lebensohl-bundle"),
+    auction: &["1NT", "2S"],
+    auction_meaning_ids: &["bridge:1nt-opening", "lebensohl:opp-overcall-2s"],
+    auction_seats: &[Seat::North, Seat::East],
+    hand_pbn: "742.AQ4.KJ52.A83",
+    user_seat: Seat::South,
+    expected_call: "3NT",
+    authority:
+        "BridgeBum Lebensohl: fast denies -- a direct 3NT has no stopper in the overcalled suit",
+    note: "Lebensohl fast 3NT",
+};
+
+const LEBENSOHL_SLOW_3NT_SHOWS_STOPPER: Canary = Canary {
+    module: "lebensohl",
+    target: Target::Bundle("lebensohl-bundle"),
+    auction: &["1NT", "2S"],
+    auction_meaning_ids: &["bridge:1nt-opening", "lebensohl:opp-overcall-2s"],
+    auction_seats: &[Seat::North, Seat::East],
+    hand_pbn: "K72.AQ4.J852.Q83",
+    user_seat: Seat::South,
+    expected_call: "2NT",
+    authority: "BridgeBum Lebensohl: slow shows -- relay through 2NT before 3NT with a stopper",
+    note: "Lebensohl slow 3NT",
+};
+
 define_canary_test!(
     stayman_show_hearts_on_four_four,
     STAYMAN_SHOW_HEARTS_ON_FOUR_FOUR
@@ -665,3 +722,13 @@ define_canary_test!(
     strong_2c_unbalanced_playing_tricks,
     STRONG_2C_UNBALANCED_PLAYING_TRICKS
 );
+define_canary_test!(lebensohl_relay_weak_diamonds, LEBENSOHL_RELAY_WEAK_DIAMONDS);
+define_canary_test!(lebensohl_forced_relay_reply, LEBENSOHL_FORCED_RELAY_REPLY);
+define_canary_test!(
+    lebensohl_fast_3nt_denies_stopper,
+    LEBENSOHL_FAST_3NT_DENIES_STOPPER
+);
+define_canary_test!(
+    lebensohl_slow_3nt_shows_stopper,
+    LEBENSOHL_SLOW_3NT_SHOWS_STOPPER
+);
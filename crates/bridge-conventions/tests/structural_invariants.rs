@@ -932,6 +932,14 @@ fn all_module_fixtures_declare_default_role_in_source_and_samples_match() {
         ("smolen", PracticeRole::Responder),
         ("stayman-garbage", PracticeRole::Responder),
         ("natural-bids", PracticeRole::Opener),
+        ("suit-responses", PracticeRole::Responder),
+        ("opener-rebids", PracticeRole::Opener),
+        ("responder-rebids", PracticeRole::Responder),
+        ("notrump-openings", PracticeRole::Opener),
+        ("preempts", PracticeRole::Opener),
+        ("lebensohl", PracticeRole::Responder),
+        ("drury", PracticeRole::Responder),
+        ("fourth-suit-forcing", PracticeRole::Responder),
     ];
 
     for (module_id, expected_role) in expected_samples {
@@ -553,3 +553,92 @@ fn project_witness_nt_stayman_constrains_opponents() {
         east
     );
 }
+
+#[test]
+fn project_witness_2nt_stayman_reply_is_not_read_as_a_preempt() {
+    // N:2NT S:3C N:3D — opener's 3D denial must project from the 2NT
+    // structure, not from the preempts module's 3D opening.
+    let modules = loaded_modules();
+    let witness = enumerate_witnesses(
+        "notrump-openings",
+        "nt-openings:3nt-after-stayman-3d",
+        &modules,
+        Seat::North,
+        Seat::South,
+        16,
+    )
+    .into_iter()
+    .next()
+    .expect("need a witness through the 2NT Stayman denial");
+
+    let projections = project_witness(&witness, &modules, None);
+    let dc = projections
+        .first()
+        .expect("should produce at least one projected branch");
+    let north = dc
+        .seats
+        .iter()
+        .find(|s| s.seat == Seat::North)
+        .expect("N seat (2NT opener) should be projected");
+    assert_eq!(north.min_hcp, Some(20), "N min_hcp should be 20");
+    assert_eq!(north.max_hcp, Some(21), "N max_hcp should be 21");
+    assert!(
+        north
+            .min_length
+            .as_ref()
+            .is_none_or(|ml| ml.get(&Suit::Diamonds).copied().unwrap_or(0) < 7),
+        "N should not carry a preempt's diamond length, got {:?}",
+        north
+    );
+}
+
+#[test]
+fn enumerate_witnesses_drury_deals_from_the_passed_hand() {
+    // Drury attaches on `P P 1♥`: with N as the opener the witness deals
+    // from S so the user's pass and W's pass come before N's opening.
+    use bridge_conventions::fact_dsl::witness::WitnessRole;
+    let drury = get_module("drury", BaseSystemId::Sayc).expect("drury module");
+    let mut modules = loaded_modules();
+    modules.push(drury);
+    let witness = enumerate_witnesses(
+        "drury",
+        "drury:2c-over-1h",
+        &modules,
+        Seat::North,
+        Seat::South,
+        16,
+    )
+    .into_iter()
+    .next()
+    .expect("need a witness for the Drury ask");
+
+    assert_eq!(witness.dealer, Seat::South);
+    assert_eq!(witness.opener, Seat::North);
+    let calls: Vec<(Seat, Option<&Call>)> = witness
+        .prefix
+        .iter()
+        .map(|entry| (entry.seat, entry.concrete_call()))
+        .collect();
+    assert_eq!(
+        calls[..3],
+        [
+            (Seat::South, Some(&Call::Pass)),
+            (Seat::West, Some(&Call::Pass)),
+            (
+                Seat::North,
+                Some(&Call::Bid {
+                    level: 1,
+                    strain: BidSuit::Hearts
+                })
+            ),
+        ]
+    );
+    assert_eq!(witness.prefix[0].role, WitnessRole::Partnership);
+    assert_eq!(witness.prefix[1].role, WitnessRole::Opponent);
+
+    let projections = project_witness(&witness, &modules, None);
+    let dc = projections
+        .first()
+        .expect("should produce at least one projected branch");
+    assert_eq!(dc.dealer, Some(Seat::South));
+}
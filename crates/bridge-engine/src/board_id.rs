@@ -0,0 +1,330 @@
+//! Canonical identifiers for boards and auctions.
+//!
+//! A deal is numbered by its rank among all 52! / (13!)^4 ways to deal the
+//! cards (Pavlicek's scheme): cards are taken in a fixed order, spades ace
+//! first down to the club deuce, and each one narrows the range of numbers
+//! by the share of deals in which that seat holds it. The number does not
+//! depend on the order cards sit in a hand. A board id adds the dealer and
+//! vulnerability, e.g. `N0-02c4e1a9f1b3c5d7e9f0a1b2`.
+//!
+//! Auctions get a canonical text key (`N:1NT P 2C P`) and a stable 64-bit
+//! FNV-1a hash of that key, so the same sequence hashes identically on every
+//! platform and build.
+
+use std::collections::HashMap;
+
+use crate::constants::{seat_index, SEATS, SUIT_ORDER};
+use crate::error::EngineError;
+use crate::hand_record::{seat_char, seat_from_char, strain_str};
+use crate::types::{Auction, Call, Card, Deal, Hand, Rank, Seat, Suit, Vulnerability};
+
+/// Number of distinct deals: 52! / (13!)^4.
+pub const DEAL_COUNT: u128 = 53_644_737_765_488_792_839_237_440_000;
+
+const RANKS_HIGH_TO_LOW: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+/// Hex digits in a board id's deal number; `DEAL_COUNT` fits in 96 bits.
+const NUMBER_DIGITS: usize = 24;
+
+/// The 52 cards in numbering order.
+fn numbering_order() -> impl Iterator<Item = Card> {
+    SUIT_ORDER.into_iter().flat_map(|suit| {
+        RANKS_HIGH_TO_LOW
+            .into_iter()
+            .map(move |rank| Card { suit, rank })
+    })
+}
+
+/// The deal's number in `0..DEAL_COUNT`. The deal must hold all 52 cards,
+/// 13 to a seat.
+pub fn deal_number(deal: &Deal) -> u128 {
+    let owner: HashMap<(Suit, Rank), usize> = SEATS
+        .iter()
+        .flat_map(|&seat| {
+            deal.hands[&seat]
+                .cards
+                .iter()
+                .map(move |card| ((card.suit, card.rank), seat_index(seat)))
+        })
+        .collect();
+    debug_assert_eq!(owner.len(), 52, "deal must hold every card once");
+
+    let mut remaining = [13u128; 4];
+    let mut span = DEAL_COUNT;
+    let mut number = 0u128;
+    for (left, card) in (1..=52u128).rev().zip(numbering_order()) {
+        let holder = owner[&(card.suit, card.rank)];
+        for earlier in &remaining[..holder] {
+            number += span * earlier / left;
+        }
+        span = span * remaining[holder] / left;
+        remaining[holder] -= 1;
+    }
+    number
+}
+
+/// Rebuild the deal numbered `number`. Hands come back in display order:
+/// suits spades to clubs, high card first.
+pub fn deal_from_number(
+    number: u128,
+    dealer: Seat,
+    vulnerability: Vulnerability,
+) -> Result<Deal, EngineError> {
+    if number >= DEAL_COUNT {
+        return Err(EngineError::InvalidBoardId(format!(
+            "deal number {number} is out of range"
+        )));
+    }
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    let mut remaining = [13u128; 4];
+    let mut span = DEAL_COUNT;
+    let mut offset = number;
+    for (left, card) in (1..=52u128).rev().zip(numbering_order()) {
+        let mut holder = 0;
+        loop {
+            let share = span * remaining[holder] / left;
+            if offset < share {
+                span = share;
+                break;
+            }
+            offset -= share;
+            holder += 1;
+        }
+        hands[holder].push(card);
+        remaining[holder] -= 1;
+    }
+    Ok(Deal {
+        hands: SEATS
+            .into_iter()
+            .zip(hands)
+            .map(|(seat, cards)| (seat, Hand { cards }))
+            .collect(),
+        dealer,
+        vulnerability,
+    })
+}
+
+fn vulnerability_digit(vulnerability: Vulnerability) -> char {
+    match vulnerability {
+        Vulnerability::None => '0',
+        Vulnerability::NorthSouth => '1',
+        Vulnerability::EastWest => '2',
+        Vulnerability::Both => '3',
+    }
+}
+
+fn vulnerability_from_digit(c: char) -> Option<Vulnerability> {
+    let vulnerability = match c {
+        '0' => Vulnerability::None,
+        '1' => Vulnerability::NorthSouth,
+        '2' => Vulnerability::EastWest,
+        '3' => Vulnerability::Both,
+        _ => return None,
+    };
+    Some(vulnerability)
+}
+
+/// Board id: dealer, vulnerability digit (0 none, 1 NS, 2 EW, 3 both) and
+/// the deal number in hex.
+pub fn board_id(deal: &Deal) -> String {
+    format!(
+        "{}{}-{:0width$x}",
+        seat_char(deal.dealer),
+        vulnerability_digit(deal.vulnerability),
+        deal_number(deal),
+        width = NUMBER_DIGITS
+    )
+}
+
+/// Parse a board id written by `board_id`. Case-insensitive.
+pub fn parse_board_id(id: &str) -> Result<Deal, EngineError> {
+    let invalid = || EngineError::InvalidBoardId(id.to_string());
+    let (prefix, number) = id.trim().split_once('-').ok_or_else(invalid)?;
+    let mut prefix = prefix.chars();
+    let dealer = prefix.next().and_then(seat_from_char).ok_or_else(invalid)?;
+    let vulnerability = prefix
+        .next()
+        .and_then(vulnerability_from_digit)
+        .ok_or_else(invalid)?;
+    if prefix.next().is_some() || number.len() != NUMBER_DIGITS {
+        return Err(invalid());
+    }
+    let number = u128::from_str_radix(number, 16).map_err(|_| invalid())?;
+    deal_from_number(number, dealer, vulnerability)
+}
+
+fn call_str(call: &Call) -> String {
+    match call {
+        Call::Bid { level, strain } => format!("{level}{}", strain_str(*strain)),
+        Call::Pass => "P".to_string(),
+        Call::Double => "X".to_string(),
+        Call::Redouble => "XX".to_string(),
+    }
+}
+
+/// Canonical text of an auction: the first caller's seat, then the calls.
+/// Alerts and explanations are not part of the key. Empty for an empty
+/// auction.
+pub fn auction_key(auction: &Auction) -> String {
+    let Some(first) = auction.entries.first() else {
+        return String::new();
+    };
+    let calls = auction
+        .entries
+        .iter()
+        .map(|entry| call_str(&entry.call))
+        .collect::<Vec<_>>();
+    format!("{}:{}", seat_char(first.seat), calls.join(" "))
+}
+
+/// Stable 64-bit hash of `auction_key` (FNV-1a).
+pub fn auction_hash(auction: &Auction) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    auction_key(auction)
+        .bytes()
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(PRIME)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deal_generator::generate_deal;
+    use crate::types::{AuctionEntry, BidSuit, DealConstraints};
+
+    fn random_deal(seed: u64) -> Deal {
+        generate_deal(&DealConstraints {
+            seats: vec![],
+            vulnerability: Some(Vulnerability::EastWest),
+            dealer: Some(Seat::South),
+            max_attempts: None,
+            seed: Some(seed),
+            ..Default::default()
+        })
+        .unwrap()
+        .deal
+    }
+
+    #[test]
+    fn deal_numbers_round_trip() {
+        for seed in 0..20 {
+            let deal = random_deal(seed);
+            let number = deal_number(&deal);
+            assert!(number < DEAL_COUNT);
+            let decoded = deal_from_number(number, deal.dealer, deal.vulnerability).unwrap();
+            assert_eq!(deal_number(&decoded), number);
+            for seat in SEATS {
+                let held = |d: &Deal| {
+                    d.hands[&seat]
+                        .cards
+                        .iter()
+                        .map(|c| (c.suit, c.rank))
+                        .collect::<std::collections::HashSet<_>>()
+                };
+                assert_eq!(held(&deal), held(&decoded));
+            }
+        }
+    }
+
+    #[test]
+    fn first_and_last_deal_numbers() {
+        // Number 0 gives each card to the first seat with room: North gets
+        // the spades, West the clubs. The last number reverses that.
+        let first = deal_from_number(0, Seat::North, Vulnerability::None).unwrap();
+        let north = &first.hands[&Seat::North].cards;
+        assert_eq!(
+            north[0],
+            Card {
+                suit: Suit::Spades,
+                rank: Rank::Ace
+            }
+        );
+        assert!(north.iter().all(|c| c.suit == Suit::Spades));
+        assert_eq!(deal_number(&first), 0);
+
+        let last = deal_from_number(DEAL_COUNT - 1, Seat::North, Vulnerability::None).unwrap();
+        assert!(last.hands[&Seat::West]
+            .cards
+            .iter()
+            .all(|c| c.suit == Suit::Spades));
+        assert!(deal_from_number(DEAL_COUNT, Seat::North, Vulnerability::None).is_err());
+    }
+
+    #[test]
+    fn card_order_does_not_change_the_id() {
+        let deal = random_deal(3);
+        let mut shuffled = deal.clone();
+        for hand in shuffled.hands.values_mut() {
+            hand.cards.reverse();
+        }
+        assert_eq!(board_id(&deal), board_id(&shuffled));
+    }
+
+    #[test]
+    fn board_ids_carry_dealer_and_vulnerability() {
+        let deal = random_deal(11);
+        let id = board_id(&deal);
+        assert!(id.starts_with("S2-"));
+        assert_eq!(id.len(), 3 + NUMBER_DIGITS);
+        let parsed = parse_board_id(&id.to_uppercase()).unwrap();
+        assert_eq!(parsed.dealer, Seat::South);
+        assert_eq!(parsed.vulnerability, Vulnerability::EastWest);
+        assert_eq!(board_id(&parsed), id);
+
+        for bad in ["", "S2", "Q2-00", "S9-000000000000000000000000", "S2-zz"] {
+            assert!(
+                matches!(parse_board_id(bad), Err(EngineError::InvalidBoardId(_))),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn auction_key_and_hash_are_canonical() {
+        let entry = |seat, call| AuctionEntry { seat, call };
+        let one_nt = Call::Bid {
+            level: 1,
+            strain: BidSuit::NoTrump,
+        };
+        let auction = Auction {
+            entries: vec![
+                entry(Seat::East, one_nt.clone()),
+                entry(Seat::South, Call::Double),
+                entry(Seat::West, Call::Pass),
+            ],
+            is_complete: false,
+        };
+        assert_eq!(auction_key(&auction), "E:1NT X P");
+        assert_eq!(auction_hash(&auction), auction_hash(&auction.clone()));
+
+        let mut other_dealer = auction.clone();
+        other_dealer
+            .entries
+            .insert(0, entry(Seat::North, Call::Pass));
+        assert_ne!(auction_hash(&auction), auction_hash(&other_dealer));
+        // FNV-1a of the empty key is the offset basis.
+        assert_eq!(
+            auction_hash(&Auction {
+                entries: vec![],
+                is_complete: false
+            }),
+            0xcbf2_9ce4_8422_2325
+        );
+    }
+}
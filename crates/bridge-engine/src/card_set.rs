@@ -0,0 +1,302 @@
+//! `CardSet` — a set of cards packed into one `u64`.
+//!
+//! Each suit owns a 16-bit lane, in `SUIT_ORDER` (spades in the low lane),
+//! with one bit per rank: bit 0 is the deuce, bit 12 the ace. Membership,
+//! union and difference are single instructions; suit lengths and HCP are
+//! popcounts. Samplers that deal millions of candidate hands work on these
+//! and only build a `Hand` for the samples they keep.
+
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{rank_index, RANKS, SUIT_ORDER};
+use crate::types::{Card, Hand, Rank, Suit, SuitLength};
+
+const LANE: u32 = 16;
+const RANK_BITS: u64 = 0x1FFF;
+
+/// One bit per suit at `rank`.
+const fn rank_mask(rank_bit: u32) -> u64 {
+    let bit = 1u64 << rank_bit;
+    bit | bit << LANE | bit << (2 * LANE) | bit << (3 * LANE)
+}
+
+const ACES: u64 = rank_mask(12);
+const KINGS: u64 = rank_mask(11);
+const QUEENS: u64 = rank_mask(10);
+const JACKS: u64 = rank_mask(9);
+
+fn lane(suit: Suit) -> u32 {
+    match suit {
+        Suit::Spades => 0,
+        Suit::Hearts => 1,
+        Suit::Diamonds => 2,
+        Suit::Clubs => 3,
+    }
+}
+
+/// A set of cards; see the module docs for the bit layout. Serializes as the
+/// packed `u64`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const FULL: CardSet = CardSet(RANK_BITS * 0x0001_0001_0001_0001);
+
+    /// Position of `card`'s bit, in `0..64`.
+    pub fn position(card: &Card) -> u32 {
+        lane(card.suit) * LANE + rank_index(card.rank) as u32
+    }
+
+    /// The card at bit `position`, as returned by `position` or `positions`.
+    pub fn card_at(position: u32) -> Card {
+        Card {
+            suit: SUIT_ORDER[(position / LANE) as usize],
+            rank: RANKS[(position % LANE) as usize],
+        }
+    }
+
+    pub fn single(card: &Card) -> Self {
+        CardSet(1 << Self::position(card))
+    }
+
+    pub fn from_position(position: u32) -> Self {
+        CardSet(1 << position)
+    }
+
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub fn insert(&mut self, card: &Card) {
+        self.0 |= 1 << Self::position(card);
+    }
+
+    pub fn remove(&mut self, card: &Card) {
+        self.0 &= !(1 << Self::position(card));
+    }
+
+    pub fn contains(self, card: &Card) -> bool {
+        self.0 & (1 << Self::position(card)) != 0
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The cards of one suit.
+    pub fn suit(self, suit: Suit) -> CardSet {
+        CardSet(self.0 & RANK_BITS << (lane(suit) * LANE))
+    }
+
+    /// Rank bits of one suit, deuce in bit 0.
+    pub fn suit_ranks(self, suit: Suit) -> u16 {
+        ((self.0 >> (lane(suit) * LANE)) & RANK_BITS) as u16
+    }
+
+    pub fn suit_length(self, suit: Suit) -> u8 {
+        self.suit_ranks(suit).count_ones() as u8
+    }
+
+    /// Suit lengths in `SUIT_ORDER`, like `get_suit_length`.
+    pub fn shape(self) -> SuitLength {
+        SUIT_ORDER.map(|suit| self.suit_length(suit))
+    }
+
+    /// High-card points (A=4, K=3, Q=2, J=1), like `calculate_hcp`.
+    pub fn hcp(self) -> u32 {
+        4 * (self.0 & ACES).count_ones()
+            + 3 * (self.0 & KINGS).count_ones()
+            + 2 * (self.0 & QUEENS).count_ones()
+            + (self.0 & JACKS).count_ones()
+    }
+
+    /// Number of cards of `rank` across the four suits.
+    pub fn count_rank(self, rank: Rank) -> u32 {
+        (self.0 & rank_mask(rank_index(rank) as u32)).count_ones()
+    }
+
+    /// Bit positions of the members, lowest first.
+    pub fn positions(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            (bits != 0).then(|| {
+                let position = bits.trailing_zeros();
+                bits &= bits - 1;
+                position
+            })
+        })
+    }
+
+    /// The cards in display order: spades to clubs, high card first.
+    pub fn cards(self) -> Vec<Card> {
+        let mut cards = Vec::with_capacity(self.len() as usize);
+        for suit in SUIT_ORDER {
+            let ranks = self.suit_ranks(suit);
+            for (i, &rank) in RANKS.iter().enumerate().rev() {
+                if ranks & (1 << i) != 0 {
+                    cards.push(Card { suit, rank });
+                }
+            }
+        }
+        cards
+    }
+
+    pub fn to_hand(self) -> Hand {
+        Hand {
+            cards: self.cards(),
+        }
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        cards.iter().collect()
+    }
+}
+
+impl From<&Hand> for CardSet {
+    fn from(hand: &Hand) -> Self {
+        hand.cards.as_slice().into()
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(iter: I) -> Self {
+        let mut set = CardSet::EMPTY;
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+    fn bitor(self, rhs: CardSet) -> CardSet {
+        CardSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, rhs: CardSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+    fn bitand(self, rhs: CardSet) -> CardSet {
+        CardSet(self.0 & rhs.0)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+    fn sub(self, rhs: CardSet) -> CardSet {
+        CardSet(self.0 & !rhs.0)
+    }
+}
+
+/// The cards missing from the deck.
+impl Not for CardSet {
+    type Output = CardSet;
+    fn not(self) -> CardSet {
+        CardSet(!self.0 & CardSet::FULL.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::create_deck;
+    use crate::deal_generator::generate_deal;
+    use crate::hand_evaluator::{calculate_hcp, get_suit_length};
+    use crate::types::{DealConstraints, Seat};
+
+    #[test]
+    fn every_card_has_its_own_bit() {
+        let deck = create_deck();
+        let full: CardSet = deck.iter().collect();
+        assert_eq!(full, CardSet::FULL);
+        assert_eq!(full.len(), 52);
+        for card in &deck {
+            assert_eq!(CardSet::card_at(CardSet::position(card)), *card);
+        }
+        assert!((!CardSet::FULL).is_empty());
+    }
+
+    #[test]
+    fn hcp_and_shape_match_the_hand_evaluator() {
+        for seed in 0..20 {
+            let deal = generate_deal(&DealConstraints {
+                seats: vec![],
+                vulnerability: None,
+                dealer: None,
+                max_attempts: None,
+                seed: Some(seed),
+                ..Default::default()
+            })
+            .unwrap()
+            .deal;
+            for hand in deal.hands.values() {
+                let set = CardSet::from(hand);
+                assert_eq!(set.hcp(), calculate_hcp(hand));
+                assert_eq!(set.shape(), get_suit_length(hand));
+                assert_eq!(set.count_rank(Rank::Ace), {
+                    hand.cards.iter().filter(|c| c.rank == Rank::Ace).count() as u32
+                });
+                assert_eq!(CardSet::from(&set.to_hand()), set);
+            }
+            let union = deal
+                .hands
+                .values()
+                .fold(CardSet::EMPTY, |acc, hand| acc | CardSet::from(hand));
+            assert_eq!(union, CardSet::FULL);
+            assert_eq!(
+                CardSet::FULL - CardSet::from(&deal.hands[&Seat::North]),
+                !CardSet::from(&deal.hands[&Seat::North])
+            );
+        }
+    }
+
+    #[test]
+    fn cards_come_out_in_display_order() {
+        let cards = [
+            Card {
+                suit: Suit::Clubs,
+                rank: Rank::Two,
+            },
+            Card {
+                suit: Suit::Spades,
+                rank: Rank::Ten,
+            },
+            Card {
+                suit: Suit::Spades,
+                rank: Rank::Ace,
+            },
+        ];
+        let set = CardSet::from(&cards[..]);
+        assert_eq!(
+            set.cards(),
+            vec![cards[2].clone(), cards[1].clone(), cards[0].clone()]
+        );
+        assert_eq!(set.suit_length(Suit::Spades), 2);
+        assert_eq!(set.suit(Suit::Clubs).len(), 1);
+        assert_eq!(
+            set.positions().map(CardSet::card_at).collect::<Vec<_>>(),
+            vec![cards[1].clone(), cards[2].clone(), cards[0].clone()]
+        );
+        let mut less = set;
+        less.remove(&cards[2]);
+        assert!(!less.contains(&cards[2]));
+        assert!(less.contains(&cards[1]));
+    }
+}
@@ -1,59 +1,72 @@
+//! Constrained deal generation.
+//!
+//! Deals are built rather than filtered: suit lengths for the seats with
+//! shape constraints are drawn first, weighted by how many deals share
+//! them, then the aces, kings, queens and jacks are placed so high-card
+//! constraints can reject early, and only then are the spot cards and the
+//! unconstrained seats filled in. Every step draws from the exact
+//! conditional distribution, so accepted deals stay uniform over all deals
+//! that match — the same distribution plain rejection sampling gives.
+
 use std::collections::HashMap;
+use std::ops::Range;
 
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 
-use crate::constants::{create_deck, SUIT_ORDER};
+use crate::constants::{SEATS, SUIT_ORDER};
 use crate::error::EngineError;
-use crate::hand_evaluator::{calculate_hcp, calculate_hcp_and_shape, get_suit_length, is_balanced};
+use crate::hand_evaluator::{
+    calculate_hcp, count_controls, count_key_cards, count_top_honors, get_suit_length, is_balanced,
+    losing_trick_count,
+};
 use crate::types::{
-    Card, Deal, DealConstraints, DealGeneratorResult, Hand, Seat, SeatConstraint, SuitLength,
-    Vulnerability,
+    Card, Deal, DealConstraints, DealGeneratorResult, Hand, Partnership, PartnershipConstraint,
+    Rank, Seat, SeatConstraint, ShapePattern, SuitLength, Vulnerability,
 };
 
 const DEFAULT_MAX_ATTEMPTS: u32 = 10_000;
 
-fn fisher_yates_shuffle<R: Rng + ?Sized>(cards: &[Card], rng: &mut R) -> Vec<Card> {
-    let mut buf: Vec<Card> = cards.to_vec();
-    let len = buf.len();
-    for i in (1..len).rev() {
-        let j = rng.gen_range(0..=i);
-        buf.swap(i, j);
+/// Most joint shape combinations enumerated up front. Shape-constrained
+/// seats past this budget are checked by rejection instead.
+const JOINT_SHAPE_LIMIT: usize = 200_000;
+
+/// Ranks high to low; the first `HONOR_COUNT` are dealt before the spots.
+const RANKS_HIGH_TO_LOW: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+const HONOR_COUNT: usize = 4;
+
+/// Fisher-Yates over `positions` only, so a shuffle can be started, looked
+/// at and finished later without changing its distribution.
+fn shuffle_positions<T, R: Rng + ?Sized>(items: &mut [T], positions: Range<usize>, rng: &mut R) {
+    let len = items.len();
+    for i in positions.start..positions.end.min(len) {
+        let j = rng.gen_range(i..len);
+        items.swap(i, j);
     }
-    buf
 }
 
-fn deal_from_shuffled(cards: &[Card], dealer: Seat, vulnerability: Vulnerability) -> Deal {
-    let mut hands = HashMap::new();
-    hands.insert(
-        Seat::North,
-        Hand {
-            cards: cards[0..13].to_vec(),
-        },
-    );
-    hands.insert(
-        Seat::East,
-        Hand {
-            cards: cards[13..26].to_vec(),
-        },
-    );
-    hands.insert(
-        Seat::South,
-        Hand {
-            cards: cards[26..39].to_vec(),
-        },
-    );
-    hands.insert(
-        Seat::West,
-        Hand {
-            cards: cards[39..52].to_vec(),
-        },
-    );
-    Deal {
-        hands,
-        dealer,
-        vulnerability,
-    }
+fn needs_shape(constraint: &SeatConstraint) -> bool {
+    constraint.balanced.is_some()
+        || constraint.min_length.is_some()
+        || constraint.max_length.is_some()
+        || constraint.min_length_any.is_some()
+        || constraint.shapes.is_some()
+        || constraint.max_shortest.is_some()
 }
 
 fn check_shape_constraint(shape: &SuitLength, constraint: &SeatConstraint) -> bool {
@@ -98,9 +111,34 @@ fn check_shape_constraint(shape: &SuitLength, constraint: &SeatConstraint) -> bo
         }
     }
 
+    if let Some(ref shapes) = constraint.shapes {
+        if !shapes.iter().any(|pattern| matches_pattern(shape, pattern)) {
+            return false;
+        }
+    }
+
+    if let Some(max_shortest) = constraint.max_shortest {
+        if shape.iter().all(|&length| length > max_shortest) {
+            return false;
+        }
+    }
+
     true
 }
 
+fn matches_pattern(shape: &SuitLength, pattern: &ShapePattern) -> bool {
+    match pattern {
+        ShapePattern::Exact { lengths } => shape == lengths,
+        ShapePattern::AnyOrder { lengths } => {
+            let mut have = *shape;
+            let mut want = *lengths;
+            have.sort_unstable();
+            want.sort_unstable();
+            have == want
+        }
+    }
+}
+
 fn check_hcp_constraint(hcp: u32, constraint: &SeatConstraint) -> bool {
     if let Some(min) = constraint.min_hcp {
         if hcp < min {
@@ -117,29 +155,124 @@ fn check_hcp_constraint(hcp: u32, constraint: &SeatConstraint) -> bool {
     true
 }
 
-fn check_seat_constraint(hand: &Hand, constraint: &SeatConstraint) -> bool {
-    let needs_hcp = constraint.min_hcp.is_some() || constraint.max_hcp.is_some();
-    let needs_shape = constraint.balanced.is_some()
-        || constraint.min_length.is_some()
-        || constraint.max_length.is_some()
-        || constraint.min_length_any.is_some();
+/// Checks that depend only on where the aces, kings, queens and jacks lie,
+/// so they can run before the spot cards are dealt.
+fn check_high_card_constraint(hand: &Hand, constraint: &SeatConstraint) -> bool {
+    if (constraint.min_hcp.is_some() || constraint.max_hcp.is_some())
+        && !check_hcp_constraint(calculate_hcp(hand), constraint)
+    {
+        return false;
+    }
+
+    if constraint.min_controls.is_some() || constraint.max_controls.is_some() {
+        let controls = count_controls(hand);
+        if constraint.min_controls.is_some_and(|min| controls < min)
+            || constraint.max_controls.is_some_and(|max| controls > max)
+        {
+            return false;
+        }
+    }
+
+    if let Some(ref key_cards) = constraint.key_cards {
+        let count = count_key_cards(hand, key_cards.trump);
+        if key_cards.min.is_some_and(|min| count < min)
+            || key_cards.max.is_some_and(|max| count > max)
+        {
+            return false;
+        }
+        if let Some(queen) = key_cards.queen {
+            let held = hand
+                .cards
+                .iter()
+                .any(|c| c.suit == key_cards.trump && c.rank == Rank::Queen);
+            if held != queen {
+                return false;
+            }
+        }
+    }
+
+    true
+}
 
-    if needs_hcp && needs_shape {
-        let (hcp, shape) = calculate_hcp_and_shape(hand);
-        if !check_hcp_constraint(hcp, constraint) {
+fn check_honor_constraint(hand: &Hand, constraint: &SeatConstraint) -> bool {
+    if constraint.min_losers.is_some() || constraint.max_losers.is_some() {
+        let losers = losing_trick_count(hand);
+        if constraint.min_losers.is_some_and(|min| losers < min)
+            || constraint.max_losers.is_some_and(|max| losers > max)
+        {
             return false;
         }
-        if !check_shape_constraint(&shape, constraint) {
+    }
+
+    if let Some(ref suit_quality) = constraint.suit_quality {
+        for quality in suit_quality {
+            if count_top_honors(hand, quality.suit, quality.of_top) < quality.min_honors {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn check_seat_constraint(hand: &Hand, constraint: &SeatConstraint) -> bool {
+    if !check_high_card_constraint(hand, constraint) {
+        return false;
+    }
+    if needs_shape(constraint) && !check_shape_constraint(&get_suit_length(hand), constraint) {
+        return false;
+    }
+    check_honor_constraint(hand, constraint)
+}
+
+fn count_rank(hand: &Hand, rank: Rank) -> u32 {
+    hand.cards.iter().filter(|c| c.rank == rank).count() as u32
+}
+
+fn within(value: u32, min: Option<u32>, max: Option<u32>) -> bool {
+    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+}
+
+/// Combined HCP, aces and kings — like `check_high_card_constraint`, these
+/// only need the honors dealt.
+fn check_partnership_high_cards(hands: [&Hand; 2], constraint: &PartnershipConstraint) -> bool {
+    let hcp = hands.iter().map(|hand| calculate_hcp(hand)).sum();
+    let aces = hands.iter().map(|hand| count_rank(hand, Rank::Ace)).sum();
+    let kings = hands.iter().map(|hand| count_rank(hand, Rank::King)).sum();
+    within(hcp, constraint.min_hcp, constraint.max_hcp)
+        && within(
+            aces,
+            constraint.min_aces.map(u32::from),
+            constraint.max_aces.map(u32::from),
+        )
+        && within(
+            kings,
+            constraint.min_kings.map(u32::from),
+            constraint.max_kings.map(u32::from),
+        )
+}
+
+fn check_partnership_constraint(hands: [&Hand; 2], constraint: &PartnershipConstraint) -> bool {
+    if !check_partnership_high_cards(hands, constraint) {
+        return false;
+    }
+
+    let [first, second] = hands.map(get_suit_length);
+    let combined: [u8; 4] = [0, 1, 2, 3].map(|i| first[i] + second[i]);
+    let length = |suit| combined[SUIT_ORDER.iter().position(|&s| s == suit).unwrap_or(0)];
+
+    if let Some(ref min_fit) = constraint.min_fit {
+        if min_fit.iter().any(|(&suit, &min)| length(suit) < min) {
             return false;
         }
-    } else if needs_hcp {
-        let hcp = calculate_hcp(hand);
-        if !check_hcp_constraint(hcp, constraint) {
+    }
+    if let Some(ref max_fit) = constraint.max_fit {
+        if max_fit.iter().any(|(&suit, &max)| length(suit) > max) {
             return false;
         }
-    } else if needs_shape {
-        let shape = get_suit_length(hand);
-        if !check_shape_constraint(&shape, constraint) {
+    }
+    if let Some(ref min_fit_any) = constraint.min_fit_any {
+        if !min_fit_any.iter().any(|(&suit, &min)| length(suit) >= min) {
             return false;
         }
     }
@@ -147,6 +280,11 @@ fn check_seat_constraint(hand: &Hand, constraint: &SeatConstraint) -> bool {
     true
 }
 
+fn partnership_hands(hands: &HashMap<Seat, Hand>, partnership: Partnership) -> Option<[&Hand; 2]> {
+    let [first, second] = partnership.seats();
+    Some([hands.get(&first)?, hands.get(&second)?])
+}
+
 pub fn check_constraints(deal: &Deal, constraints: &DealConstraints) -> bool {
     for sc in &constraints.seats {
         if let Some(hand) = deal.hands.get(&sc.seat) {
@@ -155,16 +293,482 @@ pub fn check_constraints(deal: &Deal, constraints: &DealConstraints) -> bool {
             }
         }
     }
+    for pc in &constraints.partnerships {
+        if let Some(hands) = partnership_hands(&deal.hands, pc.partnership) {
+            if !check_partnership_constraint(hands, pc) {
+                return false;
+            }
+        }
+    }
     true
 }
 
-/// Generate a random deal satisfying the given constraints via rejection sampling.
+// ── Shapes ──────────────────────────────────────────────────────────
+
+/// Every suit-length pattern a 13-card hand can have (560 of them).
+fn all_shapes() -> Vec<SuitLength> {
+    let mut shapes = Vec::with_capacity(560);
+    for s in 0..=13u8 {
+        for h in 0..=13 - s {
+            for d in 0..=13 - s - h {
+                shapes.push([s, h, d, 13 - s - h - d]);
+            }
+        }
+    }
+    shapes
+}
+
+fn ln_factorial(n: u8) -> f64 {
+    (2..=u32::from(n)).map(|k| f64::from(k).ln()).sum()
+}
+
+/// Suit lengths for the seats dealt shape-first: every joint combination
+/// that fits in one deck, weighted by the number of deals that have it.
+struct ShapePlan {
+    seats: Vec<Seat>,
+    tuples: Vec<Vec<SuitLength>>,
+    cumulative: Vec<f64>,
+}
+
+impl ShapePlan {
+    /// `allowed` lists each shape-constrained seat with the shapes its
+    /// constraints admit, most restrictive first.
+    fn new(allowed: &[(Seat, Vec<SuitLength>)]) -> Result<Self, EngineError> {
+        let mut seats = Vec::new();
+        let mut combinations = 1usize;
+        for (seat, shapes) in allowed {
+            combinations = combinations.saturating_mul(shapes.len());
+            if !seats.is_empty() && combinations > JOINT_SHAPE_LIMIT {
+                break;
+            }
+            seats.push(*seat);
+        }
+
+        let ln_fact: Vec<f64> = (0..=13).map(ln_factorial).collect();
+        let mut plan = ShapePlan {
+            seats,
+            tuples: Vec::new(),
+            cumulative: Vec::new(),
+        };
+        let mut current = Vec::with_capacity(plan.seats.len());
+        plan.enumerate(
+            &allowed[..plan.seats.len()],
+            &ln_fact,
+            [0; 4],
+            0.0,
+            &mut current,
+        );
+
+        if plan.tuples.is_empty() {
+            if let Some(&last) = plan.seats.last() {
+                let others = plan.seats[..plan.seats.len() - 1]
+                    .iter()
+                    .map(|seat| format!("{seat:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(EngineError::UnsatisfiableConstraint(
+                    last,
+                    format!("suit lengths cannot fit in one deck alongside {others}"),
+                ));
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Walk every joint shape, accumulating the negated log of the
+    /// multinomial denominators (the 13! numerators are common to all).
+    fn enumerate(
+        &mut self,
+        allowed: &[(Seat, Vec<SuitLength>)],
+        ln_fact: &[f64],
+        used: [u8; 4],
+        ln_weight: f64,
+        current: &mut Vec<SuitLength>,
+    ) {
+        let Some(((_, shapes), rest)) = allowed.split_first() else {
+            let rest_pile: f64 = used.iter().map(|&u| ln_fact[usize::from(13 - u)]).sum();
+            let total = self.cumulative.last().copied().unwrap_or(0.0);
+            self.tuples.push(current.clone());
+            self.cumulative.push(total + (ln_weight - rest_pile).exp());
+            return;
+        };
+        for shape in shapes {
+            if (0..4).any(|i| used[i] + shape[i] > 13) {
+                continue;
+            }
+            let next_used = [0, 1, 2, 3].map(|i| used[i] + shape[i]);
+            let ln_shape: f64 = shape.iter().map(|&n| ln_fact[usize::from(n)]).sum();
+            current.push(*shape);
+            self.enumerate(rest, ln_fact, next_used, ln_weight - ln_shape, current);
+            current.pop();
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &[SuitLength] {
+        let Some(&total) = self.cumulative.last() else {
+            return &[];
+        };
+        let target = rng.gen::<f64>() * total;
+        let index = self.cumulative.partition_point(|&c| c <= target);
+        &self.tuples[index.min(self.tuples.len() - 1)]
+    }
+}
+
+// ── Unsatisfiable constraints ───────────────────────────────────────
+
+/// Name the constraint no hand can meet, before any dealing is tried.
+fn find_unsatisfiable(constraints: &[SeatConstraint]) -> Option<(Seat, String)> {
+    let mut min_hcp_total = 0;
+    for sc in constraints {
+        if let Some(reason) = unsatisfiable_bounds(sc) {
+            return Some((sc.seat, reason));
+        }
+        min_hcp_total += sc.min_hcp.unwrap_or(0);
+        if min_hcp_total > 40 {
+            return Some((
+                sc.seat,
+                format!("minHcp totals {min_hcp_total} across seats, more than the deck's 40"),
+            ));
+        }
+    }
+    None
+}
+
+fn unsatisfiable_bounds(sc: &SeatConstraint) -> Option<String> {
+    fn range(name: &str, min: Option<u32>, max: Option<u32>, most: u32) -> Option<String> {
+        match (min, max) {
+            (Some(min), _) if min > most => Some(format!("min{name} {min} is more than {most}")),
+            (Some(min), Some(max)) if min > max => {
+                Some(format!("min{name} {min} is above max{name} {max}"))
+            }
+            _ => None,
+        }
+    }
+
+    range("Hcp", sc.min_hcp, sc.max_hcp, 37)
+        .or_else(|| range("Controls", sc.min_controls, sc.max_controls, 12))
+        .or_else(|| range("Losers", sc.min_losers, sc.max_losers, 12))
+        .or_else(|| {
+            let key_cards = sc.key_cards.as_ref()?;
+            range(
+                "KeyCards",
+                key_cards.min.map(u32::from),
+                key_cards.max.map(u32::from),
+                5,
+            )
+        })
+        .or_else(|| {
+            sc.suit_quality.iter().flatten().find_map(|quality| {
+                (quality.min_honors > quality.of_top.min(13)).then(|| {
+                    format!(
+                        "suitQuality asks for {} of the top {} {:?}",
+                        quality.min_honors, quality.of_top, quality.suit
+                    )
+                })
+            })
+        })
+}
+
+fn unsatisfiable_partnership(pc: &PartnershipConstraint) -> Option<String> {
+    let small = |value: Option<u8>| value.map(u32::from);
+    let range = |name: &str, min: Option<u32>, max: Option<u32>, most: u32| match (min, max) {
+        (Some(min), _) if min > most => Some(format!("min{name} {min} is more than {most}")),
+        (Some(min), Some(max)) if min > max => {
+            Some(format!("min{name} {min} is above max{name} {max}"))
+        }
+        _ => None,
+    };
+    let fit = |suit| {
+        let min = pc.min_fit.as_ref().and_then(|m| m.get(&suit)).copied();
+        let max = pc.max_fit.as_ref().and_then(|m| m.get(&suit)).copied();
+        range(
+            &format!("Fit {suit:?}"),
+            min.map(u32::from),
+            max.map(u32::from),
+            13,
+        )
+    };
+
+    range("Hcp", pc.min_hcp, pc.max_hcp, 40)
+        .or_else(|| range("Aces", small(pc.min_aces), small(pc.max_aces), 4))
+        .or_else(|| range("Kings", small(pc.min_kings), small(pc.max_kings), 4))
+        .or_else(|| SUIT_ORDER.iter().find_map(|&suit| fit(suit)))
+        .or_else(|| {
+            // The partnership holds 26 cards between them.
+            let room: u32 = SUIT_ORDER
+                .iter()
+                .map(|suit| {
+                    pc.max_fit
+                        .as_ref()
+                        .and_then(|m| m.get(suit))
+                        .map_or(13, |&max| u32::from(max))
+                })
+                .sum();
+            let needed: u32 = pc
+                .min_fit
+                .iter()
+                .flatten()
+                .map(|(_, &min)| u32::from(min))
+                .sum();
+            if room < 26 {
+                Some(format!(
+                    "maxFit leaves room for {room} of the partnership's 26 cards"
+                ))
+            } else if needed > 26 {
+                Some(format!(
+                    "minFit needs {needed} of the partnership's 26 cards"
+                ))
+            } else {
+                None
+            }
+        })
+}
+
+/// Which shape fields rule out every hand, alone or together.
+fn describe_empty_shape(sc: &SeatConstraint, shapes: &[SuitLength]) -> String {
+    let fields: [(&str, SeatConstraint); 6] = [
+        (
+            "balanced",
+            SeatConstraint {
+                balanced: sc.balanced,
+                ..shape_free(sc)
+            },
+        ),
+        (
+            "minLength",
+            SeatConstraint {
+                min_length: sc.min_length.clone(),
+                ..shape_free(sc)
+            },
+        ),
+        (
+            "maxLength",
+            SeatConstraint {
+                max_length: sc.max_length.clone(),
+                ..shape_free(sc)
+            },
+        ),
+        (
+            "minLengthAny",
+            SeatConstraint {
+                min_length_any: sc.min_length_any.clone(),
+                ..shape_free(sc)
+            },
+        ),
+        (
+            "shapes",
+            SeatConstraint {
+                shapes: sc.shapes.clone(),
+                ..shape_free(sc)
+            },
+        ),
+        (
+            "maxShortest",
+            SeatConstraint {
+                max_shortest: sc.max_shortest,
+                ..shape_free(sc)
+            },
+        ),
+    ];
+    let set = fields
+        .iter()
+        .filter(|(_, only)| needs_shape(only))
+        .collect::<Vec<_>>();
+    if let Some((name, _)) = set.iter().find(|(_, only)| {
+        !shapes
+            .iter()
+            .any(|shape| check_shape_constraint(shape, only))
+    }) {
+        return format!("{name} admits no 13-card hand");
+    }
+    let names = set.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    format!("{} admit no 13-card hand together", names.join(" + "))
+}
+
+fn shape_free(sc: &SeatConstraint) -> SeatConstraint {
+    SeatConstraint {
+        balanced: None,
+        min_length: None,
+        max_length: None,
+        min_length_any: None,
+        shapes: None,
+        max_shortest: None,
+        ..sc.clone()
+    }
+}
+
+// ── Dealing ─────────────────────────────────────────────────────────
+
+/// Deal one candidate. Returns `None` when the high cards already rule it
+/// out, before the spot cards are dealt.
+fn deal_constructively<R: Rng + ?Sized>(
+    plan: &ShapePlan,
+    by_seat: &HashMap<Seat, Vec<&SeatConstraint>>,
+    partnerships: &[PartnershipConstraint],
+    dealer: Seat,
+    vulnerability: Vulnerability,
+    rng: &mut R,
+) -> Option<Deal> {
+    let shapes = plan.sample(rng);
+
+    // Owner of each rank (high to low) per suit; `None` is the pile the
+    // seats without a drawn shape share.
+    let mut suit_owners: [Vec<Option<Seat>>; 4] = [0, 1, 2, 3].map(|i| {
+        let mut owners = Vec::with_capacity(13);
+        for (&seat, shape) in plan.seats.iter().zip(shapes) {
+            owners.extend(std::iter::repeat_n(Some(seat), usize::from(shape[i])));
+        }
+        owners.resize(13, None);
+        owners
+    });
+    let mut pile_owners: Vec<Seat> = SEATS
+        .iter()
+        .filter(|seat| !plan.seats.contains(seat))
+        .flat_map(|&seat| std::iter::repeat_n(seat, 13))
+        .collect();
+    let mut pile: Vec<Card> = Vec::with_capacity(pile_owners.len());
+    let mut hands: HashMap<Seat, Vec<Card>> = SEATS
+        .iter()
+        .map(|&seat| (seat, Vec::with_capacity(13)))
+        .collect();
+
+    // High cards first.
+    for (i, owners) in suit_owners.iter_mut().enumerate() {
+        shuffle_positions(owners, 0..HONOR_COUNT, rng);
+        for (&rank, owner) in RANKS_HIGH_TO_LOW.iter().zip(&owners[..HONOR_COUNT]) {
+            let card = Card {
+                suit: SUIT_ORDER[i],
+                rank,
+            };
+            match owner {
+                Some(seat) => hands.get_mut(seat).unwrap().push(card),
+                None => pile.push(card),
+            }
+        }
+    }
+    let honors_in_pile = pile.len();
+    shuffle_positions(&mut pile_owners, 0..honors_in_pile, rng);
+    for (card, seat) in pile.drain(..).zip(&pile_owners) {
+        hands.get_mut(seat).unwrap().push(card);
+    }
+    let honors: HashMap<Seat, Hand> = hands
+        .iter()
+        .map(|(&seat, cards)| {
+            (
+                seat,
+                Hand {
+                    cards: cards.clone(),
+                },
+            )
+        })
+        .collect();
+    for (seat, constraints) in by_seat {
+        if !constraints
+            .iter()
+            .all(|sc| check_high_card_constraint(&honors[seat], sc))
+        {
+            return None;
+        }
+    }
+    for pc in partnerships {
+        let pair = partnership_hands(&honors, pc.partnership)?;
+        if !check_partnership_high_cards(pair, pc) {
+            return None;
+        }
+    }
+
+    // Then the spot cards.
+    for (i, owners) in suit_owners.iter_mut().enumerate() {
+        shuffle_positions(owners, HONOR_COUNT..13, rng);
+        for (&rank, owner) in RANKS_HIGH_TO_LOW.iter().zip(&owners[..]).skip(HONOR_COUNT) {
+            let card = Card {
+                suit: SUIT_ORDER[i],
+                rank,
+            };
+            match owner {
+                Some(seat) => hands.get_mut(seat).unwrap().push(card),
+                None => pile.push(card),
+            }
+        }
+    }
+    shuffle_positions(
+        &mut pile_owners,
+        honors_in_pile..honors_in_pile + pile.len(),
+        rng,
+    );
+    for (card, seat) in pile.into_iter().zip(&pile_owners[honors_in_pile..]) {
+        hands.get_mut(seat).unwrap().push(card);
+    }
+
+    Some(Deal {
+        hands: hands
+            .into_iter()
+            .map(|(seat, cards)| (seat, Hand { cards }))
+            .collect(),
+        dealer,
+        vulnerability,
+    })
+}
+
+/// Generate a random deal satisfying the given constraints.
+///
+/// Shapes and high cards for the constrained seats are dealt before the
+/// rest, so tight constraints need far fewer attempts than rejecting whole
+/// shuffles, while the result is still uniform over the matching deals.
+/// Constraints no deal can meet fail fast with
+/// [`EngineError::UnsatisfiableConstraint`] naming the seat and reason.
 pub fn generate_deal(constraints: &DealConstraints) -> Result<DealGeneratorResult, EngineError> {
     let dealer = constraints.dealer.unwrap_or(Seat::North);
     let vulnerability = constraints.vulnerability.unwrap_or(Vulnerability::None);
     let max_attempts = constraints.max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS);
 
-    let deck = create_deck();
+    if let Some((seat, reason)) = find_unsatisfiable(&constraints.seats) {
+        return Err(EngineError::UnsatisfiableConstraint(seat, reason));
+    }
+    for pc in &constraints.partnerships {
+        if let Some(reason) = unsatisfiable_partnership(pc) {
+            return Err(EngineError::UnsatisfiablePartnership(
+                pc.partnership,
+                reason,
+            ));
+        }
+    }
+
+    let mut by_seat: HashMap<Seat, Vec<&SeatConstraint>> = HashMap::new();
+    for sc in &constraints.seats {
+        by_seat.entry(sc.seat).or_default().push(sc);
+    }
+
+    let shapes = all_shapes();
+    let mut allowed: Vec<(Seat, Vec<SuitLength>)> = Vec::new();
+    for seat in SEATS {
+        let Some(seat_constraints) = by_seat.get(&seat) else {
+            continue;
+        };
+        let shaped = seat_constraints
+            .iter()
+            .copied()
+            .filter(|sc| needs_shape(sc))
+            .collect::<Vec<_>>();
+        if shaped.is_empty() {
+            continue;
+        }
+        let fits = shapes
+            .iter()
+            .copied()
+            .filter(|shape| shaped.iter().all(|sc| check_shape_constraint(shape, sc)))
+            .collect::<Vec<_>>();
+        if fits.is_empty() {
+            let reason = match shaped.as_slice() {
+                [only] => describe_empty_shape(only, &shapes),
+                _ => "its shape constraints admit no 13-card hand together".to_string(),
+            };
+            return Err(EngineError::UnsatisfiableConstraint(seat, reason));
+        }
+        allowed.push((seat, fits));
+    }
+    allowed.sort_by_key(|(_, fits)| fits.len());
+    let plan = ShapePlan::new(&allowed)?;
 
     let mut rng: Box<dyn RngCore> = match constraints.seed {
         Some(seed) => Box::new(ChaCha8Rng::seed_from_u64(seed)),
@@ -172,9 +776,16 @@ pub fn generate_deal(constraints: &DealConstraints) -> Result<DealGeneratorResul
     };
 
     for attempt in 1..=max_attempts {
-        let shuffled = fisher_yates_shuffle(&deck, &mut *rng);
-        let deal = deal_from_shuffled(&shuffled, dealer, vulnerability);
-
+        let Some(deal) = deal_constructively(
+            &plan,
+            &by_seat,
+            &constraints.partnerships,
+            dealer,
+            vulnerability,
+            &mut *rng,
+        ) else {
+            continue;
+        };
         if check_constraints(&deal, constraints) {
             return Ok(DealGeneratorResult {
                 deal,
@@ -190,7 +801,7 @@ pub fn generate_deal(constraints: &DealConstraints) -> Result<DealGeneratorResul
 mod tests {
     use super::*;
     use crate::constants::SEATS;
-    use crate::types::{Suit, Vulnerability};
+    use crate::types::{KeyCardRange, Suit, SuitQuality, Vulnerability};
 
     #[test]
     fn unconstrained_deal_always_succeeds() {
@@ -200,6 +811,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(42),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         assert_eq!(result.iterations, 1);
@@ -218,6 +830,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(42),
+            ..Default::default()
         };
         let r1 = generate_deal(&constraints).unwrap();
         let r2 = generate_deal(&constraints).unwrap();
@@ -239,6 +852,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(1),
+            ..Default::default()
         };
         let c2 = DealConstraints {
             seats: vec![],
@@ -246,6 +860,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(2),
+            ..Default::default()
         };
         let r1 = generate_deal(&c1).unwrap();
         let r2 = generate_deal(&c2).unwrap();
@@ -267,11 +882,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             vulnerability: None,
             dealer: None,
             max_attempts: Some(50_000),
             seed: Some(100),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         let hcp = calculate_hcp(&result.deal.hands[&Seat::South]);
@@ -289,11 +906,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             vulnerability: None,
             dealer: None,
             max_attempts: Some(50_000),
             seed: Some(200),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         let shape = get_suit_length(&result.deal.hands[&Seat::South]);
@@ -305,17 +924,19 @@ mod tests {
         let constraints = DealConstraints {
             seats: vec![SeatConstraint {
                 seat: Seat::South,
-                min_hcp: Some(40), // impossible
+                min_hcp: Some(35), // possible, but vanishingly rare
                 max_hcp: None,
                 balanced: None,
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             vulnerability: None,
             dealer: None,
             max_attempts: Some(10),
             seed: Some(1),
+            ..Default::default()
         };
         let result = generate_deal(&constraints);
         assert!(result.is_err());
@@ -323,6 +944,198 @@ mod tests {
         assert!(err.to_string().contains("10 attempts"));
     }
 
+    fn seat(seat: Seat) -> SeatConstraint {
+        SeatConstraint {
+            seat,
+            ..Default::default()
+        }
+    }
+
+    fn constrained(seats: Vec<SeatConstraint>, seed: u64) -> DealConstraints {
+        DealConstraints {
+            seats,
+            vulnerability: None,
+            dealer: None,
+            max_attempts: None,
+            seed: Some(seed),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn strong_two_club_opener_facing_a_shape_fits_the_default_budget() {
+        let opener = SeatConstraint {
+            min_hcp: Some(22),
+            ..seat(Seat::North)
+        };
+        let responder = SeatConstraint {
+            max_hcp: Some(7),
+            shapes: Some(vec![ShapePattern::Exact {
+                lengths: [4, 4, 4, 1],
+            }]),
+            ..seat(Seat::South)
+        };
+        for seed in 0..20 {
+            let result =
+                generate_deal(&constrained(vec![opener.clone(), responder.clone()], seed)).unwrap();
+            assert!(calculate_hcp(&result.deal.hands[&Seat::North]) >= 22);
+            let south = &result.deal.hands[&Seat::South];
+            assert!(calculate_hcp(south) <= 7);
+            assert_eq!(get_suit_length(south), [4, 4, 4, 1]);
+            for hand in result.deal.hands.values() {
+                assert_eq!(hand.cards.len(), 13);
+            }
+        }
+    }
+
+    #[test]
+    fn constructed_shapes_keep_the_natural_distribution() {
+        // P(exactly 5 spades | 5+ spades) for a random hand, from the
+        // hypergeometric distribution: C(13,k) C(39,13-k) / C(52,13).
+        fn choose(n: u64, k: u64) -> f64 {
+            (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+        }
+        let p = |k: u64| choose(13, k) * choose(39, 13 - k);
+        let expected = p(5) / (5..=13).map(p).sum::<f64>();
+
+        let north = SeatConstraint {
+            min_length: Some(HashMap::from([(Suit::Spades, 5)])),
+            ..seat(Seat::North)
+        };
+        let trials = 4_000;
+        let mut fives = 0;
+        for seed in 0..trials {
+            let deal = generate_deal(&constrained(vec![north.clone()], seed))
+                .unwrap()
+                .deal;
+            if get_suit_length(&deal.hands[&Seat::North])[0] == 5 {
+                fives += 1;
+            }
+        }
+        let observed = fives as f64 / trials as f64;
+        assert!(
+            (observed - expected).abs() < 0.03,
+            "observed {observed:.3}, expected {expected:.3}"
+        );
+    }
+
+    #[test]
+    fn unsatisfiable_constraints_name_the_seat_and_reason() {
+        let err = generate_deal(&constrained(
+            vec![SeatConstraint {
+                min_hcp: Some(40),
+                ..seat(Seat::South)
+            }],
+            1,
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(&err, EngineError::UnsatisfiableConstraint(Seat::South, reason) if reason.contains("minHcp")),
+            "{err}"
+        );
+
+        let err = generate_deal(&constrained(
+            vec![SeatConstraint {
+                min_length: Some(HashMap::from([(Suit::Spades, 7), (Suit::Hearts, 7)])),
+                ..seat(Seat::East)
+            }],
+            1,
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(&err, EngineError::UnsatisfiableConstraint(Seat::East, reason) if reason.contains("minLength")),
+            "{err}"
+        );
+
+        let seven_spades = |s| SeatConstraint {
+            min_length: Some(HashMap::from([(Suit::Spades, 7)])),
+            ..seat(s)
+        };
+        let err = generate_deal(&constrained(
+            vec![seven_spades(Seat::North), seven_spades(Seat::South)],
+            1,
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(&err, EngineError::UnsatisfiableConstraint(_, reason) if reason.contains("one deck")),
+            "{err}"
+        );
+    }
+
+    fn partnership(partnership: Partnership) -> PartnershipConstraint {
+        PartnershipConstraint {
+            partnership,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn partnership_constraints_respected() {
+        let ns = PartnershipConstraint {
+            min_hcp: Some(25),
+            max_hcp: Some(30),
+            min_fit: Some(HashMap::from([(Suit::Hearts, 8)])),
+            ..partnership(Partnership::NorthSouth)
+        };
+        let ew = PartnershipConstraint {
+            max_fit: Some(SUIT_ORDER.iter().map(|&suit| (suit, 7)).collect()),
+            ..partnership(Partnership::EastWest)
+        };
+        let opener = SeatConstraint {
+            min_hcp: Some(12),
+            ..seat(Seat::North)
+        };
+        for seed in 0..10 {
+            let constraints = DealConstraints {
+                partnerships: vec![ns.clone(), ew.clone()],
+                ..constrained(vec![opener.clone()], seed)
+            };
+            let deal = generate_deal(&constraints).unwrap().deal;
+            let hcp = |seat| calculate_hcp(&deal.hands[&seat]);
+            let shape = |seat| get_suit_length(&deal.hands[&seat]);
+            assert!((25..=30).contains(&(hcp(Seat::North) + hcp(Seat::South))));
+            assert!(hcp(Seat::North) >= 12);
+            assert!(shape(Seat::North)[1] + shape(Seat::South)[1] >= 8);
+            for i in 0..4 {
+                assert!(shape(Seat::East)[i] + shape(Seat::West)[i] <= 7);
+            }
+        }
+    }
+
+    #[test]
+    fn partnership_aces_count_both_hands() {
+        let constraints = DealConstraints {
+            partnerships: vec![PartnershipConstraint {
+                min_aces: Some(4),
+                ..partnership(Partnership::EastWest)
+            }],
+            ..constrained(vec![], 7)
+        };
+        let deal = generate_deal(&constraints).unwrap().deal;
+        let aces = [Seat::East, Seat::West]
+            .iter()
+            .flat_map(|seat| &deal.hands[seat].cards)
+            .filter(|c| c.rank == Rank::Ace)
+            .count();
+        assert_eq!(aces, 4);
+    }
+
+    #[test]
+    fn unsatisfiable_partnership_names_the_side() {
+        let constraints = DealConstraints {
+            partnerships: vec![PartnershipConstraint {
+                max_fit: Some(SUIT_ORDER.iter().map(|&suit| (suit, 6)).collect()),
+                ..partnership(Partnership::NorthSouth)
+            }],
+            ..constrained(vec![], 1)
+        };
+        let err = generate_deal(&constraints).unwrap_err();
+        assert!(
+            matches!(&err, EngineError::UnsatisfiablePartnership(Partnership::NorthSouth, reason) if reason.contains("maxFit")),
+            "{err}"
+        );
+    }
+
     #[test]
     fn dealer_and_vulnerability_passed_through() {
         let constraints = DealConstraints {
@@ -331,6 +1144,7 @@ mod tests {
             dealer: Some(Seat::East),
             max_attempts: None,
             seed: Some(42),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         assert_eq!(result.deal.dealer, Seat::East);
@@ -352,11 +1166,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: Some(min_any),
+                ..Default::default()
             }],
             vulnerability: None,
             dealer: None,
             max_attempts: Some(50_000),
             seed: Some(300),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         let shape = get_suit_length(&result.deal.hands[&Seat::South]);
@@ -364,6 +1180,83 @@ mod tests {
         assert!(shape[0] >= 5 || shape[1] >= 5, "Shape was {:?}", shape);
     }
 
+    #[test]
+    fn honor_constraints_respected() {
+        let constraints = DealConstraints {
+            seats: vec![SeatConstraint {
+                seat: Seat::North,
+                min_controls: Some(5),
+                max_losers: Some(7),
+                key_cards: Some(KeyCardRange {
+                    trump: Suit::Hearts,
+                    min: Some(3),
+                    max: None,
+                    queen: Some(true),
+                }),
+                suit_quality: Some(vec![SuitQuality {
+                    suit: Suit::Hearts,
+                    min_honors: 2,
+                    of_top: 3,
+                }]),
+                ..Default::default()
+            }],
+            vulnerability: None,
+            dealer: None,
+            max_attempts: Some(200_000),
+            seed: Some(400),
+            ..Default::default()
+        };
+        let result = generate_deal(&constraints).unwrap();
+        let hand = &result.deal.hands[&Seat::North];
+        assert!(count_controls(hand) >= 5);
+        assert!(losing_trick_count(hand) <= 7);
+        assert!(count_key_cards(hand, Suit::Hearts) >= 3);
+        assert!(count_top_honors(hand, Suit::Hearts, 3) >= 2);
+        assert!(hand
+            .cards
+            .iter()
+            .any(|c| c.suit == Suit::Hearts && c.rank == Rank::Queen));
+    }
+
+    #[test]
+    fn shape_patterns_and_shortness_respected() {
+        let mut sc = SeatConstraint {
+            seat: Seat::East,
+            shapes: Some(vec![ShapePattern::AnyOrder {
+                lengths: [4, 4, 4, 1],
+            }]),
+            ..Default::default()
+        };
+        let mut constraints = DealConstraints {
+            seats: vec![sc.clone()],
+            vulnerability: None,
+            dealer: None,
+            max_attempts: Some(50_000),
+            seed: Some(500),
+            ..Default::default()
+        };
+        let result = generate_deal(&constraints).unwrap();
+        let mut shape = get_suit_length(&result.deal.hands[&Seat::East]);
+        shape.sort_unstable();
+        assert_eq!(shape, [1, 4, 4, 4]);
+
+        sc.shapes = Some(vec![ShapePattern::Exact {
+            lengths: [5, 4, 3, 1],
+        }]);
+        sc.max_shortest = Some(1);
+        constraints.seats = vec![sc];
+        let result = generate_deal(&constraints).unwrap();
+        assert_eq!(
+            get_suit_length(&result.deal.hands[&Seat::East]),
+            [5, 4, 3, 1]
+        );
+
+        // Void requested on top of a 5-4-3-1: impossible.
+        constraints.seats[0].max_shortest = Some(0);
+        constraints.max_attempts = Some(2_000);
+        assert!(generate_deal(&constraints).is_err());
+    }
+
     #[test]
     fn total_hcp_invariant() {
         let constraints = DealConstraints {
@@ -372,6 +1265,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(42),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         let total: u32 = SEATS
@@ -389,6 +1283,7 @@ mod tests {
             dealer: None,
             max_attempts: None,
             seed: Some(42),
+            ..Default::default()
         };
         let result = generate_deal(&constraints).unwrap();
         let mut all_cards: Vec<_> = result
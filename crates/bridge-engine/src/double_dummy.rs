@@ -0,0 +1,1797 @@
+//! Double-dummy solver — perfect-information trick search.
+//!
+//! Null-window alpha-beta over card plays with partition search: every search
+//! result records which ranks actually decided it, and the transposition table
+//! stores bounds that hold for every position with the same suit lengths and
+//! the same owners of those deciding cards. Quick-trick cutoffs at trick
+//! boundaries, equivalent-card pruning, and heuristic move ordering keep the
+//! tree small. Mirrors the contract of the DDS `SolveBoard` call: every legal
+//! card for the side to play, scored by the number of tricks that side takes
+//! from here on (current trick included).
+
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use crate::card_set::CardSet;
+use crate::constants::{bid_suit_to_suit, next_seat, rank_index};
+use crate::error::EngineError;
+use crate::types::{BidSuit, Card, Deal, PlayedCard, Rank, Seat, Suit};
+
+/// Suit order used by the solver (matches DDS: 0=S, 1=H, 2=D, 3=C).
+const SOLVER_SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+/// Seat order used by the solver (matches DDS: 0=N, 1=E, 2=S, 3=W).
+const SOLVER_SEATS: [Seat; 4] = [Seat::North, Seat::East, Seat::South, Seat::West];
+
+/// Strains in the order the trick table is solved: notrump first, then the
+/// suits in DDS order.
+const TABLE_STRAINS: [BidSuit; 5] = [
+    BidSuit::NoTrump,
+    BidSuit::Spades,
+    BidSuit::Hearts,
+    BidSuit::Diamonds,
+    BidSuit::Clubs,
+];
+
+/// Transposition table is flushed once it holds more than this many entries.
+const MAX_TT_ENTRIES: usize = 2_000_000;
+
+const SUIT_MASK: u64 = 0x1fff;
+
+/// Patterns kept per suit-length bucket; the oldest is dropped beyond this.
+const MAX_BUCKET_ENTRIES: usize = 24;
+
+/// Empty slot in the killer-move table.
+const NO_CARD: u8 = u8::MAX;
+
+/// A position in the play: remaining cards for every seat, the trump suit,
+/// the seat that led (or is about to lead) the current trick, and any cards
+/// already played to that trick in play order.
+///
+/// Cards on the table are NOT part of `hands`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayPosition {
+    pub hands: HashMap<Seat, Vec<Card>>,
+    pub trump: Option<Suit>,
+    pub leader: Seat,
+    pub current_trick: Vec<Card>,
+}
+
+/// Double-dummy result for one legal card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardTricks {
+    pub card: Card,
+    /// Tricks won by the side playing this card, from the current trick to the end.
+    pub tricks: u8,
+}
+
+/// Bounds on NS tricks that hold for every position in the bucket whose top
+/// `depth[suit]` cards in each suit belong to the same seats as `owners[suit]`.
+#[derive(Debug, Clone)]
+struct TtEntry {
+    depth: [u8; 4],
+    owners: [u32; 4],
+    lower: u8,
+    upper: u8,
+    /// Lead that produced a cutoff here, as suit * 16 + live cards above it.
+    best_lead: Option<u8>,
+}
+
+/// Multiplicative hasher for the table's integer keys; the default SipHash
+/// would dominate probe time.
+#[derive(Debug, Default, Clone, Copy)]
+struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        let h = self.0.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        h ^ (h >> 32)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0.rotate_left(5) ^ b as u64).wrapping_mul(0x517c_c1b7_2722_0a95);
+        }
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        let folded = (value as u64) ^ ((value >> 64) as u64).rotate_left(29);
+        self.0 = (self.0.rotate_left(5) ^ folded).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+type Table = HashMap<u128, Vec<TtEntry>, BuildHasherDefault<KeyHasher>>;
+
+/// Fixed-capacity list of moves (a hand never holds more than 13 cards).
+#[derive(Debug, Clone, Copy)]
+struct MoveList<T: Copy + Default> {
+    items: [T; 13],
+    len: usize,
+}
+
+impl<T: Copy + Default> MoveList<T> {
+    fn new() -> Self {
+        Self {
+            items: [T::default(); 13],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: T) {
+        self.items[self.len] = item;
+        self.len += 1;
+    }
+
+    fn as_slice(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+/// Reusable solver. The transposition table survives across calls, so solving
+/// many related positions (Monte Carlo samples, the five strains of a deal)
+/// with one instance is much cheaper than starting cold each time.
+#[derive(Debug, Default)]
+pub struct DoubleDummySolver {
+    /// Keyed by suit lengths per seat, trump and leader.
+    tt: Table,
+    entries: usize,
+    nodes: u64,
+}
+
+impl DoubleDummySolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of search nodes visited since this solver was created.
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    /// Score every legal card for the seat to play.
+    ///
+    /// Results are sorted best-first; ties keep suit (S, H, D, C) and
+    /// descending rank order.
+    pub fn solve_position(
+        &mut self,
+        position: &PlayPosition,
+    ) -> Result<Vec<CardTricks>, EngineError> {
+        let mut search = Search::from_position(position, &mut self.tt, &mut self.entries)?;
+        let player = search.player();
+        let total = search.tricks_left();
+        let best = search.value();
+
+        let mut results = Vec::new();
+        for &group in search.move_groups(player).as_slice() {
+            let ns_tricks = search.value_after(lowest_bit(group), best);
+            let tricks = if is_ns(player) {
+                ns_tricks
+            } else {
+                total - ns_tricks
+            };
+            let mut members = group;
+            while members != 0 {
+                let member = highest_bit(members);
+                members &= !(1u64 << member);
+                results.push(CardTricks {
+                    card: card_from_bit(member),
+                    tricks: tricks as u8,
+                });
+            }
+        }
+        self.nodes += search.nodes;
+        self.trim_table();
+
+        results.sort_by(|a, b| {
+            b.tricks
+                .cmp(&a.tricks)
+                .then(suit_index(a.card.suit).cmp(&suit_index(b.card.suit)))
+                .then(rank_index(b.card.rank).cmp(&rank_index(a.card.rank)))
+        });
+        Ok(results)
+    }
+
+    /// Maximum tricks the side to play can take from the current trick on.
+    pub fn best_tricks(&mut self, position: &PlayPosition) -> Result<u8, EngineError> {
+        let mut search = Search::from_position(position, &mut self.tt, &mut self.entries)?;
+        let player = search.player();
+        let total = search.tricks_left();
+        let ns_tricks = search.value();
+        self.nodes += search.nodes;
+        self.trim_table();
+        Ok(if is_ns(player) {
+            ns_tricks as u8
+        } else {
+            (total - ns_tricks) as u8
+        })
+    }
+
+    /// Double-dummy tricks for every declarer in every strain of a complete
+    /// deal (the 20-entry table). The opening leader is declarer's LHO.
+    pub fn solve_table(
+        &mut self,
+        deal: &Deal,
+    ) -> Result<HashMap<Seat, HashMap<BidSuit, u32>>, EngineError> {
+        let hands = deal
+            .hands
+            .iter()
+            .map(|(seat, hand)| (*seat, hand.cards.clone()))
+            .collect::<HashMap<_, _>>();
+        let total = hands.get(&Seat::North).map_or(0, Vec::len) as u32;
+
+        let mut table: HashMap<Seat, HashMap<BidSuit, u32>> = HashMap::new();
+        for strain in TABLE_STRAINS {
+            for declarer in SOLVER_SEATS {
+                let position = PlayPosition {
+                    hands: hands.clone(),
+                    trump: bid_suit_to_suit(strain),
+                    leader: next_seat(declarer),
+                    current_trick: Vec::new(),
+                };
+                let defence = u32::from(self.best_tricks(&position)?);
+                table
+                    .entry(declarer)
+                    .or_default()
+                    .insert(strain, total - defence);
+            }
+        }
+        Ok(table)
+    }
+
+    /// The rest of the play with every seat playing its best card (the first
+    /// card `solve_position` returns), current trick first, in play order.
+    pub fn optimal_line(
+        &mut self,
+        position: &PlayPosition,
+    ) -> Result<Vec<PlayedCard>, EngineError> {
+        let mut position = position.clone();
+        let mut line = Vec::new();
+        loop {
+            let seat = seat_to_play(&position);
+            if position.hands.get(&seat).is_none_or(Vec::is_empty) {
+                break;
+            }
+            let Some(best) = self.solve_position(&position)?.into_iter().next() else {
+                break;
+            };
+            play_card(&mut position, seat, &best.card);
+            line.push(PlayedCard {
+                card: best.card,
+                seat,
+            });
+        }
+        Ok(line)
+    }
+
+    fn trim_table(&mut self) {
+        if self.entries > MAX_TT_ENTRIES {
+            self.tt.clear();
+            self.entries = 0;
+        }
+    }
+}
+
+/// Score every legal card for the seat to play with a fresh solver.
+pub fn solve_position(position: &PlayPosition) -> Result<Vec<CardTricks>, EngineError> {
+    DoubleDummySolver::new().solve_position(position)
+}
+
+// ── Lines of play ───────────────────────────────────────────────────
+
+fn seat_to_play(position: &PlayPosition) -> Seat {
+    SOLVER_SEATS[(seat_idx(position.leader) + position.current_trick.len()) % 4]
+}
+
+/// Index into `trick` of the card winning it.
+fn winning_card(trick: &[Card], trump: Option<Suit>) -> usize {
+    let mut winner = 0;
+    for (i, card) in trick.iter().enumerate().skip(1) {
+        let best = &trick[winner];
+        let beats = if card.suit == best.suit {
+            rank_index(card.rank) > rank_index(best.rank)
+        } else {
+            Some(card.suit) == trump
+        };
+        if beats {
+            winner = i;
+        }
+    }
+    winner
+}
+
+/// Play `card` from `seat`'s hand, closing the trick after the fourth card.
+fn play_card(position: &mut PlayPosition, seat: Seat, card: &Card) {
+    if let Some(hand) = position.hands.get_mut(&seat) {
+        hand.retain(|c| c != card);
+    }
+    position.current_trick.push(card.clone());
+    if position.current_trick.len() == 4 {
+        let winner = winning_card(&position.current_trick, position.trump);
+        position.leader = SOLVER_SEATS[(seat_idx(position.leader) + winner) % 4];
+        position.current_trick.clear();
+    }
+}
+
+/// Tricks the side to play takes from here on (current trick included) when
+/// that number is the same on every legal line — no seat's choice of card can
+/// change it. `None` when two lines end differently, or when settling the
+/// question would visit more than `node_limit` positions.
+///
+/// Unlike the solver this walks every line, not just the best ones, so it is
+/// only practical near the end of the play.
+pub fn fixed_outcome(position: &PlayPosition, node_limit: u64) -> Option<u8> {
+    let mut walk = LineWalk {
+        hands: SOLVER_SEATS.map(|seat| {
+            position
+                .hands
+                .get(&seat)
+                .map_or(CardSet::EMPTY, |cards| CardSet::from(cards.as_slice()))
+        }),
+        trump: position.trump,
+        side: seat_idx(seat_to_play(position)) % 2,
+        nodes: 0,
+        node_limit,
+        outcome: None,
+    };
+    let leader = seat_idx(position.leader);
+    walk.visit(leader, position.current_trick.clone(), 0)
+        .then_some(walk.outcome)
+        .flatten()
+}
+
+/// Exhaustive walk for `fixed_outcome`.
+struct LineWalk {
+    hands: [CardSet; 4],
+    trump: Option<Suit>,
+    /// Partnership (seat index mod 2) whose tricks are counted.
+    side: usize,
+    nodes: u64,
+    node_limit: u64,
+    /// Tricks counted at the end of the first line walked.
+    outcome: Option<u8>,
+}
+
+impl LineWalk {
+    /// Walk every line from here; false as soon as one ends differently from
+    /// the first or the node budget runs out.
+    fn visit(&mut self, leader: usize, trick: Vec<Card>, won: u8) -> bool {
+        self.nodes += 1;
+        if self.nodes > self.node_limit {
+            return false;
+        }
+        let player = (leader + trick.len()) % 4;
+        let hand = self.hands[player];
+        if hand.is_empty() {
+            return *self.outcome.get_or_insert(won) == won;
+        }
+
+        let playable = match trick.first() {
+            Some(lead) if !hand.suit(lead.suit).is_empty() => hand.suit(lead.suit),
+            _ => hand,
+        };
+        let live = self
+            .hands
+            .iter()
+            .fold(CardSet::from(trick.as_slice()), |all, &h| all | h);
+        for card in distinct_cards(playable, live) {
+            self.hands[player].remove(&card);
+            let mut next = trick.clone();
+            next.push(card.clone());
+            let settled = if next.len() == 4 {
+                let winner = (leader + winning_card(&next, self.trump)) % 4;
+                let won = won + u8::from(winner % 2 == self.side);
+                self.visit(winner, Vec::new(), won)
+            } else {
+                self.visit(leader, next, won)
+            };
+            self.hands[player].insert(&card);
+            if !settled {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One card from each run of `playable` cards with no other `live` card
+/// between them — the rest of the run plays exactly the same.
+fn distinct_cards(playable: CardSet, live: CardSet) -> Vec<Card> {
+    playable
+        .positions()
+        .filter(|&position| {
+            let lane_end = position - position % 16 + 13;
+            let next_live = (position + 1..lane_end).find(|&p| live.bits() & (1 << p) != 0);
+            next_live.is_none_or(|p| playable.bits() & (1 << p) == 0)
+        })
+        .map(CardSet::card_at)
+        .collect()
+}
+
+// ── Search state ────────────────────────────────────────────────────
+
+/// Outcome of a null-window search: whether NS reach the target, plus the
+/// cards (same bit layout as the hands) whose ranks decided it.
+type Outcome = (bool, u64);
+
+struct Search<'a> {
+    /// Remaining cards per seat (N, E, S, W). Bit = suit * 16 + rank (0 = deuce).
+    hands: [u64; 4],
+    trump: Option<usize>,
+    leader: usize,
+    /// Cards on the table for the current trick, in play order.
+    trick: [u8; 4],
+    played: usize,
+    /// Every card still in play: the hands plus the cards on the table.
+    live: u64,
+    /// Last card that produced a cutoff, per number of live cards.
+    killers: [u8; 53],
+    tt: &'a mut Table,
+    entries: &'a mut usize,
+    nodes: u64,
+}
+
+impl<'a> Search<'a> {
+    fn from_position(
+        position: &PlayPosition,
+        tt: &'a mut Table,
+        entries: &'a mut usize,
+    ) -> Result<Self, EngineError> {
+        let invalid = |msg: String| EngineError::InvalidPosition(msg);
+
+        if position.current_trick.len() > 3 {
+            return Err(invalid(format!(
+                "current trick has {} cards (max 3)",
+                position.current_trick.len()
+            )));
+        }
+
+        let mut hands = [0u64; 4];
+        let mut seen = 0u64;
+        for (seat_idx, seat) in SOLVER_SEATS.iter().enumerate() {
+            for card in position.hands.get(seat).map_or(&[][..], Vec::as_slice) {
+                let bit = card_bit(card);
+                if seen & (1u64 << bit) != 0 {
+                    return Err(invalid(format!("duplicate card {:?}", card)));
+                }
+                seen |= 1u64 << bit;
+                hands[seat_idx] |= 1u64 << bit;
+            }
+        }
+
+        let mut trick = [0u8; 4];
+        for (i, card) in position.current_trick.iter().enumerate() {
+            let bit = card_bit(card);
+            if seen & (1u64 << bit) != 0 {
+                return Err(invalid(format!("duplicate card {:?}", card)));
+            }
+            seen |= 1u64 << bit;
+            trick[i] = bit;
+        }
+
+        let leader = seat_idx(position.leader);
+        let played = position.current_trick.len();
+        let to_move_len = hands[(leader + played) % 4].count_ones();
+        if to_move_len == 0 {
+            return Err(invalid("no cards left to play".to_string()));
+        }
+        for offset in 0..4 {
+            let seat = (leader + offset) % 4;
+            let expected = if offset < played {
+                to_move_len - 1
+            } else {
+                to_move_len
+            };
+            if hands[seat].count_ones() != expected {
+                return Err(invalid(format!(
+                    "{:?} holds {} cards, expected {}",
+                    SOLVER_SEATS[seat],
+                    hands[seat].count_ones(),
+                    expected
+                )));
+            }
+        }
+
+        let search = Self {
+            hands,
+            trump: position.trump.map(suit_index),
+            leader,
+            trick,
+            played,
+            live: seen,
+            killers: [NO_CARD; 53],
+            tt,
+            entries,
+            nodes: 0,
+        };
+
+        // Cards already on the table must have followed suit where possible.
+        if played > 0 {
+            let lead_suit = (trick[0] >> 4) as usize;
+            for (i, &bit) in trick.iter().enumerate().take(played).skip(1) {
+                let seat = (leader + i) % 4;
+                if (bit >> 4) as usize != lead_suit && search.suit_bits(seat, lead_suit) != 0 {
+                    return Err(invalid(format!(
+                        "{:?} revoked on the current trick",
+                        SOLVER_SEATS[seat]
+                    )));
+                }
+            }
+        }
+
+        Ok(search)
+    }
+
+    fn player(&self) -> usize {
+        (self.leader + self.played) % 4
+    }
+
+    /// Tricks left including the one in progress.
+    fn tricks_left(&self) -> i32 {
+        self.hands[self.player()].count_ones() as i32
+    }
+
+    fn suit_bits(&self, seat: usize, suit: usize) -> u64 {
+        (self.hands[seat] >> (suit * 16)) & SUIT_MASK
+    }
+
+    fn live_suit_bits(&self, suit: usize) -> u64 {
+        (self.live >> (suit * 16)) & SUIT_MASK
+    }
+
+    /// Exact NS tricks from the current point (current trick included).
+    fn value(&mut self) -> i32 {
+        let mut lo = 0;
+        let mut hi = self.tricks_left();
+        while lo < hi {
+            let mid = (lo + hi + 1) / 2;
+            let ok = self.search(mid).0;
+            if ok {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// Exact NS tricks after the player to move plays `bit`, given the value
+    /// `best` of the position itself. Most cards score at or next to `best`,
+    /// so step away from it rather than bisecting.
+    fn value_after(&mut self, bit: u8, best: i32) -> i32 {
+        let player = self.player();
+        let mut value = best;
+        if is_ns(player) {
+            while value > 0 && !self.play_and_search(player, bit, value).0 {
+                value -= 1;
+            }
+        } else {
+            let total = self.tricks_left();
+            while value < total && self.play_and_search(player, bit, value + 1).0 {
+                value += 1;
+            }
+        }
+        value
+    }
+
+    /// Can NS take at least `target` tricks from here (current trick included)?
+    fn search(&mut self, target: i32) -> Outcome {
+        self.nodes += 1;
+
+        let remaining = self.hands[self.leader].count_ones() as i32 + (self.played > 0) as i32;
+        if target <= 0 {
+            return (true, 0);
+        }
+        if target > remaining {
+            return (false, 0);
+        }
+        if self.played > 0 {
+            let (result, rel, _) = self.expand(target, None);
+            return (result, rel);
+        }
+
+        if remaining == 1 {
+            // Last trick: every card is forced.
+            for offset in 0..4 {
+                self.trick[offset] = lowest_bit(self.hands[(self.leader + offset) % 4]);
+            }
+            self.played = 4;
+            let (winner, rel) = self.completed_trick();
+            self.played = 0;
+            return (is_ns(winner), rel);
+        }
+
+        let key = self.tt_key();
+        let owners = self.owner_sequences();
+        let (hit, hint) = self.probe(key, &owners, target);
+        if let Some(hit) = hit {
+            return hit;
+        }
+
+        if let Some((result, rel)) = self.bounds_cutoff(target, remaining) {
+            self.store(key, &owners, target, (result, rel, None), remaining);
+            return (result, rel);
+        }
+
+        let (result, rel, best) = self.expand(target, hint);
+        self.store(key, &owners, target, (result, rel, best), remaining);
+        (result, rel)
+    }
+
+    /// Cheap bounds at a trick boundary: the leader's cashable winners and
+    /// the guaranteed trick for whoever holds the top trump.
+    fn bounds_cutoff(&self, target: i32, remaining: i32) -> Option<Outcome> {
+        let needed = if is_ns(self.leader) {
+            target
+        } else {
+            remaining - target + 1
+        };
+        let (quick, rel) = self.quick_tricks(self.leader, needed);
+        let quick = quick.min(remaining);
+        if is_ns(self.leader) {
+            if quick >= target {
+                return Some((true, rel));
+            }
+        } else if remaining - quick < target {
+            return Some((false, rel));
+        }
+
+        if let Some(trump) = self.trump {
+            let live = self.live_suit_bits(trump);
+            if live != 0 {
+                let top = highest_bit(live);
+                let bit = (trump * 16) as u8 + top;
+                let ns_top = (self.hands[0] | self.hands[2]) & (1u64 << bit) != 0;
+                if ns_top && target <= 1 {
+                    return Some((true, 1u64 << bit));
+                }
+                if !ns_top && target > remaining - 1 {
+                    return Some((false, 1u64 << bit));
+                }
+            }
+        }
+        None
+    }
+
+    /// Try every move; on a cutoff also report the card that produced it.
+    fn expand(&mut self, target: i32, hint: Option<u8>) -> (bool, u64, Option<u8>) {
+        let player = self.player();
+        let maximizing = is_ns(player);
+        let moves = self.ordered_moves(player, hint);
+
+        // Once a card below every deciding rank of its suit has failed, the
+        // other cards below that rank are small cards too: skip them.
+        let mut lowest_win = [0u8; 4];
+        let mut rel = 0u64;
+        for &(bit, top) in moves.as_slice() {
+            let suit = (bit >> 4) as usize;
+            if top < lowest_win[suit] {
+                continue;
+            }
+            let (child, child_rel) = self.play_and_search(player, bit, target);
+            if child == maximizing {
+                self.killers[self.live.count_ones() as usize] = bit;
+                return (maximizing, child_rel, Some(bit));
+            }
+            rel |= child_rel;
+            if lowest_win[suit] == 0 {
+                let relevant = (rel >> (suit * 16)) & SUIT_MASK;
+                let low = if relevant == 0 {
+                    16
+                } else {
+                    lowest_bit(relevant)
+                };
+                if top < low {
+                    lowest_win[suit] = low;
+                }
+            }
+        }
+        (!maximizing, rel, None)
+    }
+
+    fn play_and_search(&mut self, player: usize, bit: u8, target: i32) -> Outcome {
+        self.hands[player] &= !(1u64 << bit);
+        self.trick[self.played] = bit;
+        self.played += 1;
+
+        let outcome = if self.played == 4 {
+            let saved_leader = self.leader;
+            let saved_trick = self.trick;
+            let (winner, trick_rel) = self.completed_trick();
+            let ns_won = if is_ns(winner) { 1 } else { 0 };
+
+            let trick_cards = self.trick.iter().fold(0u64, |acc, &b| acc | 1u64 << b);
+            self.live &= !trick_cards;
+            self.leader = winner;
+            self.played = 0;
+            let (result, rel) = self.search(target - ns_won);
+            self.live |= trick_cards;
+            self.leader = saved_leader;
+            self.trick = saved_trick;
+            self.played = 4;
+            (result, rel | trick_rel)
+        } else {
+            self.search(target)
+        };
+
+        self.played -= 1;
+        self.hands[player] |= 1u64 << bit;
+        outcome
+    }
+
+    /// Offset (from the leader) of the card currently winning the trick.
+    /// Winner of the four cards on the table, plus the winning card when its
+    /// rank mattered (it beat another card of its suit).
+    fn completed_trick(&self) -> (usize, u64) {
+        let winner_offset = self.trick_winner_offset();
+        let winning_bit = self.trick[winner_offset];
+        let same_suit = self
+            .trick
+            .iter()
+            .filter(|&&b| b >> 4 == winning_bit >> 4)
+            .count();
+        let rel = if same_suit > 1 {
+            1u64 << winning_bit
+        } else {
+            0
+        };
+        ((self.leader + winner_offset) % 4, rel)
+    }
+
+    fn trick_winner_offset(&self) -> usize {
+        let mut best = 0;
+        for i in 1..self.played {
+            if self.beats(self.trick[i], self.trick[best]) {
+                best = i;
+            }
+        }
+        best
+    }
+
+    fn beats(&self, challenger: u8, current: u8) -> bool {
+        let (cs, cr) = (challenger >> 4, challenger & 15);
+        let (ws, wr) = (current >> 4, current & 15);
+        if cs == ws {
+            return cr > wr;
+        }
+        self.trump.is_some_and(|t| t as u8 == cs)
+    }
+
+    // ── Transposition table ─────────────────────────────────────────
+
+    /// Bucket key: every seat's length in every suit, plus trump and leader.
+    fn tt_key(&self) -> u128 {
+        let mut key: u128 = 0;
+        for seat in 0..4 {
+            for suit in 0..4 {
+                key = (key << 4) | self.suit_bits(seat, suit).count_ones() as u128;
+            }
+        }
+        let trump = self.trump.map_or(4, |t| t) as u128;
+        (key << 5) | (trump << 2) | self.leader as u128
+    }
+
+    /// Per suit: owners of the live cards from the top down (2 bits each, top
+    /// card in the most significant position) and the number of live cards.
+    fn owner_sequences(&self) -> [(u32, u8); 4] {
+        let mut sequences = [(0u32, 0u8); 4];
+        for (suit, sequence) in sequences.iter_mut().enumerate() {
+            let east = self.suit_bits(1, suit);
+            let south = self.suit_bits(2, suit);
+            let west = self.suit_bits(3, suit);
+            let mut live = self.live_suit_bits(suit);
+            while live != 0 {
+                let rank = highest_bit(live);
+                live &= !(1u64 << rank);
+                let owner =
+                    ((east >> rank) & 1) | (((south >> rank) & 1) * 2) | (((west >> rank) & 1) * 3);
+                sequence.0 = (sequence.0 << 2) | owner as u32;
+                sequence.1 += 1;
+            }
+        }
+        sequences
+    }
+
+    /// Look up a bound that decides `target`; failing that, return the
+    /// remembered best lead of any matching entry as an ordering hint.
+    fn probe(
+        &self,
+        key: u128,
+        owners: &[(u32, u8); 4],
+        target: i32,
+    ) -> (Option<Outcome>, Option<u8>) {
+        let Some(bucket) = self.tt.get(&key) else {
+            return (None, None);
+        };
+        let mut hint = None;
+        for entry in bucket.iter().rev() {
+            if !matches_entry(entry, owners) {
+                continue;
+            }
+            if entry.lower as i32 >= target || (entry.upper as i32) < target {
+                let outcome = (entry.lower as i32 >= target, self.top_cards(&entry.depth));
+                return (Some(outcome), None);
+            }
+            hint = hint.or(entry.best_lead);
+        }
+        (None, hint.and_then(|lead| self.absolute_card(lead)))
+    }
+
+    fn store(
+        &mut self,
+        key: u128,
+        owners: &[(u32, u8); 4],
+        target: i32,
+        (result, rel, best): (bool, u64, Option<u8>),
+        remaining: i32,
+    ) {
+        let best_lead = best.map(|bit| self.relative_card(bit));
+        let mut depth = [0u8; 4];
+        let mut pattern = [0u32; 4];
+        for suit in 0..4 {
+            let relevant = (rel >> (suit * 16)) & SUIT_MASK;
+            if relevant == 0 {
+                continue;
+            }
+            let live = self.live_suit_bits(suit);
+            let k = (live >> lowest_bit(relevant)).count_ones() as u8;
+            let (sequence, len) = owners[suit];
+            depth[suit] = k;
+            pattern[suit] = sequence >> (2 * (len - k));
+        }
+
+        let bucket = self.tt.entry(key).or_default();
+        let entry = match bucket
+            .iter_mut()
+            .position(|e| e.depth == depth && e.owners == pattern)
+        {
+            Some(i) => &mut bucket[i],
+            None => {
+                if bucket.len() >= MAX_BUCKET_ENTRIES {
+                    bucket.remove(0);
+                } else {
+                    *self.entries += 1;
+                }
+                bucket.push(TtEntry {
+                    depth,
+                    owners: pattern,
+                    lower: 0,
+                    upper: remaining as u8,
+                    best_lead,
+                });
+                bucket.last_mut().expect("entry just pushed")
+            }
+        };
+        if result {
+            entry.lower = entry.lower.max(target as u8);
+        } else {
+            entry.upper = entry.upper.min((target - 1) as u8);
+        }
+        if best_lead.is_some() {
+            entry.best_lead = best_lead;
+        }
+    }
+
+    /// Card bit -> suit * 16 + number of live cards of that suit above it.
+    fn relative_card(&self, bit: u8) -> u8 {
+        let suit = (bit >> 4) as usize;
+        let above = self.live_suit_bits(suit) >> ((bit & 15) + 1);
+        (suit as u8) << 4 | above.count_ones() as u8
+    }
+
+    /// Inverse of `relative_card` for the current position, if the card is
+    /// still live.
+    fn absolute_card(&self, relative: u8) -> Option<u8> {
+        let suit = (relative >> 4) as usize;
+        let mut live = self.live_suit_bits(suit);
+        for _ in 0..(relative & 15) {
+            live &= !(1u64 << highest_bit(live.max(1)));
+        }
+        (live != 0).then(|| (suit * 16) as u8 + highest_bit(live))
+    }
+
+    /// The top `depth[suit]` live cards of each suit, as a card mask.
+    fn top_cards(&self, depth: &[u8; 4]) -> u64 {
+        let mut mask = 0u64;
+        for (suit, &k) in depth.iter().enumerate() {
+            let mut live = self.live_suit_bits(suit);
+            for _ in 0..k {
+                let rank = highest_bit(live);
+                live &= !(1u64 << rank);
+                mask |= 1u64 << (suit * 16 + rank as usize);
+            }
+        }
+        mask
+    }
+
+    /// Tricks the leader's side can cash off the top, stopping once `needed`
+    /// is reached, plus the cards those tricks depend on.
+    ///
+    /// The leader first runs their own top winners; then, if a low card can
+    /// reach a top card in partner's hand, partner runs theirs. Side-suit
+    /// winners are capped by the length of any opponent who could ruff.
+    fn quick_tricks(&self, leader: usize, needed: i32) -> (i32, u64) {
+        let partner = (leader + 2) % 4;
+        let opponents = [(leader + 1) % 4, (leader + 3) % 4];
+        let ruff_cap = |suit: usize, gone: i32| -> i32 {
+            let Some(trump) = self.trump.filter(|&t| t != suit) else {
+                return i32::MAX;
+            };
+            opponents
+                .iter()
+                .filter(|&&opp| self.suit_bits(opp, trump) != 0)
+                .map(|&opp| self.suit_bits(opp, suit).count_ones() as i32 - gone)
+                .min()
+                .unwrap_or(i32::MAX)
+        };
+
+        // Leader's own winners.
+        let mut won = [0i32; 4];
+        let mut cashed = [0u64; 4];
+        let mut total = 0;
+        let mut rel = 0u64;
+        for suit in 0..4 {
+            let own = self.suit_bits(leader, suit);
+            let cap = ruff_cap(suit, 0);
+            let mut live = self.live_suit_bits(suit);
+            while live != 0 && won[suit] < cap {
+                let rank = highest_bit(live);
+                if own & (1u64 << rank) == 0 {
+                    break;
+                }
+                won[suit] += 1;
+                live &= !(1u64 << rank);
+                cashed[suit] |= 1u64 << rank;
+            }
+            total += won[suit];
+            rel |= cashed[suit] << (suit * 16);
+            if total >= needed {
+                return (total, rel);
+            }
+        }
+
+        // Partner's winners, once the leader's are gone. Partner follows with
+        // their lowest cards and keeps winners when discarding, if they can.
+        let mut partner_won = [0i32; 4];
+        let mut partner_cards = [0u64; 4];
+        let mut spare = 0;
+        let mut discards = 0;
+        for suit in 0..4 {
+            let own = self.suit_bits(partner, suit);
+            let len = own.count_ones() as i32;
+            let mut live = self.live_suit_bits(suit) & !cashed[suit];
+            let mut run = 0;
+            while live != 0 {
+                let rank = highest_bit(live);
+                if own & (1u64 << rank) == 0 {
+                    break;
+                }
+                run += 1;
+                live &= !(1u64 << rank);
+                partner_cards[suit] |= 1u64 << rank;
+            }
+            let left = (len - won[suit]).max(0);
+            discards += (won[suit] - len).max(0);
+            let kept = run.min(left);
+            spare += left - kept;
+            partner_won[suit] = kept.min(ruff_cap(suit, won[suit])).max(0);
+        }
+        if discards > spare {
+            return (total, rel);
+        }
+        let entry = (0..4).find(|&suit| {
+            partner_won[suit] > 0 && self.suit_bits(leader, suit).count_ones() as i32 > won[suit]
+        });
+        if entry.is_none() {
+            return (total, rel);
+        }
+        for suit in 0..4 {
+            if partner_won[suit] == 0 {
+                continue;
+            }
+            total += partner_won[suit];
+            let mut cards = partner_cards[suit];
+            for _ in 0..partner_won[suit] {
+                let rank = highest_bit(cards);
+                cards &= !(1u64 << rank);
+                rel |= 1u64 << (suit * 16 + rank as usize);
+            }
+            if total >= needed {
+                break;
+            }
+        }
+        (total, rel)
+    }
+
+    /// Legal cards for `player`, one mask per group of equivalent cards
+    /// (touching cards in one hand with no live card between them).
+    fn move_groups(&self, player: usize) -> MoveList<u64> {
+        let lead_suit = (self.played > 0).then(|| (self.trick[0] >> 4) as usize);
+        let suits = match lead_suit {
+            Some(suit) if self.suit_bits(player, suit) != 0 => suit..suit + 1,
+            _ => 0..4,
+        };
+
+        let mut groups = MoveList::new();
+        for suit in suits {
+            let own = self.suit_bits(player, suit);
+            if own == 0 {
+                continue;
+            }
+            let mut live = self.live_suit_bits(suit);
+            let mut current: u64 = 0;
+            while live != 0 {
+                let rank = highest_bit(live);
+                live &= !(1u64 << rank);
+                if own & (1u64 << rank) != 0 {
+                    current |= 1u64 << (suit * 16 + rank as usize);
+                } else if current != 0 {
+                    groups.push(current);
+                    current = 0;
+                }
+            }
+            if current != 0 {
+                groups.push(current);
+            }
+        }
+        groups
+    }
+
+    /// One representative card per equivalence group, best guesses first,
+    /// paired with the rank of the group's highest card.
+    fn ordered_moves(&self, player: usize, hint: Option<u8>) -> MoveList<(u8, u8)> {
+        let killer = self.killers[self.live.count_ones() as usize];
+        let mut scored = MoveList::<(i32, u8, u8)>::new();
+        for &group in self.move_groups(player).as_slice() {
+            let bit = lowest_bit(group);
+            let mut score = self.move_score(player, bit, group);
+            if hint.is_some_and(|h| group & (1u64 << h) != 0) {
+                score = i32::MAX;
+            } else if self.played == 0 && killer != NO_CARD && group & (1u64 << killer) != 0 {
+                score += 50;
+            }
+            scored.push((score, bit, highest_bit(group) & 15));
+        }
+        let len = scored.len;
+        scored.items[..len].sort_by_key(|item| std::cmp::Reverse(item.0));
+
+        let mut moves = MoveList::new();
+        for &(_, bit, top) in scored.as_slice() {
+            moves.push((bit, top));
+        }
+        moves
+    }
+
+    fn move_score(&self, player: usize, bit: u8, group: u64) -> i32 {
+        let suit = (bit >> 4) as usize;
+        let rank = (bit & 15) as i32;
+        let top = highest_bit(group) as i32 - (suit as i32) * 16;
+
+        if self.played == 0 {
+            return self.lead_score(player, suit, top, group.count_ones() > 1);
+        }
+
+        let winner_offset = self.trick_winner_offset();
+        let winning_bit = self.trick[winner_offset];
+        let partner_has_it = (self.leader + winner_offset) % 4 == (player + 2) % 4
+            && !self.can_be_overtaken(player, winning_bit);
+        let lead_suit = (self.trick[0] >> 4) as usize;
+
+        if suit != lead_suit && self.trump != Some(suit) {
+            return self.discard_score(player, suit, rank, partner_has_it);
+        }
+        if partner_has_it {
+            // Partner has the trick: play the weakest card, never ruff it.
+            return -rank - if suit != lead_suit { 30 } else { 0 };
+        }
+        if self.beats(bit, winning_bit) && !self.can_be_overtaken(player, bit) {
+            // Win as cheaply as possible.
+            return 100 - rank;
+        }
+        let top_bit = (suit * 16) as u8 + top as u8;
+        if suit == lead_suit && self.beats(top_bit, winning_bit) {
+            if self.played == 1 {
+                // Second hand low, unless it wins outright.
+                return 10 - rank;
+            }
+            // Third hand high: force out the opponents' high cards.
+            return 50 + top;
+        }
+        if suit != lead_suit {
+            // A ruff that will be overruffed still costs them a trump.
+            return 15 - rank;
+        }
+        -rank
+    }
+
+    /// Lead ordering, after the DDS weights: prefer suits where the
+    /// opponents have few cards to play, where our side holds the top cards,
+    /// or where the opponents' high cards sit badly for them.
+    fn lead_score(&self, leader: usize, suit: usize, top: i32, sequence: bool) -> i32 {
+        let partner = (leader + 2) % 4;
+        let lho = (leader + 1) % 4;
+        let rho = (leader + 3) % 4;
+        let len = |seat: usize| self.suit_bits(seat, suit).count_ones() as i32;
+        let high = |seat: usize| {
+            let bits = self.suit_bits(seat, suit);
+            if bits == 0 {
+                -1
+            } else {
+                highest_bit(bits) as i32
+            }
+        };
+
+        let live = self.live_suit_bits(suit);
+        let mut ranked = [None; 3];
+        let mut rest = live;
+        for slot in ranked.iter_mut() {
+            if rest == 0 {
+                break;
+            }
+            let r = highest_bit(rest);
+            rest &= !(1u64 << r);
+            *slot = (0..4).find(|&seat| self.suit_bits(seat, suit) >> r & 1 == 1);
+        }
+        let [winner, second, third] = ranked;
+        let below_top = live & !(1u64 << highest_bit(live));
+        let second_rank = if below_top == 0 {
+            -1
+        } else {
+            highest_bit(below_top) as i32
+        };
+        let rel_rank = (live >> (top + 1)).count_ones() as i32 + 1;
+        let tricks = self.tricks_left();
+        let spread = |l: i32| if l == 0 { tricks + 1 } else { l } << 2;
+        let (lho_len, rho_len) = (len(lho), len(rho));
+        let we_win = winner == Some(leader) && highest_bit(live) as i32 == top
+            || high(partner) > high(lho).max(high(rho));
+
+        let ruff_test = self
+            .trump
+            .filter(|&t| self.live_suit_bits(t) != 0 && t != suit);
+
+        let Some(trump) = ruff_test else {
+            // Notrump (or trumps drawn / leading trumps).
+            let mut delta = -(((spread(lho_len) + spread(rho_len)) << 5) / 19);
+            if len(partner) == 0 {
+                delta -= 9;
+            }
+            if we_win {
+                if second == Some(rho) {
+                    if rho_len != 1 {
+                        delta -= 1;
+                    }
+                } else if second == Some(lho) {
+                    delta += if lho_len != 1 { 22 } else { 16 };
+                }
+                return if (second != Some(lho) || lho_len == 1)
+                    && (second != Some(rho) || rho_len == 1)
+                {
+                    delta + 45 + rel_rank
+                } else {
+                    delta + 18 + rel_rank
+                };
+            }
+            if winner == Some(rho) || second == Some(rho) {
+                if rho_len != 1 {
+                    delta -= 10;
+                }
+            } else if winner == Some(lho) && second == Some(partner) && len(partner) != 1 {
+                delta += 31;
+            }
+            if second == Some(partner) && third == Some(partner) {
+                delta += 35;
+            } else if len(partner) > 1
+                && (second == Some(leader) && third == Some(partner)
+                    || second == Some(partner) && third == Some(leader))
+            {
+                delta += 25;
+            }
+            return if lho_len == 1 && winner == Some(lho) || rho_len == 1 && winner == Some(rho) {
+                delta + 28 + rel_rank
+            } else if winner == Some(leader) {
+                delta - 17 + rel_rank
+            } else if !sequence {
+                delta + 12 + rel_rank
+            } else if top == second_rank {
+                delta + 48
+            } else {
+                delta + 29 - rel_rank
+            };
+        };
+
+        // Side-suit lead in a trump contract.
+        let trumps = |seat: usize| self.suit_bits(seat, trump).count_ones() as i32;
+        let can_ruff = |seat: usize| len(seat) == 0 && trumps(seat) > 0;
+        let mut delta = -(((spread(lho_len) + spread(rho_len)) << 5) / 13);
+        if can_ruff(lho) || can_ruff(rho) {
+            delta -= 12;
+        }
+        if can_ruff(partner) && rho_len > 0 {
+            delta += 17;
+        }
+        if winner == Some(rho) || second == Some(rho) {
+            if rho_len != 1 {
+                delta -= 12;
+            }
+        } else if winner == Some(lho) && second == Some(partner) && len(partner) != 1 {
+            delta += 27;
+        }
+        if len(leader) == 1 && trumps(leader) > 0 && len(partner) > 1 && winner == Some(partner) {
+            delta += 19;
+        }
+
+        let safe = !can_ruff(lho) && !can_ruff(rho);
+        if we_win && safe {
+            return if lho_len == 1 && winner == Some(lho) || rho_len == 1 && winner == Some(rho) {
+                delta + 35 + rel_rank
+            } else if winner == Some(leader) {
+                if second == Some(partner) {
+                    delta + 48 + rel_rank
+                } else if highest_bit(live) as i32 == top {
+                    delta + 31
+                } else {
+                    delta - 3 + rel_rank
+                }
+            } else if winner == Some(partner) {
+                if second == Some(leader) {
+                    delta + 42 + rel_rank
+                } else {
+                    delta + 28 + rel_rank
+                }
+            } else if sequence && top == second_rank {
+                delta + 40
+            } else if sequence {
+                delta + 22 + rel_rank
+            } else {
+                delta + 11 + rel_rank
+            };
+        }
+        if second == Some(partner) && third == Some(partner) {
+            delta += 20;
+        } else if len(partner) > 1
+            && (second == Some(leader) && third == Some(partner)
+                || second == Some(partner) && third == Some(leader))
+        {
+            delta += 13;
+        }
+        if lho_len == 1 && winner == Some(lho) || rho_len == 1 && winner == Some(rho) {
+            delta + rel_rank + 2
+        } else if winner == Some(leader) {
+            if second == Some(partner) {
+                delta + 33 + rel_rank
+            } else if highest_bit(live) as i32 == top {
+                delta + 38
+            } else {
+                delta - 14 + rel_rank
+            }
+        } else if winner == Some(partner) {
+            delta + 34 + rel_rank
+        } else if sequence && top == second_rank {
+            delta + 35
+        } else {
+            delta + 17 - (top + 2)
+        }
+    }
+
+    /// Discard ordering: throw from long suits, keep guards (Kx) and
+    /// stranded top cards.
+    fn discard_score(&self, player: usize, suit: usize, rank: i32, partner_has_it: bool) -> i32 {
+        let count = self.suit_bits(player, suit).count_ones() as i32;
+        let live = self.live_suit_bits(suit);
+        let top = highest_bit(live);
+        let second = live & !(1u64 << top);
+        let holds = |bits: u64, r: u8| bits >> r & 1 == 1;
+        let own = self.suit_bits(player, suit);
+        let holds_second = second != 0 && holds(own, highest_bit(second));
+
+        let mut add = if partner_has_it {
+            60 + (count << 6) / 44
+        } else {
+            (count << 6) / 33
+        };
+        if count == 2 && holds_second {
+            add -= 6;
+        } else if count == 1 && holds(own, top) {
+            add -= 8;
+        }
+        add - (rank + 2)
+    }
+
+    /// Could an opponent of `player` who is still to play beat `card`?
+    fn can_be_overtaken(&self, player: usize, card: u8) -> bool {
+        let lead_suit = (self.trick[0] >> 4) as usize;
+        let card_suit = (card >> 4) as usize;
+        (self.played + 1..4)
+            .map(|offset| (self.leader + offset) % 4)
+            .filter(|&seat| is_ns(seat) != is_ns(player))
+            .any(|seat| {
+                let follow = self.suit_bits(seat, lead_suit);
+                if follow != 0 {
+                    return card_suit == lead_suit && follow >> ((card & 15) + 1) != 0;
+                }
+                match self.trump {
+                    Some(trump) if trump != lead_suit => {
+                        let trumps = self.suit_bits(seat, trump);
+                        if card_suit == trump {
+                            trumps >> ((card & 15) + 1) != 0
+                        } else {
+                            trumps != 0
+                        }
+                    }
+                    _ => false,
+                }
+            })
+    }
+}
+
+// ── Bit helpers ─────────────────────────────────────────────────────
+
+fn matches_entry(entry: &TtEntry, owners: &[(u32, u8); 4]) -> bool {
+    (0..4).all(|suit| {
+        let k = entry.depth[suit];
+        let (sequence, len) = owners[suit];
+        k == 0 || sequence >> (2 * (len - k)) == entry.owners[suit]
+    })
+}
+
+fn is_ns(seat: usize) -> bool {
+    seat.is_multiple_of(2)
+}
+
+fn highest_bit(mask: u64) -> u8 {
+    63 - mask.leading_zeros() as u8
+}
+
+fn lowest_bit(mask: u64) -> u8 {
+    mask.trailing_zeros() as u8
+}
+
+fn seat_idx(seat: Seat) -> usize {
+    match seat {
+        Seat::North => 0,
+        Seat::East => 1,
+        Seat::South => 2,
+        Seat::West => 3,
+    }
+}
+
+fn suit_index(suit: Suit) -> usize {
+    match suit {
+        Suit::Spades => 0,
+        Suit::Hearts => 1,
+        Suit::Diamonds => 2,
+        Suit::Clubs => 3,
+    }
+}
+
+fn card_bit(card: &Card) -> u8 {
+    (suit_index(card.suit) * 16 + rank_index(card.rank)) as u8
+}
+
+fn card_from_bit(bit: u8) -> Card {
+    const RANKS: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+    Card {
+        suit: SOLVER_SUITS[(bit >> 4) as usize],
+        rank: RANKS[(bit & 15) as usize],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::create_deck;
+    use crate::play::get_legal_plays;
+    use crate::types::{Hand, Vulnerability};
+    use rand::prelude::*;
+    use rand_chacha::ChaCha8Rng;
+
+    fn card(suit: Suit, rank: Rank) -> Card {
+        Card { suit, rank }
+    }
+
+    fn position(
+        hands: [Vec<Card>; 4],
+        trump: Option<Suit>,
+        leader: Seat,
+        current_trick: Vec<Card>,
+    ) -> PlayPosition {
+        let mut map = HashMap::new();
+        for (seat, cards) in SOLVER_SEATS.iter().zip(hands) {
+            map.insert(*seat, cards);
+        }
+        PlayPosition {
+            hands: map,
+            trump,
+            leader,
+            current_trick,
+        }
+    }
+
+    /// Plain minimax over every legal card — reference for small endings.
+    fn brute_force(
+        hands: &mut [Vec<Card>; 4],
+        trump: Option<Suit>,
+        leader: usize,
+        trick: &mut Vec<Card>,
+    ) -> i32 {
+        let player = (leader + trick.len()) % 4;
+        if hands[player].is_empty() {
+            return 0;
+        }
+        let lead = trick.first().map(|c| c.suit);
+        let legal = get_legal_plays(
+            &Hand {
+                cards: hands[player].clone(),
+            },
+            lead,
+        );
+        let mut best = if is_ns(player) { -1 } else { i32::MAX };
+        for c in legal {
+            let idx = hands[player].iter().position(|h| *h == c).unwrap();
+            hands[player].remove(idx);
+            trick.push(c.clone());
+            let value = if trick.len() == 4 {
+                let mut win = 0;
+                for i in 1..4 {
+                    let (a, b) = (&trick[i], &trick[win]);
+                    let beats = if a.suit == b.suit {
+                        rank_index(a.rank) > rank_index(b.rank)
+                    } else {
+                        Some(a.suit) == trump
+                    };
+                    if beats {
+                        win = i;
+                    }
+                }
+                let winner = (leader + win) % 4;
+                let taken = std::mem::take(trick);
+                let v = brute_force(hands, trump, winner, trick) + is_ns(winner) as i32;
+                *trick = taken;
+                v
+            } else {
+                brute_force(hands, trump, leader, trick)
+            };
+            trick.pop();
+            hands[player].insert(idx, c);
+            best = if is_ns(player) {
+                best.max(value)
+            } else {
+                best.min(value)
+            };
+        }
+        best
+    }
+
+    fn random_ending(rng: &mut ChaCha8Rng, per_hand: usize) -> [Vec<Card>; 4] {
+        let mut deck = create_deck();
+        deck.shuffle(rng);
+        let mut hands: [Vec<Card>; 4] = Default::default();
+        for (i, hand) in hands.iter_mut().enumerate() {
+            *hand = deck[i * per_hand..(i + 1) * per_hand].to_vec();
+        }
+        hands
+    }
+
+    #[test]
+    fn top_cards_cash_in_notrump() {
+        let hands = [
+            vec![
+                card(Suit::Spades, Rank::Ace),
+                card(Suit::Spades, Rank::King),
+            ],
+            vec![
+                card(Suit::Hearts, Rank::Ace),
+                card(Suit::Hearts, Rank::King),
+            ],
+            vec![
+                card(Suit::Spades, Rank::Two),
+                card(Suit::Spades, Rank::Three),
+            ],
+            vec![card(Suit::Clubs, Rank::Ace), card(Suit::Clubs, Rank::King)],
+        ];
+        let result = solve_position(&position(hands, None, Seat::North, vec![])).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|r| r.tricks == 2));
+    }
+
+    #[test]
+    fn trump_ruff_beats_side_suit_winner() {
+        // North leads; East is void in spades with a trump.
+        let hands = [
+            vec![card(Suit::Spades, Rank::Ace)],
+            vec![card(Suit::Hearts, Rank::Two)],
+            vec![card(Suit::Spades, Rank::Two)],
+            vec![card(Suit::Spades, Rank::Three)],
+        ];
+        let nt = solve_position(&position(hands.clone(), None, Seat::North, vec![])).unwrap();
+        assert_eq!(nt[0].tricks, 1);
+        let hearts =
+            solve_position(&position(hands, Some(Suit::Hearts), Seat::North, vec![])).unwrap();
+        assert_eq!(hearts[0].tricks, 0);
+    }
+
+    #[test]
+    fn mid_trick_position_scores_follow_suit_cards() {
+        // North led the SK; East to play with SA and a small club.
+        let hands = [
+            vec![card(Suit::Hearts, Rank::Two)],
+            vec![card(Suit::Spades, Rank::Ace), card(Suit::Clubs, Rank::Two)],
+            vec![
+                card(Suit::Spades, Rank::Two),
+                card(Suit::Hearts, Rank::Three),
+            ],
+            vec![
+                card(Suit::Spades, Rank::Three),
+                card(Suit::Clubs, Rank::Three),
+            ],
+        ];
+        let result = solve_position(&position(
+            hands,
+            None,
+            Seat::North,
+            vec![card(Suit::Spades, Rank::King)],
+        ))
+        .unwrap();
+        // East must follow with the ace: only one legal card.
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].card, card(Suit::Spades, Rank::Ace));
+    }
+
+    #[test]
+    fn equivalent_cards_share_a_score() {
+        let hands = [
+            vec![
+                card(Suit::Spades, Rank::Queen),
+                card(Suit::Spades, Rank::Jack),
+                card(Suit::Spades, Rank::Ten),
+            ],
+            vec![
+                card(Suit::Spades, Rank::King),
+                card(Suit::Hearts, Rank::Two),
+                card(Suit::Hearts, Rank::Three),
+            ],
+            vec![
+                card(Suit::Hearts, Rank::Ace),
+                card(Suit::Hearts, Rank::King),
+                card(Suit::Hearts, Rank::Queen),
+            ],
+            vec![
+                card(Suit::Spades, Rank::Ace),
+                card(Suit::Clubs, Rank::Two),
+                card(Suit::Clubs, Rank::Three),
+            ],
+        ];
+        let result = solve_position(&position(hands, None, Seat::North, vec![])).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(result.windows(2).all(|w| w[0].tricks == w[1].tricks));
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_endings() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let trumps = [None, Some(Suit::Spades), Some(Suit::Hearts)];
+        for round in 0..24 {
+            let hands = random_ending(&mut rng, 4);
+            let trump = trumps[round % trumps.len()];
+            let leader = round % 4;
+            let pos = position(hands.clone(), trump, SOLVER_SEATS[leader], vec![]);
+            let result = solve_position(&pos).unwrap();
+
+            let mut reference = hands.clone();
+            let ns = brute_force(&mut reference, trump, leader, &mut Vec::new());
+            let expected = if is_ns(leader) { ns } else { 4 - ns };
+            assert_eq!(result[0].tricks as i32, expected, "round {round}");
+
+            // Every card score must match the reference after playing it.
+            for entry in &result {
+                let mut after = hands.clone();
+                after[leader].retain(|c| *c != entry.card);
+                let ns_after =
+                    brute_force(&mut after, trump, leader, &mut vec![entry.card.clone()]);
+                let side = if is_ns(leader) {
+                    ns_after
+                } else {
+                    4 - ns_after
+                };
+                assert_eq!(
+                    entry.tricks as i32, side,
+                    "round {round} card {:?}",
+                    entry.card
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn optimal_line_takes_the_double_dummy_tricks() {
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        let mut solver = DoubleDummySolver::new();
+        for round in 0..8 {
+            let hands = random_ending(&mut rng, 4);
+            let leader = SOLVER_SEATS[round % 4];
+            let pos = position(hands, Some(Suit::Spades), leader, vec![]);
+            let best = solver.best_tricks(&pos).unwrap();
+
+            let line = solver.optimal_line(&pos).unwrap();
+            assert_eq!(line.len(), 16);
+            let mut taken = 0;
+            for (trick, plays) in line.chunks(4).enumerate() {
+                let cards = plays.iter().map(|p| p.card.clone()).collect::<Vec<_>>();
+                let winner = plays[winning_card(&cards, pos.trump)].seat;
+                if trick == 0 {
+                    assert_eq!(plays[0].seat, leader);
+                }
+                if is_ns(seat_idx(winner)) == is_ns(seat_idx(leader)) {
+                    taken += 1;
+                }
+            }
+            assert_eq!(taken, best, "round {round}");
+        }
+    }
+
+    #[test]
+    fn fixed_outcome_only_when_no_card_matters() {
+        // North cashes two top spades whatever anyone plays.
+        let cashing = [
+            vec![
+                card(Suit::Spades, Rank::Ace),
+                card(Suit::Spades, Rank::King),
+            ],
+            vec![
+                card(Suit::Hearts, Rank::Two),
+                card(Suit::Hearts, Rank::Three),
+            ],
+            vec![
+                card(Suit::Spades, Rank::Two),
+                card(Suit::Spades, Rank::Three),
+            ],
+            vec![card(Suit::Clubs, Rank::Two), card(Suit::Clubs, Rank::Three)],
+        ];
+        let pos = position(cashing, None, Seat::North, vec![]);
+        assert_eq!(fixed_outcome(&pos, 10_000), Some(2));
+        assert_eq!(fixed_outcome(&pos, 1), None);
+
+        // South can waste the king under the ace and let East's queen score.
+        let blockage = [
+            vec![card(Suit::Spades, Rank::Ace), card(Suit::Spades, Rank::Two)],
+            vec![
+                card(Suit::Spades, Rank::Queen),
+                card(Suit::Hearts, Rank::Two),
+            ],
+            vec![
+                card(Suit::Spades, Rank::King),
+                card(Suit::Spades, Rank::Three),
+            ],
+            vec![
+                card(Suit::Hearts, Rank::Three),
+                card(Suit::Hearts, Rank::Four),
+            ],
+        ];
+        let pos = position(blockage, None, Seat::North, vec![]);
+        assert_eq!(fixed_outcome(&pos, 10_000), None);
+        assert_eq!(solve_position(&pos).unwrap()[0].tricks, 2);
+    }
+
+    #[test]
+    fn fixed_outcome_agrees_with_the_solver() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let mut fixed = 0;
+        for round in 0..40 {
+            let hands = random_ending(&mut rng, 3);
+            let pos = position(hands, Some(Suit::Hearts), SOLVER_SEATS[round % 4], vec![]);
+            if let Some(tricks) = fixed_outcome(&pos, 100_000) {
+                fixed += 1;
+                let results = solve_position(&pos).unwrap();
+                // Every card, not just the best one, leads to the same count.
+                assert!(results.iter().all(|r| r.tricks == tricks), "round {round}");
+            }
+        }
+        assert!(fixed > 0);
+    }
+
+    #[test]
+    fn rejects_inconsistent_hand_sizes() {
+        let hands = [
+            vec![card(Suit::Spades, Rank::Ace)],
+            vec![],
+            vec![card(Suit::Spades, Rank::Two)],
+            vec![card(Suit::Spades, Rank::Three)],
+        ];
+        let err = solve_position(&position(hands, None, Seat::North, vec![])).unwrap_err();
+        assert!(matches!(err, EngineError::InvalidPosition(_)));
+    }
+
+    #[test]
+    fn rejects_duplicate_cards() {
+        let hands = [
+            vec![card(Suit::Spades, Rank::Ace)],
+            vec![card(Suit::Spades, Rank::Ace)],
+            vec![card(Suit::Spades, Rank::Two)],
+            vec![card(Suit::Spades, Rank::Three)],
+        ];
+        assert!(solve_position(&position(hands, None, Seat::North, vec![])).is_err());
+    }
+
+    #[test]
+    fn solves_full_deal_with_solid_suits() {
+        // Each seat holds one complete suit: the leader's side takes every trick
+        // it leads in notrump, and nobody else can ever win one.
+        let suit_of = |suit: Suit| -> Vec<Card> {
+            create_deck()
+                .into_iter()
+                .filter(|c| c.suit == suit)
+                .collect()
+        };
+        let hands = [
+            suit_of(Suit::Spades),
+            suit_of(Suit::Hearts),
+            suit_of(Suit::Diamonds),
+            suit_of(Suit::Clubs),
+        ];
+        let mut solver = DoubleDummySolver::new();
+        let nt = solver
+            .best_tricks(&position(hands.clone(), None, Seat::North, vec![]))
+            .unwrap();
+        assert_eq!(nt, 13);
+        let east_trumps = solver
+            .best_tricks(&position(hands, Some(Suit::Hearts), Seat::North, vec![]))
+            .unwrap();
+        // East ruffs the first spade and runs hearts.
+        assert_eq!(east_trumps, 0);
+    }
+
+    #[test]
+    fn solve_table_fills_every_declarer_and_strain() {
+        let suit_of = |suit: Suit| -> Hand {
+            Hand {
+                cards: create_deck()
+                    .into_iter()
+                    .filter(|c| c.suit == suit)
+                    .collect(),
+            }
+        };
+        let deal = Deal {
+            hands: HashMap::from([
+                (Seat::North, suit_of(Suit::Spades)),
+                (Seat::East, suit_of(Suit::Hearts)),
+                (Seat::South, suit_of(Suit::Diamonds)),
+                (Seat::West, suit_of(Suit::Clubs)),
+            ]),
+            dealer: Seat::North,
+            vulnerability: Vulnerability::None,
+        };
+        let table = DoubleDummySolver::new().solve_table(&deal).unwrap();
+
+        assert_eq!(table.len(), 4);
+        for (seat, strains) in &table {
+            assert_eq!(strains.len(), 5);
+            // The opening leader runs their suit in notrump.
+            assert_eq!(strains[&BidSuit::NoTrump], 0);
+            // Whichever side owns the trump suit takes every trick.
+            let ns = matches!(seat, Seat::North | Seat::South);
+            for (strain, ns_owns) in [
+                (BidSuit::Spades, true),
+                (BidSuit::Diamonds, true),
+                (BidSuit::Hearts, false),
+                (BidSuit::Clubs, false),
+            ] {
+                let expected = if ns == ns_owns { 13 } else { 0 };
+                assert_eq!(strains[&strain], expected, "{seat:?} {strain:?}");
+            }
+        }
+    }
+}
@@ -16,4 +16,22 @@ pub enum EngineError {
 
     #[error("Failed to generate deal after {0} attempts")]
     MaxAttemptsExceeded(u32),
+
+    #[error("No deal satisfies the {0:?} constraint: {1}")]
+    UnsatisfiableConstraint(crate::types::Seat, String),
+
+    #[error("No deal satisfies the {0:?} partnership constraint: {1}")]
+    UnsatisfiablePartnership(crate::types::Partnership, String),
+
+    #[error("Invalid play position: {0}")]
+    InvalidPosition(String),
+
+    #[error("PBN parse error: {0}")]
+    PbnParse(String),
+
+    #[error("LIN parse error: {0}")]
+    LinParse(String),
+
+    #[error("Invalid board id: {0}")]
+    InvalidBoardId(String),
 }
@@ -1,5 +1,5 @@
-use crate::constants::hcp_value;
-use crate::types::{DistributionPoints, Hand, HandEvaluation, Suit, SuitLength};
+use crate::constants::{hcp_value, rank_index, SUIT_ORDER};
+use crate::types::{DistributionPoints, Hand, HandEvaluation, Rank, Suit, SuitLength};
 
 /// Sum of high card points (A=4, K=3, Q=2, J=1) in the hand.
 pub fn calculate_hcp(hand: &Hand) -> u32 {
@@ -88,6 +88,49 @@ pub fn calculate_distribution_points(shape: &SuitLength) -> DistributionPoints {
     }
 }
 
+/// Controls: ace = 2, king = 1.
+pub fn count_controls(hand: &Hand) -> u32 {
+    hand.cards
+        .iter()
+        .map(|c| match c.rank {
+            Rank::Ace => 2,
+            Rank::King => 1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Losing-trick count: per suit, the missing ace, king and queen among the
+/// first min(length, 3) cards.
+pub fn losing_trick_count(hand: &Hand) -> u32 {
+    let shape = get_suit_length(hand);
+    let mut losers = 0u32;
+    for (i, &suit) in SUIT_ORDER.iter().enumerate() {
+        let held = |rank: Rank| hand.cards.iter().any(|c| c.suit == suit && c.rank == rank);
+        let top = [Rank::Ace, Rank::King, Rank::Queen];
+        let counted = usize::from(shape[i].min(3));
+        losers += top[..counted].iter().filter(|&&rank| !held(rank)).count() as u32;
+    }
+    losers
+}
+
+/// Roman keycards for `trump`: the four aces plus the trump king.
+pub fn count_key_cards(hand: &Hand, trump: Suit) -> u8 {
+    hand.cards
+        .iter()
+        .filter(|c| c.rank == Rank::Ace || (c.suit == trump && c.rank == Rank::King))
+        .count() as u8
+}
+
+/// How many of the top `of_top` ranks of `suit` the hand holds.
+pub fn count_top_honors(hand: &Hand, suit: Suit, of_top: u8) -> u8 {
+    let lowest = 13usize.saturating_sub(usize::from(of_top));
+    hand.cards
+        .iter()
+        .filter(|c| c.suit == suit && rank_index(c.rank) >= lowest)
+        .count() as u8
+}
+
 /// Evaluate a hand using HCP scoring.
 pub fn evaluate_hand_hcp(hand: &Hand) -> HandEvaluation {
     let (hcp, shape) = calculate_hcp_and_shape(hand);
@@ -137,6 +180,34 @@ mod tests {
         Hand { cards }
     }
 
+    #[test]
+    fn controls_losers_and_key_cards() {
+        // AKxx / Qxx / Kx / xxxx
+        let hand = make_hand(&[
+            ("S", "A"),
+            ("S", "K"),
+            ("S", "5"),
+            ("S", "4"),
+            ("H", "Q"),
+            ("H", "7"),
+            ("H", "6"),
+            ("D", "K"),
+            ("D", "2"),
+            ("C", "9"),
+            ("C", "8"),
+            ("C", "7"),
+            ("C", "6"),
+        ]);
+        assert_eq!(count_controls(&hand), 4);
+        // Spades 1, hearts 2, diamonds 1, clubs 3.
+        assert_eq!(losing_trick_count(&hand), 7);
+        assert_eq!(count_key_cards(&hand, Suit::Spades), 2);
+        assert_eq!(count_key_cards(&hand, Suit::Hearts), 1);
+        assert_eq!(count_top_honors(&hand, Suit::Spades, 3), 2);
+        assert_eq!(count_top_honors(&hand, Suit::Hearts, 3), 1);
+        assert_eq!(count_top_honors(&hand, Suit::Clubs, 5), 0);
+    }
+
     #[test]
     fn hcp_all_aces() {
         // 4 aces + 9 spot cards = 16 HCP
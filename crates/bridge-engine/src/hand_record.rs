@@ -0,0 +1,394 @@
+//! Hand records — a played board as exchanged with other bridge software.
+//!
+//! `HandRecord` is the format-neutral shape that the PBN and LIN readers
+//! and writers work against: the deal, the auction with its alerts and
+//! explanations, the final contract, the play and the result. The text
+//! helpers here are shared by every file format.
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{create_deck, next_seat, rank_index, SEATS, SUIT_ORDER};
+use crate::play::get_trick_winner;
+use crate::types::{
+    Auction, BidSuit, Card, Contract, Deal, Hand, PlayedCard, Rank, Seat, Suit, Trick,
+    Vulnerability,
+};
+
+/// One board: the deal plus whatever was recorded about how it went.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandRecord {
+    pub deal: Deal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub board: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auction: Option<Auction>,
+    /// Alerts and explanations, keyed by index into `auction.entries`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CallAnnotation>,
+    /// Final contract. `None` when unknown or when the board was passed out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<Contract>,
+    /// Tricks in play order, each led by the previous trick's winner. The
+    /// last trick may be partial.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub play: Vec<Trick>,
+    /// Tricks taken by declarer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<u8>,
+    /// Tags this crate has no field for (Event, Site, player names, ...),
+    /// kept in file order so they survive a round trip.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<(String, String)>,
+}
+
+impl HandRecord {
+    pub fn new(deal: Deal) -> Self {
+        Self {
+            deal,
+            board: None,
+            auction: None,
+            annotations: Vec::new(),
+            contract: None,
+            play: Vec::new(),
+            result: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Value of an extra tag, if present.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Alert and explanation attached to one call of the auction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallAnnotation {
+    pub call_index: usize,
+    pub alert: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+// ── Text helpers ────────────────────────────────────────────────────
+
+/// Rank as written in PBN, LIN and DDS hands: `A`, `K`, ..., `T`, `9`, ..., `2`.
+pub fn rank_char(rank: Rank) -> char {
+    match rank {
+        Rank::Ace => 'A',
+        Rank::King => 'K',
+        Rank::Queen => 'Q',
+        Rank::Jack => 'J',
+        Rank::Ten => 'T',
+        Rank::Nine => '9',
+        Rank::Eight => '8',
+        Rank::Seven => '7',
+        Rank::Six => '6',
+        Rank::Five => '5',
+        Rank::Four => '4',
+        Rank::Three => '3',
+        Rank::Two => '2',
+    }
+}
+
+/// Parse a rank character, in either case.
+pub fn rank_from_char(c: char) -> Option<Rank> {
+    let rank = match c.to_ascii_uppercase() {
+        'A' => Rank::Ace,
+        'K' => Rank::King,
+        'Q' => Rank::Queen,
+        'J' => Rank::Jack,
+        'T' => Rank::Ten,
+        '9' => Rank::Nine,
+        '8' => Rank::Eight,
+        '7' => Rank::Seven,
+        '6' => Rank::Six,
+        '5' => Rank::Five,
+        '4' => Rank::Four,
+        '3' => Rank::Three,
+        '2' => Rank::Two,
+        _ => return None,
+    };
+    Some(rank)
+}
+
+/// Suit letter: `S`, `H`, `D`, `C`.
+pub fn suit_char(suit: Suit) -> char {
+    match suit {
+        Suit::Spades => 'S',
+        Suit::Hearts => 'H',
+        Suit::Diamonds => 'D',
+        Suit::Clubs => 'C',
+    }
+}
+
+/// Parse a suit letter, in either case.
+pub fn suit_from_char(c: char) -> Option<Suit> {
+    let suit = match c.to_ascii_uppercase() {
+        'S' => Suit::Spades,
+        'H' => Suit::Hearts,
+        'D' => Suit::Diamonds,
+        'C' => Suit::Clubs,
+        _ => return None,
+    };
+    Some(suit)
+}
+
+pub(crate) fn seat_char(seat: Seat) -> char {
+    match seat {
+        Seat::North => 'N',
+        Seat::East => 'E',
+        Seat::South => 'S',
+        Seat::West => 'W',
+    }
+}
+
+pub(crate) fn seat_from_char(c: char) -> Option<Seat> {
+    let seat = match c.to_ascii_uppercase() {
+        'N' => Seat::North,
+        'E' => Seat::East,
+        'S' => Seat::South,
+        'W' => Seat::West,
+        _ => return None,
+    };
+    Some(seat)
+}
+
+/// Strain as written after a bid level: `C`, `D`, `H`, `S`, `NT`.
+pub(crate) fn strain_str(strain: BidSuit) -> &'static str {
+    match strain {
+        BidSuit::Clubs => "C",
+        BidSuit::Diamonds => "D",
+        BidSuit::Hearts => "H",
+        BidSuit::Spades => "S",
+        BidSuit::NoTrump => "NT",
+    }
+}
+
+/// Parse a strain, accepting both `NT` and the short `N`.
+pub(crate) fn strain_from_str(s: &str) -> Option<BidSuit> {
+    let strain = match s.to_ascii_uppercase().as_str() {
+        "C" => BidSuit::Clubs,
+        "D" => BidSuit::Diamonds,
+        "H" => BidSuit::Hearts,
+        "S" => BidSuit::Spades,
+        "N" | "NT" => BidSuit::NoTrump,
+        _ => return None,
+    };
+    Some(strain)
+}
+
+/// Parse a level-and-strain bid such as `4S` or `3NT`.
+pub(crate) fn bid_from_str(s: &str) -> Option<(u8, BidSuit)> {
+    let level = s.chars().next()?.to_digit(10)? as u8;
+    if !(1..=7).contains(&level) {
+        return None;
+    }
+    Some((level, strain_from_str(&s[1..])?))
+}
+
+/// Card as suit then rank: `SA`, `H7`.
+pub(crate) fn card_str(card: &Card) -> String {
+    format!("{}{}", suit_char(card.suit), rank_char(card.rank))
+}
+
+pub(crate) fn card_from_str(s: &str) -> Option<Card> {
+    let mut chars = s.chars();
+    let suit = suit_from_char(chars.next()?)?;
+    let rank = rank_from_char(chars.next()?)?;
+    chars.next().is_none().then_some(Card { suit, rank })
+}
+
+/// Build a deal from per-seat holdings. A single missing hand is filled
+/// with the cards nobody else holds; otherwise all 52 cards must be dealt
+/// exactly once.
+pub(crate) fn deal_from_hands(
+    mut hands: HashMap<Seat, Vec<Card>>,
+    dealer: Seat,
+    vulnerability: Vulnerability,
+) -> Result<Deal, String> {
+    let missing = SEATS
+        .iter()
+        .copied()
+        .filter(|seat| !hands.contains_key(seat))
+        .collect::<Vec<_>>();
+    if missing.len() > 1 {
+        return Err(format!("{} hands are missing", missing.len()));
+    }
+
+    let mut seen = HashSet::new();
+    for card in hands.values().flatten() {
+        if !seen.insert((card.suit, card.rank)) {
+            return Err(format!("{} is dealt twice", card_str(card)));
+        }
+    }
+    if let Some(&seat) = missing.first() {
+        // Sorted the way hands are written: suit order, high to low.
+        let mut rest = create_deck()
+            .into_iter()
+            .filter(|card| !seen.contains(&(card.suit, card.rank)))
+            .collect::<Vec<_>>();
+        rest.sort_by_key(|card| {
+            let suit = SUIT_ORDER.iter().position(|&suit| suit == card.suit);
+            (suit, Reverse(rank_index(card.rank)))
+        });
+        hands.insert(seat, rest);
+    }
+
+    for seat in SEATS {
+        if hands[&seat].len() != 13 {
+            return Err(format!(
+                "{} holds {} cards",
+                seat_char(seat),
+                hands[&seat].len()
+            ));
+        }
+    }
+
+    Ok(Deal {
+        hands: hands
+            .into_iter()
+            .map(|(seat, cards)| (seat, Hand { cards }))
+            .collect(),
+        dealer,
+        vulnerability,
+    })
+}
+
+/// A recorded play that could not have happened with the recorded deal.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HandRecordError {
+    #[error("{} plays {}, which is not in their hand", seat_char(*.0), card_str(.1))]
+    CardNotHeld(Seat, Card),
+    #[error("{} plays {} but can follow to the {} lead", seat_char(*.0), card_str(.1), suit_char(*.2))]
+    RevokedSuit(Seat, Card, Suit),
+}
+
+/// Group cards into tricks. `next_card(trick, seat)` yields the card `seat`
+/// played to trick `trick`, or `None` once the play record runs out. Each
+/// trick after the first is led by the previous trick's winner. Every card
+/// must come from its player's remaining hand in `deal` and follow suit
+/// when it can.
+pub(crate) fn tricks_from_play(
+    deal: &Deal,
+    opening_leader: Seat,
+    trump_suit: Option<Suit>,
+    mut next_card: impl FnMut(usize, Seat) -> Option<Card>,
+) -> Result<Vec<Trick>, HandRecordError> {
+    let mut remaining: HashMap<Seat, Vec<Card>> = deal
+        .hands
+        .iter()
+        .map(|(&seat, hand)| (seat, hand.cards.clone()))
+        .collect();
+    let mut tricks = Vec::new();
+    let mut leader = opening_leader;
+    while tricks.len() < 13 {
+        let mut trick = Trick {
+            plays: Vec::with_capacity(4),
+            trump_suit,
+            winner: None,
+        };
+        let mut seat = leader;
+        for _ in 0..4 {
+            let Some(card) = next_card(tricks.len(), seat) else {
+                break;
+            };
+            let hand = remaining.entry(seat).or_default();
+            let Some(position) = hand.iter().position(|held| *held == card) else {
+                return Err(HandRecordError::CardNotHeld(seat, card));
+            };
+            if let Some(led) = trick.plays.first().map(|play| play.card.suit) {
+                if card.suit != led && hand.iter().any(|held| held.suit == led) {
+                    return Err(HandRecordError::RevokedSuit(seat, card, led));
+                }
+            }
+            hand.remove(position);
+            trick.plays.push(PlayedCard { card, seat });
+            seat = next_seat(seat);
+        }
+        if trick.plays.is_empty() {
+            break;
+        }
+        trick.winner = get_trick_winner(&trick).ok();
+        let complete = trick.winner;
+        tricks.push(trick);
+        match complete {
+            Some(winner) => leader = winner,
+            None => break,
+        }
+    }
+    Ok(tricks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_and_bid_text_round_trip() {
+        for card in create_deck() {
+            assert_eq!(card_from_str(&card_str(&card)), Some(card));
+        }
+        assert_eq!(card_from_str("s10"), None);
+        assert_eq!(bid_from_str("3NT"), Some((3, BidSuit::NoTrump)));
+        assert_eq!(bid_from_str("3n"), Some((3, BidSuit::NoTrump)));
+        assert_eq!(bid_from_str("8C"), None);
+    }
+
+    #[test]
+    fn deal_from_hands_fills_a_single_missing_hand() {
+        let deck = create_deck();
+        let hands = HashMap::from([
+            (Seat::North, deck[0..13].to_vec()),
+            (Seat::East, deck[13..26].to_vec()),
+            (Seat::South, deck[26..39].to_vec()),
+        ]);
+        let deal = deal_from_hands(hands.clone(), Seat::North, Vulnerability::None).unwrap();
+        let mut spades = deck[39..52].to_vec();
+        spades.reverse();
+        assert_eq!(deal.hands[&Seat::West].cards, spades);
+
+        let mut short = hands;
+        short.remove(&Seat::South);
+        assert!(deal_from_hands(short, Seat::North, Vulnerability::None).is_err());
+    }
+
+    #[test]
+    fn tricks_from_play_follows_the_winner() {
+        let deal = Deal {
+            hands: HashMap::from([
+                (Seat::West, hand("SA", "HK")),
+                (Seat::North, hand("S2", "H2")),
+                (Seat::East, hand("S3", "HA")),
+                (Seat::South, hand("S4", "H3")),
+            ]),
+            dealer: Seat::North,
+            vulnerability: Vulnerability::None,
+        };
+        let sequence =
+            ["SA", "S2", "S3", "S4", "HK", "H2", "HA", "H3"].map(|s| card_from_str(s).unwrap());
+        let mut cards = sequence.into_iter();
+        let tricks = tricks_from_play(&deal, Seat::West, None, |_, _| cards.next()).unwrap();
+        assert_eq!(tricks.len(), 2);
+        assert_eq!(tricks[0].winner, Some(Seat::West));
+        assert_eq!(tricks[1].plays[0].seat, Seat::West);
+        assert_eq!(tricks[1].winner, Some(Seat::East));
+    }
+
+    fn hand(first: &str, second: &str) -> Hand {
+        Hand {
+            cards: vec![
+                card_from_str(first).unwrap(),
+                card_from_str(second).unwrap(),
+            ],
+        }
+    }
+}
@@ -1,9 +1,17 @@
 pub mod auction;
+pub mod board_id;
+pub mod card_set;
 pub mod constants;
 pub mod deal_generator;
+pub mod double_dummy;
 pub mod error;
 pub mod hand_evaluator;
+pub mod hand_record;
+pub mod lin;
+pub mod par;
+pub mod pbn;
 pub mod play;
+pub mod rubber;
 pub mod scoring;
 pub mod strategy;
 pub mod types;
@@ -13,12 +21,25 @@ pub use auction::{
     add_call, compare_bids, get_contract, get_declarer, get_legal_calls, is_auction_complete,
     is_legal_call,
 };
+pub use board_id::{auction_hash, board_id, parse_board_id};
+pub use card_set::CardSet;
 pub use constants::{bid_suit_to_suit, create_deck, next_seat, partner_seat, SEATS};
 pub use deal_generator::generate_deal;
+pub use double_dummy::{
+    fixed_outcome, solve_position, CardTricks, DoubleDummySolver, PlayPosition,
+};
 pub use error::EngineError;
 pub use hand_evaluator::{
-    calculate_distribution_points, calculate_hcp, evaluate_hand_hcp, get_suit_length, is_balanced,
+    calculate_distribution_points, calculate_hcp, count_controls, count_key_cards,
+    count_top_honors, evaluate_hand_hcp, get_suit_length, is_balanced, losing_trick_count,
 };
+pub use hand_record::{CallAnnotation, HandRecord, HandRecordError};
+pub use lin::{parse_lin, write_lin};
+pub use par::{calculate_par, double_dummy_score, solve_deal, TrickTable};
+pub use pbn::{parse_pbn, write_pbn};
 pub use play::{get_legal_plays, get_trick_winner};
-pub use scoring::calculate_score;
+pub use rubber::{honors_bonus, Chicago, Honors, Rubber};
+pub use scoring::{
+    butler_imps, calculate_score, cross_imps, imps, imps_versus, matchpoint_percentage, matchpoints,
+};
 pub use types::*;
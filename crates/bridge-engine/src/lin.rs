@@ -0,0 +1,488 @@
+//! LIN (BBO hand record) import and export.
+//!
+//! A LIN string is a flat run of `key|value|` pairs: `md` deals the cards,
+//! `sv` sets vulnerability, `mb` makes a call (`!` marks an alert and the
+//! following `an` explains it), `pc` plays a card and `mc` records a claim.
+//! Each `md` (or `qx` board id) starts a new board. Player names from `pn`
+//! land in the `South`/`West`/`North`/`East` tags, as in PBN.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::auction::{add_call, get_contract};
+use crate::constants::{bid_suit_to_suit, next_seat, partner_seat, rank_index, SUIT_ORDER};
+use crate::error::EngineError;
+use crate::hand_record::{
+    bid_from_str, card_from_str, card_str, deal_from_hands, rank_char, rank_from_char, strain_str,
+    suit_char, suit_from_char, tricks_from_play, CallAnnotation, HandRecord,
+};
+use crate::types::{Auction, AuctionEntry, Call, Card, Seat, Trick, Vulnerability};
+
+/// Hands as listed in LIN, keyed by seat; a left-out hand is absent.
+type LinHands = HashMap<Seat, Vec<Card>>;
+
+/// LIN lists hands and player names starting with South.
+const LIN_SEAT_ORDER: [Seat; 4] = [Seat::South, Seat::West, Seat::North, Seat::East];
+
+const SEAT_TAGS: [&str; 4] = ["South", "West", "North", "East"];
+
+/// Parse every board in a LIN string.
+pub fn parse_lin(text: &str) -> Result<Vec<HandRecord>, EngineError> {
+    let parts = text.split('|').collect::<Vec<_>>();
+    let pairs = parts.chunks_exact(2);
+    let trailing = pairs.remainder().first().map_or("", |rest| rest.trim());
+    if !trailing.is_empty() {
+        return Err(parse_error(format!("`{trailing}` has no value")));
+    }
+
+    let mut records = Vec::new();
+    let mut names: Vec<(String, String)> = Vec::new();
+    let mut board = LinBoard::default();
+    for pair in pairs {
+        let (key, value) = (pair[0].trim(), pair[1]);
+        match key.to_ascii_lowercase().as_str() {
+            "pn" => {
+                names = SEAT_TAGS
+                    .iter()
+                    .zip(value.split(','))
+                    .filter(|(_, name)| !name.trim().is_empty())
+                    .map(|(tag, name)| (tag.to_string(), name.trim().to_string()))
+                    .collect();
+            }
+            "qx" => {
+                if board.hands.is_some() {
+                    records.push(std::mem::take(&mut board).finish(&names)?);
+                }
+                board.number = board_number(value);
+            }
+            "md" => {
+                if board.hands.is_some() {
+                    records.push(std::mem::take(&mut board).finish(&names)?);
+                }
+                let (dealer, hands) = parse_md(value)?;
+                board.dealer = dealer;
+                board.hands = Some(hands);
+            }
+            "ah" => board.number = board_number(value).or(board.number),
+            "sv" => board.vulnerability = parse_vulnerability(value)?,
+            "mb" => {
+                let token = value.trim();
+                let core = token.trim_end_matches(['!', '?']);
+                let call = parse_call(core)
+                    .ok_or_else(|| parse_error(format!("unknown call `{token}`")))?;
+                board.calls.push((call, token.ends_with('!')));
+                board.explanations.push(None);
+            }
+            "an" => match board.explanations.last_mut() {
+                Some(explanation) => *explanation = Some(value.to_string()),
+                None => return Err(parse_error("`an` before the first call")),
+            },
+            "pc" => {
+                let card = card_from_str(value.trim())
+                    .ok_or_else(|| parse_error(format!("unknown card `{value}`")))?;
+                board.cards.push(card);
+            }
+            "mc" => {
+                let tricks = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| parse_error(format!("unknown claim `{value}`")))?;
+                board.claim = Some(tricks);
+            }
+            // Layout, chat and commentary keys carry nothing to import.
+            _ => {}
+        }
+    }
+    if board.hands.is_some() {
+        records.push(board.finish(&names)?);
+    }
+    Ok(records)
+}
+
+/// Write hand records as LIN, one board per line.
+pub fn write_lin(records: &[HandRecord]) -> String {
+    records
+        .iter()
+        .map(write_board)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_error(msg: impl Into<String>) -> EngineError {
+    EngineError::LinParse(msg.into())
+}
+
+// ── Reading ─────────────────────────────────────────────────────────
+
+/// One board's worth of LIN pairs, gathered before validation.
+#[derive(Default)]
+struct LinBoard {
+    number: Option<u32>,
+    dealer: Option<Seat>,
+    hands: Option<LinHands>,
+    vulnerability: Option<Vulnerability>,
+    calls: Vec<(Call, bool)>,
+    explanations: Vec<Option<String>>,
+    cards: Vec<Card>,
+    claim: Option<u8>,
+}
+
+impl LinBoard {
+    fn finish(self, names: &[(String, String)]) -> Result<HandRecord, EngineError> {
+        let dealer = self.dealer.unwrap_or(Seat::South);
+        let hands = self.hands.unwrap_or_default();
+        let vulnerability = self.vulnerability.unwrap_or(Vulnerability::None);
+        let deal = deal_from_hands(hands, dealer, vulnerability).map_err(parse_error)?;
+
+        let mut record = HandRecord::new(deal);
+        record.board = self.number;
+        record.tags = names.to_vec();
+
+        if !self.calls.is_empty() {
+            let mut auction = Auction {
+                entries: Vec::new(),
+                is_complete: false,
+            };
+            let mut seat = dealer;
+            for (call, _) in &self.calls {
+                auction = add_call(
+                    &auction,
+                    AuctionEntry {
+                        seat,
+                        call: call.clone(),
+                    },
+                )?;
+                seat = next_seat(seat);
+            }
+            if auction.is_complete {
+                record.contract = get_contract(&auction)?;
+            }
+            record.auction = Some(auction);
+        }
+        record.annotations = self
+            .calls
+            .iter()
+            .zip(self.explanations)
+            .enumerate()
+            .filter(|(_, ((_, alert), explanation))| *alert || explanation.is_some())
+            .map(|(call_index, ((_, alert), explanation))| CallAnnotation {
+                call_index,
+                alert: *alert,
+                explanation,
+            })
+            .collect();
+
+        if let Some(contract) = &record.contract {
+            let trump = bid_suit_to_suit(contract.strain);
+            let mut cards = self.cards.into_iter();
+            record.play =
+                tricks_from_play(&record.deal, next_seat(contract.declarer), trump, |_, _| {
+                    cards.next()
+                })
+                .map_err(|err| parse_error(err.to_string()))?;
+            record.result = self
+                .claim
+                .or_else(|| declarer_tricks(&record.play, contract.declarer));
+        }
+        Ok(record)
+    }
+}
+
+/// Tricks won by declarer's side, once all 13 have been played.
+fn declarer_tricks(tricks: &[Trick], declarer: Seat) -> Option<u8> {
+    if tricks.len() != 13 || tricks.iter().any(|trick| trick.winner.is_none()) {
+        return None;
+    }
+    let won = tricks
+        .iter()
+        .filter(|trick| {
+            trick
+                .winner
+                .is_some_and(|w| w == declarer || w == partner_seat(declarer))
+        })
+        .count();
+    Some(won as u8)
+}
+
+/// Board number from `ah|Board 7|` or `qx|o7|`.
+fn board_number(value: &str) -> Option<u32> {
+    let digits = value
+        .chars()
+        .rev()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    digits.chars().rev().collect::<String>().parse().ok()
+}
+
+/// Parse `md|3S..H..D..C..,S..,S..,S..|`: the dealer digit (1 = South,
+/// clockwise), then hands from South. Trailing hands may be left out.
+fn parse_md(value: &str) -> Result<(Option<Seat>, LinHands), EngineError> {
+    let value = value.trim();
+    let mut chars = value.chars();
+    let dealer = match chars.next().and_then(|c| c.to_digit(10)) {
+        Some(digit @ 1..=4) => Some(LIN_SEAT_ORDER[digit as usize - 1]),
+        _ => None,
+    };
+    let hand_strs = if dealer.is_some() {
+        chars.as_str()
+    } else {
+        value
+    };
+
+    let mut hands = HashMap::new();
+    for (seat, hand_str) in LIN_SEAT_ORDER.iter().zip(hand_strs.split(',')) {
+        let hand_str = hand_str.trim();
+        if hand_str.is_empty() {
+            continue;
+        }
+        let mut cards = Vec::new();
+        let mut suit = None;
+        for c in hand_str.chars() {
+            if let Some(next) = suit_from_char(c) {
+                suit = Some(next);
+                continue;
+            }
+            let (Some(suit), Some(rank)) = (suit, rank_from_char(c)) else {
+                return Err(parse_error(format!("unreadable hand `{hand_str}`")));
+            };
+            cards.push(Card { suit, rank });
+        }
+        hands.insert(*seat, cards);
+    }
+    Ok((dealer, hands))
+}
+
+fn parse_vulnerability(value: &str) -> Result<Option<Vulnerability>, EngineError> {
+    let vulnerability = match value.trim().to_ascii_lowercase().as_str() {
+        "" => return Ok(None),
+        "o" | "0" | "-" => Vulnerability::None,
+        "n" => Vulnerability::NorthSouth,
+        "e" => Vulnerability::EastWest,
+        "b" => Vulnerability::Both,
+        other => return Err(parse_error(format!("unknown vulnerability `{other}`"))),
+    };
+    Ok(Some(vulnerability))
+}
+
+fn parse_call(token: &str) -> Option<Call> {
+    match token.to_ascii_lowercase().as_str() {
+        "p" | "pass" => Some(Call::Pass),
+        "d" | "x" => Some(Call::Double),
+        "r" | "xx" => Some(Call::Redouble),
+        bid => bid_from_str(bid).map(|(level, strain)| Call::Bid { level, strain }),
+    }
+}
+
+// ── Writing ─────────────────────────────────────────────────────────
+
+fn write_board(record: &HandRecord) -> String {
+    let mut out = String::new();
+    let mut pair = |key: &str, value: &str| {
+        out.push_str(key);
+        out.push('|');
+        // `|` would end the value early.
+        out.push_str(&value.replace('|', "/"));
+        out.push('|');
+    };
+
+    let names = SEAT_TAGS
+        .iter()
+        .map(|tag| record.tag(tag).unwrap_or(""))
+        .collect::<Vec<_>>();
+    if names.iter().any(|name| !name.is_empty()) {
+        pair("pn", &names.join(","));
+    }
+    pair("st", "");
+    pair("md", &md_value(record));
+    pair("rh", "");
+    if let Some(board) = record.board {
+        pair("ah", &format!("Board {board}"));
+    }
+    pair("sv", vulnerability_str(record.deal.vulnerability));
+
+    if let Some(auction) = &record.auction {
+        for (index, entry) in auction.entries.iter().enumerate() {
+            let annotation = record.annotations.iter().find(|a| a.call_index == index);
+            let mut call = call_str(&entry.call);
+            if annotation.is_some_and(|a| a.alert) {
+                call.push('!');
+            }
+            pair("mb", &call);
+            if let Some(text) = annotation.and_then(|a| a.explanation.as_deref()) {
+                pair("an", text);
+            }
+        }
+    }
+
+    for trick in &record.play {
+        for play in &trick.plays {
+            pair("pc", &card_str(&play.card));
+        }
+        if trick.winner.is_some() {
+            pair("pg", "");
+        }
+    }
+    let played_out = record.play.len() == 13 && record.play.iter().all(|t| t.winner.is_some());
+    if let (Some(result), false) = (record.result, played_out) {
+        pair("mc", &result.to_string());
+    }
+    out
+}
+
+/// Dealer digit followed by all four hands from South.
+fn md_value(record: &HandRecord) -> String {
+    let dealer = LIN_SEAT_ORDER
+        .iter()
+        .position(|&seat| seat == record.deal.dealer)
+        .map_or(1, |index| index + 1);
+    let hands = LIN_SEAT_ORDER
+        .iter()
+        .map(|seat| {
+            let cards = record
+                .deal
+                .hands
+                .get(seat)
+                .map_or(&[][..], |hand| hand.cards.as_slice());
+            SUIT_ORDER
+                .iter()
+                .map(|&suit| {
+                    let mut ranks = cards
+                        .iter()
+                        .filter(|card| card.suit == suit)
+                        .map(|card| card.rank)
+                        .collect::<Vec<_>>();
+                    ranks.sort_by_key(|&rank| Reverse(rank_index(rank)));
+                    let ranks = ranks.into_iter().map(rank_char).collect::<String>();
+                    format!("{}{ranks}", suit_char(suit))
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    format!("{dealer}{}", hands.join(","))
+}
+
+fn vulnerability_str(vulnerability: Vulnerability) -> &'static str {
+    match vulnerability {
+        Vulnerability::None => "o",
+        Vulnerability::NorthSouth => "n",
+        Vulnerability::EastWest => "e",
+        Vulnerability::Both => "b",
+    }
+}
+
+fn call_str(call: &Call) -> String {
+    match call {
+        Call::Bid { level, strain } => {
+            let strain = match strain_str(*strain) {
+                "NT" => "N",
+                other => other,
+            };
+            format!("{level}{strain}")
+        }
+        Call::Pass => "p".to_string(),
+        Call::Double => "d".to_string(),
+        Call::Redouble => "r".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BidSuit;
+
+    const BOARD: &str = "pn|ann,bob,cat,dan|st||md|3SAK32HQJ2DK43CQ32,SQJT9HK43DA2CKJT9,S876HA765DQJ5CA87,|\
+                         rh||ah|Board 4|sv|b|mb|1C!|an|could be short|mb|p|mb|1S|mb|p|mb|2N|mb|p|\
+                         mb|3N|mb|p|mb|p|mb|p|pc|DT|pc|D3|pc|DA|pc|D5|pg||pc|C9|pc|C7|pc|C4|pc|CQ|pg||mc|9|";
+
+    fn parse_one(text: &str) -> HandRecord {
+        let mut records = parse_lin(text).unwrap();
+        assert_eq!(records.len(), 1);
+        records.remove(0)
+    }
+
+    #[test]
+    fn parses_a_bbo_board() {
+        let record = parse_one(BOARD);
+        assert_eq!(record.board, Some(4));
+        assert_eq!(record.deal.dealer, Seat::North);
+        assert_eq!(record.deal.vulnerability, Vulnerability::Both);
+        // East's hand was left out and is filled from the rest of the deck.
+        assert_eq!(record.deal.hands[&Seat::East].cards.len(), 13);
+        assert_eq!(record.tag("South"), Some("ann"));
+        assert_eq!(record.tag("East"), Some("dan"));
+
+        let auction = record.auction.as_ref().unwrap();
+        assert!(auction.is_complete);
+        assert_eq!(auction.entries[0].seat, Seat::North);
+        assert_eq!(
+            record.annotations,
+            vec![CallAnnotation {
+                call_index: 0,
+                alert: true,
+                explanation: Some("could be short".to_string()),
+            }]
+        );
+
+        let contract = record.contract.as_ref().unwrap();
+        assert_eq!(
+            (contract.level, contract.strain, contract.declarer),
+            (3, BidSuit::NoTrump, Seat::North)
+        );
+        // East leads; West's ace wins and West leads the next trick.
+        assert_eq!(record.play[0].plays[0].seat, Seat::East);
+        assert_eq!(record.play[0].winner, Some(Seat::West));
+        assert_eq!(record.play[1].plays[0].seat, Seat::West);
+        assert_eq!(record.play[1].winner, Some(Seat::South));
+        assert_eq!(record.result, Some(9));
+    }
+
+    #[test]
+    fn write_then_parse_round_trips() {
+        let record = parse_one(BOARD);
+        let written = write_lin(std::slice::from_ref(&record));
+        assert!(written.starts_with("pn|ann,bob,cat,dan|st||md|3SAK32HQJ2DK43CQ32,"));
+        assert!(written.contains("mb|1C!|an|could be short|"));
+        assert!(written.ends_with("mc|9|"));
+        assert_eq!(parse_one(&written), record);
+    }
+
+    #[test]
+    fn each_deal_starts_a_new_board() {
+        let text = "qx|o1|md|1SAKQJHT98D765C432,S765H432DAKQJCT98,S432HAKQJDT98C765,|sv|o|\
+                    qx|o2|md|2SAKQJHT98D765C432,S765H432DAKQJCT98,S432HAKQJDT98C765,|sv|n|";
+        let records = parse_lin(text).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].board, Some(1));
+        assert_eq!(records[0].deal.dealer, Seat::South);
+        assert_eq!(records[1].board, Some(2));
+        assert_eq!(records[1].deal.dealer, Seat::West);
+        assert_eq!(records[1].deal.vulnerability, Vulnerability::NorthSouth);
+        assert!(records[1].auction.is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_boards() {
+        assert!(parse_lin("md|3SAKQ|").is_err());
+        assert!(parse_lin("md|3SAK32HQJ2DK43CQ32,,,|").is_err());
+        assert!(parse_lin(&BOARD.replace("mb|2N|", "mb|1C|")).is_err());
+        assert!(parse_lin(&BOARD.replace("pc|DT|", "pc|Z2|")).is_err());
+        assert!(parse_lin("st||sv").is_err());
+    }
+
+    #[test]
+    fn rejects_a_card_the_player_does_not_hold() {
+        // West, not East, holds the diamond two.
+        let err = parse_lin(&BOARD.replace("pc|DT|", "pc|D2|")).unwrap_err();
+        assert!(err.to_string().contains("not in their hand"), "{err}");
+    }
+
+    #[test]
+    fn rejects_a_failure_to_follow_suit() {
+        // South plays a club while holding the diamond king-four-three.
+        let err = parse_lin(&BOARD.replace("pc|D3|", "pc|C2|")).unwrap_err();
+        assert!(
+            err.to_string().contains("can follow to the D lead"),
+            "{err}"
+        );
+    }
+}
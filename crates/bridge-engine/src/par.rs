@@ -0,0 +1,352 @@
+//! Par score — the double-dummy result when both sides bid perfectly.
+//!
+//! Par is found by backward induction over the 35 contracts in bidding
+//! order: after one side names a contract, the other side either lets it
+//! play (doubled if it fails) or outbids it with any higher contract of
+//! their own. Making contracts are scored undoubled; failing ones doubled,
+//! which is how sacrifices enter.
+
+use std::collections::HashMap;
+
+use crate::constants::SEATS;
+use crate::double_dummy::DoubleDummySolver;
+use crate::error::EngineError;
+use crate::scoring::calculate_score;
+use crate::types::{
+    BidSuit, Contract, DDSolution, Deal, ParContract, ParInfo, Seat, Vulnerability,
+};
+
+/// Strains in bidding order.
+const STRAINS: [BidSuit; 5] = [
+    BidSuit::Clubs,
+    BidSuit::Diamonds,
+    BidSuit::Hearts,
+    BidSuit::Spades,
+    BidSuit::NoTrump,
+];
+
+const CONTRACT_COUNT: usize = 35;
+
+/// Double-dummy trick table: tricks for each declarer in each strain.
+pub type TrickTable = HashMap<Seat, HashMap<BidSuit, u32>>;
+
+/// Solve all 20 declarer/strain combinations of a deal and compute par.
+pub fn solve_deal(deal: &Deal) -> Result<DDSolution, EngineError> {
+    let tricks = DoubleDummySolver::new().solve_table(deal)?;
+    let par = calculate_par(&tricks, deal.vulnerability, deal.dealer);
+    Ok(DDSolution {
+        tricks,
+        par: Some(par),
+    })
+}
+
+/// Par score (North-South perspective) and the contracts that achieve it.
+///
+/// `dealer` breaks ties when both sides could open the same best contract:
+/// the dealer's side gets to bid first.
+pub fn calculate_par(tricks: &TrickTable, vulnerability: Vulnerability, dealer: Seat) -> ParInfo {
+    let table = ParTable::new(tricks, vulnerability);
+    let dealer_side = side_of(dealer);
+    let other_side = 1 - dealer_side;
+
+    // value[k][side]: NS score once `side` has bid contract k and the other
+    // side is on turn. best_above[k][side]: the best of value[j][side] for
+    // j >= k from `side`'s point of view (None past 7NT).
+    let mut value = [[0i32; 2]; CONTRACT_COUNT];
+    let mut best_above = [[None::<i32>; 2]; CONTRACT_COUNT + 1];
+    for k in (0..CONTRACT_COUNT).rev() {
+        let above = best_above[k + 1];
+        value[k] = [0, 1].map(|side| prefer(1 - side, table.score(k, side), above[1 - side]));
+        best_above[k] = [0, 1].map(|side| Some(prefer(side, value[k][side], above[side])));
+    }
+
+    let after_pass = prefer(other_side, 0, best_above[0][other_side]);
+    let score = prefer(dealer_side, after_pass, best_above[0][dealer_side]);
+
+    // Follow the bidding to the final contract, remembering the contract it
+    // outbid: par contracts are the final side's alternatives over that.
+    let opener = if score != after_pass {
+        Some(dealer_side)
+    } else if after_pass != 0 {
+        Some(other_side)
+    } else {
+        None
+    };
+    let mut contracts = Vec::new();
+    if let Some(mut side) = opener {
+        let lowest_with = |from: usize, side: usize, target: i32| {
+            (from..CONTRACT_COUNT)
+                .find(|&k| value[k][side] == target)
+                .expect("best_above is attained")
+        };
+        let mut outbid = None;
+        let mut k = lowest_with(0, side, score);
+        loop {
+            let opponents = 1 - side;
+            let played = table.score(k, side);
+            let best = prefer(opponents, played, best_above[k + 1][opponents]);
+            if best == played {
+                break;
+            }
+            outbid = Some(k);
+            k = lowest_with(k + 1, opponents, best);
+            side = opponents;
+        }
+
+        let first = outbid.map_or(0, |k| k + 1);
+        for strain_index in 0..STRAINS.len() {
+            // Lowest level that yields par and that the opponents leave alone.
+            let found = (0..7)
+                .map(|level| level * STRAINS.len() + strain_index)
+                .filter(|&k| k >= first)
+                .find(|&k| table.score(k, side) == score && value[k][side] == score);
+            if let Some(k) = found {
+                contracts.extend(table.par_contracts(k, side));
+            }
+        }
+    }
+
+    ParInfo { score, contracts }
+}
+
+/// Double-dummy score of an actual contract (North-South perspective), for
+/// comparing the contract a drill reached against par.
+pub fn double_dummy_score(
+    contract: &Contract,
+    tricks: &TrickTable,
+    vulnerability: Vulnerability,
+) -> i32 {
+    let table = ParTable::new(tricks, vulnerability);
+    let taken = table.declarer_tricks(contract.declarer, contract.strain);
+    let score = calculate_score(contract, taken as u8, vulnerability);
+    if side_of(contract.declarer) == 0 {
+        score
+    } else {
+        -score
+    }
+}
+
+/// The result the side on turn chooses between letting `pass` stand and its
+/// best continuation. Ties go to passing.
+fn prefer(side: usize, pass: i32, continuation: Option<i32>) -> i32 {
+    match continuation {
+        Some(bid) if side == 0 && bid > pass => bid,
+        Some(bid) if side == 1 && bid < pass => bid,
+        _ => pass,
+    }
+}
+
+/// 0 for North-South, 1 for East-West.
+fn side_of(seat: Seat) -> usize {
+    match seat {
+        Seat::North | Seat::South => 0,
+        Seat::East | Seat::West => 1,
+    }
+}
+
+fn contract_of(k: usize) -> (u8, BidSuit) {
+    ((k / STRAINS.len()) as u8 + 1, STRAINS[k % STRAINS.len()])
+}
+
+/// Per side and strain: the best declarer's tricks, plus the vulnerability
+/// used for scoring.
+struct ParTable<'a> {
+    tricks: &'a TrickTable,
+    vulnerability: Vulnerability,
+}
+
+impl<'a> ParTable<'a> {
+    fn new(tricks: &'a TrickTable, vulnerability: Vulnerability) -> Self {
+        Self {
+            tricks,
+            vulnerability,
+        }
+    }
+
+    fn declarer_tricks(&self, declarer: Seat, strain: BidSuit) -> u32 {
+        self.tricks
+            .get(&declarer)
+            .and_then(|strains| strains.get(&strain))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The side's declarers with the most tricks in `strain`, and that count.
+    fn best_declarers(&self, side: usize, strain: BidSuit) -> (Vec<Seat>, u32) {
+        let seats = SEATS
+            .iter()
+            .copied()
+            .filter(|&seat| side_of(seat) == side)
+            .collect::<Vec<_>>();
+        let best = seats
+            .iter()
+            .map(|&seat| self.declarer_tricks(seat, strain))
+            .max()
+            .unwrap_or(0);
+        let declarers = seats
+            .into_iter()
+            .filter(|&seat| self.declarer_tricks(seat, strain) == best)
+            .collect();
+        (declarers, best)
+    }
+
+    /// NS score if `side` plays contract `k`: undoubled when it makes,
+    /// doubled when it fails.
+    fn score(&self, k: usize, side: usize) -> i32 {
+        let (level, strain) = contract_of(k);
+        let (declarers, tricks) = self.best_declarers(side, strain);
+        let contract = Contract {
+            level,
+            strain,
+            doubled: tricks < u32::from(level) + 6,
+            redoubled: false,
+            declarer: declarers[0],
+        };
+        let score = calculate_score(&contract, tricks as u8, self.vulnerability);
+        if side == 0 {
+            score
+        } else {
+            -score
+        }
+    }
+
+    fn par_contracts(&self, k: usize, side: usize) -> Vec<ParContract> {
+        let (level, strain) = contract_of(k);
+        let (declarers, tricks) = self.best_declarers(side, strain);
+        let overtricks = tricks as i8 - (level as i8 + 6);
+        declarers
+            .into_iter()
+            .map(|declarer| ParContract {
+                level,
+                strain,
+                declarer,
+                doubled: overtricks < 0,
+                overtricks,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(entries: &[(Seat, [u32; 5])]) -> TrickTable {
+        entries
+            .iter()
+            .map(|(seat, tricks)| {
+                let strains = STRAINS
+                    .iter()
+                    .zip(tricks)
+                    .map(|(strain, &t)| (*strain, t))
+                    .collect();
+                (*seat, strains)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn making_game_is_par_when_opponents_have_no_sacrifice() {
+        // C, D, H, S, NT
+        let tricks = table(&[
+            (Seat::North, [7, 7, 6, 10, 9]),
+            (Seat::South, [7, 7, 6, 10, 9]),
+            (Seat::East, [6, 6, 7, 3, 4]),
+            (Seat::West, [6, 6, 7, 3, 4]),
+        ]);
+        let par = calculate_par(&tricks, Vulnerability::None, Seat::North);
+        assert_eq!(par.score, 420);
+        assert_eq!(par.contracts.len(), 2);
+        for contract in &par.contracts {
+            assert_eq!((contract.level, contract.strain), (4, BidSuit::Spades));
+            assert!(!contract.doubled);
+            assert_eq!(contract.overtricks, 0);
+        }
+    }
+
+    #[test]
+    fn cheap_sacrifice_sets_par() {
+        // NS vulnerable make 4S (620); EW non-vulnerable go two down in 5H.
+        let tricks = table(&[
+            (Seat::North, [6, 6, 4, 10, 7]),
+            (Seat::South, [6, 6, 4, 10, 7]),
+            (Seat::East, [6, 6, 9, 3, 5]),
+            (Seat::West, [6, 6, 8, 3, 5]),
+        ]);
+        let par = calculate_par(&tricks, Vulnerability::NorthSouth, Seat::North);
+        assert_eq!(par.score, 300);
+        assert_eq!(
+            par.contracts,
+            vec![ParContract {
+                level: 5,
+                strain: BidSuit::Hearts,
+                declarer: Seat::East,
+                doubled: true,
+                overtricks: -2,
+            }]
+        );
+    }
+
+    #[test]
+    fn passed_out_when_nobody_can_make_anything() {
+        let tricks = table(&[
+            (Seat::North, [6; 5]),
+            (Seat::South, [6; 5]),
+            (Seat::East, [6; 5]),
+            (Seat::West, [6; 5]),
+        ]);
+        let par = calculate_par(&tricks, Vulnerability::Both, Seat::East);
+        assert_eq!(par.score, 0);
+        assert!(par.contracts.is_empty());
+    }
+
+    #[test]
+    fn dealer_side_wins_a_symmetric_partscore() {
+        // Both sides make exactly 1NT and nothing else.
+        let tricks = table(&[
+            (Seat::North, [6, 6, 6, 6, 7]),
+            (Seat::South, [6, 6, 6, 6, 7]),
+            (Seat::East, [6, 6, 6, 6, 7]),
+            (Seat::West, [6, 6, 6, 6, 7]),
+        ]);
+        let par = calculate_par(&tricks, Vulnerability::None, Seat::West);
+        assert_eq!(par.score, -90);
+        assert!(par
+            .contracts
+            .iter()
+            .all(|c| matches!(c.declarer, Seat::East | Seat::West)));
+    }
+
+    #[test]
+    fn double_dummy_score_is_from_north_south_view() {
+        let tricks = table(&[
+            (Seat::North, [7, 7, 6, 10, 9]),
+            (Seat::South, [7, 7, 6, 10, 9]),
+            (Seat::East, [6, 6, 7, 3, 4]),
+            (Seat::West, [6, 6, 7, 3, 4]),
+        ]);
+        let contract = |level, strain, declarer| Contract {
+            level,
+            strain,
+            doubled: false,
+            redoubled: false,
+            declarer,
+        };
+        assert_eq!(
+            double_dummy_score(
+                &contract(3, BidSuit::NoTrump, Seat::South),
+                &tricks,
+                Vulnerability::None
+            ),
+            400
+        );
+        assert_eq!(
+            double_dummy_score(
+                &contract(1, BidSuit::Hearts, Seat::East),
+                &tricks,
+                Vulnerability::None
+            ),
+            -80
+        );
+    }
+}
@@ -0,0 +1,735 @@
+//! PBN (Portable Bridge Notation) import and export.
+//!
+//! Reads and writes whole PBN games — Dealer, Vulnerable, Deal, Auction
+//! (with alerts and notes), Contract, Declarer, Play and Result — as
+//! `HandRecord`s. Tags this crate has no field for are carried through
+//! unchanged. `Deal::to_pbn` remains the way to get just the `[Deal]` value.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+use crate::auction::{add_call, get_contract};
+use crate::constants::{bid_suit_to_suit, next_seat, seat_index, SEATS, SUIT_ORDER};
+use crate::error::EngineError;
+use crate::hand_record::{
+    bid_from_str, card_from_str, card_str, deal_from_hands, rank_from_char, seat_char,
+    seat_from_char, strain_str, tricks_from_play, CallAnnotation, HandRecord,
+};
+use crate::types::{Auction, AuctionEntry, Call, Card, Contract, Seat, Trick, Vulnerability};
+
+/// Tags with a dedicated `HandRecord` field; everything else lands in
+/// `HandRecord::tags`.
+const KNOWN_TAGS: [&str; 10] = [
+    "Board",
+    "Dealer",
+    "Vulnerable",
+    "Deal",
+    "Auction",
+    "Note",
+    "Contract",
+    "Declarer",
+    "Play",
+    "Result",
+];
+
+/// Seven-tag roster entries written before the deal, in export order.
+const ROSTER_TAGS: [&str; 7] = ["Event", "Site", "Date", "West", "North", "East", "South"];
+
+/// Parse every game in a PBN file.
+pub fn parse_pbn(text: &str) -> Result<Vec<HandRecord>, EngineError> {
+    let mut records = Vec::new();
+    let mut game = Vec::new();
+    for token in tokenize(text)? {
+        match token {
+            Token::Tag(name, value) => game.push(RawTag {
+                name,
+                value,
+                section: Vec::new(),
+            }),
+            Token::Word(word) => match game.last_mut() {
+                Some(tag) => tag.section.push(word),
+                None => return Err(parse_error(format!("`{word}` before any tag"))),
+            },
+            Token::GameEnd => {
+                if !game.is_empty() {
+                    records.push(record_from_tags(std::mem::take(&mut game))?);
+                }
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Write hand records as a PBN file, one game per record.
+pub fn write_pbn(records: &[HandRecord]) -> String {
+    records
+        .iter()
+        .map(write_game)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_error(msg: impl Into<String>) -> EngineError {
+    EngineError::PbnParse(msg.into())
+}
+
+// ── Lexing ──────────────────────────────────────────────────────────
+
+enum Token {
+    Tag(String, String),
+    Word(String),
+    /// A blank line: the end of a game.
+    GameEnd,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, EngineError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line_start = true;
+    let mut line_blank = true;
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            if line_blank {
+                tokens.push(Token::GameEnd);
+            }
+            line_start = true;
+            line_blank = true;
+            continue;
+        }
+        let was_line_start = line_start;
+        line_start = false;
+        if c.is_whitespace() {
+            continue;
+        }
+        line_blank = false;
+
+        match c {
+            // Escape lines and rest-of-line comments.
+            '%' if was_line_start => skip_to_line_end(&mut chars),
+            ';' => skip_to_line_end(&mut chars),
+            '{' => {
+                if !chars.by_ref().any(|c| c == '}') {
+                    return Err(parse_error("unterminated `{` comment"));
+                }
+            }
+            '[' => tokens.push(read_tag(&mut chars)?),
+            _ => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '[' | '{' | ';') {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    tokens.push(Token::GameEnd);
+    Ok(tokens)
+}
+
+fn skip_to_line_end(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while chars.next_if(|&c| c != '\n').is_some() {}
+}
+
+/// Read `Name "value"]` after an opening bracket.
+fn read_tag(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Result<Token, EngineError> {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    let mut name = String::new();
+    while let Some(c) = chars.next_if(|&c| !c.is_whitespace() && c != '"') {
+        name.push(c);
+    }
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    if chars.next() != Some('"') {
+        return Err(parse_error(format!("tag `{name}` has no quoted value")));
+    }
+
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('\\') => match chars.next() {
+                Some(escaped) => value.push(escaped),
+                None => break,
+            },
+            Some('"') => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                if chars.next() != Some(']') {
+                    return Err(parse_error(format!("tag `{name}` is not closed")));
+                }
+                return Ok(Token::Tag(name, value));
+            }
+            Some(c) => value.push(c),
+            None => break,
+        }
+    }
+    Err(parse_error(format!(
+        "tag `{name}` has an unterminated value"
+    )))
+}
+
+// ── Games ───────────────────────────────────────────────────────────
+
+/// A tag and the section tokens that follow it.
+struct RawTag {
+    name: String,
+    value: String,
+    section: Vec<String>,
+}
+
+fn record_from_tags(tags: Vec<RawTag>) -> Result<HandRecord, EngineError> {
+    let find = |name: &str| tags.iter().find(|tag| tag.name == name);
+    // Unknown values are written as "?" or left empty.
+    let known = |name: &str| {
+        find(name)
+            .map(|tag| tag.value.trim())
+            .filter(|value| !value.is_empty() && *value != "?")
+    };
+
+    let deal_tag = known("Deal").ok_or_else(|| parse_error("game has no Deal tag"))?;
+    let (first_seat, hands) = parse_deal(deal_tag)?;
+    let dealer = match known("Dealer") {
+        Some(value) => parse_seat(value)?,
+        None => first_seat,
+    };
+    let vulnerability = known("Vulnerable")
+        .map(parse_vulnerability)
+        .transpose()?
+        .unwrap_or(Vulnerability::None);
+    let deal = deal_from_hands(hands, dealer, vulnerability).map_err(parse_error)?;
+
+    let mut record = HandRecord::new(deal);
+    record.board = known("Board").and_then(|value| value.parse().ok());
+    record.result = known("Result").and_then(|value| value.parse().ok());
+
+    // Notes belong to the section tag they follow.
+    let mut auction_notes = HashMap::new();
+    let mut section = "";
+    for tag in &tags {
+        match tag.name.as_str() {
+            "Auction" | "Play" => section = tag.name.as_str(),
+            "Note" if section == "Auction" => {
+                if let Some((number, text)) = tag.value.split_once(':') {
+                    auction_notes.insert(number.trim().to_string(), text.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(tag) = find("Auction") {
+        let first = parse_seat(&tag.value)?;
+        let (auction, annotations) = parse_auction(first, &tag.section, &auction_notes)?;
+        record.auction = Some(auction);
+        record.annotations = annotations;
+    }
+
+    record.contract = match (known("Contract"), known("Declarer")) {
+        (Some(contract), _) if contract.eq_ignore_ascii_case("pass") => None,
+        (Some(contract), Some(declarer)) => Some(parse_contract(contract, parse_seat(declarer)?)?),
+        _ => match &record.auction {
+            Some(auction) if auction.is_complete => get_contract(auction).unwrap_or(None),
+            _ => None,
+        },
+    };
+
+    if let Some(tag) = find("Play") {
+        let leader = parse_seat(&tag.value)?;
+        let trump = record
+            .contract
+            .as_ref()
+            .and_then(|contract| bid_suit_to_suit(contract.strain));
+        let rows = parse_play_rows(&tag.section)?;
+        record.play = tricks_from_play(&record.deal, leader, trump, |trick, seat| {
+            let column = (seat_index(seat) + 4 - seat_index(leader)) % 4;
+            rows.get(trick)?[column].clone()
+        })
+        .map_err(|err| parse_error(err.to_string()))?;
+    }
+
+    record.tags = tags
+        .into_iter()
+        .filter(|tag| !KNOWN_TAGS.contains(&tag.name.as_str()))
+        .filter(|tag| !matches!(tag.value.trim(), "" | "?"))
+        .map(|tag| (tag.name, tag.value))
+        .collect();
+
+    Ok(record)
+}
+
+fn parse_seat(value: &str) -> Result<Seat, EngineError> {
+    let mut chars = value.trim().chars();
+    match (chars.next().and_then(seat_from_char), chars.next()) {
+        (Some(seat), None) => Ok(seat),
+        _ => Err(parse_error(format!("unknown seat `{value}`"))),
+    }
+}
+
+fn parse_vulnerability(value: &str) -> Result<Vulnerability, EngineError> {
+    match value {
+        "None" | "Love" | "-" => Ok(Vulnerability::None),
+        "NS" => Ok(Vulnerability::NorthSouth),
+        "EW" => Ok(Vulnerability::EastWest),
+        "All" | "Both" => Ok(Vulnerability::Both),
+        other => Err(parse_error(format!("unknown vulnerability `{other}`"))),
+    }
+}
+
+/// Parse a `[Deal]` value: the first hand's seat, then four hands clockwise.
+/// A hand written as `-` is unknown.
+fn parse_deal(value: &str) -> Result<(Seat, HashMap<Seat, Vec<Card>>), EngineError> {
+    let (first, rest) = value
+        .split_once(':')
+        .ok_or_else(|| parse_error(format!("deal `{value}` has no seat prefix")))?;
+    let first = parse_seat(first)?;
+
+    let hand_strs = rest.split_whitespace().collect::<Vec<_>>();
+    if hand_strs.len() != 4 {
+        return Err(parse_error(format!(
+            "deal `{value}` does not have four hands"
+        )));
+    }
+
+    let mut hands = HashMap::new();
+    let mut seat = first;
+    for hand_str in hand_strs {
+        if hand_str != "-" {
+            let suit_strs = hand_str.split('.').collect::<Vec<_>>();
+            if suit_strs.len() != 4 {
+                return Err(parse_error(format!(
+                    "hand `{hand_str}` does not have four suits"
+                )));
+            }
+            let mut cards = Vec::new();
+            for (suit, ranks) in SUIT_ORDER.iter().zip(suit_strs) {
+                for c in ranks.chars() {
+                    let rank = rank_from_char(c).ok_or_else(|| {
+                        parse_error(format!("unknown rank `{c}` in `{hand_str}`"))
+                    })?;
+                    cards.push(Card { suit: *suit, rank });
+                }
+            }
+            hands.insert(seat, cards);
+        }
+        seat = next_seat(seat);
+    }
+    Ok((first, hands))
+}
+
+fn parse_call(token: &str) -> Option<Call> {
+    match token.to_ascii_uppercase().as_str() {
+        "PASS" | "P" => Some(Call::Pass),
+        "X" => Some(Call::Double),
+        "XX" => Some(Call::Redouble),
+        bid => bid_from_str(bid).map(|(level, strain)| Call::Bid { level, strain }),
+    }
+}
+
+/// Parse the Auction section: calls from `first` on, `AP` for the closing
+/// passes, `!` suffixes for alerts and `=n=` references to Note tags.
+fn parse_auction(
+    first: Seat,
+    section: &[String],
+    notes: &HashMap<String, String>,
+) -> Result<(Auction, Vec<CallAnnotation>), EngineError> {
+    let mut auction = Auction {
+        entries: Vec::new(),
+        is_complete: false,
+    };
+    let mut seat = first;
+    let mut annotations: BTreeMap<usize, CallAnnotation> = BTreeMap::new();
+
+    for token in section {
+        let last_call = auction.entries.len().checked_sub(1);
+        let mut annotate = |update: &dyn Fn(&mut CallAnnotation)| -> Result<(), EngineError> {
+            let index =
+                last_call.ok_or_else(|| parse_error(format!("`{token}` before the first call")))?;
+            update(annotations.entry(index).or_insert(CallAnnotation {
+                call_index: index,
+                alert: false,
+                explanation: None,
+            }));
+            Ok(())
+        };
+
+        if token == "*" {
+            break;
+        }
+        if token == "-" || token == "+" || token.starts_with('$') {
+            continue;
+        }
+        if let Some(number) = token.strip_prefix('=').and_then(|t| t.strip_suffix('=')) {
+            let text = notes.get(number).cloned();
+            annotate(&|a| a.explanation.clone_from(&text))?;
+            continue;
+        }
+        if token.eq_ignore_ascii_case("AP") {
+            while !auction.is_complete {
+                auction = add_call(
+                    &auction,
+                    AuctionEntry {
+                        seat,
+                        call: Call::Pass,
+                    },
+                )?;
+                seat = next_seat(seat);
+            }
+            continue;
+        }
+
+        // Suffix annotations: `!` marks an alert; `?` and friends are
+        // commentary and dropped.
+        let core = token.trim_end_matches(['!', '?']);
+        let alert = token[core.len()..].contains('!');
+        if core.is_empty() {
+            if alert {
+                annotate(&|a| a.alert = true)?;
+            }
+            continue;
+        }
+        let call =
+            parse_call(core).ok_or_else(|| parse_error(format!("unknown call `{token}`")))?;
+        auction = add_call(&auction, AuctionEntry { seat, call })?;
+        seat = next_seat(seat);
+        if alert {
+            let index = auction.entries.len() - 1;
+            annotations.insert(
+                index,
+                CallAnnotation {
+                    call_index: index,
+                    alert: true,
+                    explanation: None,
+                },
+            );
+        }
+    }
+
+    Ok((auction, annotations.into_values().collect()))
+}
+
+/// Parse a `[Contract]` value such as `4SX` or `3NT`.
+fn parse_contract(value: &str, declarer: Seat) -> Result<Contract, EngineError> {
+    let upper = value.to_ascii_uppercase();
+    let bid = upper.trim_end_matches('X');
+    let doubles = upper.len() - bid.len();
+    let (level, strain) = bid_from_str(bid)
+        .filter(|_| doubles <= 2)
+        .ok_or_else(|| parse_error(format!("unknown contract `{value}`")))?;
+    Ok(Contract {
+        level,
+        strain,
+        doubled: doubles == 1,
+        redoubled: doubles == 2,
+        declarer,
+    })
+}
+
+/// Split the Play section into rows of four columns, one row per trick.
+/// `-` marks a card that was never played.
+fn parse_play_rows(section: &[String]) -> Result<Vec<[Option<Card>; 4]>, EngineError> {
+    let mut cards = Vec::new();
+    for token in section {
+        match token.as_str() {
+            "*" => break,
+            "-" => cards.push(None),
+            t if t.starts_with('=') || t.starts_with('$') => {}
+            t => {
+                let core = t.trim_end_matches(['!', '?']);
+                let card = card_from_str(core)
+                    .ok_or_else(|| parse_error(format!("unknown card `{t}`")))?;
+                cards.push(Some(card));
+            }
+        }
+    }
+    Ok(cards
+        .chunks(4)
+        .map(|row| std::array::from_fn(|i| row.get(i).cloned().flatten()))
+        .collect())
+}
+
+// ── Writing ─────────────────────────────────────────────────────────
+
+fn write_game(record: &HandRecord) -> String {
+    let mut out = String::new();
+    let passed_out = record.contract.is_none()
+        && record
+            .auction
+            .as_ref()
+            .is_some_and(|auction| auction.is_complete);
+    let extra = |name: &str| record.tag(name).unwrap_or("?").to_string();
+
+    for name in &ROSTER_TAGS[..3] {
+        write_tag(&mut out, name, &extra(name));
+    }
+    let board = record.board.map_or("?".to_string(), |b| b.to_string());
+    write_tag(&mut out, "Board", &board);
+    for name in &ROSTER_TAGS[3..] {
+        write_tag(&mut out, name, &extra(name));
+    }
+    write_tag(
+        &mut out,
+        "Dealer",
+        &seat_char(record.deal.dealer).to_string(),
+    );
+    write_tag(
+        &mut out,
+        "Vulnerable",
+        vulnerability_str(record.deal.vulnerability),
+    );
+    write_tag(&mut out, "Deal", &record.deal.to_pbn());
+    write_tag(&mut out, "Scoring", &extra("Scoring"));
+
+    let (declarer, contract, result) = match &record.contract {
+        Some(contract) => (
+            seat_char(contract.declarer).to_string(),
+            contract_str(contract),
+            record.result.map_or("?".to_string(), |r| r.to_string()),
+        ),
+        None if passed_out => (String::new(), "Pass".to_string(), String::new()),
+        None => ("?".to_string(), "?".to_string(), "?".to_string()),
+    };
+    write_tag(&mut out, "Declarer", &declarer);
+    write_tag(&mut out, "Contract", &contract);
+    write_tag(&mut out, "Result", &result);
+
+    for (name, value) in &record.tags {
+        if !ROSTER_TAGS.contains(&name.as_str()) && name != "Scoring" {
+            write_tag(&mut out, name, value);
+        }
+    }
+
+    if let Some(auction) = &record.auction {
+        write_auction(&mut out, auction, &record.annotations, record.deal.dealer);
+    }
+    if let Some(first) = record.play.first().and_then(|trick| trick.plays.first()) {
+        write_play(&mut out, &record.play, first.seat);
+    }
+    out
+}
+
+fn write_tag(out: &mut String, name: &str, value: &str) {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    let _ = writeln!(out, "[{name} \"{escaped}\"]");
+}
+
+fn vulnerability_str(vulnerability: Vulnerability) -> &'static str {
+    match vulnerability {
+        Vulnerability::None => "None",
+        Vulnerability::NorthSouth => "NS",
+        Vulnerability::EastWest => "EW",
+        Vulnerability::Both => "All",
+    }
+}
+
+fn contract_str(contract: &Contract) -> String {
+    let doubles = if contract.redoubled {
+        "XX"
+    } else if contract.doubled {
+        "X"
+    } else {
+        ""
+    };
+    format!("{}{}{doubles}", contract.level, strain_str(contract.strain))
+}
+
+fn call_str(call: &Call) -> String {
+    match call {
+        Call::Bid { level, strain } => format!("{level}{}", strain_str(*strain)),
+        Call::Pass => "Pass".to_string(),
+        Call::Double => "X".to_string(),
+        Call::Redouble => "XX".to_string(),
+    }
+}
+
+fn write_auction(
+    out: &mut String,
+    auction: &Auction,
+    annotations: &[CallAnnotation],
+    dealer: Seat,
+) {
+    let first = auction.entries.first().map_or(dealer, |entry| entry.seat);
+    write_tag(out, "Auction", &seat_char(first).to_string());
+
+    let mut notes = Vec::new();
+    let tokens = auction
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let mut token = call_str(&entry.call);
+            if let Some(annotation) = annotations.iter().find(|a| a.call_index == index) {
+                if annotation.alert {
+                    token.push('!');
+                }
+                if let Some(text) = &annotation.explanation {
+                    notes.push(text.clone());
+                    let _ = write!(token, " ={}=", notes.len());
+                }
+            }
+            token
+        })
+        .collect::<Vec<_>>();
+    for row in tokens.chunks(4) {
+        let _ = writeln!(out, "{}", row.join(" "));
+    }
+    for (number, text) in notes.iter().enumerate() {
+        write_tag(out, "Note", &format!("{}:{text}", number + 1));
+    }
+}
+
+/// Write the Play section with one row per trick and columns in seat order
+/// starting from the opening leader.
+fn write_play(out: &mut String, tricks: &[Trick], leader: Seat) {
+    write_tag(out, "Play", &seat_char(leader).to_string());
+    for trick in tricks {
+        let row = (0..4)
+            .map(|offset| {
+                let seat = SEATS[(seat_index(leader) + offset) % 4];
+                trick
+                    .plays
+                    .iter()
+                    .find(|play| play.seat == seat)
+                    .map_or("-".to_string(), |play| card_str(&play.card))
+            })
+            .collect::<Vec<_>>();
+        let _ = writeln!(out, "{}", row.join(" "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BidSuit, Suit};
+
+    const GAME: &str = r#"% PBN 2.1
+[Event "Club Pairs"]
+[Site "?"]
+[Board "3"]
+[North "Ann \"Ace\" Lee"]
+[Dealer "S"]
+[Vulnerable "EW"]
+[Deal "S:AK32.QJ2.K43.Q32 QJT9.K43.A2.KJT9 876.A765.QJ5.A87 54.T98.T9876.654"]
+[Declarer "S"]
+[Contract "4S"]
+[Result "10"]
+[Auction "S"]
+1C! =1= Pass 1D Pass
+1S Pass 4S AP
+[Note "1:could be short"]
+{ commentary with a blank line
+
+inside }
+[Play "W"]
+D2 D5 DT DK
+S9 S6 S4 S2 ; trumps
+"#;
+
+    fn parse_one(text: &str) -> HandRecord {
+        let mut records = parse_pbn(text).unwrap();
+        assert_eq!(records.len(), 1);
+        records.remove(0)
+    }
+
+    #[test]
+    fn parses_a_full_game() {
+        let record = parse_one(GAME);
+        assert_eq!(record.board, Some(3));
+        assert_eq!(record.deal.dealer, Seat::South);
+        assert_eq!(record.deal.vulnerability, Vulnerability::EastWest);
+        assert_eq!(record.deal.hands[&Seat::West].cards.len(), 13);
+        assert_eq!(record.tag("North"), Some("Ann \"Ace\" Lee"));
+
+        let auction = record.auction.as_ref().unwrap();
+        assert!(auction.is_complete);
+        assert_eq!(auction.entries.len(), 10);
+        assert_eq!(
+            record.annotations,
+            vec![CallAnnotation {
+                call_index: 0,
+                alert: true,
+                explanation: Some("could be short".to_string()),
+            }]
+        );
+
+        let contract = record.contract.as_ref().unwrap();
+        assert_eq!(
+            (contract.level, contract.strain, contract.declarer),
+            (4, BidSuit::Spades, Seat::South)
+        );
+        assert_eq!(record.result, Some(10));
+
+        assert_eq!(record.play.len(), 2);
+        assert_eq!(record.play[0].winner, Some(Seat::South));
+        // South won the first trick and leads the second from the last column.
+        assert_eq!(record.play[1].plays[0].seat, Seat::South);
+        assert_eq!(record.play[1].plays[0].card.suit, Suit::Spades);
+        assert_eq!(record.play[1].winner, Some(Seat::West));
+    }
+
+    #[test]
+    fn write_then_parse_round_trips() {
+        let record = parse_one(GAME);
+        let written = write_pbn(std::slice::from_ref(&record));
+        assert!(written
+            .starts_with("[Event \"Club Pairs\"]\n[Site \"?\"]\n[Date \"?\"]\n[Board \"3\"]"));
+        assert!(written.contains("[Vulnerable \"EW\"]"));
+        assert!(written.contains("1C! =1= Pass 1D Pass"));
+        assert_eq!(parse_one(&written), record);
+    }
+
+    #[test]
+    fn blank_lines_separate_games() {
+        let text = "[Board \"1\"]\n[Deal \"N:AKQJ.T98.765.432 - 765.432.AKQJ.T98 432.AKQJ.T98.765\"]\n\n\
+                    [Board \"2\"]\n[Dealer \"E\"]\n[Vulnerable \"All\"]\n[Deal \"N:AKQJ.T98.765.432 - 765.432.AKQJ.T98 432.AKQJ.T98.765\"]\n\
+                    [Auction \"E\"]\nPass Pass Pass Pass\n[Contract \"Pass\"]\n";
+        let records = parse_pbn(text).unwrap();
+        assert_eq!(records.len(), 2);
+        // Dealer defaults to the first hand; the missing hand is filled in.
+        assert_eq!(records[0].deal.dealer, Seat::North);
+        assert_eq!(records[0].deal.hands[&Seat::East].cards.len(), 13);
+        assert_eq!(records[1].deal.vulnerability, Vulnerability::Both);
+        assert!(records[1].auction.as_ref().unwrap().is_complete);
+        assert!(records[1].contract.is_none());
+        assert!(write_pbn(&records).contains("[Contract \"Pass\"]"));
+    }
+
+    #[test]
+    fn contract_falls_back_to_the_auction() {
+        let text = GAME
+            .replace("[Contract \"4S\"]\n", "")
+            .replace("[Declarer \"S\"]\n", "");
+        let record = parse_one(&text);
+        assert_eq!(record.contract.unwrap().declarer, Seat::South);
+    }
+
+    #[test]
+    fn rejects_malformed_games() {
+        assert!(parse_pbn("[Board \"1\"]\n").is_err());
+        assert!(parse_pbn("[Deal \"N:AKQ\"]\n").is_err());
+        assert!(parse_pbn("[Deal \"N:- - - -\"]\n").is_err());
+        let illegal = GAME.replace("1S Pass 4S", "1S Pass 1C");
+        assert!(parse_pbn(&illegal).is_err());
+        assert!(parse_pbn("[Deal \"unterminated]\n").is_err());
+    }
+
+    #[test]
+    fn rejects_a_card_the_player_does_not_hold() {
+        // West holds the diamond ace-two, not the three.
+        let text = GAME.replace("D2 D5 DT DK", "D3 D5 DT DK");
+        let err = parse_pbn(&text).unwrap_err();
+        assert!(err.to_string().contains("not in their hand"), "{err}");
+    }
+
+    #[test]
+    fn rejects_a_failure_to_follow_suit() {
+        // North discards a club while holding the diamond queen-jack-five.
+        let text = GAME.replace("D2 D5 DT DK", "D2 C7 DT DK");
+        let err = parse_pbn(&text).unwrap_err();
+        assert!(
+            err.to_string().contains("can follow to the D lead"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! Rubber and Chicago scoring — the home-game forms, where vulnerability and
+//! game bonuses depend on what happened on earlier deals.
+//!
+//! Both keep the score line by partnership. Contract points go below the
+//! line and build towards game; overtricks, slam and insult bonuses,
+//! penalties and honors go above it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::SEATS;
+use crate::scoring::{calculate_penalty, calculate_trick_points, is_vulnerable, trick_value};
+use crate::types::{BidSuit, Contract, Deal, Partnership, Rank, Seat, Suit, Vulnerability};
+
+/// Deals in one Chicago.
+pub const CHICAGO_DEALS: u8 = 4;
+
+/// Honors bonus held by one partnership.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Honors {
+    pub partnership: Partnership,
+    pub points: i32,
+}
+
+const HONOR_RANKS: [Rank; 5] = [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten];
+
+/// Honors for the contract: 150 for all five trump honors (or all four aces
+/// at no trump) in one hand, 100 for four of the five trump honors. Either
+/// side can hold them.
+pub fn honors_bonus(deal: &Deal, contract: &Contract) -> Option<Honors> {
+    let held = |seat: Seat, ranks: &[Rank], suit: Option<Suit>| {
+        deal.hands[&seat]
+            .cards
+            .iter()
+            .filter(|c| ranks.contains(&c.rank) && suit.is_none_or(|s| c.suit == s))
+            .count()
+    };
+    let trump = crate::constants::bid_suit_to_suit(contract.strain);
+    SEATS.iter().find_map(|&seat| {
+        let points = match contract.strain {
+            BidSuit::NoTrump => (held(seat, &[Rank::Ace], None) == 4).then_some(150),
+            _ => match held(seat, &HONOR_RANKS, trump) {
+                5 => Some(150),
+                4 => Some(100),
+                _ => None,
+            },
+        }?;
+        Some(Honors {
+            partnership: Partnership::of(seat),
+            points,
+        })
+    })
+}
+
+fn side(partnership: Partnership) -> usize {
+    match partnership {
+        Partnership::NorthSouth => 0,
+        Partnership::EastWest => 1,
+    }
+}
+
+/// Above-the-line points for a made contract: overtricks, slam bonus and
+/// the insult for making doubled or redoubled.
+fn premiums(contract: &Contract, overtricks: i32, vulnerable: bool) -> i32 {
+    let overtrick_points = if contract.redoubled {
+        overtricks * if vulnerable { 400 } else { 200 }
+    } else if contract.doubled {
+        overtricks * if vulnerable { 200 } else { 100 }
+    } else {
+        overtricks * trick_value(contract.strain)
+    };
+    let slam = match (contract.level, vulnerable) {
+        (6, false) => 500,
+        (6, true) => 750,
+        (7, false) => 1000,
+        (7, true) => 1500,
+        _ => 0,
+    };
+    let insult = if contract.redoubled {
+        100
+    } else if contract.doubled {
+        50
+    } else {
+        0
+    };
+    overtrick_points + slam + insult
+}
+
+/// What one deal contributes to the score line.
+enum DealOutcome {
+    PassedOut,
+    Made {
+        side: usize,
+        below: i32,
+        above: i32,
+        vulnerable: bool,
+    },
+    Defeated {
+        side: usize,
+        penalty: i32,
+    },
+}
+
+fn outcome(
+    contract: Option<&Contract>,
+    tricks_won: u8,
+    vulnerable: impl Fn(Seat) -> bool,
+) -> DealOutcome {
+    let Some(contract) = contract else {
+        return DealOutcome::PassedOut;
+    };
+    let declarer_side = side(Partnership::of(contract.declarer));
+    let vulnerable = vulnerable(contract.declarer);
+    let required = contract.level as i32 + 6;
+    let tricks = tricks_won as i32;
+    if tricks >= required {
+        DealOutcome::Made {
+            side: declarer_side,
+            below: calculate_trick_points(contract),
+            above: premiums(contract, tricks - required, vulnerable),
+            vulnerable,
+        }
+    } else {
+        DealOutcome::Defeated {
+            side: 1 - declarer_side,
+            penalty: calculate_penalty(contract, required - tricks, vulnerable),
+        }
+    }
+}
+
+/// A rubber: best of three games, a side that has won one game is
+/// vulnerable.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rubber {
+    above: [i32; 2],
+    /// Below-the-line points in the game in progress.
+    below: [i32; 2],
+    /// Below-the-line points from finished games.
+    below_banked: [i32; 2],
+    games: [u8; 2],
+}
+
+impl Rubber {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn games_won(&self, partnership: Partnership) -> u8 {
+        self.games[side(partnership)]
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.games.contains(&2)
+    }
+
+    /// Vulnerability for the next deal.
+    pub fn vulnerability(&self) -> Vulnerability {
+        match self.games {
+            [0, 0] => Vulnerability::None,
+            [_, 0] => Vulnerability::NorthSouth,
+            [0, _] => Vulnerability::EastWest,
+            _ => Vulnerability::Both,
+        }
+    }
+
+    /// Score one deal. `contract` is `None` for a passed-out deal.
+    pub fn record(&mut self, contract: Option<&Contract>, tricks_won: u8, honors: Option<Honors>) {
+        debug_assert!(!self.is_complete(), "rubber already finished");
+        let vulnerability = self.vulnerability();
+        match outcome(contract, tricks_won, |seat| {
+            is_vulnerable(seat, vulnerability)
+        }) {
+            DealOutcome::PassedOut => {}
+            DealOutcome::Made {
+                side, below, above, ..
+            } => {
+                self.below[side] += below;
+                self.above[side] += above;
+                if self.below[side] >= 100 {
+                    self.games[side] += 1;
+                    for (banked, below) in self.below_banked.iter_mut().zip(self.below) {
+                        *banked += below;
+                    }
+                    self.below = [0, 0];
+                }
+            }
+            DealOutcome::Defeated { side, penalty } => self.above[side] += penalty,
+        }
+        if let Some(honors) = honors {
+            self.above[side(honors.partnership)] += honors.points;
+        }
+    }
+
+    /// Total for a partnership, including the rubber bonus — 700 for winning
+    /// two games to none, 500 for two to one. An unfinished rubber scores
+    /// 300 for the only side with a game and 100 for a part score in the
+    /// game in progress.
+    pub fn total(&self, partnership: Partnership) -> i32 {
+        let s = side(partnership);
+        let bonus = if self.games[s] == 2 {
+            if self.games[1 - s] == 0 {
+                700
+            } else {
+                500
+            }
+        } else if self.is_complete() {
+            0
+        } else {
+            let game_up = if self.games[s] == 1 && self.games[1 - s] == 0 {
+                300
+            } else {
+                0
+            };
+            let part_score = if self.below[s] > 0 { 100 } else { 0 };
+            game_up + part_score
+        };
+        self.above[s] + self.below[s] + self.below_banked[s] + bonus
+    }
+}
+
+/// Chicago: four deals with fixed vulnerability, each scored on its own like
+/// duplicate except that part scores carry over towards game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chicago {
+    deals_played: u8,
+    /// Part scores carried towards game.
+    part_scores: [i32; 2],
+    totals: [i32; 2],
+}
+
+impl Chicago {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn deals_played(&self) -> u8 {
+        self.deals_played
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.deals_played >= CHICAGO_DEALS
+    }
+
+    /// Dealer of the next deal; North deals first.
+    pub fn dealer(&self) -> Seat {
+        SEATS[(self.deals_played % CHICAGO_DEALS) as usize]
+    }
+
+    /// Vulnerability of the next deal: none on the first, the dealer's side
+    /// on the second and third, both on the fourth.
+    pub fn vulnerability(&self) -> Vulnerability {
+        match self.deals_played % CHICAGO_DEALS {
+            0 => Vulnerability::None,
+            3 => Vulnerability::Both,
+            _ => match Partnership::of(self.dealer()) {
+                Partnership::NorthSouth => Vulnerability::NorthSouth,
+                Partnership::EastWest => Vulnerability::EastWest,
+            },
+        }
+    }
+
+    /// Score one deal. A contract that brings the side's part scores to 100
+    /// earns the game bonus (300, or 500 vulnerable) and wipes both sides'
+    /// part scores; a part score made on the last deal earns 100.
+    pub fn record(&mut self, contract: Option<&Contract>, tricks_won: u8, honors: Option<Honors>) {
+        debug_assert!(!self.is_complete(), "chicago already finished");
+        let vulnerability = self.vulnerability();
+        let last_deal = self.deals_played + 1 == CHICAGO_DEALS;
+        match outcome(contract, tricks_won, |seat| {
+            is_vulnerable(seat, vulnerability)
+        }) {
+            DealOutcome::PassedOut => {}
+            DealOutcome::Made {
+                side,
+                below,
+                above,
+                vulnerable,
+            } => {
+                self.part_scores[side] += below;
+                let bonus = if self.part_scores[side] >= 100 {
+                    self.part_scores = [0, 0];
+                    if vulnerable {
+                        500
+                    } else {
+                        300
+                    }
+                } else if last_deal {
+                    100
+                } else {
+                    0
+                };
+                self.totals[side] += below + above + bonus;
+            }
+            DealOutcome::Defeated { side, penalty } => self.totals[side] += penalty,
+        }
+        if let Some(honors) = honors {
+            self.totals[side(honors.partnership)] += honors.points;
+        }
+        self.deals_played += 1;
+    }
+
+    pub fn total(&self, partnership: Partnership) -> i32 {
+        self.totals[side(partnership)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pbn::parse_pbn;
+
+    fn contract(level: u8, strain: BidSuit, declarer: Seat) -> Contract {
+        Contract {
+            level,
+            strain,
+            doubled: false,
+            redoubled: false,
+            declarer,
+        }
+    }
+
+    #[test]
+    fn honors_in_trumps_and_aces_at_no_trump() {
+        let records = parse_pbn(
+            "[Deal \"N:AKQJ.AKQT.A2.A32 T987.J987.KQJ.KQ 65432.65432.T9.J ..876543.T987654\"]\n",
+        )
+        .unwrap();
+        let deal = &records[0].deal;
+        let hearts = honors_bonus(deal, &contract(4, BidSuit::Hearts, Seat::East));
+        assert_eq!(
+            hearts,
+            Some(Honors {
+                partnership: Partnership::NorthSouth,
+                points: 100
+            })
+        );
+        let no_trump = honors_bonus(deal, &contract(3, BidSuit::NoTrump, Seat::South));
+        assert_eq!(no_trump.map(|h| h.points), Some(150));
+        assert_eq!(
+            honors_bonus(deal, &contract(5, BidSuit::Clubs, Seat::West)),
+            None
+        );
+    }
+
+    #[test]
+    fn rubber_won_two_games_to_none() {
+        let mut rubber = Rubber::new();
+        rubber.record(Some(&contract(4, BidSuit::Hearts, Seat::South)), 10, None);
+        assert_eq!(rubber.games_won(Partnership::NorthSouth), 1);
+        assert_eq!(rubber.vulnerability(), Vulnerability::NorthSouth);
+
+        rubber.record(Some(&contract(2, BidSuit::Spades, Seat::East)), 9, None);
+        rubber.record(Some(&contract(3, BidSuit::NoTrump, Seat::North)), 9, None);
+        assert!(rubber.is_complete());
+        assert_eq!(rubber.total(Partnership::NorthSouth), 120 + 100 + 700);
+        // The part score cut off by NS's game still counts.
+        assert_eq!(rubber.total(Partnership::EastWest), 60 + 30);
+    }
+
+    #[test]
+    fn rubber_two_to_one_and_penalties_when_vulnerable() {
+        let mut rubber = Rubber::new();
+        rubber.record(Some(&contract(4, BidSuit::Spades, Seat::North)), 10, None);
+        rubber.record(Some(&contract(5, BidSuit::Diamonds, Seat::West)), 11, None);
+        assert_eq!(rubber.vulnerability(), Vulnerability::Both);
+        // Vulnerable now: one down costs 100.
+        rubber.record(Some(&contract(4, BidSuit::Hearts, Seat::East)), 9, None);
+        rubber.record(Some(&contract(3, BidSuit::NoTrump, Seat::South)), 9, None);
+        assert_eq!(rubber.total(Partnership::NorthSouth), 120 + 100 + 100 + 500);
+        assert_eq!(rubber.total(Partnership::EastWest), 100);
+    }
+
+    #[test]
+    fn unfinished_rubber_scores_game_and_part_score() {
+        let mut rubber = Rubber::new();
+        rubber.record(Some(&contract(4, BidSuit::Hearts, Seat::South)), 10, None);
+        rubber.record(Some(&contract(2, BidSuit::Hearts, Seat::North)), 8, None);
+        rubber.record(None, 0, None);
+        assert_eq!(rubber.total(Partnership::NorthSouth), 120 + 60 + 300 + 100);
+        assert_eq!(rubber.total(Partnership::EastWest), 0);
+    }
+
+    #[test]
+    fn chicago_vulnerability_rotates_with_the_dealer() {
+        let mut chicago = Chicago::new();
+        let mut schedule = Vec::new();
+        while !chicago.is_complete() {
+            schedule.push((chicago.dealer(), chicago.vulnerability()));
+            chicago.record(None, 0, None);
+        }
+        assert_eq!(
+            schedule,
+            vec![
+                (Seat::North, Vulnerability::None),
+                (Seat::East, Vulnerability::EastWest),
+                (Seat::South, Vulnerability::NorthSouth),
+                (Seat::West, Vulnerability::Both),
+            ]
+        );
+    }
+
+    #[test]
+    fn chicago_part_scores_carry_towards_game() {
+        let mut chicago = Chicago::new();
+        chicago.record(Some(&contract(2, BidSuit::Hearts, Seat::North)), 8, None);
+        assert_eq!(chicago.total(Partnership::NorthSouth), 60);
+        // 60 + 60 reaches game, non-vulnerable on deal two.
+        chicago.record(Some(&contract(2, BidSuit::Spades, Seat::South)), 9, None);
+        assert_eq!(chicago.total(Partnership::NorthSouth), 60 + 60 + 30 + 300);
+
+        let honors = Some(Honors {
+            partnership: Partnership::EastWest,
+            points: 150,
+        });
+        chicago.record(Some(&contract(1, BidSuit::NoTrump, Seat::East)), 7, honors);
+        // A part score on the last deal earns 100.
+        chicago.record(Some(&contract(1, BidSuit::NoTrump, Seat::West)), 7, None);
+        assert_eq!(chicago.total(Partnership::EastWest), 40 + 150 + 40 + 100);
+        assert!(chicago.is_complete());
+    }
+}
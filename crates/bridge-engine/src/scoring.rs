@@ -1,3 +1,10 @@
+//! Duplicate scoring of a single contract, and the comparisons built on it:
+//! IMPs, matchpoints against a field, and Butler / cross-IMP scoring. Rubber
+//! and Chicago, which carry state across deals, live in `rubber`.
+//!
+//! Every comparison takes scores from one side's perspective (conventionally
+//! North-South) and returns the result for that side.
+
 use crate::types::{BidSuit, Contract, Seat, Vulnerability};
 
 pub fn is_vulnerable(declarer: Seat, vulnerability: Vulnerability) -> bool {
@@ -10,7 +17,7 @@ pub fn is_vulnerable(declarer: Seat, vulnerability: Vulnerability) -> bool {
 }
 
 /// Trick points for the contract (before bonuses).
-fn calculate_trick_points(contract: &Contract) -> i32 {
+pub(crate) fn calculate_trick_points(contract: &Contract) -> i32 {
     let base = match contract.strain {
         BidSuit::Clubs | BidSuit::Diamonds => 20 * contract.level as i32,
         BidSuit::Hearts | BidSuit::Spades => 30 * contract.level as i32,
@@ -26,7 +33,7 @@ fn calculate_trick_points(contract: &Contract) -> i32 {
     }
 }
 
-fn trick_value(strain: BidSuit) -> i32 {
+pub(crate) fn trick_value(strain: BidSuit) -> i32 {
     match strain {
         BidSuit::Clubs | BidSuit::Diamonds => 20,
         BidSuit::Hearts | BidSuit::Spades => 30,
@@ -92,7 +99,7 @@ fn calculate_doubled_penalty(undertricks: i32, vulnerable: bool) -> i32 {
     total
 }
 
-fn calculate_penalty(contract: &Contract, undertricks: i32, vulnerable: bool) -> i32 {
+pub(crate) fn calculate_penalty(contract: &Contract, undertricks: i32, vulnerable: bool) -> i32 {
     if contract.redoubled {
         calculate_doubled_penalty(undertricks, vulnerable) * 2
     } else if contract.doubled {
@@ -115,6 +122,82 @@ pub fn calculate_score(contract: &Contract, tricks_won: u8, vulnerability: Vulne
     }
 }
 
+// ── Comparisons ─────────────────────────────────────────────────────
+
+/// Upper bounds of the score-difference bands on the WBF IMP scale: a
+/// difference below `IMP_BANDS[i]` is worth `i` IMPs, 4000 or more is 24.
+const IMP_BANDS: [i32; 24] = [
+    20, 50, 90, 130, 170, 220, 270, 320, 370, 430, 500, 600, 750, 900, 1100, 1300, 1500, 1750,
+    2000, 2250, 2500, 3000, 3500, 4000,
+];
+
+/// IMPs for a score difference, signed like the difference.
+pub fn imps(difference: i32) -> i32 {
+    let won = IMP_BANDS.partition_point(|&band| band <= difference.abs()) as i32;
+    won * difference.signum()
+}
+
+/// IMPs gained by `score` over `reference` — par, a datum, or the score at
+/// the other table.
+pub fn imps_versus(score: i32, reference: i32) -> i32 {
+    imps(score - reference)
+}
+
+/// Matchpoints for `score` against the other results on the board: one for
+/// every score beaten, a half for every tie.
+pub fn matchpoints(score: i32, field: &[i32]) -> f64 {
+    field
+        .iter()
+        .map(|&other| match score.cmp(&other) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        })
+        .sum()
+}
+
+/// Matchpoints as a percentage of the top. A board with no other results
+/// is an average, 50%.
+pub fn matchpoint_percentage(score: i32, field: &[i32]) -> f64 {
+    if field.is_empty() {
+        return 50.0;
+    }
+    100.0 * matchpoints(score, field) / field.len() as f64
+}
+
+/// Butler datum: the field average with the highest and lowest scores
+/// dropped (once the field has five or more results), rounded to the
+/// nearest 10.
+pub fn butler_datum(field: &[i32]) -> i32 {
+    if field.is_empty() {
+        return 0;
+    }
+    let mut sorted = field.to_vec();
+    sorted.sort_unstable();
+    let kept = if sorted.len() >= 5 {
+        &sorted[1..sorted.len() - 1]
+    } else {
+        &sorted[..]
+    };
+    let mean = kept.iter().sum::<i32>() as f64 / kept.len() as f64;
+    ((mean / 10.0).round() * 10.0) as i32
+}
+
+/// Butler IMPs: `score` against the datum of the field.
+pub fn butler_imps(score: i32, field: &[i32]) -> i32 {
+    imps_versus(score, butler_datum(field))
+}
+
+/// Cross-IMPs: the average of the IMPs `score` wins against each other
+/// result on the board.
+pub fn cross_imps(score: i32, field: &[i32]) -> f64 {
+    if field.is_empty() {
+        return 0.0;
+    }
+    let total: i32 = field.iter().map(|&other| imps_versus(score, other)).sum();
+    total as f64 / field.len() as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,4 +391,52 @@ mod tests {
         // 120 trick + 300 game + 50 insult = 470
         assert_eq!(score, 470);
     }
+
+    // --- Comparisons ---
+
+    #[test]
+    fn imp_scale_boundaries() {
+        assert_eq!(imps(0), 0);
+        assert_eq!(imps(10), 0);
+        assert_eq!(imps(20), 1);
+        assert_eq!(imps(-50), -2);
+        // 3NT making vs 3NT down one, non-vulnerable: 450 apart.
+        assert_eq!(imps(450), 10);
+        assert_eq!(imps(-620 - 100), -12);
+        assert_eq!(imps(3990), 23);
+        assert_eq!(imps(4000), 24);
+        assert_eq!(imps(7000), 24);
+    }
+
+    #[test]
+    fn imps_versus_par() {
+        // Stopped in 4H (420) when 6H (980) was par: lose 11.
+        assert_eq!(imps_versus(420, 980), -11);
+        assert_eq!(imps_versus(-100, -620), 11);
+    }
+
+    #[test]
+    fn matchpoints_count_ties_as_half() {
+        let field = [420, 450, 420, -50, 170];
+        assert_eq!(matchpoints(420, &field), 3.0);
+        assert_eq!(matchpoints(460, &field), 5.0);
+        assert_eq!(matchpoint_percentage(420, &field), 60.0);
+        assert_eq!(matchpoint_percentage(420, &[]), 50.0);
+    }
+
+    #[test]
+    fn butler_drops_the_extremes() {
+        let field = [420, 450, 420, -50, 170, 1430];
+        // (170 + 420 + 420 + 450) / 4 = 365 -> 370
+        assert_eq!(butler_datum(&field), 370);
+        assert_eq!(butler_imps(420, &field), 2);
+        assert_eq!(butler_datum(&[100, 200]), 150);
+    }
+
+    #[test]
+    fn cross_imps_average_each_comparison() {
+        // vs 420: 0; vs -50: +10; vs 170: +6
+        assert_eq!(cross_imps(420, &[420, -50, 170]), 16.0 / 3.0);
+        assert_eq!(cross_imps(420, &[]), 0.0);
+    }
 }
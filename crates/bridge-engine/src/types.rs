@@ -45,8 +45,11 @@ pub enum Rank {
     Ace,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord,
+)]
 pub enum Seat {
+    #[default]
     #[serde(rename = "N")]
     North,
     #[serde(rename = "E")]
@@ -147,6 +150,7 @@ impl Deal {
     /// Format: "N:AK.QJT98.765.432 QJ.K654.AK43.765 ..."
     pub fn to_pbn(&self) -> String {
         use crate::constants::{rank_index, seat_index, SEATS, SUIT_ORDER};
+        use crate::hand_record::rank_char;
 
         let dealer_idx = seat_index(self.dealer);
         let prefix = match self.dealer {
@@ -156,24 +160,6 @@ impl Deal {
             Seat::West => "W:",
         };
 
-        let rank_char = |r: Rank| -> char {
-            match r {
-                Rank::Ace => 'A',
-                Rank::King => 'K',
-                Rank::Queen => 'Q',
-                Rank::Jack => 'J',
-                Rank::Ten => 'T',
-                Rank::Nine => '9',
-                Rank::Eight => '8',
-                Rank::Seven => '7',
-                Rank::Six => '6',
-                Rank::Five => '5',
-                Rank::Four => '4',
-                Rank::Three => '3',
-                Rank::Two => '2',
-            }
-        };
-
         let hand_strs: Vec<String> = (0..4)
             .map(|i| {
                 let seat = SEATS[(dealer_idx + i) % 4];
@@ -236,7 +222,7 @@ pub struct Trick {
 
 // --- Constraint types (for deal generation over HTTP) ---
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SeatConstraint {
     pub seat: Seat,
@@ -252,12 +238,126 @@ pub struct SeatConstraint {
     pub max_length: Option<HashMap<Suit, u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_length_any: Option<HashMap<Suit, u8>>,
+    /// Controls, counting an ace as 2 and a king as 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_controls: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_controls: Option<u32>,
+    /// Losing-trick count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_losers: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_losers: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_cards: Option<KeyCardRange>,
+    /// Every listed suit must hold enough of its top honors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suit_quality: Option<Vec<SuitQuality>>,
+    /// OR constraint: the hand matches at least ONE pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shapes: Option<Vec<ShapePattern>>,
+    /// Longest the shortest suit may be: 1 asks for a singleton or void,
+    /// 0 for a void.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_shortest: Option<u8>,
+}
+
+/// Roman keycards held for a trump suit: the four aces and the trump king.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyCardRange {
+    pub trump: Suit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u8>,
+    /// Whether the trump queen must be held (`true`) or missing (`false`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queen: Option<bool>,
 }
 
+/// At least `min_honors` of the top `of_top` cards in `suit` — "two of the
+/// top three" is `{ minHonors: 2, ofTop: 3 }`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct SuitQuality {
+    pub suit: Suit,
+    pub min_honors: u8,
+    pub of_top: u8,
+}
+
+/// A hand pattern, lengths listed as [Spades, Hearts, Diamonds, Clubs].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ShapePattern {
+    /// These exact suit lengths (5-4-3-1 with five spades, ...).
+    Exact { lengths: SuitLength },
+    /// The same lengths in any suit order (any 4-4-4-1).
+    AnyOrder { lengths: SuitLength },
+}
+
+/// A side of the table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Partnership {
+    #[default]
+    #[serde(rename = "NS")]
+    NorthSouth,
+    #[serde(rename = "EW")]
+    EastWest,
+}
+
+impl Partnership {
+    pub fn of(seat: Seat) -> Self {
+        match seat {
+            Seat::North | Seat::South => Partnership::NorthSouth,
+            Seat::East | Seat::West => Partnership::EastWest,
+        }
+    }
+
+    pub fn seats(self) -> [Seat; 2] {
+        match self {
+            Partnership::NorthSouth => [Seat::North, Seat::South],
+            Partnership::EastWest => [Seat::East, Seat::West],
+        }
+    }
+}
+
+/// Requirements on two partners' hands taken together.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartnershipConstraint {
+    pub partnership: Partnership,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_hcp: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_hcp: Option<u32>,
+    /// Combined length per suit: an 8+ card heart fit is `{ "H": 8 }`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_fit: Option<HashMap<Suit, u8>>,
+    /// "No fit" caps every suit at 7.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fit: Option<HashMap<Suit, u8>>,
+    /// OR constraint: at least ONE listed suit meets its combined minimum.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_fit_any: Option<HashMap<Suit, u8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_aces: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_aces: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_kings: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_kings: Option<u8>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DealConstraints {
     pub seats: Vec<SeatConstraint>,
+    /// Conditions across both hands of a partnership, checked alongside the
+    /// per-seat ones.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub partnerships: Vec<PartnershipConstraint>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vulnerability: Option<Vulnerability>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -450,6 +550,7 @@ mod tests {
             dealer: None,
             max_attempts: Some(5000),
             seed: None,
+            ..Default::default()
         };
         let json = serde_json::to_string(&dc).unwrap();
         assert!(json.contains("maxAttempts"));
@@ -531,6 +632,51 @@ mod tests {
         assert_eq!(sc.balanced, Some(true));
     }
 
+    #[test]
+    fn seat_constraint_honor_and_pattern_fields_roundtrip() {
+        let json = r#"{"seat":"N","minControls":5,"maxLosers":7,"keyCards":{"trump":"H","min":3,"queen":true},"suitQuality":[{"suit":"S","minHonors":2,"ofTop":3}],"shapes":[{"type":"anyOrder","lengths":[4,4,4,1]}],"maxShortest":1}"#;
+        let sc: SeatConstraint = serde_json::from_str(json).unwrap();
+        assert_eq!(sc.min_controls, Some(5));
+        assert_eq!(sc.max_losers, Some(7));
+        assert_eq!(
+            sc.key_cards,
+            Some(KeyCardRange {
+                trump: Suit::Hearts,
+                min: Some(3),
+                max: None,
+                queen: Some(true),
+            })
+        );
+        assert_eq!(
+            sc.shapes,
+            Some(vec![ShapePattern::AnyOrder {
+                lengths: [4, 4, 4, 1]
+            }])
+        );
+        assert_eq!(sc.max_shortest, Some(1));
+        let back: SeatConstraint =
+            serde_json::from_str(&serde_json::to_string(&sc).unwrap()).unwrap();
+        assert_eq!(back, sc);
+    }
+
+    #[test]
+    fn partnership_constraints_are_optional_in_json() {
+        let dc: DealConstraints = serde_json::from_str(r#"{"seats":[]}"#).unwrap();
+        assert!(dc.partnerships.is_empty());
+        assert!(!serde_json::to_string(&dc).unwrap().contains("partnerships"));
+
+        let json =
+            r#"{"seats":[],"partnerships":[{"partnership":"NS","minHcp":25,"minFit":{"H":8}}]}"#;
+        let dc: DealConstraints = serde_json::from_str(json).unwrap();
+        assert_eq!(dc.partnerships[0].partnership, Partnership::NorthSouth);
+        assert_eq!(dc.partnerships[0].min_hcp, Some(25));
+        assert_eq!(
+            dc.partnerships[0].min_fit.as_ref().unwrap()[&Suit::Hearts],
+            8
+        );
+        assert_eq!(Partnership::of(Seat::West), Partnership::EastWest);
+    }
+
     #[test]
     fn par_contract_camel_case() {
         let pc = ParContract {
@@ -98,15 +98,18 @@ pub(crate) fn resolve_config(config: &SessionConfig) -> ResolvedConfig {
         };
         DrillTuning {
             vulnerability_distribution: dist,
-            module_weights: None,
+            module_weights: config.module_weights.clone(),
         }
     } else {
         match config.vulnerability_distribution.clone() {
             Some(dist) => DrillTuning {
                 vulnerability_distribution: dist,
-                module_weights: None,
+                module_weights: config.module_weights.clone(),
+            },
+            None => DrillTuning {
+                module_weights: config.module_weights.clone(),
+                ..DrillTuning::default()
             },
-            None => DrillTuning::default(),
         }
     };
 
@@ -117,6 +120,7 @@ pub(crate) fn resolve_config(config: &SessionConfig) -> ResolvedConfig {
         opponent_mode,
         tuning,
         seed: config.seed,
+        preset_deal: config.deal.clone(),
         ..Default::default()
     };
 
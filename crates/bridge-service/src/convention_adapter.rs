@@ -10,16 +10,18 @@ use bridge_conventions::adapter::protocol_adapter::ConventionStrategy;
 use bridge_conventions::adapter::strategy_evaluation::StrategyEvaluation;
 use bridge_conventions::fact_dsl::evaluator::evaluate_facts;
 use bridge_conventions::fact_dsl::types::{FactData, FactValue};
+use bridge_conventions::pipeline::observation::attachment::{
+    advance_module_phase, provided_capabilities,
+};
 use bridge_conventions::pipeline::observation::committed_step::initial_negotiation;
 use bridge_conventions::pipeline::observation::committed_step::{
     AuctionContext, ClaimRef, CommittedStep, CommittedStepStatus,
 };
-use bridge_conventions::pipeline::observation::local_fsm::advance_local_fsm;
 use bridge_conventions::pipeline::observation::negotiation_extractor::{
     apply_negotiation_actions, compute_kernel_delta,
 };
-use bridge_conventions::pipeline::observation::normalize_intent::normalize_intent;
 use bridge_conventions::pipeline::observation::public_commitments::derive_public_commitments;
+use bridge_conventions::pipeline::observation::relay::observed_actions;
 use bridge_conventions::pipeline::observation::rule_interpreter::{
     collect_matching_claims_with_phases, flatten_surfaces,
 };
@@ -148,6 +150,7 @@ impl ConventionStrategyAdapter {
         // and call-inferred steps.
         let mut log: Vec<CommittedStep> = Vec::new();
         let mut local_phases: HashMap<String, String> = HashMap::new();
+        let capabilities = provided_capabilities(&self.modules);
         for module in &self.modules {
             local_phases.insert(module.module_id.clone(), module.local.initial.clone());
         }
@@ -269,13 +272,13 @@ impl ConventionStrategyAdapter {
                 Self::build_step_from_carrier(entry.seat, &entry.call, None, &log)
             };
 
-            // Advance local FSMs
+            // Advance local FSMs; dormant add-ons wait for their host attachment.
             for module in &self.modules {
                 let current = local_phases
                     .get(&module.module_id)
                     .cloned()
                     .unwrap_or_else(|| module.local.initial.clone());
-                let next = advance_local_fsm(&current, &step, &log, &module.local.transitions);
+                let next = advance_module_phase(module, &current, &step, &log, &capabilities);
                 local_phases.insert(module.module_id.clone(), next);
             }
 
@@ -379,7 +382,10 @@ impl ConventionStrategyAdapter {
         });
 
         let public_actions = carrier
-            .map(|c| normalize_intent(&c.proposal().source_intent))
+            .map(|c| {
+                let proposal = c.proposal();
+                observed_actions(&proposal.encoding, &proposal.source_intent, call)
+            })
             .unwrap_or_default();
 
         let status = if carrier.is_some() {
@@ -426,7 +432,7 @@ impl ConventionStrategyAdapter {
                     semantic_class_id: surface.semantic_class_id.clone(),
                     source_intent: surface.source_intent.clone(),
                 };
-                let actions = normalize_intent(&surface.source_intent);
+                let actions = observed_actions(&surface.encoding, &surface.source_intent, call);
                 (Some(claim), actions, CommittedStepStatus::Resolved)
             }
             None => {
@@ -446,7 +452,8 @@ impl ConventionStrategyAdapter {
                             semantic_class_id: surface.semantic_class_id.clone(),
                             source_intent: surface.source_intent.clone(),
                         };
-                        let actions = normalize_intent(&surface.source_intent);
+                        let actions =
+                            observed_actions(&surface.encoding, &surface.source_intent, call);
                         (Some(claim), actions, CommittedStepStatus::Resolved)
                     }
                     None => (None, Vec::new(), CommittedStepStatus::OffSystem),
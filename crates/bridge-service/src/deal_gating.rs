@@ -11,18 +11,29 @@
 //!
 //! Mismatch anywhere → reject; try next deal. Replaces the v1 "matched module
 //! ∈ target_module_ids" predicate.
+//!
+//! `partnership_constraints_for_witness` complements the predicate: target
+//! surfaces that test a fact declaring a `PartnershipMeasure` (combined HCP,
+//! a suit fit, Blackwood's combined aces and kings) become
+//! `PartnershipConstraint`s, so the generator deals hands where the joint
+//! requirement holds instead of the predicate rejecting them one by one.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use bridge_conventions::adapter::strategy_evaluation::StrategyEvaluation;
 use bridge_conventions::fact_dsl::witness::{Witness, WitnessCallSpec, WitnessRole};
 use bridge_conventions::teaching::teaching_types::SurfaceGroup;
+use bridge_conventions::types::fact_types::PartnershipMeasure;
+use bridge_conventions::types::meaning::{BidMeaningClause, ConstraintValue, FactOperator};
+use bridge_conventions::types::module_types::ConventionModule;
 use bridge_conventions::types::spec_types::ConventionSpec;
 use bridge_engine::constants::{next_seat, partner_seat};
 use bridge_engine::hand_evaluator::evaluate_hand_hcp;
-use bridge_engine::types::{Auction, AuctionEntry, Call, Deal, Seat};
 use bridge_engine::strategy::BidResult;
+use bridge_engine::types::{
+    Auction, AuctionEntry, Call, Deal, Partnership, PartnershipConstraint, Seat,
+};
 use bridge_session::heuristics::{
     BiddingContext, BiddingStrategy, NaturalFallbackStrategy, PassStrategy, PragmaticStrategy,
     StrategyChain,
@@ -203,6 +214,137 @@ fn build_predicate_seat_strategies(
     m
 }
 
+type Bounds = (Option<u8>, Option<u8>);
+
+/// Inclusive bounds a numeric clause places on its fact.
+fn clause_bounds(clause: &BidMeaningClause) -> Bounds {
+    let num = |n: &serde_json::Number| n.as_u64().map(|v| v.min(u8::MAX as u64) as u8);
+    match (&clause.operator, &clause.value) {
+        (FactOperator::Eq, ConstraintValue::Number(n)) => (num(n), num(n)),
+        (FactOperator::Gte, ConstraintValue::Number(n)) => (num(n), None),
+        (FactOperator::Lte, ConstraintValue::Number(n)) => (None, num(n)),
+        (FactOperator::Range, ConstraintValue::Range { min, max }) => (num(min), num(max)),
+        _ => (None, None),
+    }
+}
+
+/// Bounds satisfying both `a` and `b` (clauses of one surface all hold).
+fn tightest(a: Bounds, b: Bounds) -> Bounds {
+    let max = match (a.1, b.1) {
+        (Some(x), Some(y)) => Some(x.min(y)),
+        (x, y) => x.or(y),
+    };
+    (a.0.max(b.0), max)
+}
+
+/// Loosest bounds that every surface in `per_surface` satisfies. A bound
+/// survives only if each surface sets it, so a deal one surface accepts is
+/// never excluded.
+fn loosest(per_surface: &[Bounds]) -> Bounds {
+    let min = per_surface
+        .iter()
+        .map(|b| b.0)
+        .reduce(|a, b| a.zip(b).map(|(a, b)| a.min(b)))
+        .flatten();
+    let max = per_surface
+        .iter()
+        .map(|b| b.1)
+        .reduce(|a, b| a.zip(b).map(|(a, b)| a.max(b)))
+        .flatten();
+    (min, max)
+}
+
+/// Partnership constraints implied by the witness's target surface.
+///
+/// Clauses count when their fact declares a `PartnershipMeasure`. The target
+/// `meaning_id` may be authored in several states with different clauses
+/// (e.g. `blackwood:signoff-no-grand`); each measure's constraint is the
+/// loosest one all of them agree on. Returns an empty list when the target
+/// tests no partnership-wide fact.
+pub(crate) fn partnership_constraints_for_witness(
+    witness: &Witness,
+    loaded_modules: &[&ConventionModule],
+) -> Vec<PartnershipConstraint> {
+    let surfaces = loaded_modules
+        .iter()
+        .filter(|m| m.module_id == witness.target_module_id)
+        .filter_map(|m| m.states.as_ref())
+        .flatten()
+        .flat_map(|se| &se.surfaces)
+        .filter(|s| s.meaning_id == witness.target_surface_id)
+        .collect::<Vec<_>>();
+    if surfaces.is_empty() {
+        return Vec::new();
+    }
+
+    let measures: HashMap<&str, PartnershipMeasure> = loaded_modules
+        .iter()
+        .flat_map(|m| &m.facts.definitions)
+        .filter_map(|def| Some((def.id.as_str(), def.partnership_measure?)))
+        .collect();
+    let tested: HashSet<PartnershipMeasure> = surfaces
+        .iter()
+        .flat_map(|s| &s.clauses)
+        .filter_map(|c| measures.get(c.fact_id.as_str()).copied())
+        .collect();
+
+    let mut constraint = PartnershipConstraint {
+        partnership: Partnership::of(witness.user_seat),
+        ..Default::default()
+    };
+    let mut constrained = false;
+    for measure in tested {
+        let per_surface = surfaces
+            .iter()
+            .map(|s| {
+                s.clauses
+                    .iter()
+                    .filter(|c| measures.get(c.fact_id.as_str()) == Some(&measure))
+                    .map(clause_bounds)
+                    .fold((None, None), tightest)
+            })
+            .collect::<Vec<_>>();
+        let (min, max) = loosest(&per_surface);
+        if min.is_none() && max.is_none() {
+            continue;
+        }
+        constrained = true;
+        match measure {
+            PartnershipMeasure::Hcp => {
+                constraint.min_hcp = min.map(u32::from);
+                constraint.max_hcp = max.map(u32::from);
+            }
+            PartnershipMeasure::Fit { suit } => {
+                if let Some(min) = min {
+                    constraint
+                        .min_fit
+                        .get_or_insert_with(HashMap::new)
+                        .insert(suit, min);
+                }
+                if let Some(max) = max {
+                    constraint
+                        .max_fit
+                        .get_or_insert_with(HashMap::new)
+                        .insert(suit, max);
+                }
+            }
+            PartnershipMeasure::Aces => {
+                constraint.min_aces = min;
+                constraint.max_aces = max;
+            }
+            PartnershipMeasure::Kings => {
+                constraint.min_kings = min;
+                constraint.max_kings = max;
+            }
+        }
+    }
+    if constrained {
+        vec![constraint]
+    } else {
+        Vec::new()
+    }
+}
+
 /// Build a witness-verifying rejection-sampling predicate.
 ///
 /// Returns `None` if there is no `ConventionSpec` (e.g., unknown bundle id);
@@ -472,6 +614,58 @@ mod tests {
     use crate::service_impl::ServicePortImpl;
     use crate::ServiceError;
 
+    fn blackwood_witness(surface_id: &str) -> (Witness, Vec<&'static ConventionModule>) {
+        let module = bridge_conventions::registry::module_registry::get_module(
+            "blackwood",
+            BaseSystemId::Sayc,
+        )
+        .expect("blackwood module");
+        let witness = Witness {
+            prefix: Vec::new(),
+            target_surface_id: surface_id.to_string(),
+            target_module_id: "blackwood".to_string(),
+            target_surface_module_id: "blackwood".to_string(),
+            user_seat: Seat::West,
+            dealer: Seat::North,
+            opener: Seat::North,
+        };
+        (witness, vec![module])
+    }
+
+    #[test]
+    fn ask_kings_target_requires_all_four_aces_in_the_partnership() {
+        let (witness, modules) = blackwood_witness("blackwood:ask-kings");
+        let constraints = partnership_constraints_for_witness(&witness, &modules);
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(constraints[0].partnership, Partnership::EastWest);
+        assert_eq!(constraints[0].min_aces, Some(4));
+        assert_eq!(constraints[0].max_aces, Some(4));
+        assert_eq!(constraints[0].min_kings, None);
+
+        let (witness, modules) = blackwood_witness("blackwood:ask-aces");
+        assert!(partnership_constraints_for_witness(&witness, &modules).is_empty());
+    }
+
+    #[test]
+    fn partnership_constraints_follow_the_declared_measure() {
+        let (witness, modules) = blackwood_witness("blackwood:ask-kings");
+        let mut module = modules[0].clone();
+        for def in &mut module.facts.definitions {
+            if def.partnership_measure == Some(PartnershipMeasure::Aces) {
+                def.partnership_measure = Some(PartnershipMeasure::Fit {
+                    suit: bridge_engine::Suit::Spades,
+                });
+            }
+        }
+        let constraints = partnership_constraints_for_witness(&witness, &[&module]);
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(constraints[0].min_aces, None);
+        assert_eq!(
+            constraints[0].min_fit,
+            Some(HashMap::from([(bridge_engine::Suit::Spades, 4)]))
+        );
+    }
+
     fn stayman_config(seed: u64) -> SessionConfig {
         SessionConfig {
             convention_id: "stayman-bundle".to_string(),
@@ -488,6 +682,9 @@ mod tests {
             vulnerability: None,
             play_profile_id: None,
             vulnerability_distribution: None,
+            deal: None,
+            undo_policy: None,
+            module_weights: None,
             seed: Some(seed),
         }
     }
@@ -537,6 +734,9 @@ mod tests {
                 vulnerability: None,
                 play_profile_id: None,
                 vulnerability_distribution: None,
+                deal: None,
+                undo_policy: None,
+                module_weights: None,
                 seed: Some(seed),
             };
             match service.create_drill_session(config) {
@@ -582,6 +782,7 @@ mod tests {
             target_surface_module_id: "stayman".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
 
         let base = BASE_MODULE_IDS
@@ -610,6 +811,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: Some(50_000),
                 seed: Some(1),
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -665,6 +867,7 @@ mod tests {
             target_surface_module_id: "stayman".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
 
         let base = BASE_MODULE_IDS
@@ -694,6 +897,7 @@ mod tests {
             vulnerability: None,
             max_attempts: Some(1_000),
             seed: Some(42),
+            ..Default::default()
         };
         let deal = generate_deal(&constraints).expect("deal").deal;
         assert!(
@@ -747,6 +951,7 @@ mod tests {
             target_surface_module_id: "negative-doubles".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
         let strategy = ScriptedOpponentStrategy { witness };
         // After N's opening, at index=1 with seat=East: returns the witness's 1D.
@@ -805,6 +1010,7 @@ mod tests {
             target_surface_module_id: "ignored".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
         let strategy = ScriptedOpponentStrategy { witness };
         // Position 0 (dealer N) — strategy is at East, witness step is at E
@@ -924,6 +1130,7 @@ mod tests {
             target_surface_module_id: "negative-doubles".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
 
         let base = BASE_MODULE_IDS
@@ -950,6 +1157,7 @@ mod tests {
             vulnerability: None,
             max_attempts: Some(1_000),
             seed: Some(42),
+            ..Default::default()
         };
         let deal = generate_deal(&constraints).expect("deal").deal;
         // Live opponent will not bid 7D over 1C, and the witness step has no
@@ -150,6 +150,9 @@ pub(crate) fn build_drill_setup(config: &SessionConfig) -> Result<DrillSetupResu
     // 2. Validate injected system config
     validation::validate_system_config(&resolved.system_config)?;
     validation::validate_base_module_ids(&resolved.base_module_ids)?;
+    if let Some(ref deal) = config.deal {
+        validation::validate_preset_deal(deal)?;
+    }
 
     // 3. Look up bundle metadata
     let bundle_input = bundle_resolver::get_bundle_input(&config.convention_id)?;
@@ -199,28 +202,35 @@ pub(crate) fn build_drill_setup(config: &SessionConfig) -> Result<DrillSetupResu
             let witness_dealer = witness_dealer_for_role(resolved.user_seat, resolved_role);
 
             let target_selector = config.target.clone().unwrap_or_default();
-            let witness_selection: Option<WitnessSelection> = match select_witness(
-                &bundle_input.member_ids,
-                &resolved.base_module_ids,
-                system,
-                &resolved.system_config,
-                resolved_role,
-                witness_dealer,
-                &target_selector,
-                attempt_seed,
-            ) {
-                Ok(w) => w,
-                Err(msg) => {
-                    let err = ServiceError::DealGenerationExhausted {
-                        witness_summary: msg,
-                    };
-                    tracing::warn!(
-                        attempt,
-                        convention_id = %config.convention_id,
-                        "witness selection failed; retrying with shifted seed"
-                    );
-                    last_err = Some(err);
-                    continue;
+            // A preset deal is drilled as dealt, so there is no witness to
+            // select and no predicate to satisfy.
+            let witness_selection: Option<WitnessSelection> = if config.deal.is_some() {
+                None
+            } else {
+                match select_witness(
+                    &bundle_input.member_ids,
+                    &resolved.base_module_ids,
+                    system,
+                    &resolved.system_config,
+                    resolved_role,
+                    witness_dealer,
+                    &target_selector,
+                    options.tuning.module_weights.as_ref(),
+                    attempt_seed,
+                ) {
+                    Ok(w) => w,
+                    Err(msg) => {
+                        let err = ServiceError::DealGenerationExhausted {
+                            witness_summary: msg,
+                        };
+                        tracing::warn!(
+                            attempt,
+                            convention_id = %config.convention_id,
+                            "witness selection failed; retrying with shifted seed"
+                        );
+                        last_err = Some(err);
+                        continue;
+                    }
                 }
             };
 
@@ -412,6 +422,7 @@ pub(crate) fn build_drill_setup(config: &SessionConfig) -> Result<DrillSetupResu
         resolved.play_profile_id,
         seed,
     );
+    state.undo_policy = config.undo_policy.unwrap_or_default();
 
     // Pattern witness materialization wins over start_drill's fallback —
     // start_drill ran `derive_initial_auction` against projected constraints,
@@ -450,6 +461,51 @@ pub(crate) fn build_drill_setup(config: &SessionConfig) -> Result<DrillSetupResu
     })
 }
 
+/// Rebuild the drill context around a restored `SessionState`.
+///
+/// The deal, auction and play come from the snapshot; only the seat
+/// strategies are rebuilt from `config`, the way `build_drill_setup` builds
+/// them for a new drill.
+pub(crate) fn restore_drill_setup(
+    config: &SessionConfig,
+    state: SessionState,
+) -> Result<DrillSetupResult, ServiceError> {
+    let resolved = config_resolver::resolve_config(config);
+    validation::validate_system_config(&resolved.system_config)?;
+    validation::validate_base_module_ids(&resolved.base_module_ids)?;
+    bundle_resolver::get_bundle_input(&config.convention_id)?;
+
+    let spec = bridge_conventions::registry::spec_builder::spec_from_bundle(
+        &config.convention_id,
+        &resolved.system_config,
+        &resolved.base_module_ids,
+        &HashMap::new(),
+    );
+    let surface_groups = bundle_resolver::resolve_surface_groups(
+        &config.convention_id,
+        resolved.system_config.system_id,
+    );
+    let seat_strategies = config_resolver::build_seat_strategies(
+        state.user_seat,
+        resolved.opponent_mode,
+        &spec,
+        &surface_groups,
+    );
+    let drill_config = bridge_session::session::DrillConfig {
+        convention_id: config.convention_id.clone(),
+        user_seat: state.user_seat,
+        seat_strategies: HashMap::new(),
+    };
+
+    Ok(DrillSetupResult {
+        state,
+        seat_strategies,
+        drill_config,
+        target_module_id: None,
+        target_surface_id: None,
+    })
+}
+
 /// Build a `ConventionConfig` using projected witness constraints when
 /// present, or a minimal dealer-only constraint when absent. Replaces v1's
 /// `bundle_resolver::build_convention_config` which derived bounds from
@@ -468,6 +524,7 @@ fn build_convention_config_with_witness(
             vulnerability: None,
             max_attempts: Some(50_000),
             seed: None,
+            ..Default::default()
         },
     };
 
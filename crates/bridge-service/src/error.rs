@@ -26,6 +26,15 @@ pub enum ServiceError {
     #[error("Invalid system config: {0}")]
     InvalidConfig(String),
 
+    #[error("Invalid hand record: {0}")]
+    InvalidHandRecord(String),
+
+    #[error("Cannot undo: {0}")]
+    Undo(#[from] bridge_session::session::UndoError),
+
+    #[error("Cannot restore session: {0}")]
+    Snapshot(#[from] bridge_session::session::SnapshotError),
+
     #[error("Internal error: {0}")]
     Internal(String),
 
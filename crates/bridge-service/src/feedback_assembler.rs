@@ -7,7 +7,7 @@ use bridge_conventions::teaching::projection_builder::format_feedback_label;
 use bridge_conventions::teaching::teaching_types::{
     ContributionRole, ExplanationKind, WhyNotGrade,
 };
-use bridge_session::session::{format_call, BidFeedbackDTO, BidGrade};
+use bridge_session::session::{format_call, BidFeedbackDTO, BidGrade, DecisionPoint};
 
 use crate::response_types::*;
 
@@ -43,6 +43,15 @@ pub fn assemble_viewport_feedback(
         ),
     };
 
+    // The surface the model would have bid is the decision being graded.
+    let decision_point = evaluation
+        .and_then(|eval| eval.pipeline_result.as_ref())
+        .and_then(|pr| pr.selected.as_ref())
+        .map(|carrier| DecisionPoint {
+            module_id: carrier.encoded.proposal.module_id.clone(),
+            surface_id: carrier.encoded.proposal.meaning_id.clone(),
+        });
+
     ViewportBidFeedbackDTO {
         grade: feedback.grade,
         user_call: feedback.user_call.clone(),
@@ -57,6 +66,7 @@ pub fn assemble_viewport_feedback(
         partner_hand_space,
         conventions_applied,
         requires_retry,
+        decision_point,
     }
 }
 
@@ -624,6 +634,7 @@ mod tests {
             encoding: BidEncoding {
                 default_call: make_call_2c(),
                 alternate_encodings: None,
+                relay: None,
             },
         }
     }
@@ -841,6 +852,25 @@ mod tests {
         assert_eq!(result.user_call_display, "Pass");
     }
 
+    #[test]
+    fn feedback_names_the_graded_decision_point() {
+        let feedback = make_feedback(BidGrade::Incorrect, Call::Pass, Some(make_call_2c()));
+        let eval = make_eval_with_projection();
+        let result = assemble_viewport_feedback(&feedback, Some(&eval));
+        assert_eq!(
+            result.decision_point,
+            Some(DecisionPoint {
+                module_id: "stayman".to_string(),
+                surface_id: "stayman:ask".to_string(),
+            })
+        );
+
+        assert_eq!(
+            assemble_viewport_feedback(&feedback, None).decision_point,
+            None
+        );
+    }
+
     #[test]
     fn near_miss_bid_requires_retry() {
         let feedback = make_feedback(BidGrade::NearMiss, make_call_2h(), Some(make_call_2c()));
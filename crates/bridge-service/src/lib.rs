@@ -21,11 +21,12 @@ pub(crate) mod witness_selection;
 
 pub use error::ServiceError;
 pub use port::{DevServicePort, ServicePort};
-pub use request_types::{DrillHandle, SessionConfig};
-pub use service_impl::{DdsPlayContext, ServicePortImpl};
+pub use request_types::{DrillHandle, DrillSnapshot, SessionConfig};
+pub use service_impl::{load_modules, DdsPlayContext, ServicePortImpl};
+pub use session_manager::SessionLimits;
 
 // Re-export response types
 pub use response_types::{
     AiBidEntryDTO, AiPlayEntryDTO, BidSubmitResult, ConventionInfo, DDSolutionResult,
-    DrillStartResult, PhaseTransition, PlayEntryResult, ServicePublicBeliefState,
+    DrillStartResult, ParComparisonDTO, PhaseTransition, PlayEntryResult, ServicePublicBeliefState,
 };
@@ -5,13 +5,14 @@
 
 use bridge_engine::types::{Call, Card, Seat};
 use bridge_session::session::{
-    BiddingViewport, DeclarerPromptViewport, ExplanationViewport, ModuleCatalogEntry,
-    ModuleFlowTreeViewport, ModuleLearningViewport, PlayCardResult, PlayingViewport,
+    AuctionAnalysis, AuctionReplayViewport, BiddingViewport, ClaimResult, DeclarerPromptViewport,
+    ExplanationViewport, ModuleCatalogEntry, ModuleFlowTreeViewport, ModuleLearningViewport,
+    PlayCardResult, PlayingViewport, UndoResult,
 };
 
 use crate::config_schema_types::{ModuleConfigSchemaView, ValidationResult};
 use crate::error::ServiceError;
-use crate::request_types::{DrillHandle, SessionConfig};
+use crate::request_types::{AuctionAnalysisRequest, DrillHandle, DrillSnapshot, SessionConfig};
 use crate::response_types::{
     BidSubmitResult, ConventionInfo, DDSolutionResult, DrillStartResult, InferenceTimelineEntryDTO,
     PlayEntryResult, ServiceDebugLogEntryDTO, ServicePublicBeliefState,
@@ -27,6 +28,18 @@ pub trait ServicePort {
     /// Start the drill: generate deal, run initial AI bids, return viewport.
     fn start_drill(&mut self, handle: &str) -> Result<DrillStartResult, ServiceError>;
 
+    /// Destroy a session. Its handle is invalid afterwards.
+    fn destroy_drill_session(&mut self, handle: &str) -> Result<(), ServiceError>;
+
+    /// Capture the whole session so it can be saved and resumed later.
+    fn snapshot_drill_session(&self, handle: &str) -> Result<DrillSnapshot, ServiceError>;
+
+    /// Recreate a saved session under a new handle.
+    fn restore_drill_session(
+        &mut self,
+        snapshot: DrillSnapshot,
+    ) -> Result<DrillHandle, ServiceError>;
+
     // ── Bidding ────────────────────────────────────────────────────
 
     /// Submit a user bid, get grading + AI continuation.
@@ -60,6 +73,16 @@ pub trait ServicePort {
         seat: Seat,
     ) -> Result<PlayCardResult, ServiceError>;
 
+    /// Claim `tricks` of the remaining tricks for a user-controlled seat.
+    /// Accepted claims end play; rejected ones come back with a counter-line.
+    fn claim(&mut self, handle: &str, seat: Seat, tricks: u8) -> Result<ClaimResult, ServiceError>;
+
+    // ── Undo ───────────────────────────────────────────────────────
+
+    /// Take back the user's last bid, card or claim, along with the AI
+    /// actions that followed it. Refused when the drill's undo policy forbids it.
+    fn undo_last_action(&mut self, handle: &str) -> Result<UndoResult, ServiceError>;
+
     /// Skip play phase, go directly to review.
     fn skip_to_review(&mut self, handle: &str) -> Result<(), ServiceError>;
 
@@ -78,6 +101,11 @@ pub trait ServicePort {
         &self,
         handle: &str,
     ) -> Result<Option<ExplanationViewport>, ServiceError>;
+    /// Call-by-call walkthrough of the finished auction. None while bidding.
+    fn get_auction_replay_viewport(
+        &self,
+        handle: &str,
+    ) -> Result<Option<AuctionReplayViewport>, ServiceError>;
 
     // ── Inference ──────────────────────────────────────────────────
 
@@ -86,6 +114,15 @@ pub trait ServicePort {
         handle: &str,
     ) -> Result<ServicePublicBeliefState, ServiceError>;
 
+    // ── Analysis ───────────────────────────────────────────────────
+
+    /// Grade North–South's calls in an auction played elsewhere. Stateless:
+    /// no drill session is created.
+    fn analyze_auction(
+        &self,
+        request: AuctionAnalysisRequest,
+    ) -> Result<AuctionAnalysis, ServiceError>;
+
     // ── DDS ────────────────────────────────────────────────────────
 
     fn get_dds_solution(&self, handle: &str) -> Result<DDSolutionResult, ServiceError>;
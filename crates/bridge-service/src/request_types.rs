@@ -1,12 +1,15 @@
 //! Service request types — shapes the client provides to the service.
 
+use std::collections::HashMap;
+
 use bridge_conventions::types::rule_types::TargetSelector;
 use bridge_conventions::types::system_config::SystemConfig;
-use bridge_engine::types::{Seat, Vulnerability};
+use bridge_engine::types::{Auction, Deal, Seat, Vulnerability};
 use serde::{Deserialize, Serialize};
 
 use bridge_session::heuristics::play_profiles::PlayProfileId;
-use bridge_session::types::{OpponentMode, PlayPreference, PracticeMode, PracticeRole};
+use bridge_session::session::SessionSnapshot;
+use bridge_session::types::{OpponentMode, PlayPreference, PracticeMode, PracticeRole, UndoPolicy};
 
 /// Re-exported from `bridge_session::types::VulnerabilityDistribution` so
 /// the service request types and the drill-options sampler share one
@@ -63,4 +66,42 @@ pub struct SessionConfig {
     /// drill creation. Ignored when `vulnerability` is set.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vulnerability_distribution: Option<VulnerabilityDistribution>,
+    /// Preset deal, e.g. from an imported hand record. When set, the drill
+    /// plays this deal as dealt — dealer and vulnerability included —
+    /// instead of generating one, and the auction starts from the dealer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deal: Option<Deal>,
+    /// Which actions the user may take back. `None` allows cards and claims
+    /// but not bids, which are graded on the first attempt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub undo_policy: Option<UndoPolicy>,
+    /// Relative weight per module when choosing which surface to drill,
+    /// typically from the user's review schedule. Modules not listed weigh
+    /// 1.0. Ignored when `target` pins a module or surface.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_weights: Option<HashMap<String, f64>>,
+}
+
+/// A saved drill: the configuration it was created from plus the session
+/// state. Returned by `snapshot_drill_session`, accepted by
+/// `restore_drill_session`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DrillSnapshot {
+    pub config: SessionConfig,
+    pub session: SessionSnapshot,
+}
+
+/// An auction played outside the app, to be graded against the convention
+/// model. North–South are the analyzed partnership.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionAnalysisRequest {
+    pub convention_id: String,
+    pub system_config: SystemConfig,
+    pub base_module_ids: Vec<String>,
+    /// All four hands, dealer and vulnerability as played.
+    pub deal: Deal,
+    /// The auction as it went at the table, starting from the dealer.
+    pub auction: Auction,
 }
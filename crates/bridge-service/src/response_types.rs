@@ -3,13 +3,14 @@
 
 use bridge_conventions::types::meaning::FactConstraint;
 use bridge_engine::strategy::ChainTrace;
-use bridge_engine::types::{Call, Seat, Suit};
+use bridge_engine::types::{Call, Contract, Seat, Suit};
 use bridge_session::inference::types::{
     BidAnnotation, DerivedRanges, DescriptiveConstraint, NumberRange, PublicBeliefState,
     PublicBeliefs,
 };
 use bridge_session::session::{
     AiBidEntry, AiPlayEntry, BidGrade, BidHistoryEntryView, BiddingViewport, DebugLogEntry,
+    DecisionPoint,
 };
 use bridge_session::types::{GamePhase, PlayPreference, PracticeMode, PracticeRole};
 use serde::{Deserialize, Serialize};
@@ -75,6 +76,39 @@ pub struct PlayEntryResult {
 pub struct DDSolutionResult {
     pub solution: Option<serde_json::Value>,
     pub error: Option<String>,
+    /// The drill's final contract measured against par. None when the deal
+    /// was passed out or the solution has no par.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub par_comparison: Option<ParComparisonDTO>,
+}
+
+/// Double-dummy score of the final contract next to par, both from the
+/// North-South perspective.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParComparisonDTO {
+    pub contract: Contract,
+    pub contract_score: i32,
+    pub par_score: i32,
+    /// `contract_score - par_score`: positive means NS beat par.
+    pub delta: i32,
+    /// `delta` converted to IMPs.
+    pub imps: i32,
+}
+
+// ── Board references ──────────────────────────────────────────────
+
+/// Stable identity of a session's board, for saved drills and review links.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardReference {
+    /// Deal with dealer and vulnerability; `deal_from_board_id` restores it.
+    pub board_id: String,
+    /// Canonical text of the auction so far, e.g. `N:1NT P 2C P`.
+    pub auction_key: String,
+    /// 64-bit hash of `auction_key` as 16 hex digits (too wide for a JS
+    /// number).
+    pub auction_hash: String,
 }
 
 // ── Catalog ───────────────────────────────────────────────────────
@@ -323,6 +357,10 @@ pub struct ViewportBidFeedbackDTO {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conventions_applied: Option<Vec<ConventionViewDTO>>,
     pub requires_retry: bool,
+    /// The `(module, surface)` this call was graded against — the key for
+    /// recording the outcome in a review schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision_point: Option<DecisionPoint>,
 }
 
 /// Teaching label DTO.
@@ -741,6 +779,7 @@ mod tests {
         let result = DDSolutionResult {
             solution: Some(serde_json::json!({"tricks": 9})),
             error: None,
+            par_comparison: None,
         };
         let json = serde_json::to_string(&result).unwrap();
         let rt: DDSolutionResult = serde_json::from_str(&json).unwrap();
@@ -6,34 +6,43 @@
 
 use std::collections::{HashMap, HashSet};
 
-use bridge_conventions::types::meaning::{ConstraintValue, FactOperator};
+use bridge_conventions::registry::{
+    active_registry, install_modules, ModuleLoadReport, ModuleSource,
+};
+use bridge_conventions::types::meaning::ConstraintValue;
 use bridge_conventions::types::module_types::ConventionModule;
 use bridge_conventions::BaseSystemId;
 use bridge_engine::constants::{partner_seat, SEATS};
+use bridge_engine::hand_record::{CallAnnotation, HandRecord};
 use bridge_engine::strategy::BiddingStrategy;
-use bridge_engine::types::{Call, Card, Seat};
+use bridge_engine::types::{Call, Card, Deal, Seat, Trick};
 use bridge_session::session::{
-    build_bidding_viewport, build_declarer_prompt_viewport, build_explanation_viewport,
-    build_module_catalog, build_module_flow_tree, build_module_learning_viewport,
-    build_playing_viewport, format_call, process_bid, process_play_card, run_initial_ai_bids,
-    run_initial_ai_plays, AiPlayEntry, BiddingViewport, BuildBiddingViewportInput,
-    BuildDeclarerPromptViewportInput, BuildExplanationViewportInput, BuildPlayingViewportInput,
-    DeclarerPromptViewport, ExplanationViewport, ModuleCatalogEntry, ModuleFlowTreeViewport,
-    ModuleLearningViewport, PlayCardResult, PlayingViewport, SeatStrategy,
+    analyze_auction, build_bidding_viewport, build_declarer_prompt_viewport,
+    build_explanation_viewport, build_module_catalog, build_module_flow_tree,
+    build_module_learning_viewport, build_playing_viewport, build_replay_viewport, format_call,
+    process_bid, process_claim, process_play_card, restore_session, run_initial_ai_bids,
+    run_initial_ai_plays, snapshot_session, undo_last_action, AiPlayEntry, AnalyzeAuctionInput,
+    AnnotationType, AuctionAnalysis, AuctionReplayViewport, BiddingViewport,
+    BuildBiddingViewportInput, BuildDeclarerPromptViewportInput, BuildExplanationViewportInput,
+    BuildPlayingViewportInput, BuildReplayViewportInput, ClaimResult, DeclarerPromptViewport,
+    ExplanationViewport, ModuleCatalogEntry, ModuleFlowTreeViewport, ModuleLearningViewport,
+    PlayCardResult, PlayingViewport, SeatStrategy, UndoResult,
 };
-use bridge_session::types::{GamePhase, PromptMode};
+use bridge_session::types::{GamePhase, OpponentMode, PromptMode};
 
 use crate::bundle_resolver;
+use crate::config_resolver;
 use crate::error::ServiceError;
 use crate::port::{DevServicePort, ServicePort};
-use crate::request_types::{DrillHandle, SessionConfig};
+use crate::request_types::{AuctionAnalysisRequest, DrillHandle, DrillSnapshot, SessionConfig};
 use crate::response_types::{
-    AiBidEntryDTO, AiPlayEntryDTO, BidSubmitResult, ConventionInfo, DDSolutionResult,
-    DrillStartResult, InferenceTimelineEntryDTO, PhaseTransition, PlayEntryResult,
-    ServiceDebugLogEntryDTO, ServiceFactConstraintDTO, ServicePublicBeliefState,
-    ServicePublicBeliefsDTO,
+    AiBidEntryDTO, AiPlayEntryDTO, BidSubmitResult, BoardReference, ConventionInfo,
+    DDSolutionResult, DrillStartResult, InferenceTimelineEntryDTO, ParComparisonDTO,
+    PhaseTransition, PlayEntryResult, ServiceDebugLogEntryDTO, ServiceFactConstraintDTO,
+    ServicePublicBeliefState, ServicePublicBeliefsDTO,
 };
-use crate::session_manager::SessionManager;
+use crate::session_manager::{SessionLimits, SessionManager};
+use crate::validation;
 
 fn user_face_up_seats(user_seat: Seat) -> HashSet<Seat> {
     let mut seats = HashSet::new();
@@ -154,14 +163,6 @@ fn infer_valid_range(fact_id: &str) -> Option<crate::config_schema_types::ValidR
     }
 }
 
-/// Extract an i32 from a ConstraintValue::Number if possible.
-fn constraint_value_as_i32(value: &ConstraintValue) -> Option<i32> {
-    match value {
-        ConstraintValue::Number(n) => n.as_i64().map(|v| v as i32),
-        _ => None,
-    }
-}
-
 /// Convert a ConstraintValue to a ParameterValue.
 fn to_parameter_value(
     value: &ConstraintValue,
@@ -247,102 +248,13 @@ fn build_configurable_surfaces(
 fn validate_module_content(
     module: &ConventionModule,
 ) -> Vec<crate::config_schema_types::ValidationError> {
-    use crate::config_schema_types::ValidationError;
-
-    let mut errors = Vec::new();
-
-    // Display name must be non-empty
-    if module.display_name.trim().is_empty() {
-        errors.push(ValidationError {
-            field: "displayName".to_string(),
-            message: "Display name must not be empty".to_string(),
-        });
-    }
-
-    // Must have at least one surface
-    let has_surfaces = module
-        .states
-        .as_ref()
-        .is_some_and(|states| states.iter().any(|s| !s.surfaces.is_empty()));
-
-    if !has_surfaces {
-        errors.push(ValidationError {
-            field: "states".to_string(),
-            message: "Module must have at least one surface".to_string(),
-        });
-    }
-
-    // Validate clause values
-    if let Some(states) = &module.states {
-        for (si, state) in states.iter().enumerate() {
-            for (surf_i, surface) in state.surfaces.iter().enumerate() {
-                let path_prefix = format!("states[{}].surfaces[{}]", si, surf_i);
-
-                // Collect gte/lte pairs per fact_id for range consistency
-                let mut gte_values: HashMap<String, (usize, i32)> = HashMap::new();
-                let mut lte_values: HashMap<String, (usize, i32)> = HashMap::new();
-
-                for (ci, clause) in surface.clauses.iter().enumerate() {
-                    let clause_path = format!("{}.clauses[{}]", path_prefix, ci);
-
-                    // Validate numeric ranges
-                    if let Some(val) = constraint_value_as_i32(&clause.value) {
-                        let fact = &clause.fact_id;
-
-                        if fact.contains("hcp") || fact.contains("points") || fact.contains("tp") {
-                            if !(0..=40).contains(&val) {
-                                errors.push(ValidationError {
-                                    field: clause_path.clone(),
-                                    message: format!(
-                                        "Value {} for '{}' outside valid range 0-40",
-                                        val, fact
-                                    ),
-                                });
-                            }
-                        } else if fact.contains("length") || fact.contains("count") {
-                            if !(0..=13).contains(&val) {
-                                errors.push(ValidationError {
-                                    field: clause_path.clone(),
-                                    message: format!(
-                                        "Value {} for '{}' outside valid range 0-13",
-                                        val, fact
-                                    ),
-                                });
-                            }
-                        }
-
-                        // Track gte/lte for consistency check
-                        match clause.operator {
-                            FactOperator::Gte => {
-                                gte_values.insert(fact.clone(), (ci, val));
-                            }
-                            FactOperator::Lte => {
-                                lte_values.insert(fact.clone(), (ci, val));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-
-                // Check gte <= lte for same fact
-                for (fact_id, (_gte_idx, gte_val)) in &gte_values {
-                    if let Some((_lte_idx, lte_val)) = lte_values.get(fact_id) {
-                        if gte_val > lte_val {
-                            errors.push(ValidationError {
-                                field: format!("{}.clauses", path_prefix),
-                                message: format!(
-                                    "Range error for '{}': gte ({}) > lte ({})",
-                                    fact_id, gte_val, lte_val
-                                ),
-                            });
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    errors
+    bridge_conventions::registry::validate_module(module)
+        .into_iter()
+        .map(|issue| crate::config_schema_types::ValidationError {
+            field: issue.field,
+            message: issue.message,
+        })
+        .collect()
 }
 
 // ── ServicePortImpl ───────────────────────────────────────────────
@@ -353,12 +265,30 @@ pub struct ServicePortImpl {
 }
 
 impl ServicePortImpl {
-    /// Create a new service port with an empty session manager.
+    /// Create a new service port with an empty single-session manager.
     pub fn new() -> Self {
         Self {
             manager: SessionManager::new(),
         }
     }
+
+    /// Create a new service port that keeps several sessions at once.
+    pub fn with_session_limits(limits: SessionLimits) -> Self {
+        Self {
+            manager: SessionManager::with_limits(limits),
+        }
+    }
+
+    /// Whether `handle` names a live session — false once it was destroyed,
+    /// evicted or expired.
+    pub fn has_drill_session(&self, handle: &str) -> bool {
+        self.manager.contains(handle)
+    }
+
+    /// Drop every session idle for longer than the TTL. Returns their handles.
+    pub fn evict_expired_sessions(&mut self) -> Vec<DrillHandle> {
+        self.manager.evict_expired()
+    }
 }
 
 impl Default for ServicePortImpl {
@@ -375,13 +305,45 @@ impl ServicePort for ServicePortImpl {
     fn create_drill_session(&mut self, config: SessionConfig) -> Result<DrillHandle, ServiceError> {
         let setup = crate::drill_setup::build_drill_setup(&config)?;
 
-        let handle = self
-            .manager
-            .create(setup.state, setup.drill_config, setup.seat_strategies);
+        let handle = self.manager.create(
+            setup.state,
+            setup.drill_config,
+            setup.seat_strategies,
+            config,
+        );
+
+        Ok(handle)
+    }
+
+    fn snapshot_drill_session(&self, handle: &str) -> Result<DrillSnapshot, ServiceError> {
+        let session = self.manager.get(handle)?;
+        Ok(DrillSnapshot {
+            config: session.session_config.clone(),
+            session: snapshot_session(&session.state)?,
+        })
+    }
+
+    fn restore_drill_session(
+        &mut self,
+        snapshot: DrillSnapshot,
+    ) -> Result<DrillHandle, ServiceError> {
+        let state = restore_session(snapshot.session)?;
+        let setup = crate::drill_setup::restore_drill_setup(&snapshot.config, state)?;
+
+        let handle = self.manager.create(
+            setup.state,
+            setup.drill_config,
+            setup.seat_strategies,
+            snapshot.config,
+        );
 
         Ok(handle)
     }
 
+    fn destroy_drill_session(&mut self, handle: &str) -> Result<(), ServiceError> {
+        self.manager.destroy(handle)
+    }
+
     fn start_drill(&mut self, handle: &str) -> Result<DrillStartResult, ServiceError> {
         let session = self.manager.get_mut(handle)?;
 
@@ -502,14 +464,34 @@ impl ServicePort for ServicePortImpl {
         card: Card,
         seat: Seat,
     ) -> Result<PlayCardResult, ServiceError> {
+        if self.manager.get(handle)?.state.phase != GamePhase::Playing {
+            return Err(ServiceError::WrongPhase);
+        }
+        // The native search takes seconds per card; in wasm it would run on
+        // the UI thread, so the browser keeps heuristic play unless its JS
+        // solver drives the async path.
+        if cfg!(not(target_arch = "wasm32")) && self.needs_dds_play(handle)? {
+            return self.play_card_native_dds(handle, card, seat);
+        }
+
+        let session = self.manager.get_mut(handle)?;
+        let result = process_play_card(&mut session.state, card, seat);
+        Ok(result)
+    }
+
+    fn claim(&mut self, handle: &str, seat: Seat, tricks: u8) -> Result<ClaimResult, ServiceError> {
         let session = self.manager.get_mut(handle)?;
 
         if session.state.phase != GamePhase::Playing {
             return Err(ServiceError::WrongPhase);
         }
 
-        let result = process_play_card(&mut session.state, card, seat);
-        Ok(result)
+        Ok(process_claim(&mut session.state, seat, tricks))
+    }
+
+    fn undo_last_action(&mut self, handle: &str) -> Result<UndoResult, ServiceError> {
+        let session = self.manager.get_mut(handle)?;
+        Ok(undo_last_action(&mut session.state)?)
     }
 
     fn skip_to_review(&mut self, handle: &str) -> Result<(), ServiceError> {
@@ -725,6 +707,29 @@ impl ServicePort for ServicePortImpl {
         Ok(Some(viewport))
     }
 
+    fn get_auction_replay_viewport(
+        &self,
+        handle: &str,
+    ) -> Result<Option<AuctionReplayViewport>, ServiceError> {
+        let session = self.manager.get(handle)?;
+
+        if session.state.phase == GamePhase::Bidding {
+            return Ok(None);
+        }
+
+        // Same inference setup as the live session (see drill_setup).
+        let viewport = build_replay_viewport(BuildReplayViewportInput {
+            deal: &session.state.deal,
+            user_seat: session.state.user_seat,
+            auction: &session.state.auction,
+            convention_id: &session.state.convention_id,
+            seat_strategies: &session.seat_strategies,
+            system_config: None,
+        });
+
+        Ok(Some(viewport))
+    }
+
     // ── Inference ──────────────────────────────────────────────────
 
     fn get_public_belief_state(
@@ -737,10 +742,64 @@ impl ServicePort for ServicePortImpl {
         ))
     }
 
+    // ── Analysis ───────────────────────────────────────────────────
+
+    fn analyze_auction(
+        &self,
+        request: AuctionAnalysisRequest,
+    ) -> Result<AuctionAnalysis, ServiceError> {
+        validation::validate_system_config(&request.system_config)?;
+        validation::validate_base_module_ids(&request.base_module_ids)?;
+        validation::validate_preset_deal(&request.deal)?;
+        validation::validate_external_auction(&request.deal, &request.auction)?;
+
+        let spec = bridge_conventions::registry::spec_builder::spec_from_bundle(
+            &request.convention_id,
+            &request.system_config,
+            &request.base_module_ids,
+            &HashMap::new(),
+        );
+        if spec.is_none() {
+            return Err(ServiceError::BundleNotFound(request.convention_id));
+        }
+        let surface_groups = bundle_resolver::resolve_surface_groups(
+            &request.convention_id,
+            request.system_config.system_id,
+        );
+        // Opponents are never graded, so they need no strategy of their own.
+        let seat_strategies = config_resolver::build_seat_strategies(
+            Seat::South,
+            OpponentMode::None,
+            &spec,
+            &surface_groups,
+        );
+
+        Ok(analyze_auction(AnalyzeAuctionInput {
+            deal: &request.deal,
+            auction: &request.auction,
+            seats: &[Seat::North, Seat::South],
+            seat_strategies: &seat_strategies,
+        }))
+    }
+
     // ── DDS ────────────────────────────────────────────────────────
 
-    fn get_dds_solution(&self, _handle: &str) -> Result<DDSolutionResult, ServiceError> {
-        Err(ServiceError::DdsNotAvailable)
+    fn get_dds_solution(&self, handle: &str) -> Result<DDSolutionResult, ServiceError> {
+        // Seconds of search on the UI thread in wasm; the browser solves
+        // tables through its JS solver instead.
+        if cfg!(target_arch = "wasm32") {
+            return Err(ServiceError::DdsNotAvailable);
+        }
+        let session = self.manager.get(handle)?;
+        let result = match bridge_engine::par::solve_deal(&session.state.deal) {
+            Ok(solution) => self.dd_solution_result(handle, solution)?,
+            Err(err) => DDSolutionResult {
+                solution: None,
+                error: Some(err.to_string()),
+                par_comparison: None,
+            },
+        };
+        Ok(result)
     }
 
     // ── Catalog ────────────────────────────────────────────────────
@@ -855,7 +914,7 @@ impl ServicePortImpl {
     }
 }
 
-// ── DDS play helpers (not on ServicePort trait — WASM-only) ─────
+// ── DDS play helpers (not on ServicePort trait) ─────────────────
 
 /// Context for MC+DDS play decision at the current position.
 pub struct DdsPlayContext {
@@ -995,6 +1054,148 @@ impl ServicePortImpl {
         }))
     }
 
+    /// Native DDS play path — `play_card` for Expert/WorldClass profiles on
+    /// native hosts. Plays the user's card, then runs MC+DDS AI plays with
+    /// the native double-dummy solver until it is the user's turn or play
+    /// ends. Defenders concede at trick boundaries as in heuristic play.
+    fn play_card_native_dds(
+        &mut self,
+        handle: &str,
+        card: Card,
+        seat: Seat,
+    ) -> Result<PlayCardResult, ServiceError> {
+        let user_result = self.apply_single_card(handle, card, seat)?;
+        if !user_result.accepted || user_result.play_complete {
+            return Ok(PlayCardResult {
+                accepted: user_result.accepted,
+                trick_complete: user_result.trick_complete,
+                play_complete: user_result.play_complete,
+                score: user_result.score,
+                ai_plays: Vec::new(),
+                legal_plays: None,
+                current_player: None,
+                concession: None,
+            });
+        }
+
+        let ai_plays = if user_result.trick_complete && self.try_concede(handle)? {
+            Vec::new()
+        } else {
+            self.run_native_dds_play_loop(handle)?
+        };
+
+        let state = &self.manager.get(handle)?.state;
+        let play_complete = state.play.current_player.is_none();
+        Ok(PlayCardResult {
+            accepted: true,
+            trick_complete: user_result.trick_complete,
+            play_complete,
+            score: if play_complete {
+                state.play.play_score
+            } else {
+                None
+            },
+            ai_plays,
+            legal_plays: None,
+            current_player: state.play.current_player,
+            concession: state.play.claim.clone().filter(|claim| claim.conceded),
+        })
+    }
+
+    /// Run MC+DDS AI plays with the native solver until it's the user's turn
+    /// or play completes, conceding after each trick the way the heuristic
+    /// loop does.
+    fn run_native_dds_play_loop(&mut self, handle: &str) -> Result<Vec<AiPlayEntry>, ServiceError> {
+        let mut ai_plays = Vec::new();
+
+        while let Some(ctx) = self.get_dds_play_context(handle)? {
+            let params = bridge_session::dds::McddParams {
+                seat: ctx.current_player,
+                legal_plays: ctx.legal_plays,
+                contract: ctx.contract,
+                current_trick: ctx.current_trick,
+                remaining_cards: ctx.remaining_cards,
+                visible_seats: ctx.visible_seats,
+                beliefs: ctx.beliefs,
+            };
+
+            // Same per-decision seed as the WASM loop, so a seeded drill
+            // reaches the same decisions on either solver.
+            let mut rng =
+                <rand_chacha::ChaCha8Rng as rand::SeedableRng>::seed_from_u64(ctx.play_rng_seed);
+            let (ai_card, reason) = match bridge_session::dds::mc_dds_suggest_native(
+                &params,
+                ctx.use_constraints,
+                &mut rng,
+            ) {
+                Some(result) => (result.best_card, result.reason),
+                None => (params.legal_plays[0].clone(), "mc-dds:fallback".to_string()),
+            };
+
+            let ai_seat = ctx.current_player;
+            let result = self.apply_single_card(handle, ai_card.clone(), ai_seat)?;
+            ai_plays.push(AiPlayEntry {
+                seat: ai_seat,
+                card: ai_card,
+                reason,
+                trick_complete: result.trick_complete,
+            });
+
+            if result.play_complete || (result.trick_complete && self.try_concede(handle)?) {
+                break;
+            }
+        }
+
+        Ok(ai_plays)
+    }
+
+    /// Let the AI defenders concede at a trick boundary; true if play ended.
+    fn try_concede(&mut self, handle: &str) -> Result<bool, ServiceError> {
+        let session = self.manager.get_mut(handle)?;
+        Ok(bridge_session::session::try_concede(&mut session.state))
+    }
+
+    /// Wrap a double-dummy solution for the session's deal: fill in par when
+    /// the solver left it out (the JS table solver never computes it) and
+    /// compare the final contract against it.
+    pub fn dd_solution_result(
+        &self,
+        handle: &str,
+        mut solution: bridge_engine::types::DDSolution,
+    ) -> Result<DDSolutionResult, ServiceError> {
+        let session = self.manager.get(handle)?;
+        let deal = &session.state.deal;
+        let par = solution
+            .par
+            .get_or_insert_with(|| {
+                bridge_engine::par::calculate_par(&solution.tricks, deal.vulnerability, deal.dealer)
+            })
+            .clone();
+
+        let par_comparison = session.state.contract.as_ref().map(|contract| {
+            let contract_score = bridge_engine::par::double_dummy_score(
+                contract,
+                &solution.tricks,
+                deal.vulnerability,
+            );
+            ParComparisonDTO {
+                contract: contract.clone(),
+                contract_score,
+                par_score: par.score,
+                delta: contract_score - par.score,
+                imps: bridge_engine::scoring::imps_versus(contract_score, par.score),
+            }
+        });
+
+        let solution = serde_json::to_value(&solution)
+            .map_err(|e| ServiceError::Internal(format!("DD solution serialization: {e}")))?;
+        Ok(DDSolutionResult {
+            solution: Some(solution),
+            error: None,
+            par_comparison,
+        })
+    }
+
     /// Get deal PBN for internal use (WASM DDS table solver).
     /// Not on ServicePort — only used by WASM layer.
     pub fn get_deal_pbn(&self, handle: &str) -> Result<String, ServiceError> {
@@ -1003,6 +1204,119 @@ impl ServicePortImpl {
     }
 }
 
+// ── Runtime modules (process-wide, not on ServicePort) ──────────
+
+/// Load convention modules from JSON and make them available to every
+/// lookup — new drills, catalogs and bundles — without a rebuild.
+///
+/// The module registry is process-wide, so this is not a port method: it
+/// affects every `ServicePortImpl` in the process. Replaces the modules from
+/// any earlier call; a source that fails keeps its previously loaded version.
+/// Per-source failures are in the report.
+pub fn load_modules(sources: &[ModuleSource]) -> ModuleLoadReport {
+    let (registry, report) = active_registry().reload(sources);
+    install_modules(registry);
+    report
+}
+
+// ── Hand records (not on ServicePort trait) ─────────────────────
+
+impl ServicePortImpl {
+    /// Parse a PBN file into hand records. Their deals can be drilled by
+    /// passing one as `SessionConfig.deal`.
+    pub fn import_pbn(&self, text: &str) -> Result<Vec<HandRecord>, ServiceError> {
+        bridge_engine::pbn::parse_pbn(text)
+            .map_err(|e| ServiceError::InvalidHandRecord(e.to_string()))
+    }
+
+    /// Export the session's board as a PBN game: deal, auction with alerts,
+    /// contract, the play so far and, once all 13 tricks are played, the
+    /// result.
+    pub fn export_session_pbn(&self, handle: &str) -> Result<String, ServiceError> {
+        let session = self.manager.get(handle)?;
+        let record = hand_record_from_session(&session.state);
+        Ok(bridge_engine::pbn::write_pbn(std::slice::from_ref(&record)))
+    }
+
+    /// Parse BBO LIN into hand records, one per board.
+    pub fn import_lin(&self, text: &str) -> Result<Vec<HandRecord>, ServiceError> {
+        bridge_engine::lin::parse_lin(text)
+            .map_err(|e| ServiceError::InvalidHandRecord(e.to_string()))
+    }
+
+    /// Export the session's board as a LIN string for BBO's hand viewer.
+    pub fn export_session_lin(&self, handle: &str) -> Result<String, ServiceError> {
+        let session = self.manager.get(handle)?;
+        let record = hand_record_from_session(&session.state);
+        Ok(bridge_engine::lin::write_lin(std::slice::from_ref(&record)))
+    }
+}
+
+// ── Board references (not on ServicePort trait) ─────────────────
+
+impl ServicePortImpl {
+    /// Board id and auction hash identifying the session's board.
+    pub fn session_board_reference(&self, handle: &str) -> Result<BoardReference, ServiceError> {
+        let session = self.manager.get(handle)?;
+        let auction = &session.state.auction;
+        Ok(BoardReference {
+            board_id: bridge_engine::board_id::board_id(&session.state.deal),
+            auction_key: bridge_engine::board_id::auction_key(auction),
+            auction_hash: format!("{:016x}", bridge_engine::board_id::auction_hash(auction)),
+        })
+    }
+
+    /// Restore the deal behind a board id. Drill it by passing it as
+    /// `SessionConfig.deal`.
+    pub fn deal_from_board_id(&self, board_id: &str) -> Result<Deal, ServiceError> {
+        bridge_engine::board_id::parse_board_id(board_id)
+            .map_err(|e| ServiceError::InvalidHandRecord(e.to_string()))
+    }
+}
+
+fn hand_record_from_session(state: &bridge_session::session::SessionState) -> HandRecord {
+    let mut record = HandRecord::new(state.deal.clone());
+    record
+        .tags
+        .push(("Event".to_string(), state.convention_name.clone()));
+
+    if !state.auction.entries.is_empty() {
+        record.auction = Some(state.auction.clone());
+    }
+    record.annotations = state
+        .bid_history
+        .iter()
+        .enumerate()
+        .filter_map(|(call_index, entry)| {
+            let alert = matches!(
+                entry.annotation_type,
+                Some(AnnotationType::Alert | AnnotationType::Announce)
+            );
+            let explanation = entry.alert_label.clone();
+            (alert || explanation.is_some()).then_some(CallAnnotation {
+                call_index,
+                alert,
+                explanation,
+            })
+        })
+        .collect();
+    record.contract = state.contract.clone();
+
+    let play = &state.play;
+    record.play = play.tricks.clone();
+    if !play.current_trick.is_empty() {
+        record.play.push(Trick {
+            plays: play.current_trick.clone(),
+            trump_suit: play.trump_suit,
+            winner: None,
+        });
+    }
+    if play.tricks.len() == 13 {
+        record.result = Some(play.declarer_tricks_won as u8);
+    }
+    record
+}
+
 impl DevServicePort for ServicePortImpl {
     fn get_expected_bid(&self, handle: &str) -> Result<Option<Call>, ServiceError> {
         let session = self.manager.get(handle)?;
@@ -1150,10 +1464,10 @@ mod tests {
     }
 
     #[test]
-    fn dds_solution_returns_unavailable() {
+    fn dds_solution_unknown_handle_errors() {
         let service = ServicePortImpl::new();
         let result = service.get_dds_solution("session-1");
-        assert!(matches!(result, Err(ServiceError::DdsNotAvailable)));
+        assert!(matches!(result, Err(ServiceError::NoSession)));
     }
 
     // ── Initial auction integration tests ────────────────────────
@@ -1189,6 +1503,9 @@ mod tests {
             vulnerability: None,
             play_profile_id: None,
             vulnerability_distribution: None,
+            deal: None,
+            undo_policy: None,
+            module_weights: None,
         };
         let handle = match service.create_drill_session(config) {
             Ok(h) => h,
@@ -1227,6 +1544,9 @@ mod tests {
             vulnerability: None,
             play_profile_id: None,
             vulnerability_distribution: None,
+            deal: None,
+            undo_policy: None,
+            module_weights: None,
         };
         let handle = match service.create_drill_session(config) {
             Ok(h) => h,
@@ -1,15 +1,20 @@
-//! Handle-based session storage with single-session invariant.
+//! Handle-based session storage with capacity and idle-time limits.
 //!
-//! `SessionManager` enforces a single active session. Creating a new session
-//! silently destroys the previous one. Handles are sequential string IDs.
+//! `SessionManager` keeps up to `SessionLimits::capacity` sessions. Creating
+//! one more evicts the least recently used; sessions idle for longer than
+//! `SessionLimits::ttl` are dropped. The default limits keep a single session,
+//! so creating a new one replaces the previous — the WASM single-user case.
+//! Handles are sequential string IDs.
 
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use bridge_engine::types::Seat;
 use bridge_session::session::{DrillConfig, SeatStrategy, SessionState};
 
 use crate::error::ServiceError;
-use crate::request_types::DrillHandle;
+use crate::request_types::{DrillHandle, SessionConfig};
 
 // ── ActiveSession ─────────────────────────────────────────────────
 
@@ -20,73 +25,228 @@ pub struct ActiveSession {
     pub config: DrillConfig,
     /// Seat strategies extracted from DrillConfig for bidding controller.
     pub seat_strategies: HashMap<Seat, SeatStrategy>,
+    /// The configuration the session was created from, kept for snapshots.
+    pub session_config: SessionConfig,
+}
+
+// ── SessionLimits ─────────────────────────────────────────────────
+
+/// How many sessions a `SessionManager` keeps, and for how long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionLimits {
+    /// Most sessions kept at once. Creating one more evicts the least
+    /// recently used. Values below 1 are treated as 1.
+    pub capacity: usize,
+    /// Idle time after which a session expires. `None` keeps sessions until
+    /// evicted or destroyed. Expiry reads the system clock, which is not
+    /// available on `wasm32-unknown-unknown` — leave it unset there.
+    pub ttl: Option<Duration>,
+}
+
+impl SessionLimits {
+    /// One session, no expiry.
+    pub fn single() -> Self {
+        Self {
+            capacity: 1,
+            ttl: None,
+        }
+    }
+}
+
+impl Default for SessionLimits {
+    fn default() -> Self {
+        Self::single()
+    }
 }
 
 // ── SessionManager ────────────────────────────────────────────────
 
-/// Manages the single active drill session.
+/// A stored session plus its recency bookkeeping. Reads go through `&self`,
+/// so the bookkeeping lives in cells.
+struct Entry {
+    session: ActiveSession,
+    /// Logical clock value of the last access — orders LRU eviction.
+    last_used: Cell<u64>,
+    /// Wall-clock time of the last access. Only tracked when a TTL is set.
+    last_used_at: Cell<Option<Instant>>,
+}
+
+/// Manages the live drill sessions.
 ///
-/// `create()` destroys any previous session silently and returns a new handle.
+/// `create()` evicts expired sessions, then the least recently used ones
+/// while at capacity, and returns a new handle.
 pub struct SessionManager {
-    session: Option<ActiveSession>,
+    sessions: HashMap<DrillHandle, Entry>,
+    limits: SessionLimits,
     handle_counter: u64,
+    clock: Cell<u64>,
 }
 
 impl SessionManager {
-    /// Create an empty session manager.
+    /// Create an empty session manager with the default single-session limits.
     pub fn new() -> Self {
+        Self::with_limits(SessionLimits::default())
+    }
+
+    /// Create an empty session manager with the given limits.
+    pub fn with_limits(limits: SessionLimits) -> Self {
         Self {
-            session: None,
+            sessions: HashMap::new(),
+            limits: SessionLimits {
+                capacity: limits.capacity.max(1),
+                ..limits
+            },
             handle_counter: 0,
+            clock: Cell::new(0),
         }
     }
 
-    /// Create a new session, destroying any previous one.
+    /// The limits this manager enforces.
+    pub fn limits(&self) -> SessionLimits {
+        self.limits
+    }
+
+    /// Create a new session, evicting others as the limits require.
     /// Returns the new session handle.
     pub fn create(
         &mut self,
         state: SessionState,
         config: DrillConfig,
         seat_strategies: HashMap<Seat, SeatStrategy>,
+        session_config: SessionConfig,
     ) -> DrillHandle {
+        self.evict_expired();
+        while self.sessions.len() >= self.limits.capacity {
+            let Some(lru) = self
+                .sessions
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.get())
+                .map(|(handle, _)| handle.clone())
+            else {
+                break;
+            };
+            self.sessions.remove(&lru);
+        }
+
         self.handle_counter += 1;
         let handle = format!("session-{}", self.handle_counter);
-        self.session = Some(ActiveSession {
-            handle: handle.clone(),
-            state,
-            config,
-            seat_strategies,
-        });
+        let entry = Entry {
+            session: ActiveSession {
+                handle: handle.clone(),
+                state,
+                config,
+                seat_strategies,
+                session_config,
+            },
+            last_used: Cell::new(0),
+            last_used_at: Cell::new(None),
+        };
+        touch(&self.clock, self.limits, &entry);
+        self.sessions.insert(handle.clone(), entry);
         handle
     }
 
-    /// Get a reference to the active session.
+    /// Get a reference to a live session.
     pub fn get(&self, handle: &str) -> Result<&ActiveSession, ServiceError> {
-        match &self.session {
-            Some(session) if session.handle == handle => Ok(session),
-            Some(_) => Err(ServiceError::InvalidHandle(handle.to_string())),
-            None => Err(ServiceError::NoSession),
-        }
+        let entry = self.live_entry(handle)?;
+        touch(&self.clock, self.limits, entry);
+        Ok(&entry.session)
     }
 
-    /// Get a mutable reference to the active session.
+    /// Get a mutable reference to a live session.
     pub fn get_mut(&mut self, handle: &str) -> Result<&mut ActiveSession, ServiceError> {
-        match &mut self.session {
-            Some(session) if session.handle == handle => Ok(session),
-            Some(_) => Err(ServiceError::InvalidHandle(handle.to_string())),
-            None => Err(ServiceError::NoSession),
+        self.live_entry(handle)?;
+        let entry = self
+            .sessions
+            .get_mut(handle)
+            .expect("live_entry found the session");
+        touch(&self.clock, self.limits, entry);
+        Ok(&mut entry.session)
+    }
+
+    /// Destroy one session.
+    pub fn destroy(&mut self, handle: &str) -> Result<(), ServiceError> {
+        self.live_entry(handle)?;
+        self.sessions.remove(handle);
+        Ok(())
+    }
+
+    /// Destroy every session.
+    pub fn destroy_all(&mut self) {
+        self.sessions.clear();
+    }
+
+    /// Drop every session idle for longer than the TTL. Returns their handles.
+    pub fn evict_expired(&mut self) -> Vec<DrillHandle> {
+        let Some(ttl) = self.limits.ttl else {
+            return Vec::new();
+        };
+        let now = Instant::now();
+        let expired: Vec<DrillHandle> = self
+            .sessions
+            .iter()
+            .filter(|(_, entry)| is_expired(entry, ttl, now))
+            .map(|(handle, _)| handle.clone())
+            .collect();
+        for handle in &expired {
+            self.sessions.remove(handle);
         }
+        expired
     }
 
-    /// Destroy the active session.
-    pub fn destroy(&mut self) {
-        self.session = None;
+    /// Whether `handle` names a live session. Does not count as a use.
+    pub fn contains(&self, handle: &str) -> bool {
+        self.live_entry(handle).is_ok()
     }
 
-    /// Check if there is an active session.
+    /// Check if there is at least one session.
     pub fn has_session(&self) -> bool {
-        self.session.is_some()
+        !self.sessions.is_empty()
+    }
+
+    /// Number of stored sessions, including any expired but not yet evicted.
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Whether no sessions are stored.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
     }
+
+    /// Find a session that exists and has not expired.
+    fn live_entry(&self, handle: &str) -> Result<&Entry, ServiceError> {
+        let Some(entry) = self.sessions.get(handle) else {
+            return Err(if self.sessions.is_empty() {
+                ServiceError::NoSession
+            } else {
+                ServiceError::InvalidHandle(handle.to_string())
+            });
+        };
+        if let Some(ttl) = self.limits.ttl {
+            if is_expired(entry, ttl, Instant::now()) {
+                return Err(ServiceError::InvalidHandle(handle.to_string()));
+            }
+        }
+        Ok(entry)
+    }
+}
+
+/// Record an access to `entry` on the logical clock (and wall clock, if a TTL is set).
+fn touch(clock: &Cell<u64>, limits: SessionLimits, entry: &Entry) {
+    let tick = clock.get() + 1;
+    clock.set(tick);
+    entry.last_used.set(tick);
+    if limits.ttl.is_some() {
+        entry.last_used_at.set(Some(Instant::now()));
+    }
+}
+
+fn is_expired(entry: &Entry, ttl: Duration, now: Instant) -> bool {
+    entry
+        .last_used_at
+        .get()
+        .is_some_and(|at| now.saturating_duration_since(at) >= ttl)
 }
 
 impl Default for SessionManager {
@@ -131,6 +291,29 @@ mod tests {
         )
     }
 
+    fn make_session_config() -> SessionConfig {
+        SessionConfig {
+            convention_id: "test".to_string(),
+            user_seat: Some(Seat::South),
+            seed: None,
+            system_config: bridge_conventions::registry::system_configs::get_system_config(
+                bridge_conventions::types::system_config::BaseSystemId::Sayc,
+            ),
+            base_module_ids: Vec::new(),
+            practice_mode: None,
+            target: None,
+            practice_role: None,
+            play_preference: None,
+            opponent_mode: None,
+            vulnerability: None,
+            play_profile_id: None,
+            vulnerability_distribution: None,
+            deal: None,
+            undo_policy: None,
+            module_weights: None,
+        }
+    }
+
     fn make_config() -> DrillConfig {
         DrillConfig {
             convention_id: "test".to_string(),
@@ -142,24 +325,49 @@ mod tests {
     #[test]
     fn create_returns_sequential_handles() {
         let mut mgr = SessionManager::new();
-        let h1 = mgr.create(make_state(), make_config(), HashMap::new());
+        let h1 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         assert_eq!(h1, "session-1");
-        let h2 = mgr.create(make_state(), make_config(), HashMap::new());
+        let h2 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         assert_eq!(h2, "session-2");
     }
 
     #[test]
     fn get_active_session() {
         let mut mgr = SessionManager::new();
-        let h = mgr.create(make_state(), make_config(), HashMap::new());
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         assert!(mgr.get(&h).is_ok());
     }
 
     #[test]
     fn get_stale_handle_fails() {
         let mut mgr = SessionManager::new();
-        let h1 = mgr.create(make_state(), make_config(), HashMap::new());
-        let _h2 = mgr.create(make_state(), make_config(), HashMap::new());
+        let h1 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        let _h2 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         // h1 is stale
         assert!(mgr.get(&h1).is_err());
     }
@@ -173,19 +381,134 @@ mod tests {
     #[test]
     fn destroy_clears_session() {
         let mut mgr = SessionManager::new();
-        let h = mgr.create(make_state(), make_config(), HashMap::new());
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         assert!(mgr.has_session());
-        mgr.destroy();
+        mgr.destroy(&h).unwrap();
         assert!(!mgr.has_session());
         assert!(mgr.get(&h).is_err());
+        assert!(mgr.destroy(&h).is_err());
     }
 
     #[test]
     fn get_mut_works() {
         let mut mgr = SessionManager::new();
-        let h = mgr.create(make_state(), make_config(), HashMap::new());
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
         let session = mgr.get_mut(&h).unwrap();
         session.state.convention_id = "modified".to_string();
         assert_eq!(mgr.get(&h).unwrap().state.convention_id, "modified");
     }
+
+    fn multi(capacity: usize) -> SessionManager {
+        SessionManager::with_limits(SessionLimits {
+            capacity,
+            ttl: None,
+        })
+    }
+
+    #[test]
+    fn sessions_coexist_up_to_capacity() {
+        let mut mgr = multi(3);
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                mgr.create(
+                    make_state(),
+                    make_config(),
+                    HashMap::new(),
+                    make_session_config(),
+                )
+            })
+            .collect();
+        assert_eq!(mgr.len(), 3);
+        for h in &handles {
+            assert!(mgr.get(h).is_ok());
+        }
+        mgr.destroy(&handles[1]).unwrap();
+        assert!(mgr.get(&handles[0]).is_ok());
+        assert!(matches!(
+            mgr.get(&handles[1]),
+            Err(ServiceError::InvalidHandle(_))
+        ));
+    }
+
+    #[test]
+    fn create_at_capacity_evicts_least_recently_used() {
+        let mut mgr = multi(2);
+        let h1 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        let h2 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        // Reading h1 makes h2 the least recently used.
+        mgr.get(&h1).unwrap();
+        let h3 = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        assert_eq!(mgr.len(), 2);
+        assert!(mgr.get(&h1).is_ok());
+        assert!(mgr.get(&h2).is_err());
+        assert!(mgr.get(&h3).is_ok());
+    }
+
+    #[test]
+    fn idle_sessions_expire_after_ttl() {
+        let mut mgr = SessionManager::with_limits(SessionLimits {
+            capacity: 4,
+            ttl: Some(Duration::ZERO),
+        });
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        assert!(matches!(mgr.get(&h), Err(ServiceError::InvalidHandle(_))));
+        assert_eq!(mgr.evict_expired(), vec![h]);
+        assert!(mgr.is_empty());
+
+        let mut mgr = SessionManager::with_limits(SessionLimits {
+            capacity: 4,
+            ttl: Some(Duration::from_secs(3600)),
+        });
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        assert!(mgr.get(&h).is_ok());
+        assert!(mgr.evict_expired().is_empty());
+    }
+
+    #[test]
+    fn zero_capacity_still_keeps_one_session() {
+        let mut mgr = multi(0);
+        let h = mgr.create(
+            make_state(),
+            make_config(),
+            HashMap::new(),
+            make_session_config(),
+        );
+        assert_eq!(mgr.limits().capacity, 1);
+        assert!(mgr.get(&h).is_ok());
+    }
 }
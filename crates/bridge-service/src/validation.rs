@@ -1,10 +1,14 @@
-//! Runtime validation for injected system configs and base module IDs.
+//! Runtime validation for injected system configs, base module IDs, preset
+//! deals and imported auctions.
 //!
 //! Validates every session — preset and custom alike — catching
 //! misconfigurations early with descriptive errors.
 
 use bridge_conventions::registry::module_registry;
 use bridge_conventions::types::system_config::SystemConfig;
+use bridge_engine::auction::add_call;
+use bridge_engine::constants::{next_seat, SEATS};
+use bridge_engine::types::{Auction, Deal};
 
 use crate::error::ServiceError;
 
@@ -104,6 +108,59 @@ pub(crate) fn validate_base_module_ids(ids: &[String]) -> Result<(), ServiceErro
     Ok(())
 }
 
+/// Validate that a preset deal gives every seat 13 cards and deals each
+/// card exactly once.
+pub(crate) fn validate_preset_deal(deal: &Deal) -> Result<(), ServiceError> {
+    let mut seen = std::collections::HashSet::new();
+    for seat in SEATS {
+        let hand = deal.hands.get(&seat).ok_or_else(|| {
+            ServiceError::InvalidConfig(format!("Preset deal has no hand for {seat:?}"))
+        })?;
+        if hand.cards.len() != 13 {
+            return Err(ServiceError::InvalidConfig(format!(
+                "Preset deal gives {seat:?} {} cards",
+                hand.cards.len()
+            )));
+        }
+        for card in &hand.cards {
+            if !seen.insert((card.suit, card.rank)) {
+                return Err(ServiceError::InvalidConfig(format!(
+                    "Preset deal has {:?} {:?} twice",
+                    card.rank, card.suit
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An imported auction must start with the dealer, rotate clockwise and
+/// consist of legal calls.
+pub(crate) fn validate_external_auction(
+    deal: &Deal,
+    auction: &Auction,
+) -> Result<(), ServiceError> {
+    let mut replayed = Auction {
+        entries: Vec::new(),
+        is_complete: false,
+    };
+    let mut turn = deal.dealer;
+    for (i, entry) in auction.entries.iter().enumerate() {
+        if entry.seat != turn {
+            return Err(ServiceError::InvalidHandRecord(format!(
+                "Call {} is by {:?} but it is {turn:?}'s turn",
+                i + 1,
+                entry.seat
+            )));
+        }
+        replayed = add_call(&replayed, entry.clone()).map_err(|e| {
+            ServiceError::InvalidHandRecord(format!("Call {} is not allowed: {e}", i + 1))
+        })?;
+        turn = next_seat(turn);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +250,26 @@ mod tests {
         let err = validate_system_config(&config).unwrap_err();
         assert!(err.to_string().contains("Simple overcall"));
     }
+
+    #[test]
+    fn preset_deal_must_deal_every_card_once() {
+        let mut deal = bridge_engine::generate_deal(&bridge_engine::types::DealConstraints {
+            seats: Vec::new(),
+            dealer: None,
+            vulnerability: None,
+            max_attempts: None,
+            seed: Some(7),
+            ..Default::default()
+        })
+        .unwrap()
+        .deal;
+        assert!(validate_preset_deal(&deal).is_ok());
+
+        let card = deal.hands[&bridge_engine::types::Seat::North].cards[0].clone();
+        deal.hands
+            .get_mut(&bridge_engine::types::Seat::South)
+            .unwrap()
+            .cards[0] = card;
+        assert!(validate_preset_deal(&deal).is_err());
+    }
 }
@@ -23,11 +23,15 @@ use bridge_engine::hand_evaluator::evaluate_hand_hcp;
 use bridge_engine::types::{Auction, AuctionEntry, Call, Deal, DealConstraints, Seat};
 use bridge_session::heuristics::{BiddingContext, BiddingStrategy};
 use rand::seq::SliceRandom;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
 use bridge_session::types::PracticeRole;
 
+/// Floor for module weights. Zero or negative weights still leave the
+/// module drillable as a last resort.
+const MIN_MODULE_WEIGHT: f64 = 1e-6;
+
 /// Result of phase-2 selection: target module/surface, the chosen witness,
 /// and the projected deal constraints from that witness.
 pub(crate) struct WitnessSelection {
@@ -262,6 +266,31 @@ fn candidate_surfaces(
     out
 }
 
+/// Order candidates for trying. Without weights this is a uniform shuffle.
+/// With weights it is a weighted shuffle: each candidate draws `u^(1/w)`
+/// for its module's weight `w` (1.0 when unlisted) and the largest draws go
+/// first, so a surface's chance of leading scales with its module's weight.
+fn order_candidates(
+    mut candidates: Vec<(String, String)>,
+    module_weights: Option<&HashMap<String, f64>>,
+    rng: &mut ChaCha8Rng,
+) -> Vec<(String, String)> {
+    let Some(weights) = module_weights else {
+        candidates.shuffle(rng);
+        return candidates;
+    };
+    let mut keyed: Vec<(f64, (String, String))> = candidates
+        .into_iter()
+        .map(|candidate| {
+            let weight = weights.get(&candidate.0).copied().unwrap_or(1.0);
+            let u: f64 = rng.gen();
+            (u.powf(1.0 / weight.max(MIN_MODULE_WEIGHT)), candidate)
+        })
+        .collect();
+    keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+    keyed.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
 /// Phase 2a + 2b: pick a `(module, surface)`, enumerate witnesses, and
 /// project one into `DealConstraints`.
 ///
@@ -281,6 +310,7 @@ pub(crate) fn select_witness(
     role: PracticeRole,
     dealer: Seat,
     target: &TargetSelector,
+    module_weights: Option<&HashMap<String, f64>>,
     seed: u64,
 ) -> Result<Option<WitnessSelection>, String> {
     let loaded = loaded_modules_for(bundle_member_ids, base_module_ids, system);
@@ -301,8 +331,7 @@ pub(crate) fn select_witness(
     // Shuffle candidates and iterate; skip surfaces for which no witness
     // exists (phase-1 limitation: semantic-only FSMs return no reifiable
     // path). Only error if every candidate fails.
-    let mut shuffled = candidates.clone();
-    shuffled.shuffle(&mut rng);
+    let shuffled = order_candidates(candidates, module_weights, &mut rng);
 
     let mut last_failed: Option<(String, String)> = None;
     for (module_id, surface_id) in shuffled {
@@ -321,10 +350,14 @@ pub(crate) fn select_witness(
         // is returned. Document the single-branch assumption: we take the
         // first.
         let branches = project_witness(&chosen, &loaded, Some(system_config));
-        let Some(projected) = branches.into_iter().next() else {
+        let Some(mut projected) = branches.into_iter().next() else {
             last_failed = Some((module_id, surface_id));
             continue;
         };
+        // Joint requirements (e.g. Blackwood's combined aces) are dealt
+        // directly rather than left to rejection sampling.
+        projected.partnerships =
+            crate::deal_gating::partnership_constraints_for_witness(&chosen, &loaded);
 
         return Ok(Some(WitnessSelection {
             target_module_id: module_id,
@@ -377,6 +410,7 @@ mod tests {
             PracticeRole::Responder,
             Seat::North,
             &target,
+            None,
             42,
         )
         .expect("witness selection should not error");
@@ -398,6 +432,7 @@ mod tests {
             PracticeRole::Responder,
             Seat::North,
             &TargetSelector::Any,
+            None,
             7,
         )
         .expect("witness selection should not error");
@@ -409,6 +444,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn module_weights_bias_which_surface_leads() {
+        let candidates = vec![
+            ("stayman".to_string(), "stayman:ask-major".to_string()),
+            ("smolen".to_string(), "smolen:3h".to_string()),
+        ];
+        let weights = HashMap::from([("smolen".to_string(), 50.0)]);
+        let smolen_first = (0..100)
+            .filter(|&seed| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                order_candidates(candidates.clone(), Some(&weights), &mut rng)[0].0 == "smolen"
+            })
+            .count();
+        assert!(smolen_first > 90, "smolen led {smolen_first}/100 times");
+
+        // Without weights the order is the plain seeded shuffle.
+        let mut expected = candidates.clone();
+        expected.shuffle(&mut ChaCha8Rng::seed_from_u64(3));
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        assert_eq!(order_candidates(candidates, None, &mut rng), expected);
+    }
+
     #[test]
     fn initial_auction_from_witness_keeps_full_prefix_through_second_user_turn() {
         let witness = Witness {
@@ -443,6 +500,7 @@ mod tests {
             target_surface_module_id: "stayman".to_string(),
             user_seat: Seat::South,
             dealer: Seat::North,
+            opener: Seat::North,
         };
 
         // Concrete-only witness: pass a stub deal and empty seat_strategies map;
@@ -454,6 +512,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: Some(1),
                 seed: Some(0),
+                ..Default::default()
             },
         )
         .expect("stub deal")
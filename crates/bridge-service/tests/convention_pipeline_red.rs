@@ -606,3 +606,234 @@ fn observation_log_tracks_fit_through_transfer_acceptance() {
         );
     }
 }
+
+fn bid_entry(seat: Seat, level: u8, strain: BidSuit) -> AuctionEntry {
+    AuctionEntry {
+        seat,
+        call: Call::Bid { level, strain },
+    }
+}
+
+fn pass_entry(seat: Seat) -> AuctionEntry {
+    AuctionEntry {
+        seat,
+        call: Call::Pass,
+    }
+}
+
+#[test]
+fn lebensohl_opener_completes_the_relay() {
+    let hand = make_hand("SA SK S4 HK HQ H3 DA D7 D4 CJ C8 C5 C2");
+    let ctx = make_context(
+        hand,
+        vec![
+            bid_entry(Seat::South, 1, BidSuit::NoTrump),
+            bid_entry(Seat::West, 2, BidSuit::Hearts),
+            bid_entry(Seat::North, 2, BidSuit::NoTrump),
+            pass_entry(Seat::East),
+        ],
+        Seat::South,
+    );
+
+    let bid = build_adapter("lebensohl-bundle")
+        .suggest_bid(&ctx)
+        .expect("opener owes the forced 3C");
+
+    assert_eq!(
+        bid.call,
+        Call::Bid {
+            level: 3,
+            strain: BidSuit::Clubs,
+        }
+    );
+}
+
+#[test]
+fn lebensohl_relay_continuation_signs_off_in_diamonds() {
+    let hand = make_hand("S7 S4 H8 H3 H2 DQ DJ D9 D8 D6 D4 C5 C2");
+    let ctx = make_context(
+        hand,
+        vec![
+            bid_entry(Seat::North, 1, BidSuit::NoTrump),
+            bid_entry(Seat::East, 2, BidSuit::Hearts),
+            bid_entry(Seat::South, 2, BidSuit::NoTrump),
+            pass_entry(Seat::West),
+            bid_entry(Seat::North, 3, BidSuit::Clubs),
+            pass_entry(Seat::East),
+        ],
+        Seat::North,
+    );
+
+    let (result, eval) = build_adapter("lebensohl-bundle").suggest_with_evaluation(&ctx, None);
+    let bid = result.expect("responder continues after the forced reply");
+    assert_eq!(
+        bid.call,
+        Call::Bid {
+            level: 3,
+            strain: BidSuit::Diamonds,
+        }
+    );
+
+    // The relay itself is observed as a puppet, not as the sign-off.
+    let log = &eval
+        .auction_context
+        .expect("evaluation should carry the observation log")
+        .log;
+    let relay_step = log
+        .iter()
+        .find(|step| step.actor == Seat::South)
+        .expect("responder's relay should be in the log");
+    assert_eq!(
+        relay_step.public_actions,
+        vec![bridge_conventions::types::bid_action::BidAction::Puppet {
+            strain: bridge_conventions::types::bid_action::BidSuitName::Clubs,
+        }]
+    );
+}
+
+/// Meaning ID of the surface the pipeline selected, if any.
+fn selected_meaning_id(bundle_id: &str, ctx: &BiddingContext) -> Option<String> {
+    let (_bid, eval) = build_adapter(bundle_id).suggest_with_evaluation(ctx, None);
+    eval.pipeline_result?
+        .selected
+        .map(|carrier| carrier.proposal().meaning_id.clone())
+}
+
+#[test]
+fn drury_passed_hand_asks_with_2c_after_third_seat_1h() {
+    let hand = make_hand("SK S7 S4 HQ H9 H5 DA D8 D6 D3 CJ C7 C2");
+    let ctx = make_context(
+        hand,
+        vec![
+            pass_entry(Seat::South),
+            pass_entry(Seat::West),
+            bid_entry(Seat::North, 1, BidSuit::Hearts),
+            pass_entry(Seat::East),
+        ],
+        Seat::South,
+    );
+
+    let bid = build_adapter("drury-bundle")
+        .suggest_bid(&ctx)
+        .expect("passed hand with a limit raise should bid");
+
+    assert_eq!(
+        bid.call,
+        Call::Bid {
+            level: 2,
+            strain: BidSuit::Clubs,
+        }
+    );
+    assert_eq!(
+        selected_meaning_id("drury-bundle", &ctx).as_deref(),
+        Some("drury:2c-over-1h")
+    );
+}
+
+#[test]
+fn drury_stays_dormant_for_an_unpassed_responder() {
+    let hand = make_hand("SK S7 S4 HQ H9 H5 DA D8 D6 D3 CJ C7 C2");
+    let ctx = make_context(
+        hand,
+        vec![
+            bid_entry(Seat::North, 1, BidSuit::Hearts),
+            pass_entry(Seat::East),
+        ],
+        Seat::North,
+    );
+
+    let selected = selected_meaning_id("drury-bundle", &ctx);
+
+    assert!(
+        selected
+            .as_deref()
+            .is_none_or(|id| !id.starts_with("drury:")),
+        "Drury needs two passes before the opening, got {selected:?}"
+    );
+}
+
+#[test]
+fn drury_opener_signs_off_with_a_minimum() {
+    let hand = make_hand("SA S8 S4 HK HJ H9 H6 H3 DQ D7 D2 CK C4");
+    let ctx = make_context(
+        hand,
+        vec![
+            pass_entry(Seat::North),
+            pass_entry(Seat::East),
+            bid_entry(Seat::South, 1, BidSuit::Hearts),
+            pass_entry(Seat::West),
+            bid_entry(Seat::North, 2, BidSuit::Clubs),
+            pass_entry(Seat::East),
+        ],
+        Seat::North,
+    );
+
+    let bid = build_adapter("drury-bundle")
+        .suggest_bid(&ctx)
+        .expect("opener must answer the Drury ask");
+
+    assert_eq!(
+        bid.call,
+        Call::Bid {
+            level: 2,
+            strain: BidSuit::Hearts,
+        }
+    );
+}
+
+#[test]
+fn fourth_suit_forcing_after_1c_1h_1s() {
+    let hand = make_hand("SK S4 S2 HA HQ H8 H5 D7 D4 D2 CK CQ C3");
+    let ctx = make_context(
+        hand,
+        vec![
+            bid_entry(Seat::North, 1, BidSuit::Clubs),
+            pass_entry(Seat::East),
+            bid_entry(Seat::South, 1, BidSuit::Hearts),
+            pass_entry(Seat::West),
+            bid_entry(Seat::North, 1, BidSuit::Spades),
+            pass_entry(Seat::East),
+        ],
+        Seat::North,
+    );
+
+    let bid = build_adapter("fourth-suit-forcing-bundle")
+        .suggest_bid(&ctx)
+        .expect("game values without a diamond stopper should bid");
+
+    assert_eq!(
+        bid.call,
+        Call::Bid {
+            level: 2,
+            strain: BidSuit::Diamonds,
+        }
+    );
+    assert_eq!(
+        selected_meaning_id("fourth-suit-forcing-bundle", &ctx).as_deref(),
+        Some("fsf:2d-after-1c-1h-1s")
+    );
+}
+
+#[test]
+fn fourth_suit_forcing_stays_dormant_after_interference() {
+    let hand = make_hand("SK S4 S2 HA HQ H8 H5 D7 D4 D2 CK CQ C3");
+    let ctx = make_context(
+        hand,
+        vec![
+            bid_entry(Seat::North, 1, BidSuit::Clubs),
+            bid_entry(Seat::East, 1, BidSuit::Diamonds),
+            bid_entry(Seat::South, 1, BidSuit::Hearts),
+            pass_entry(Seat::West),
+            bid_entry(Seat::North, 1, BidSuit::Spades),
+            pass_entry(Seat::East),
+        ],
+        Seat::North,
+    );
+
+    let selected = selected_meaning_id("fourth-suit-forcing-bundle", &ctx);
+
+    assert!(
+        selected.as_deref().is_none_or(|id| !id.starts_with("fsf:")),
+        "the host auction must be uncontested, got {selected:?}"
+    );
+}
@@ -44,6 +44,9 @@ fn make_config(bundle_id: &str, seed: u64) -> SessionConfig {
         vulnerability: None,
         play_profile_id: None,
         vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
     }
 }
 
@@ -177,6 +180,9 @@ fn stayman_both_role_never_serves_without_partner_1nt_prefix() {
             vulnerability: None,
             play_profile_id: None,
             vulnerability_distribution: None,
+            deal: None,
+            undo_policy: None,
+            module_weights: None,
         };
 
         let handle = match service.create_drill_session(config) {
@@ -60,6 +60,9 @@ fn make_config(
         vulnerability: None,
         play_profile_id: None,
         vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
     }
 }
 
@@ -333,6 +336,7 @@ fn start_drill_returns_exhaustion_err_when_predicate_always_rejects() {
             vulnerability: None,
             max_attempts: Some(50_000),
             seed: Some(1),
+            ..Default::default()
         },
         allowed_dealers: None,
     };
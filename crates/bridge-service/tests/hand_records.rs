@@ -0,0 +1,200 @@
+//! Hand records: an imported PBN or LIN deal drives a drill, the finished
+//! session exports back to either format, its contract is scored against
+//! par, and its board id restores the deal.
+//!
+//! Run: `cargo test -p bridge-service --test hand_records`
+
+use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
+use bridge_conventions::registry::system_configs::get_system_config;
+use bridge_conventions::types::system_config::BaseSystemId;
+use bridge_engine::lin::parse_lin;
+use bridge_engine::pbn::parse_pbn;
+use bridge_engine::types::{Call, Seat, Vulnerability};
+use bridge_service::port::{DevServicePort, ServicePort};
+use bridge_service::request_types::SessionConfig;
+use bridge_service::service_impl::ServicePortImpl;
+use bridge_service::ServiceError;
+
+const CLUB_BOARD: &str = r#"[Event "Tuesday Pairs"]
+[Board "6"]
+[Dealer "E"]
+[Vulnerable "EW"]
+[Deal "E:AK32.QJ2.K43.Q32 QJT9.K43.A2.KJT9 876.A765.QJ5.A87 54.T98.T9876.654"]
+[Contract "3NT"]
+[Declarer "W"]
+[Result "9"]
+"#;
+
+fn config_with_deal(deal: bridge_engine::types::Deal) -> SessionConfig {
+    SessionConfig {
+        convention_id: "nt-bundle".to_string(),
+        user_seat: Some(Seat::South),
+        seed: Some(1),
+        system_config: get_system_config(BaseSystemId::Sayc),
+        base_module_ids: BASE_MODULE_IDS.iter().map(|s| s.to_string()).collect(),
+        practice_mode: None,
+        target: None,
+        practice_role: None,
+        play_preference: None,
+        opponent_mode: None,
+        vulnerability: None,
+        play_profile_id: None,
+        vulnerability_distribution: None,
+        deal: Some(deal),
+        undo_policy: None,
+        module_weights: None,
+    }
+}
+
+#[test]
+fn imported_deal_is_drilled_as_dealt() {
+    let mut service = ServicePortImpl::new();
+    let records = service.import_pbn(CLUB_BOARD).expect("import");
+    let deal = records[0].deal.clone();
+
+    let handle = service
+        .create_drill_session(config_with_deal(deal.clone()))
+        .expect("create_drill_session");
+    let start = service.start_drill(&handle).expect("start_drill");
+    assert_eq!(start.viewport.dealer, Seat::East);
+    assert_eq!(start.viewport.vulnerability, Vulnerability::EastWest);
+    assert_eq!(
+        start.viewport.hand.cards.len(),
+        deal.hands[&Seat::South].cards.len()
+    );
+    for card in &start.viewport.hand.cards {
+        assert!(deal.hands[&Seat::South].cards.contains(card));
+    }
+}
+
+#[test]
+fn finished_session_exports_to_pbn() {
+    let mut service = ServicePortImpl::new();
+    let deal = service.import_pbn(CLUB_BOARD).expect("import")[0]
+        .deal
+        .clone();
+    let handle = service
+        .create_drill_session(config_with_deal(deal.clone()))
+        .expect("create_drill_session");
+    let mut start = service.start_drill(&handle).expect("start_drill");
+    while !start.auction_complete {
+        let call = service
+            .get_expected_bid(&handle)
+            .ok()
+            .flatten()
+            .unwrap_or(Call::Pass);
+        let result = service.submit_bid(&handle, call).expect("submit_bid");
+        start.auction_complete = result.phase_transition.is_some();
+    }
+
+    let pbn = service.export_session_pbn(&handle).expect("export");
+    let exported = parse_pbn(&pbn).expect("exported PBN parses");
+    assert_eq!(exported.len(), 1);
+    assert_eq!(exported[0].deal, deal);
+    let auction = exported[0].auction.as_ref().expect("auction exported");
+    assert!(auction.is_complete);
+    assert_eq!(auction.entries[0].seat, Seat::East);
+}
+
+#[test]
+fn finished_auction_is_scored_in_imps_against_par() {
+    let mut service = ServicePortImpl::new();
+    let deal = service.import_pbn(CLUB_BOARD).expect("import")[0]
+        .deal
+        .clone();
+    let handle = service
+        .create_drill_session(config_with_deal(deal.clone()))
+        .expect("create_drill_session");
+    let mut complete = service
+        .start_drill(&handle)
+        .expect("start_drill")
+        .auction_complete;
+    while !complete {
+        let call = service
+            .get_expected_bid(&handle)
+            .ok()
+            .flatten()
+            .unwrap_or(Call::Pass);
+        let result = service.submit_bid(&handle, call).expect("submit_bid");
+        complete = result.phase_transition.is_some();
+    }
+
+    let solution = bridge_engine::par::solve_deal(&deal).expect("solve");
+    let result = service.dd_solution_result(&handle, solution).expect("dd");
+    let comparison = result.par_comparison.expect("auction reached a contract");
+    assert_eq!(
+        comparison.delta,
+        comparison.contract_score - comparison.par_score
+    );
+    assert_eq!(
+        comparison.imps,
+        bridge_engine::scoring::imps(comparison.delta)
+    );
+}
+
+#[test]
+fn malformed_pbn_is_an_invalid_hand_record() {
+    let service = ServicePortImpl::new();
+    let err = service
+        .import_pbn("[Deal \"N:AKQ\"]\n")
+        .expect_err("bad deal");
+    assert!(matches!(err, ServiceError::InvalidHandRecord(_)));
+}
+
+#[test]
+fn bbo_board_round_trips_through_a_drill() {
+    let lin = "pn|ann,bob,cat,dan|st||md|3SAK32HQJ2DK43CQ32,SQJT9HK43DA2CKJT9,S876HA765DQJ5CA87,|\
+               rh||ah|Board 4|sv|b|mb|1N!|an|15-17|mb|p|mb|3N|mb|p|mb|p|mb|p|";
+    let mut service = ServicePortImpl::new();
+    let records = service.import_lin(lin).expect("import");
+    assert_eq!(
+        records[0].annotations[0].explanation.as_deref(),
+        Some("15-17")
+    );
+    let deal = records[0].deal.clone();
+
+    let handle = service
+        .create_drill_session(config_with_deal(deal.clone()))
+        .expect("create_drill_session");
+    service.start_drill(&handle).expect("start_drill");
+
+    let exported = parse_lin(&service.export_session_lin(&handle).expect("export"))
+        .expect("exported LIN parses");
+    assert_eq!(exported[0].deal, deal);
+    assert_eq!(exported[0].deal.dealer, Seat::North);
+}
+
+#[test]
+fn board_id_restores_the_drilled_deal() {
+    let mut service = ServicePortImpl::new();
+    let deal = service.import_pbn(CLUB_BOARD).expect("import")[0]
+        .deal
+        .clone();
+    let handle = service
+        .create_drill_session(config_with_deal(deal.clone()))
+        .expect("create_drill_session");
+    service.start_drill(&handle).expect("start_drill");
+
+    let reference = service
+        .session_board_reference(&handle)
+        .expect("board reference");
+    assert!(reference.board_id.starts_with("E2-"));
+    assert_eq!(reference.auction_hash.len(), 16);
+    let restored = service
+        .deal_from_board_id(&reference.board_id)
+        .expect("decode");
+    assert_eq!(
+        bridge_engine::board_id::board_id(&restored),
+        reference.board_id
+    );
+    for seat in bridge_engine::SEATS {
+        assert_eq!(restored.hands[&seat].cards.len(), 13);
+        for card in &deal.hands[&seat].cards {
+            assert!(restored.hands[&seat].cards.contains(card));
+        }
+    }
+    assert!(matches!(
+        service.deal_from_board_id("not-a-board"),
+        Err(ServiceError::InvalidHandRecord(_))
+    ));
+}
@@ -8,10 +8,11 @@
 use bridge_conventions::registry::module_registry::BASE_MODULE_IDS;
 use bridge_conventions::registry::system_configs::get_system_config;
 use bridge_conventions::types::system_config::BaseSystemId;
-use bridge_engine::types::{BidSuit, Call, Seat};
+use bridge_engine::types::{Auction, AuctionEntry, BidSuit, Call, Deal, Seat};
 use bridge_service::port::{DevServicePort, ServicePort};
-use bridge_service::request_types::SessionConfig;
+use bridge_service::request_types::{AuctionAnalysisRequest, SessionConfig};
 use bridge_service::service_impl::ServicePortImpl;
+use bridge_service::SessionLimits;
 
 fn make_config(convention_id: &str, seed: u64) -> SessionConfig {
     SessionConfig {
@@ -29,6 +30,9 @@ fn make_config(convention_id: &str, seed: u64) -> SessionConfig {
         vulnerability: None,
         play_profile_id: None,
         vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
     }
 }
 
@@ -60,6 +64,9 @@ fn make_competitive_config(convention_id: &str, seed: u64) -> SessionConfig {
         vulnerability: None,
         play_profile_id: None,
         vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
     }
 }
 
@@ -353,6 +360,121 @@ fn enter_play_transitions_to_playing() {
     );
 }
 
+fn dds_rank(rank: bridge_engine::types::Rank) -> u8 {
+    use bridge_engine::types::Rank;
+    match rank {
+        Rank::Two => 2,
+        Rank::Three => 3,
+        Rank::Four => 4,
+        Rank::Five => 5,
+        Rank::Six => 6,
+        Rank::Seven => 7,
+        Rank::Eight => 8,
+        Rank::Nine => 9,
+        Rank::Ten => 10,
+        Rank::Jack => 11,
+        Rank::Queen => 12,
+        Rank::King => 13,
+        Rank::Ace => 14,
+    }
+}
+
+fn dds_suit(suit: bridge_engine::types::Suit) -> u8 {
+    use bridge_engine::types::Suit;
+    match suit {
+        Suit::Spades => 0,
+        Suit::Hearts => 1,
+        Suit::Diamonds => 2,
+        Suit::Clubs => 3,
+    }
+}
+
+fn dds_seat(seat: Seat) -> u8 {
+    match seat {
+        Seat::North => 0,
+        Seat::East => 1,
+        Seat::South => 2,
+        Seat::West => 3,
+    }
+}
+
+#[test]
+fn play_card_with_dds_profile_answers_with_a_double_dummy_card() {
+    use bridge_session::dds::SolveBoardRequest;
+    use bridge_session::dds::{pbn_to_remaining_cards, remaining_cards_to_pbn, solve_board};
+    use bridge_session::heuristics::play_profiles::PlayProfileId;
+
+    let mut service = ServicePortImpl::new();
+    let mut config = make_config("nt-bundle", 42);
+    config.play_preference = Some(bridge_session::types::PlayPreference::Prompt);
+    config.play_profile_id = Some(PlayProfileId::WorldClass);
+    let handle = service
+        .create_drill_session(config)
+        .expect("create_drill_session should succeed");
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    complete_auction(&mut service, &handle);
+    service
+        .enter_play(&handle, None)
+        .expect("enter_play should succeed");
+
+    let viewport = service
+        .get_playing_viewport(&handle)
+        .expect("get_playing_viewport should succeed")
+        .expect("playing viewport available");
+    let contract = viewport.contract.clone().expect("contract");
+    let user_card = viewport.legal_plays[0].clone();
+    let user_seat = viewport.current_player.expect("user to play");
+    let mut trick = viewport.current_trick.clone();
+    trick.push(bridge_engine::types::PlayedCard {
+        card: user_card.clone(),
+        seat: user_seat,
+    });
+    assert!(trick.len() < 4, "the user's card must leave the trick open");
+
+    // Double-dummy scores for every card the next opponent could play.
+    let mut remaining = pbn_to_remaining_cards(&service.get_deal_pbn(&handle).unwrap()).unwrap();
+    for played in &trick {
+        remaining
+            .get_mut(&played.seat)
+            .unwrap()
+            .retain(|c| *c != played.card);
+    }
+    let request = SolveBoardRequest {
+        trump: bridge_engine::constants::bid_suit_to_suit(contract.strain).map_or(4, dds_suit),
+        first: dds_seat(trick[0].seat),
+        current_trick_suit: trick.iter().map(|p| dds_suit(p.card.suit)).collect(),
+        current_trick_rank: trick.iter().map(|p| dds_rank(p.card.rank)).collect(),
+        remain_cards_pbn: remaining_cards_to_pbn(&remaining),
+    };
+    let solution = solve_board(&request).expect("solve_board should succeed");
+    let best = solution.cards.iter().map(|c| c.score).max().unwrap();
+
+    let result = service
+        .play_card(&handle, user_card, user_seat)
+        .expect("play_card should succeed");
+    assert!(result.accepted, "legal user card should be accepted");
+    let reply = result.ai_plays.first().expect("an opponent plays next");
+    assert!(
+        reply.reason.starts_with("mc-dds"),
+        "expected the native DDS solver, got reason {}",
+        reply.reason,
+    );
+    let reply_score = solution
+        .cards
+        .iter()
+        .find(|c| c.suit == reply.card.suit && c.rank == dds_rank(reply.card.rank))
+        .map(|c| c.score);
+    assert_eq!(
+        reply_score,
+        Some(best),
+        "{:?} {:?} is not double-dummy optimal",
+        reply.seat,
+        reply.card,
+    );
+}
+
 #[test]
 fn decline_play_transitions_to_explanation() {
     let mut service = ServicePortImpl::new();
@@ -581,3 +703,363 @@ fn playing_viewport_shows_both_hands_on_declarer_swap() {
         "table should be rotated 180° when effective_user_seat == North",
     );
 }
+
+#[test]
+fn claim_is_checked_against_the_remaining_cards() {
+    let mut service = ServicePortImpl::new();
+    let (handle, _contract) = drive_to_prompt(&mut service, 42);
+    assert!(
+        service.claim(&handle, Seat::South, 0).is_err(),
+        "claims are only possible in the play phase",
+    );
+    service
+        .enter_play(&handle, None)
+        .expect("enter_play should succeed");
+
+    let greedy = service
+        .claim(&handle, Seat::South, 13)
+        .expect("claim should not error");
+    assert!(!greedy.accepted, "the defence has a trick on this deal");
+    assert!(greedy.guaranteed_tricks < 13);
+    assert!(
+        !greedy.counter_line.is_empty(),
+        "a rejected claim shows the line that beats it",
+    );
+    let viewport = service.get_playing_viewport(&handle).unwrap();
+    assert!(viewport.is_some(), "play continues after a rejected claim");
+
+    let safe = service
+        .claim(&handle, Seat::South, greedy.guaranteed_tricks)
+        .expect("claim should not error");
+    assert!(safe.accepted, "claiming the guaranteed tricks must stand");
+    assert!(safe.score.is_some());
+    assert!(service.get_explanation_viewport(&handle).unwrap().is_some());
+}
+
+#[test]
+fn undo_after_the_auction_reopens_bidding() {
+    let mut service = ServicePortImpl::new();
+    let mut config = make_config("nt-bundle", 42);
+    config.play_preference = Some(bridge_session::types::PlayPreference::Prompt);
+    config.undo_policy = Some(bridge_session::types::UndoPolicy::Allowed);
+    let handle = service
+        .create_drill_session(config)
+        .expect("create_drill_session should succeed");
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    complete_auction(&mut service, &handle);
+
+    let undone = service
+        .undo_last_action(&handle)
+        .expect("the last bid should be undoable");
+    assert_eq!(undone.phase, bridge_session::types::GamePhase::Bidding);
+    let viewport = service
+        .get_bidding_viewport(&handle)
+        .unwrap()
+        .expect("bidding viewport after undo");
+    assert!(viewport.is_user_turn, "undo returns the turn to the user");
+
+    complete_auction(&mut service, &handle);
+    assert!(service
+        .get_declarer_prompt_viewport(&handle)
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn graded_bids_cannot_be_undone_by_default() {
+    let mut service = ServicePortImpl::new();
+    let (handle, _contract) = drive_to_prompt(&mut service, 42);
+
+    assert!(matches!(
+        service.undo_last_action(&handle),
+        Err(bridge_service::ServiceError::Undo(
+            bridge_session::session::UndoError::Forbidden
+        )),
+    ));
+}
+
+#[test]
+fn forbidden_undo_policy_is_an_error() {
+    let mut service = ServicePortImpl::new();
+    let mut config = make_config("nt-bundle", 42);
+    config.undo_policy = Some(bridge_session::types::UndoPolicy::Forbidden);
+    let handle = service
+        .create_drill_session(config)
+        .expect("create_drill_session should succeed");
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    complete_auction(&mut service, &handle);
+
+    assert!(matches!(
+        service.undo_last_action(&handle),
+        Err(bridge_service::ServiceError::Undo(
+            bridge_session::session::UndoError::Forbidden
+        )),
+    ));
+}
+
+#[test]
+fn auction_replay_walks_every_call_of_the_finished_auction() {
+    let mut service = ServicePortImpl::new();
+    let handle = create_test_session(&mut service, "nt-bundle", 42);
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    assert!(
+        service
+            .get_auction_replay_viewport(&handle)
+            .unwrap()
+            .is_none(),
+        "no replay while bidding"
+    );
+
+    complete_auction(&mut service, &handle);
+    let replay = service
+        .get_auction_replay_viewport(&handle)
+        .unwrap()
+        .expect("replay after the auction");
+
+    assert_eq!(replay.steps.len(), replay.auction_entries.len());
+    assert_eq!(replay.all_hands.len(), 4);
+    for (i, step) in replay.steps.iter().enumerate() {
+        assert_eq!(step.index, i);
+        assert_eq!(step.call, replay.auction_entries[i].call);
+        assert_eq!(step.is_user, step.seat == Seat::South);
+        assert_eq!(step.grade.is_some(), step.is_user);
+    }
+
+    // The user followed the expected bid throughout, so every graded call passes.
+    assert!(replay
+        .steps
+        .iter()
+        .filter_map(|s| s.grade)
+        .all(|g| g == bridge_session::session::BidGrade::Correct));
+
+    let opening = replay
+        .steps
+        .iter()
+        .find(|s| matches!(s.call, Call::Bid { .. }))
+        .expect("the auction has an opening bid");
+    let meaning = opening
+        .matched_meaning
+        .as_ref()
+        .expect("the opening bid matches a convention meaning");
+    assert!(opening.active_modules.contains(&meaning.module_id));
+    assert!(opening.teaching.is_some());
+    assert!(!opening.beliefs_after[&opening.seat].constraints.is_empty());
+}
+
+/// A finished nt-bundle auction from a live session, as an external record.
+fn played_auction(seed: u64) -> (Deal, Auction) {
+    let mut service = ServicePortImpl::new();
+    let handle = create_test_session(&mut service, "nt-bundle", seed);
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    complete_auction(&mut service, &handle);
+    let replay = service
+        .get_auction_replay_viewport(&handle)
+        .unwrap()
+        .expect("replay after the auction");
+    let deal = Deal {
+        hands: replay.all_hands,
+        dealer: replay.dealer,
+        vulnerability: replay.vulnerability,
+    };
+    let auction = Auction {
+        entries: replay
+            .auction_entries
+            .into_iter()
+            .map(|e| AuctionEntry {
+                seat: e.seat,
+                call: e.call,
+            })
+            .collect(),
+        is_complete: true,
+    };
+    (deal, auction)
+}
+
+fn analysis_request(deal: Deal, auction: Auction) -> AuctionAnalysisRequest {
+    let config = make_config("nt-bundle", 0);
+    AuctionAnalysisRequest {
+        convention_id: config.convention_id,
+        system_config: config.system_config,
+        base_module_ids: config.base_module_ids,
+        deal,
+        auction,
+    }
+}
+
+#[test]
+fn analyzing_the_models_own_auction_finds_no_divergence() {
+    let service = ServicePortImpl::new();
+    let (deal, auction) = played_auction(42);
+
+    let analysis = service
+        .analyze_auction(analysis_request(deal, auction.clone()))
+        .expect("analysis should succeed");
+
+    assert!(!analysis.decisions.is_empty());
+    for decision in &analysis.decisions {
+        assert!(matches!(decision.seat, Seat::North | Seat::South));
+        assert_eq!(decision.call, auction.entries[decision.index].call);
+    }
+    // North's opening may be scripted by the drill; South bid what the model expected.
+    assert!(analysis
+        .decisions
+        .iter()
+        .filter(|d| d.seat == Seat::South)
+        .all(|d| d.feedback.grade == bridge_session::session::BidGrade::Correct));
+    assert_eq!(analysis.first_divergence, None);
+}
+
+#[test]
+fn analysis_reports_the_first_call_that_leaves_the_model() {
+    let service = ServicePortImpl::new();
+    let (deal, auction) = played_auction(42);
+    let baseline = service
+        .analyze_auction(analysis_request(deal.clone(), auction.clone()))
+        .expect("analysis should succeed");
+    let bid = baseline
+        .decisions
+        .iter()
+        .find(|d| d.seat == Seat::South && matches!(d.call, Call::Bid { .. }))
+        .expect("South bid at least once");
+
+    // Pass instead of the model's bid; the rest of the auction is dropped.
+    let mut diverged = auction.clone();
+    diverged.entries.truncate(bid.index + 1);
+    diverged.entries[bid.index].call = Call::Pass;
+    diverged.is_complete = false;
+
+    let analysis = service
+        .analyze_auction(analysis_request(deal, diverged))
+        .expect("analysis should succeed");
+    assert_eq!(analysis.first_divergence, Some(bid.index));
+    let decision = analysis.decisions.last().unwrap();
+    assert_eq!(decision.index, bid.index);
+    assert_eq!(decision.feedback.expected_call.as_ref(), Some(&bid.call));
+    assert!(decision.expected_meaning.is_some());
+}
+
+#[test]
+fn analysis_rejects_calls_out_of_turn() {
+    let service = ServicePortImpl::new();
+    let (deal, mut auction) = played_auction(42);
+    auction.entries.remove(0);
+
+    let err = service
+        .analyze_auction(analysis_request(deal, auction))
+        .unwrap_err();
+    assert!(
+        matches!(err, bridge_service::ServiceError::InvalidHandRecord(_)),
+        "got {err:?}"
+    );
+}
+
+#[test]
+fn concurrent_sessions_are_driven_independently() {
+    let mut service = ServicePortImpl::with_session_limits(SessionLimits {
+        capacity: 2,
+        ttl: None,
+    });
+    let first = create_test_session(&mut service, "nt-bundle", 42);
+    let second = create_test_session(&mut service, "nt-bundle", 7);
+    assert_ne!(first, second);
+    service.start_drill(&first).expect("first session starts");
+    service.start_drill(&second).expect("second session starts");
+
+    complete_auction(&mut service, &first);
+    assert!(service.get_bidding_viewport(&second).unwrap().is_some());
+    assert!(service
+        .get_auction_replay_viewport(&first)
+        .unwrap()
+        .is_some());
+
+    service.destroy_drill_session(&first).unwrap();
+    assert!(matches!(
+        service.start_drill(&first),
+        Err(bridge_service::ServiceError::InvalidHandle(_))
+    ));
+    assert!(service.get_bidding_viewport(&second).unwrap().is_some());
+
+    // A third session evicts the least recently used one.
+    let third = create_test_session(&mut service, "nt-bundle", 9);
+    let fourth = create_test_session(&mut service, "nt-bundle", 11);
+    assert!(service.get_bidding_viewport(&second).is_err());
+    assert!(service.start_drill(&third).is_ok());
+    assert!(service.start_drill(&fourth).is_ok());
+}
+
+#[test]
+fn restored_snapshot_resumes_the_auction_where_it_left_off() {
+    let mut service = ServicePortImpl::new();
+    let handle = create_test_session(&mut service, "nt-bundle", 42);
+    service
+        .start_drill(&handle)
+        .expect("start_drill should succeed");
+    let snapshot = service.snapshot_drill_session(&handle).unwrap();
+
+    // Round-trip through JSON, as a client saving it would.
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let mut resumed = ServicePortImpl::new();
+    let restored = resumed
+        .restore_drill_session(serde_json::from_str(&json).unwrap())
+        .expect("restore should succeed");
+    assert_eq!(
+        serde_json::to_value(resumed.get_bidding_viewport(&restored).unwrap()).unwrap(),
+        serde_json::to_value(service.get_bidding_viewport(&handle).unwrap()).unwrap()
+    );
+
+    // Both copies finish the auction the same way.
+    complete_auction(&mut service, &handle);
+    complete_auction(&mut resumed, &restored);
+    let original = service
+        .get_auction_replay_viewport(&handle)
+        .unwrap()
+        .unwrap();
+    let replayed = resumed
+        .get_auction_replay_viewport(&restored)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&replayed.auction_entries).unwrap(),
+        serde_json::to_value(&original.auction_entries).unwrap()
+    );
+    assert_eq!(replayed.contract, original.contract);
+}
+
+#[test]
+fn restored_snapshot_resumes_play() {
+    let mut service = ServicePortImpl::new();
+    let (handle, _) = drive_to_prompt(&mut service, 42);
+    service
+        .enter_play(&handle, None)
+        .expect("enter_play should succeed");
+    let snapshot = service.snapshot_drill_session(&handle).unwrap();
+
+    let mut resumed = ServicePortImpl::new();
+    let restored = resumed.restore_drill_session(snapshot).unwrap();
+    assert_eq!(
+        serde_json::to_value(resumed.get_playing_viewport(&restored).unwrap()).unwrap(),
+        serde_json::to_value(service.get_playing_viewport(&handle).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn snapshots_from_another_version_are_refused() {
+    let mut service = ServicePortImpl::new();
+    let handle = create_test_session(&mut service, "nt-bundle", 42);
+    let mut snapshot = service.snapshot_drill_session(&handle).unwrap();
+    snapshot.session.version += 1;
+
+    let err = service.restore_drill_session(snapshot).unwrap_err();
+    assert!(
+        matches!(err, bridge_service::ServiceError::Snapshot(_)),
+        "got {err:?}"
+    );
+}
@@ -28,6 +28,9 @@ fn base_config(seed: u64) -> SessionConfig {
         vulnerability: None,
         play_profile_id: None,
         vulnerability_distribution: None,
+        deal: None,
+        undo_policy: None,
+        module_weights: None,
     }
 }
 
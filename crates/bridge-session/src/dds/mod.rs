@@ -4,18 +4,21 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 
+use bridge_engine::hand_record::{rank_char, suit_char};
 use bridge_engine::types::{Card, Contract, PlayedCard, Rank, Seat, Suit};
 use serde::{Deserialize, Serialize};
 
 use crate::inference::types::DerivedRanges;
 
 pub mod evaluation;
+pub mod native;
 pub mod pbn;
 pub mod sampling;
 pub mod suggest;
 
 pub use evaluation::evaluate_cards;
-pub use pbn::remaining_cards_to_pbn;
+pub use native::{mc_dds_suggest_native, native_solver, solve_board};
+pub use pbn::{pbn_to_remaining_cards, remaining_cards_to_pbn};
 pub use sampling::sample_deals;
 pub use suggest::mc_dds_suggest;
 
@@ -30,6 +33,8 @@ pub type DdsSolverFn = dyn FnMut(SolveBoardRequest) -> DdsFuture;
 pub enum DdsError {
     #[error("DDS solve failed: {0}")]
     SolveFailed(String),
+    #[error("Invalid DDS request: {0}")]
+    InvalidRequest(String),
 }
 
 /// Positional DDS solve request built from the current play state.
@@ -121,33 +126,6 @@ pub(crate) fn dds_card_key(suit: Suit, rank: u8) -> Option<String> {
     Some(format!("{}{}", suit_char(suit), rank_char_from_u8(rank)?))
 }
 
-pub(crate) fn suit_char(suit: Suit) -> char {
-    match suit {
-        Suit::Spades => 'S',
-        Suit::Hearts => 'H',
-        Suit::Diamonds => 'D',
-        Suit::Clubs => 'C',
-    }
-}
-
-pub(crate) fn rank_char(rank: Rank) -> char {
-    match rank {
-        Rank::Ace => 'A',
-        Rank::King => 'K',
-        Rank::Queen => 'Q',
-        Rank::Jack => 'J',
-        Rank::Ten => 'T',
-        Rank::Nine => '9',
-        Rank::Eight => '8',
-        Rank::Seven => '7',
-        Rank::Six => '6',
-        Rank::Five => '5',
-        Rank::Four => '4',
-        Rank::Three => '3',
-        Rank::Two => '2',
-    }
-}
-
 pub(crate) fn rank_char_from_u8(rank: u8) -> Option<char> {
     rank_from_u8(rank).map(rank_char)
 }
@@ -210,13 +188,10 @@ pub(crate) fn seat_to_dds_index(seat: Seat) -> u8 {
 #[cfg(test)]
 pub(crate) mod test_support {
     use std::collections::HashMap;
-    use std::future::Future;
-    use std::pin::Pin;
-    use std::ptr;
-    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
     use bridge_engine::types::{Card, Rank, Suit};
 
+    pub(crate) use super::native::block_on;
     use super::{DdsError, DdsFuture, SolveBoardRequest, SolveBoardResponse};
 
     pub(crate) fn card(suit: Suit, rank: Rank) -> Card {
@@ -236,35 +211,4 @@ pub(crate) mod test_support {
             Box::pin(async move { result })
         }
     }
-
-    pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
-        let waker = noop_waker();
-        let mut context = Context::from_waker(&waker);
-        let mut future = Box::pin(future);
-
-        loop {
-            match Future::poll(Pin::as_mut(&mut future), &mut context) {
-                Poll::Ready(output) => return output,
-                Poll::Pending => std::thread::yield_now(),
-            }
-        }
-    }
-
-    fn noop_waker() -> Waker {
-        // SAFETY: the vtable never dereferences the data pointer and is valid for a null pointer.
-        unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &NOOP_WAKER_VTABLE)) }
-    }
-
-    static NOOP_WAKER_VTABLE: RawWakerVTable = RawWakerVTable::new(
-        clone_noop_waker,
-        noop_waker_action,
-        noop_waker_action,
-        noop_waker_action,
-    );
-
-    unsafe fn clone_noop_waker(_data: *const ()) -> RawWaker {
-        RawWaker::new(ptr::null(), &NOOP_WAKER_VTABLE)
-    }
-
-    unsafe fn noop_waker_action(_data: *const ()) {}
 }
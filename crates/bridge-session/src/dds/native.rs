@@ -0,0 +1,261 @@
+//! Native double-dummy solver behind the `DdsSolverFn` boundary.
+//!
+//! Wraps `bridge_engine::DoubleDummySolver` so MC+DDS search can run without
+//! a JS solver: in tests, the CLI, and server-side hosts.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use bridge_engine::double_dummy::{DoubleDummySolver, PlayPosition};
+use bridge_engine::types::{Card, Seat, Suit};
+
+use super::pbn::pbn_to_remaining_cards;
+use super::{
+    mc_dds_suggest, rank_from_u8, rank_to_u8, DdsCardResult, DdsError, DdsFuture, McddParams,
+    McddResult, SolveBoardRequest, SolveBoardResponse, PBN_SEAT_ORDER, PBN_SUIT_ORDER,
+};
+
+/// Solve a single DDS request with a fresh solver.
+pub fn solve_board(request: &SolveBoardRequest) -> Result<SolveBoardResponse, DdsError> {
+    solve_board_with(&mut DoubleDummySolver::new(), request)
+}
+
+/// Solve a single DDS request, reusing `solver`'s transposition table.
+pub fn solve_board_with(
+    solver: &mut DoubleDummySolver,
+    request: &SolveBoardRequest,
+) -> Result<SolveBoardResponse, DdsError> {
+    let position = request_to_position(request)?;
+    let results = solver
+        .solve_position(&position)
+        .map_err(|e| DdsError::SolveFailed(e.to_string()))?;
+
+    Ok(SolveBoardResponse {
+        cards: results
+            .into_iter()
+            .map(|result| DdsCardResult {
+                suit: result.card.suit,
+                rank: rank_to_u8(result.card.rank),
+                score: i32::from(result.tricks),
+            })
+            .collect(),
+    })
+}
+
+/// Build a `DdsSolverFn`-compatible closure backed by the native solver.
+/// The closure owns one solver so consecutive requests share its table.
+pub fn native_solver() -> impl FnMut(SolveBoardRequest) -> DdsFuture {
+    let mut solver = DoubleDummySolver::new();
+    move |request: SolveBoardRequest| {
+        let result = solve_board_with(&mut solver, &request);
+        Box::pin(async move { result })
+    }
+}
+
+/// Synchronous MC+DDS suggestion using the native solver.
+pub fn mc_dds_suggest_native(
+    params: &McddParams,
+    use_constraints: bool,
+    rng: &mut impl rand::Rng,
+) -> Option<McddResult> {
+    let mut solver = native_solver();
+    block_on(mc_dds_suggest(params, use_constraints, rng, &mut solver))
+}
+
+fn request_to_position(request: &SolveBoardRequest) -> Result<PlayPosition, DdsError> {
+    let invalid = |msg: String| DdsError::InvalidRequest(msg);
+
+    let hands = pbn_to_remaining_cards(&request.remain_cards_pbn)?;
+    let trump = match request.trump {
+        0..=3 => Some(PBN_SUIT_ORDER[request.trump as usize]),
+        4 => None,
+        other => return Err(invalid(format!("unknown trump index {other}"))),
+    };
+    let leader: Seat = *PBN_SEAT_ORDER
+        .get(request.first as usize)
+        .ok_or_else(|| invalid(format!("unknown seat index {}", request.first)))?;
+
+    if request.current_trick_suit.len() != request.current_trick_rank.len() {
+        return Err(invalid(
+            "current trick suits and ranks differ in length".to_string(),
+        ));
+    }
+    let current_trick = request
+        .current_trick_suit
+        .iter()
+        .zip(&request.current_trick_rank)
+        .map(|(&suit, &rank)| {
+            let suit: Suit = *PBN_SUIT_ORDER
+                .get(suit as usize)
+                .ok_or_else(|| invalid(format!("unknown suit index {suit}")))?;
+            let rank = rank_from_u8(rank).ok_or_else(|| invalid(format!("unknown rank {rank}")))?;
+            Ok(Card { suit, rank })
+        })
+        .collect::<Result<Vec<_>, DdsError>>()?;
+
+    Ok(PlayPosition {
+        hands,
+        trump,
+        leader,
+        current_trick,
+    })
+}
+
+/// Drive a future that never waits on I/O (every native solve is ready
+/// immediately) to completion on the current thread.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match Future::poll(Pin::as_mut(&mut future), &mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::yield_now(),
+        }
+    }
+}
+
+fn noop_waker() -> Waker {
+    // SAFETY: the vtable never dereferences the data pointer and is valid for a null pointer.
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &NOOP_WAKER_VTABLE)) }
+}
+
+static NOOP_WAKER_VTABLE: RawWakerVTable = RawWakerVTable::new(
+    clone_noop_waker,
+    noop_waker_action,
+    noop_waker_action,
+    noop_waker_action,
+);
+
+unsafe fn clone_noop_waker(_data: *const ()) -> RawWaker {
+    RawWaker::new(ptr::null(), &NOOP_WAKER_VTABLE)
+}
+
+unsafe fn noop_waker_action(_data: *const ()) {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bridge_engine::types::{BidSuit, Contract, PlayedCard, Rank, Seat, Suit};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::dds::test_support::card;
+    use crate::dds::{McddParams, SolveBoardRequest};
+
+    use super::{mc_dds_suggest_native, solve_board};
+
+    fn score_of(response: &crate::dds::SolveBoardResponse, suit: Suit, rank: u8) -> i32 {
+        response
+            .cards
+            .iter()
+            .find(|c| c.suit == suit && c.rank == rank)
+            .map(|c| c.score)
+            .expect("card scored")
+    }
+
+    #[test]
+    fn solve_board_scores_every_legal_lead() {
+        // North leads in notrump and owns every spade winner.
+        let request = SolveBoardRequest {
+            trump: 4,
+            first: 0,
+            current_trick_suit: vec![],
+            current_trick_rank: vec![],
+            remain_cards_pbn: "N:AKQ... 2..32. ..AK.2 ..QJ.3".to_string(),
+        };
+        let response = solve_board(&request).unwrap();
+        assert_eq!(response.cards.len(), 3);
+        assert_eq!(score_of(&response, Suit::Spades, 14), 3);
+    }
+
+    #[test]
+    fn solve_board_handles_cards_on_the_table() {
+        // West led the club three; North must follow with a club.
+        let request = SolveBoardRequest {
+            trump: 0,
+            first: 3,
+            current_trick_suit: vec![3],
+            current_trick_rank: vec![3],
+            remain_cards_pbn: "N:A...A2 KQ...4 2..A.5 ...76".to_string(),
+        };
+        let response = solve_board(&request).unwrap();
+        let suits = response.cards.iter().map(|c| c.suit).collect::<Vec<_>>();
+        assert_eq!(suits, vec![Suit::Clubs, Suit::Clubs]);
+        assert_eq!(score_of(&response, Suit::Clubs, 14), 2);
+    }
+
+    #[test]
+    fn solve_board_rejects_bad_requests() {
+        let mut request = SolveBoardRequest {
+            trump: 7,
+            first: 0,
+            current_trick_suit: vec![],
+            current_trick_rank: vec![],
+            remain_cards_pbn: "N:A... K... Q... J...".to_string(),
+        };
+        assert!(solve_board(&request).is_err());
+        request.trump = 4;
+        request.remain_cards_pbn = "N:AK... K... Q... J...".to_string();
+        assert!(solve_board(&request).is_err());
+    }
+
+    #[test]
+    fn native_mc_dds_takes_the_obvious_winner() {
+        // Leading the diamond loses the ace whenever West holds both top
+        // diamonds; cashing the spade ace first never costs a trick.
+        let remaining_cards = HashMap::from([
+            (
+                Seat::North,
+                vec![
+                    card(Suit::Spades, Rank::Ace),
+                    card(Suit::Diamonds, Rank::Two),
+                ],
+            ),
+            (
+                Seat::East,
+                vec![
+                    card(Suit::Spades, Rank::King),
+                    card(Suit::Spades, Rank::Queen),
+                ],
+            ),
+            (
+                Seat::South,
+                vec![
+                    card(Suit::Hearts, Rank::Two),
+                    card(Suit::Hearts, Rank::Three),
+                ],
+            ),
+            (
+                Seat::West,
+                vec![
+                    card(Suit::Diamonds, Rank::Ace),
+                    card(Suit::Diamonds, Rank::King),
+                ],
+            ),
+        ]);
+        let params = McddParams {
+            seat: Seat::North,
+            legal_plays: remaining_cards[&Seat::North].clone(),
+            contract: Contract {
+                level: 1,
+                strain: BidSuit::NoTrump,
+                doubled: false,
+                redoubled: false,
+                declarer: Seat::North,
+            },
+            current_trick: Vec::<PlayedCard>::new(),
+            remaining_cards,
+            visible_seats: vec![Seat::North, Seat::South],
+            beliefs: HashMap::new(),
+        };
+
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        let result = mc_dds_suggest_native(&params, false, &mut rng).unwrap();
+        assert_eq!(result.best_card, card(Suit::Spades, Rank::Ace));
+    }
+}
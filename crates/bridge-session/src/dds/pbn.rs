@@ -3,9 +3,10 @@
 use std::collections::HashMap;
 
 use bridge_engine::constants::rank_index;
+use bridge_engine::hand_record::{rank_char, rank_from_char};
 use bridge_engine::types::{Card, Seat};
 
-use super::{rank_char, PBN_SEAT_ORDER, PBN_SUIT_ORDER};
+use super::{DdsError, PBN_SEAT_ORDER, PBN_SUIT_ORDER};
 
 /// Convert remaining cards to DDS PBN format.
 pub fn remaining_cards_to_pbn(hands: &HashMap<Seat, Vec<Card>>) -> String {
@@ -16,6 +17,47 @@ pub fn remaining_cards_to_pbn(hands: &HashMap<Seat, Vec<Card>>) -> String {
     format!("N:{}", hand_strs.join(" "))
 }
 
+/// Parse a DDS remaining-cards PBN string (`"N:AK.T.7.2 ..."`) back into
+/// hands. The first hand belongs to the seat before the colon; the rest
+/// follow clockwise.
+pub fn pbn_to_remaining_cards(pbn: &str) -> Result<HashMap<Seat, Vec<Card>>, DdsError> {
+    let invalid = |msg: &str| DdsError::InvalidRequest(format!("{msg}: {pbn}"));
+
+    let (first, rest) = pbn
+        .split_once(':')
+        .ok_or_else(|| invalid("missing seat prefix"))?;
+    let first_index = match first.trim() {
+        "N" => 0,
+        "E" => 1,
+        "S" => 2,
+        "W" => 3,
+        _ => return Err(invalid("unknown seat prefix")),
+    };
+
+    let hand_strs = rest.split_whitespace().collect::<Vec<_>>();
+    if hand_strs.len() != 4 {
+        return Err(invalid("expected four hands"));
+    }
+
+    let mut hands = HashMap::new();
+    for (offset, hand_str) in hand_strs.iter().enumerate() {
+        let seat = PBN_SEAT_ORDER[(first_index + offset) % 4];
+        let suit_strs = hand_str.split('.').collect::<Vec<_>>();
+        if suit_strs.len() != 4 {
+            return Err(invalid("expected four suits per hand"));
+        }
+        let mut cards = Vec::new();
+        for (suit, ranks) in PBN_SUIT_ORDER.iter().zip(suit_strs) {
+            for c in ranks.chars() {
+                let rank = rank_from_char(c).ok_or_else(|| invalid("unknown rank"))?;
+                cards.push(Card { suit: *suit, rank });
+            }
+        }
+        hands.insert(seat, cards);
+    }
+    Ok(hands)
+}
+
 fn cards_to_pbn_hand(cards: &[Card]) -> String {
     let mut suit_groups = PBN_SUIT_ORDER
         .iter()
@@ -48,7 +90,7 @@ mod tests {
 
     use crate::dds::test_support::card;
 
-    use super::remaining_cards_to_pbn;
+    use super::{pbn_to_remaining_cards, remaining_cards_to_pbn};
 
     #[test]
     fn remaining_cards_to_pbn_orders_seats_suits_and_ranks() {
@@ -104,4 +146,22 @@ mod tests {
             "N:AK.T.7.2 QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ"
         );
     }
+
+    #[test]
+    fn pbn_to_remaining_cards_round_trips_and_rotates_from_prefix() {
+        let pbn = "N:AK.T.7.2 QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ";
+        let hands = pbn_to_remaining_cards(pbn).unwrap();
+        assert_eq!(remaining_cards_to_pbn(&hands), pbn);
+
+        let rotated = pbn_to_remaining_cards("E:QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ AK.T.7.2").unwrap();
+        assert_eq!(rotated, hands);
+    }
+
+    #[test]
+    fn pbn_to_remaining_cards_rejects_malformed_input() {
+        assert!(pbn_to_remaining_cards("AK.T.7.2 QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ").is_err());
+        assert!(pbn_to_remaining_cards("N:AK.T.7.2 QJ.K.A.75 9.3.QJ.AT").is_err());
+        assert!(pbn_to_remaining_cards("N:AK.T.7 QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ").is_err());
+        assert!(pbn_to_remaining_cards("N:AX.T.7.2 QJ.K.A.75 9.3.QJ.AT J5.A.T9.KQ").is_err());
+    }
 }
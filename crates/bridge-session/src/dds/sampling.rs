@@ -1,9 +1,13 @@
 //! Monte Carlo remaining-card sampling with optional L1 range filtering.
+//!
+//! Candidates are dealt as `CardSet`s from a shuffled pool of card
+//! positions, so a rejected candidate costs a shuffle and a few popcounts
+//! and allocates nothing. Only accepted deals are turned back into cards.
 
 use std::collections::{HashMap, HashSet};
 
 use bridge_engine::types::{Card, Seat};
-use bridge_engine::{calculate_hcp, get_suit_length, Hand};
+use bridge_engine::CardSet;
 use rand::seq::SliceRandom;
 use rand::Rng;
 
@@ -23,7 +27,6 @@ pub fn sample_deals(
 ) -> Vec<HashMap<Seat, Vec<Card>>> {
     let visible_set: HashSet<Seat> = visible_seats.iter().copied().collect();
     let mut non_visible_seats = Vec::new();
-    let mut seat_card_counts = HashMap::new();
     let mut unknown_pool = Vec::new();
 
     for seat in ALL_SEATS {
@@ -31,45 +34,50 @@ pub fn sample_deals(
         if visible_set.contains(&seat) {
             continue;
         }
-        non_visible_seats.push(seat);
-        seat_card_counts.insert(seat, cards.len());
-        unknown_pool.extend_from_slice(cards);
+        let seat_constraints = if use_constraints {
+            constraints.get(&seat)
+        } else {
+            None
+        };
+        non_visible_seats.push((seat, cards.len(), seat_constraints));
+        unknown_pool.extend(cards.iter().map(CardSet::position));
     }
 
     let mut results = Vec::new();
     let mut attempts = 0;
+    let mut shuffled_pool = unknown_pool.clone();
 
     while results.len() < count && attempts < max_attempts {
         attempts += 1;
 
-        let mut shuffled_pool = unknown_pool.clone();
+        shuffled_pool.copy_from_slice(&unknown_pool);
         shuffled_pool.shuffle(rng);
 
         let mut offset = 0;
-        let mut deal = HashMap::new();
-        let mut valid = true;
-
-        for seat in &non_visible_seats {
-            let needed = *seat_card_counts.get(seat).unwrap_or(&0);
-            let cards = shuffled_pool[offset..offset + needed].to_vec();
+        let valid = non_visible_seats.iter().all(|&(_, needed, ranges)| {
+            let positions = &shuffled_pool[offset..offset + needed];
             offset += needed;
-
-            if use_constraints {
-                if let Some(ranges) = constraints.get(seat) {
-                    if !satisfies_constraints(&cards, ranges) {
-                        valid = false;
-                        break;
-                    }
-                }
-            }
-
-            deal.insert(*seat, cards);
-        }
-
+            ranges.is_none_or(|ranges| {
+                let hand = positions
+                    .iter()
+                    .fold(CardSet::EMPTY, |set, &p| set | CardSet::from_position(p));
+                satisfies_constraints(hand, ranges)
+            })
+        });
         if !valid {
             continue;
         }
 
+        let mut deal = HashMap::new();
+        let mut offset = 0;
+        for &(seat, needed, _) in &non_visible_seats {
+            let positions = &shuffled_pool[offset..offset + needed];
+            offset += needed;
+            deal.insert(
+                seat,
+                positions.iter().map(|&p| CardSet::card_at(p)).collect(),
+            );
+        }
         for seat in ALL_SEATS {
             if visible_set.contains(&seat) {
                 deal.insert(
@@ -85,23 +93,14 @@ pub fn sample_deals(
     results
 }
 
-fn satisfies_constraints(cards: &[Card], ranges: &DerivedRanges) -> bool {
-    let hand = Hand {
-        cards: cards.to_vec(),
-    };
-    let hcp = calculate_hcp(&hand);
+fn satisfies_constraints(hand: CardSet, ranges: &DerivedRanges) -> bool {
+    let hcp = hand.hcp();
     if hcp < ranges.hcp.min || hcp > ranges.hcp.max {
         return false;
     }
 
-    let shape = get_suit_length(&hand);
-    for (suit, range) in &ranges.suit_lengths {
-        let suit_count = match suit {
-            bridge_engine::types::Suit::Spades => shape[0] as u32,
-            bridge_engine::types::Suit::Hearts => shape[1] as u32,
-            bridge_engine::types::Suit::Diamonds => shape[2] as u32,
-            bridge_engine::types::Suit::Clubs => shape[3] as u32,
-        };
+    for (&suit, range) in &ranges.suit_lengths {
+        let suit_count = hand.suit_length(suit) as u32;
         if suit_count < range.min || suit_count > range.max {
             return false;
         }
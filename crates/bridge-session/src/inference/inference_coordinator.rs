@@ -4,6 +4,7 @@
 use bridge_conventions::types::meaning::FactConstraint;
 use bridge_conventions::types::system_config::SystemConfig;
 use bridge_engine::types::{Auction, AuctionEntry, Seat};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::annotation_producer::produce_annotation;
@@ -29,12 +30,30 @@ fn to_extractor_input(
     }
 }
 
+/// Inference state at one point in the auction, for rolling back to it.
+/// Timelines and annotations only grow between checkpoints, so their
+/// lengths are enough to roll back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InferenceCheckpoint {
+    ns_timeline_len: usize,
+    ew_timeline_len: usize,
+    annotations_len: usize,
+}
+
 /// Coordinates NS and EW inference engines for a drill.
 /// Accepts optional `SystemConfig` for system-aware natural inference.
 /// Adapts bid results to inference extractor input, manages belief state
 /// accumulation per deal.
+///
+/// Serializes the belief state and natural provider only. The engines hold
+/// provider trait objects and are not carried; live sessions never set them.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InferenceCoordinator {
+    #[serde(skip)]
     ns_engine: Option<InferenceEngine>,
+    #[serde(skip)]
     ew_engine: Option<InferenceEngine>,
     belief_state: PublicBeliefState,
     natural_provider: NaturalInferenceProvider,
@@ -146,6 +165,32 @@ impl InferenceCoordinator {
         }
     }
 
+    /// Mark the current state so `rollback` can return to it.
+    pub fn checkpoint(&self) -> InferenceCheckpoint {
+        InferenceCheckpoint {
+            ns_timeline_len: self.get_ns_timeline().len(),
+            ew_timeline_len: self.get_ew_timeline().len(),
+            annotations_len: self.belief_state.annotations.len(),
+        }
+    }
+
+    /// Drop every bid processed since `checkpoint` was taken. Beliefs are
+    /// re-derived from the annotations that remain.
+    pub fn rollback(&mut self, checkpoint: InferenceCheckpoint) {
+        if let Some(ref mut ns) = self.ns_engine {
+            ns.truncate(checkpoint.ns_timeline_len);
+        }
+        if let Some(ref mut ew) = self.ew_engine {
+            ew.truncate(checkpoint.ew_timeline_len);
+        }
+        let mut annotations = std::mem::take(&mut self.belief_state.annotations);
+        annotations.truncate(checkpoint.annotations_len);
+        self.belief_state = create_initial_belief_state();
+        for annotation in annotations {
+            apply_annotation(&mut self.belief_state, annotation);
+        }
+    }
+
     /// Reset all inference state.
     pub fn reset(&mut self) {
         self.ns_engine = None;
@@ -86,6 +86,21 @@ impl InferenceEngine {
         &self.timeline
     }
 
+    /// Roll back to the first `len` bids of the timeline, dropping the
+    /// constraints the later ones added.
+    pub fn truncate(&mut self, len: usize) {
+        self.timeline.truncate(len);
+        for constraints in self.raw_constraints.values_mut() {
+            constraints.clear();
+        }
+        for snapshot in &self.timeline {
+            self.raw_constraints
+                .get_mut(&snapshot.entry.seat)
+                .unwrap()
+                .extend(snapshot.new_constraints.iter().cloned());
+        }
+    }
+
     /// Clear all accumulated constraints and timeline.
     pub fn reset(&mut self) {
         for seat in &SEATS {
@@ -19,7 +19,7 @@ pub mod private_belief;
 pub mod types;
 
 // Re-export key types at module level
-pub use inference_coordinator::InferenceCoordinator;
+pub use inference_coordinator::{InferenceCheckpoint, InferenceCoordinator};
 pub use inference_engine::InferenceEngine;
 pub use natural_inference::NaturalInferenceProvider;
 pub use posterior::PosteriorEngine;
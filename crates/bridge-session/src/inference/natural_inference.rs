@@ -7,6 +7,7 @@
 use bridge_conventions::types::system_config::SystemConfig;
 use bridge_engine::types::{Auction, AuctionEntry, BidSuit, Call, Seat, Suit};
 use bridge_engine::{bid_suit_to_suit, partner_seat};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::types::{HandInference, InferenceProvider, SuitInference};
@@ -22,6 +23,8 @@ fn default_sayc_1nt_response() -> (u32, u32) {
 }
 
 /// Natural bidding inference provider parameterized by system config.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NaturalInferenceProvider {
     /// 1NT opening HCP range from system config.
     nt_opening_min: u32,
@@ -3,25 +3,32 @@
 //! `PosteriorEngine` runs Monte Carlo rejection sampling against L1 DerivedRanges,
 //! conditioned on the observer's known cards. Used by play heuristics gated by
 //! the active PlayProfile's `use_posterior` flag.
+//!
+//! Hands are dealt and tested as `CardSet`s, so each rejected candidate is a
+//! shuffle plus a handful of popcounts.
 
 use std::collections::HashMap;
 
 use bridge_engine::types::{Card, Seat, Suit};
-use bridge_engine::{calculate_hcp, get_suit_length, is_balanced, Hand};
+use bridge_engine::{is_balanced, CardSet};
 use rand::seq::SliceRandom;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 
 use super::types::DerivedRanges;
 
 // ── Constants ─────────────────────────────────────────────────────
 
-/// Target accepted samples. 200 gives ±1 HCP and ±0.5 suit-length resolution.
-const SAMPLE_BUDGET: usize = 200;
+/// Target accepted samples. Bitboard candidates are cheap enough that 2,000
+/// fit the time 200 used to take, for about ±0.3 HCP and ±0.15 suit-length
+/// resolution.
+const SAMPLE_BUDGET: usize = 2_000;
 
-/// Max attempts before giving up. 10× budget handles typical bridge constraints
-/// (5–20 facts) with >99% chance of reaching budget.
-const MAX_ATTEMPTS: usize = 2000;
+/// Max attempts before giving up. 100× budget lets tight constraints (a
+/// 15–17 opener, a long suit) still reach budget instead of settling for a
+/// handful of samples.
+const MAX_ATTEMPTS: usize = SAMPLE_BUDGET * 100;
 
 /// Samples needed for full confidence. At 50 samples the law of large numbers
 /// gives ±2 HCP precision — enough to trust for lead selection.
@@ -30,22 +37,58 @@ const FULL_CONFIDENCE_THRESHOLD: f64 = 50.0;
 // ── Sampled deal ──────────────────────────────────────────────────
 
 /// A single accepted sample: hands for unknown seats only.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct SampledDeal {
-    hands: HashMap<Seat, Vec<Card>>,
+    hands: HashMap<Seat, CardSet>,
 }
 
 // ── PosteriorEngine ───────────────────────────────────────────────
 
 /// Monte Carlo rejection sampler over unknown hands, constrained by L1 DerivedRanges.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PosteriorEngine {
     samples: Vec<SampledDeal>,
     constraints: HashMap<Seat, DerivedRanges>,
     _observer_seat: Seat,
     known_cards: HashMap<Seat, Vec<Card>>,
+    #[serde(with = "rng_position")]
     rng: ChaCha8Rng,
 }
 
+/// Serializes the sampler's RNG as seed, stream and word position, so a
+/// restored engine draws the same numbers the original would have.
+mod rng_position {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RngPosition {
+        seed: [u8; 32],
+        stream: u64,
+        word_pos: u128,
+    }
+
+    pub fn serialize<S: Serializer>(rng: &ChaCha8Rng, serializer: S) -> Result<S::Ok, S::Error> {
+        RngPosition {
+            seed: rng.get_seed(),
+            stream: rng.get_stream(),
+            word_pos: rng.get_word_pos(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ChaCha8Rng, D::Error> {
+        let position = RngPosition::deserialize(deserializer)?;
+        let mut rng = ChaCha8Rng::from_seed(position.seed);
+        rng.set_stream(position.stream);
+        rng.set_word_pos(position.word_pos);
+        Ok(rng)
+    }
+}
+
 impl PosteriorEngine {
     /// Create a new engine and immediately run sampling.
     pub fn new(
@@ -69,18 +112,13 @@ impl PosteriorEngine {
     fn run_sampling(&mut self) {
         self.samples.clear();
 
-        let deck = bridge_engine::create_deck();
+        let known = self.known_cards.values().flatten().collect::<CardSet>();
 
-        // Remove all known cards from the pool
-        let known_set: std::collections::HashSet<(Suit, bridge_engine::types::Rank)> = self
-            .known_cards
-            .values()
-            .flat_map(|cards| cards.iter().map(|c| (c.suit, c.rank)))
-            .collect();
-
-        let unknown_pool: Vec<Card> = deck
-            .into_iter()
-            .filter(|c| !known_set.contains(&(c.suit, c.rank)))
+        // Unknown cards as bit positions, in deck order.
+        let unknown_pool: Vec<u32> = bridge_engine::create_deck()
+            .iter()
+            .filter(|c| !known.contains(c))
+            .map(CardSet::position)
             .collect();
 
         // Identify seats that need cards dealt. A seat is "unknown" if we don't
@@ -111,53 +149,56 @@ impl PosteriorEngine {
         }
 
         let mut attempts = 0;
+        let mut pool_buf = unknown_pool.clone();
+        let mut candidate = [CardSet::EMPTY; 4];
 
         while self.samples.len() < SAMPLE_BUDGET && attempts < MAX_ATTEMPTS {
             attempts += 1;
 
             // Shuffle the unknown pool
-            let mut pool_buf = unknown_pool.clone();
+            pool_buf.copy_from_slice(&unknown_pool);
             pool_buf.shuffle(&mut self.rng);
 
             // Deal chunks to unknown seats
             let mut offset = 0;
-            let mut candidate = HashMap::new();
-            for &(seat, count) in &unknown_seats {
-                let hand_cards = pool_buf[offset..offset + count].to_vec();
-                candidate.insert(seat, hand_cards);
+            for (slot, &(_, count)) in candidate.iter_mut().zip(&unknown_seats) {
+                *slot = pool_buf[offset..offset + count]
+                    .iter()
+                    .fold(CardSet::EMPTY, |set, &p| set | CardSet::from_position(p));
                 offset += count;
             }
 
             // Check constraints for each unknown seat
-            if self.check_constraints(&candidate) {
-                self.samples.push(SampledDeal { hands: candidate });
+            if self.check_constraints(&unknown_seats, &candidate) {
+                let hands = unknown_seats
+                    .iter()
+                    .zip(candidate)
+                    .map(|(&(seat, _), hand)| (seat, hand))
+                    .collect();
+                self.samples.push(SampledDeal { hands });
             }
         }
     }
 
-    /// Check if all unknown seats' dealt hands satisfy their DerivedRanges constraints.
-    fn check_constraints(&self, candidate: &HashMap<Seat, Vec<Card>>) -> bool {
-        for (seat, cards) in candidate {
-            if let Some(ranges) = self.constraints.get(seat) {
-                let hand = Hand {
-                    cards: cards.clone(),
-                };
-                let hcp = calculate_hcp(&hand);
+    /// Check if all unknown seats' dealt hands satisfy their DerivedRanges
+    /// constraints. `candidate[i]` is the hand dealt to `unknown_seats[i]`.
+    fn check_constraints(&self, unknown_seats: &[(Seat, usize)], candidate: &[CardSet]) -> bool {
+        for (&(seat, _), &hand) in unknown_seats.iter().zip(candidate) {
+            if let Some(ranges) = self.constraints.get(&seat) {
+                let hcp = hand.hcp();
                 if hcp < ranges.hcp.min || hcp > ranges.hcp.max {
                     return false;
                 }
 
-                let shape = get_suit_length(&hand);
                 for (&suit, range) in &ranges.suit_lengths {
-                    let idx = suit_to_shape_index(suit);
-                    let len = shape[idx] as u32;
+                    let len = hand.suit_length(suit) as u32;
                     if len < range.min || len > range.max {
                         return false;
                     }
                 }
 
                 if let Some(expected_balanced) = ranges.is_balanced {
-                    if is_balanced(&shape) != expected_balanced {
+                    if is_balanced(&hand.shape()) != expected_balanced {
                         return false;
                     }
                 }
@@ -176,11 +217,8 @@ impl PosteriorEngine {
         let mut sum = 0.0;
         let mut count = 0;
         for sample in &self.samples {
-            if let Some(cards) = sample.hands.get(&seat) {
-                let hand = Hand {
-                    cards: cards.clone(),
-                };
-                sum += calculate_hcp(&hand) as f64;
+            if let Some(hand) = sample.hands.get(&seat) {
+                sum += hand.hcp() as f64;
                 count += 1;
             }
         }
@@ -195,16 +233,11 @@ impl PosteriorEngine {
         if self.samples.is_empty() {
             return (3.25, 0.0);
         }
-        let idx = suit_to_shape_index(suit);
         let mut sum = 0.0;
         let mut count = 0;
         for sample in &self.samples {
-            if let Some(cards) = sample.hands.get(&seat) {
-                let hand = Hand {
-                    cards: cards.clone(),
-                };
-                let shape = get_suit_length(&hand);
-                sum += shape[idx] as f64;
+            if let Some(hand) = sample.hands.get(&seat) {
+                sum += hand.suit_length(suit) as f64;
                 count += 1;
             }
         }
@@ -260,19 +293,6 @@ impl PosteriorEngine {
     }
 }
 
-// ── Suit index helper ─────────────────────────────────────────────
-
-/// Maps Suit to the shape array index used by bridge_engine::get_suit_length.
-/// Spades=0, Hearts=1, Diamonds=2, Clubs=3.
-fn suit_to_shape_index(suit: Suit) -> usize {
-    match suit {
-        Suit::Spades => 0,
-        Suit::Hearts => 1,
-        Suit::Diamonds => 2,
-        Suit::Clubs => 3,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::super::types::NumberRange;
@@ -317,7 +337,7 @@ mod tests {
             engine.sample_count()
         );
 
-        // Mean HCP for unknown seats should be near 10 (±3 with 200 samples)
+        // Mean HCP for unknown seats should be near 10
         let (mean_hcp, conf) = engine.marginal_hcp(Seat::North);
         assert!(
             mean_hcp > 7.0 && mean_hcp < 13.0,
@@ -360,6 +380,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tight_constraints_still_reach_the_sample_budget() {
+        // A 15-17 balanced opener: a few percent of deals qualify.
+        // South holds every two, three and four plus the five of spades.
+        let low = [Rank::Two, Rank::Three, Rank::Four];
+        let mut south: Vec<Card> = bridge_engine::create_deck()
+            .into_iter()
+            .filter(|c| low.contains(&c.rank))
+            .collect();
+        south.push(Card {
+            suit: Suit::Spades,
+            rank: Rank::Five,
+        });
+        let mut known = HashMap::new();
+        known.insert(Seat::South, south);
+
+        let mut constraints = HashMap::new();
+        constraints.insert(
+            Seat::North,
+            DerivedRanges {
+                hcp: NumberRange { min: 15, max: 17 },
+                suit_lengths: HashMap::new(),
+                is_balanced: Some(true),
+            },
+        );
+
+        let engine = PosteriorEngine::new(Seat::South, known, constraints, 7);
+        assert_eq!(engine.sample_count(), SAMPLE_BUDGET);
+        for sample in &engine.samples {
+            let north = sample.hands[&Seat::North];
+            assert!((15..=17).contains(&north.hcp()));
+            assert!(is_balanced(&north.shape()));
+        }
+    }
+
     #[test]
     fn contradictory_constraints_zero_samples() {
         // Require North to have 40 HCP (impossible with 39 remaining after South's hand)
@@ -554,4 +609,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn serde_round_trip_resumes_the_same_random_sequence() {
+        let mut known = HashMap::new();
+        known.insert(Seat::South, make_13_card_hand(Suit::Spades));
+        let mut original = PosteriorEngine::new(Seat::South, known, HashMap::new(), 7);
+
+        let json = serde_json::to_string(&original).unwrap();
+        let mut restored: PosteriorEngine = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.sample_count(), original.sample_count());
+
+        // Both engines resample from the same RNG position.
+        let played = [bridge_engine::types::PlayedCard {
+            seat: Seat::West,
+            card: Card {
+                suit: Suit::Hearts,
+                rank: Rank::Ace,
+            },
+        }];
+        original.update_with_played_cards(&played);
+        restored.update_with_played_cards(&played);
+        assert_eq!(
+            restored.marginal_hcp(Seat::North),
+            original.marginal_hcp(Seat::North)
+        );
+        assert_eq!(restored.all_suit_lengths(), original.all_suit_lengths());
+    }
 }
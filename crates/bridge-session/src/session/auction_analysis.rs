@@ -0,0 +1,249 @@
+//! Auction analysis — grades an externally played auction against the
+//! seat strategies, without a drill session.
+//!
+//! Each analyzed seat's decision is graded exactly as the bidding controller
+//! grades a live user bid (`assemble_bid_feedback`). The first call that the
+//! controller would have rejected is reported as the point of divergence.
+
+use std::collections::HashMap;
+
+use bridge_conventions::teaching::teaching_types::TeachingProjection;
+use bridge_engine::types::{Auction, Call, Deal, Seat};
+use serde::{Deserialize, Serialize};
+
+use super::bid_feedback_builder::{assemble_bid_feedback, BidFeedbackDTO, BidGrade};
+use super::replay_viewport::{evaluate_turn, find_carrier_for_call, meaning_view};
+use super::session_state::SeatStrategy;
+use super::viewport_types::ReplayMeaningView;
+
+/// Input for analyzing an auction.
+pub struct AnalyzeAuctionInput<'a> {
+    pub deal: &'a Deal,
+    pub auction: &'a Auction,
+    /// Seats whose decisions are graded, e.g. both members of a partnership.
+    pub seats: &'a [Seat],
+    pub seat_strategies: &'a HashMap<Seat, SeatStrategy>,
+}
+
+/// One graded decision of the analyzed auction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionAnalysis {
+    /// Index into `auction.entries`.
+    pub index: usize,
+    pub seat: Seat,
+    pub call: Call,
+    pub feedback: BidFeedbackDTO,
+    /// What the actual call means in the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_meaning: Option<ReplayMeaningView>,
+    /// What the model's expected call would have meant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_meaning: Option<ReplayMeaningView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teaching: Option<TeachingProjection>,
+}
+
+/// Graded decisions for the analyzed seats, in auction order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionAnalysis {
+    pub decisions: Vec<DecisionAnalysis>,
+    /// Auction index of the first decision graded near-miss or incorrect.
+    pub first_divergence: Option<usize>,
+}
+
+/// Grade every decision of `input.seats` in the auction.
+///
+/// Seats without a hand or strategy are skipped rather than graded — there
+/// is nothing to compare the actual call against.
+pub fn analyze_auction(input: AnalyzeAuctionInput) -> AuctionAnalysis {
+    let mut decisions = Vec::new();
+
+    for (index, entry) in input.auction.entries.iter().enumerate() {
+        if !input.seats.contains(&entry.seat) {
+            continue;
+        }
+        let Some(strategy) = input.seat_strategies.get(&entry.seat) else {
+            continue;
+        };
+        let auction_before = Auction {
+            entries: input.auction.entries[..index].to_vec(),
+            is_complete: false,
+        };
+        let (expected, evaluation) =
+            evaluate_turn(strategy, input.deal, &auction_before, entry.seat);
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let feedback = assemble_bid_feedback(&entry.call, Some(&expected));
+        let pipeline_result = evaluation.as_ref().and_then(|e| e.pipeline_result.as_ref());
+        let actual_meaning = pipeline_result
+            .and_then(|pr| find_carrier_for_call(pr, &entry.call))
+            .map(meaning_view);
+        let expected_meaning = pipeline_result
+            .and_then(|pr| find_carrier_for_call(pr, &expected.call))
+            .map(meaning_view);
+
+        decisions.push(DecisionAnalysis {
+            index,
+            seat: entry.seat,
+            call: entry.call.clone(),
+            feedback,
+            actual_meaning,
+            expected_meaning,
+            teaching: evaluation.and_then(|e| e.teaching_projection),
+        });
+    }
+
+    // Same acceptance policy as the bidding controller: these grades block.
+    let first_divergence = decisions
+        .iter()
+        .find(|d| matches!(d.feedback.grade, BidGrade::NearMiss | BidGrade::Incorrect))
+        .map(|d| d.index);
+
+    AuctionAnalysis {
+        decisions,
+        first_divergence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heuristics::{BidResult, BiddingContext, BiddingStrategy};
+    use bridge_engine::constants::SEATS;
+    use bridge_engine::types::{AuctionEntry, BidSuit, Card, Hand, Rank, Suit, Vulnerability};
+
+    /// Opens 1NT when first to speak, passes otherwise.
+    struct OpensOneNotrump;
+    impl BiddingStrategy for OpensOneNotrump {
+        fn id(&self) -> &str {
+            "opens-1nt"
+        }
+        fn name(&self) -> &str {
+            "Opens 1NT"
+        }
+        fn suggest_bid(&self, ctx: &BiddingContext) -> Option<BidResult> {
+            let call = if ctx.auction.entries.is_empty() {
+                one_notrump()
+            } else {
+                Call::Pass
+            };
+            Some(BidResult {
+                call,
+                rule_name: None,
+                explanation: "Test strategy".to_string(),
+                ..Default::default()
+            })
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn one_notrump() -> Call {
+        Call::Bid {
+            level: 1,
+            strain: BidSuit::NoTrump,
+        }
+    }
+
+    fn make_deal() -> Deal {
+        let mut hands = HashMap::new();
+        for &seat in &SEATS {
+            hands.insert(
+                seat,
+                Hand {
+                    cards: vec![Card {
+                        suit: Suit::Clubs,
+                        rank: Rank::Two,
+                    }],
+                },
+            );
+        }
+        Deal {
+            hands,
+            dealer: Seat::North,
+            vulnerability: Vulnerability::None,
+        }
+    }
+
+    fn make_auction(calls: &[Call]) -> Auction {
+        Auction {
+            entries: calls
+                .iter()
+                .zip(
+                    [Seat::North, Seat::East, Seat::South, Seat::West]
+                        .iter()
+                        .cycle(),
+                )
+                .map(|(call, seat)| AuctionEntry {
+                    seat: *seat,
+                    call: call.clone(),
+                })
+                .collect(),
+            is_complete: true,
+        }
+    }
+
+    fn make_strategies() -> HashMap<Seat, SeatStrategy> {
+        SEATS
+            .iter()
+            .map(|&seat| (seat, SeatStrategy::Ai(Box::new(OpensOneNotrump) as _)))
+            .collect()
+    }
+
+    #[test]
+    fn only_the_requested_seats_are_graded() {
+        let deal = make_deal();
+        let auction = make_auction(&[one_notrump(), Call::Pass, Call::Pass, Call::Pass]);
+        let strategies = make_strategies();
+
+        let analysis = analyze_auction(AnalyzeAuctionInput {
+            deal: &deal,
+            auction: &auction,
+            seats: &[Seat::North, Seat::South],
+            seat_strategies: &strategies,
+        });
+
+        let graded: Vec<_> = analysis.decisions.iter().map(|d| d.seat).collect();
+        assert_eq!(graded, vec![Seat::North, Seat::South]);
+        assert!(analysis
+            .decisions
+            .iter()
+            .all(|d| d.feedback.grade == BidGrade::Correct));
+        assert_eq!(analysis.first_divergence, None);
+    }
+
+    #[test]
+    fn first_divergence_is_the_first_rejected_call() {
+        let deal = make_deal();
+        // North should open 1NT, then South should pass.
+        let auction = make_auction(&[
+            Call::Pass,
+            Call::Pass,
+            one_notrump(),
+            Call::Pass,
+            Call::Pass,
+            Call::Pass,
+        ]);
+        let strategies = make_strategies();
+
+        let analysis = analyze_auction(AnalyzeAuctionInput {
+            deal: &deal,
+            auction: &auction,
+            seats: &[Seat::North, Seat::South],
+            seat_strategies: &strategies,
+        });
+
+        assert_eq!(analysis.first_divergence, Some(0));
+        assert_eq!(analysis.decisions[0].feedback.grade, BidGrade::Incorrect);
+        assert_eq!(
+            analysis.decisions[0].feedback.expected_call,
+            Some(one_notrump())
+        );
+        assert_eq!(analysis.decisions[1].index, 2);
+    }
+}
@@ -20,6 +20,7 @@ use crate::phase_machine::is_valid_transition;
 use crate::types::{GamePhase, PlayPreference};
 
 use super::bid_feedback_builder::{assemble_bid_feedback, BidFeedbackDTO, BidGrade};
+use super::history::{capture, commit, UndoableAction};
 use super::session_state::{get_current_turn, DebugLogEntry, SeatStrategy, SessionState};
 use super::viewport_types::{BidAttemptRecord, BidHistoryEntryView, ReviewCondition};
 
@@ -69,6 +70,9 @@ pub fn process_bid(
         _ => return empty_result(),
     };
 
+    // A rejected bid still leaves a graded attempt, so both outcomes undo.
+    let checkpoint = capture(state, UndoableAction::Bid);
+
     // Check if there's a convention strategy for grading
     let expected_result = get_expected_bid(state, current_turn, seat_strategies);
 
@@ -101,6 +105,7 @@ pub fn process_bid(
             state.deal.hands.get(&current_turn),
             seat_strategies,
         ));
+        commit(state, checkpoint);
         return BidProcessResult {
             accepted: false,
             feedback: Some(feedback),
@@ -112,14 +117,18 @@ pub fn process_bid(
     }
 
     // Apply user's bid to auction
-    apply_bid_and_run_ai(
+    let result = apply_bid_and_run_ai(
         state,
         current_turn,
         call,
         expected_result.as_ref(),
         seat_strategies,
         Some(feedback),
-    )
+    );
+    if result.accepted {
+        commit(state, checkpoint);
+    }
+    result
 }
 
 /// Run initial AI bids after drill start (before user's first turn).
@@ -1049,4 +1058,72 @@ mod tests {
         let feedback = result.feedback.unwrap();
         assert_eq!(feedback.grade, BidGrade::Correct);
     }
+
+    // ── Undo ───────────────────────────────────────────────────────
+
+    #[test]
+    fn undo_takes_back_the_bid_and_the_ai_bids_after_it() {
+        use crate::session::history::{undo_last_action, UndoError, UndoableAction};
+
+        let mut state = make_state();
+        state.deal.dealer = Seat::South;
+        state.undo_policy = crate::types::UndoPolicy::Allowed;
+        let strategies = make_strategies_all_pass();
+        let one_club = Call::Bid {
+            level: 1,
+            strain: BidSuit::Clubs,
+        };
+
+        let result = process_bid(&mut state, one_club.clone(), &strategies);
+        assert!(result.accepted);
+        assert_eq!(state.auction.entries.len(), 4);
+        assert_eq!(state.public_belief_state.annotations.len(), 4);
+
+        let undone = undo_last_action(&mut state).unwrap();
+        assert_eq!(undone.action, UndoableAction::Bid);
+        assert_eq!(undone.phase, GamePhase::Bidding);
+        assert!(state.auction.entries.is_empty());
+        assert!(state.bid_history.is_empty());
+        assert!(state.public_belief_state.annotations.is_empty());
+        assert_eq!(
+            undo_last_action(&mut state).unwrap_err(),
+            UndoError::NothingToUndo
+        );
+
+        // The same bid replays the same way.
+        let again = process_bid(&mut state, one_club, &strategies);
+        assert_eq!(again.ai_bids.len(), 3);
+        assert_eq!(state.auction.entries.len(), 4);
+    }
+
+    #[test]
+    fn bids_stand_as_first_made_unless_the_drill_allows_undo() {
+        use crate::session::history::{undo_last_action, UndoError};
+        use crate::types::UndoPolicy;
+
+        let wrong = Call::Bid {
+            level: 1,
+            strain: BidSuit::Clubs,
+        };
+        let mut state = make_state();
+        state.deal.dealer = Seat::South;
+        state.undo_policy = UndoPolicy::Allowed;
+        let strategies = make_strategies_with_user_strategy();
+        process_bid(&mut state, wrong.clone(), &strategies);
+        assert_eq!(state.pending_attempts.len(), 1);
+        undo_last_action(&mut state).unwrap();
+        assert!(state.pending_attempts.is_empty());
+
+        // Drills grade the first attempt, so by default a wrong bid stands.
+        let mut graded = make_state();
+        graded.deal.dealer = Seat::South;
+        assert_eq!(graded.undo_policy, UndoPolicy::PlayOnly);
+        process_bid(&mut graded, wrong, &strategies);
+        assert!(graded.history.is_empty());
+        assert_eq!(
+            undo_last_action(&mut graded).unwrap_err(),
+            UndoError::Forbidden
+        );
+        assert_eq!(graded.pending_attempts.len(), 1);
+    }
 }
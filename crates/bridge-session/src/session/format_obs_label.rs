@@ -141,6 +141,13 @@ pub fn format_obs_action(obs: &ObsPattern) -> String {
                 }
             }
             BidActionType::Relay => "relaying".to_string(),
+            BidActionType::Puppet => {
+                if s.is_empty() {
+                    "puppeting".to_string()
+                } else {
+                    format!("puppeting to {}", s)
+                }
+            }
             BidActionType::Overcall => format!("overcalling {}", fp),
             BidActionType::Double => "doubling".to_string(),
             BidActionType::Pass => "passing".to_string(),
@@ -207,6 +214,7 @@ mod tests {
             "redoubling"
         );
         assert_eq!(format_obs_action(&obs(BidActionType::Relay)), "relaying");
+        assert_eq!(format_obs_action(&obs(BidActionType::Puppet)), "puppeting");
         assert_eq!(format_obs_action(&obs(BidActionType::Force)), "forcing");
     }
 
@@ -0,0 +1,224 @@
+//! Undo history — a checkpoint before every user bid, card and claim, so the
+//! last action can be taken back with the whole session rolled back together.
+//!
+//! A checkpoint holds only what an action can change. The auction, bid
+//! history, debug log, completed tricks and inference annotations only grow
+//! between checkpoints, so they are recorded as lengths and truncated on
+//! undo; public beliefs are re-derived from the annotations that remain and
+//! the posterior sampler is rebuilt from the tricks. Small per-turn state
+//! (legal calls, wrong attempts, the trick in progress) is copied. The deal
+//! and drill configuration never change mid-drill and are not recorded. AI
+//! bids and cards that followed the user's action are rolled back with it
+//! and replay the same way once the user acts again.
+
+use bridge_engine::types::{Call, Contract, PlayedCard, Seat, Suit};
+use serde::{Deserialize, Serialize};
+
+use crate::inference::InferenceCheckpoint;
+use crate::types::{GamePhase, UndoPolicy};
+
+use super::session_state::{Claim, PlayState, SessionState};
+use super::viewport_types::BidAttemptRecord;
+
+/// A user action that can be taken back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UndoableAction {
+    Bid,
+    Card,
+    Claim,
+}
+
+/// Why `undo_last_action` refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum UndoError {
+    /// No user action since the drill started.
+    #[error("nothing to undo")]
+    NothingToUndo,
+    /// The drill's `UndoPolicy` does not allow taking the last action back.
+    #[error("undo is not allowed in this drill")]
+    Forbidden,
+}
+
+/// What `undo_last_action` took back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoResult {
+    pub action: UndoableAction,
+    /// Phase after the rollback.
+    pub phase: GamePhase,
+    /// Earlier actions that can still be undone.
+    pub remaining: usize,
+}
+
+/// Session state from just before a user action.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    action: UndoableAction,
+    auction_len: usize,
+    auction_complete: bool,
+    phase: GamePhase,
+    contract: Option<Contract>,
+    effective_user_seat: Option<Seat>,
+    legal_calls: Vec<Call>,
+    inference: InferenceCheckpoint,
+    bid_history_len: usize,
+    pending_attempts: Vec<BidAttemptRecord>,
+    debug_log_len: usize,
+    play_recommendations_len: usize,
+    play: PlayCheckpoint,
+}
+
+/// Play state before an action. Completed tricks are only ever appended
+/// within one play, so their count is enough.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayCheckpoint {
+    tricks_len: usize,
+    current_trick: Vec<PlayedCard>,
+    current_player: Option<Seat>,
+    declarer_tricks_won: u32,
+    defender_tricks_won: u32,
+    dummy_seat: Option<Seat>,
+    trump_suit: Option<Suit>,
+    play_score: Option<i32>,
+    claim: Option<Claim>,
+}
+
+impl PlayCheckpoint {
+    fn capture(play: &PlayState) -> Self {
+        Self {
+            tricks_len: play.tricks.len(),
+            current_trick: play.current_trick.clone(),
+            current_player: play.current_player,
+            declarer_tricks_won: play.declarer_tricks_won,
+            defender_tricks_won: play.defender_tricks_won,
+            dummy_seat: play.dummy_seat,
+            trump_suit: play.trump_suit,
+            play_score: play.play_score,
+            claim: play.claim.clone(),
+        }
+    }
+
+    fn restore(self, play: &mut PlayState) {
+        play.tricks.truncate(self.tricks_len);
+        play.current_trick = self.current_trick;
+        play.current_player = self.current_player;
+        play.declarer_tricks_won = self.declarer_tricks_won;
+        play.defender_tricks_won = self.defender_tricks_won;
+        play.dummy_seat = self.dummy_seat;
+        play.trump_suit = self.trump_suit;
+        play.play_score = self.play_score;
+        play.claim = self.claim;
+    }
+}
+
+fn permits(policy: UndoPolicy, action: UndoableAction) -> bool {
+    match policy {
+        UndoPolicy::Allowed => true,
+        UndoPolicy::PlayOnly => action != UndoableAction::Bid,
+        UndoPolicy::Forbidden => false,
+    }
+}
+
+/// Capture the state before `action`. `None` when the policy would never
+/// allow taking it back, so nothing is copied.
+pub(crate) fn capture(state: &SessionState, action: UndoableAction) -> Option<Checkpoint> {
+    if !permits(state.undo_policy, action) {
+        return None;
+    }
+    Some(Checkpoint {
+        action,
+        auction_len: state.auction.entries.len(),
+        auction_complete: state.auction.is_complete,
+        phase: state.phase,
+        contract: state.contract.clone(),
+        effective_user_seat: state.effective_user_seat,
+        legal_calls: state.legal_calls.clone(),
+        inference: state.inference_coordinator.checkpoint(),
+        bid_history_len: state.bid_history.len(),
+        pending_attempts: state.pending_attempts.clone(),
+        debug_log_len: state.debug_log.len(),
+        play_recommendations_len: state.play_recommendations.len(),
+        play: PlayCheckpoint::capture(&state.play),
+    })
+}
+
+/// Record a checkpoint once its action has gone through.
+pub(crate) fn commit(state: &mut SessionState, checkpoint: Option<Checkpoint>) {
+    if let Some(checkpoint) = checkpoint {
+        state.history.push(checkpoint);
+    }
+}
+
+/// Checkpoint an action that has already been validated.
+pub(crate) fn record(state: &mut SessionState, action: UndoableAction) {
+    let checkpoint = capture(state, action);
+    commit(state, checkpoint);
+}
+
+/// Drop the card and claim checkpoints when play starts over, so undo
+/// cannot reach back into an abandoned play.
+pub(crate) fn discard_play_history(state: &mut SessionState) {
+    state
+        .history
+        .retain(|checkpoint| checkpoint.action == UndoableAction::Bid);
+}
+
+/// Number of actions `undo_last_action` could take back in a row.
+pub fn undoable_actions(state: &SessionState) -> usize {
+    state
+        .history
+        .iter()
+        .rev()
+        .take_while(|checkpoint| permits(state.undo_policy, checkpoint.action))
+        .count()
+}
+
+/// Take back the user's last bid, card or claim, restoring the session to
+/// the moment before it.
+pub fn undo_last_action(state: &mut SessionState) -> Result<UndoResult, UndoError> {
+    let action = match state.history.last() {
+        Some(checkpoint) => checkpoint.action,
+        // Actions the policy forbids were never checkpointed.
+        None if state.undo_policy != UndoPolicy::Allowed => return Err(UndoError::Forbidden),
+        None => return Err(UndoError::NothingToUndo),
+    };
+    if !permits(state.undo_policy, action) {
+        return Err(UndoError::Forbidden);
+    }
+    let checkpoint = state.history.pop().expect("checked above");
+
+    state.auction.entries.truncate(checkpoint.auction_len);
+    state.auction.is_complete = checkpoint.auction_complete;
+    state.phase = checkpoint.phase;
+    state.contract = checkpoint.contract;
+    state.effective_user_seat = checkpoint.effective_user_seat;
+    state.legal_calls = checkpoint.legal_calls;
+    state.inference_coordinator.rollback(checkpoint.inference);
+    state.public_belief_state = state
+        .inference_coordinator
+        .get_public_belief_state()
+        .clone();
+    state.bid_history.truncate(checkpoint.bid_history_len);
+    state.pending_attempts = checkpoint.pending_attempts;
+    state.debug_log.truncate(checkpoint.debug_log_len);
+    state
+        .play_recommendations
+        .truncate(checkpoint.play_recommendations_len);
+    checkpoint.play.restore(&mut state.play);
+    // The sampler only exists in play, where it is a pure function of the
+    // beliefs, the seed and the completed tricks.
+    if state.phase == GamePhase::Playing {
+        state.rebuild_posterior();
+    } else {
+        state.posterior = None;
+    }
+
+    Ok(UndoResult {
+        action,
+        phase: state.phase,
+        remaining: undoable_actions(state),
+    })
+}
@@ -11,7 +11,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use bridge_conventions::fact_catalog::{partition_discriminants, FactValue as CatalogFactValue};
 use bridge_conventions::fact_dsl::{FactData, FactValue as EvaluatedFactValue};
-use bridge_conventions::pipeline::observation::normalize_intent::normalize_intent;
+use bridge_conventions::pipeline::observation::relay::observed_actions;
 use bridge_conventions::pipeline::observation::route_matcher::match_obs;
 use bridge_conventions::registry::bundle_registry::list_bundle_inputs;
 use bridge_conventions::registry::module_registry::{
@@ -1088,7 +1088,11 @@ fn find_trigger_call(
             continue;
         }
         for surface in &entry.surfaces {
-            let actions = normalize_intent(&surface.source_intent);
+            let actions = observed_actions(
+                &surface.encoding,
+                &surface.source_intent,
+                &surface.encoding.default_call,
+            );
             if actions.iter().any(|a| match_obs(obs, a, None)) {
                 return Some(surface.encoding.default_call.clone());
             }
@@ -1242,9 +1246,9 @@ mod tests {
     use bridge_conventions::{BidActionType, LocalFsm, ObsPatternAct, PhaseTransition};
 
     #[test]
-    fn build_module_catalog_returns_15() {
+    fn build_module_catalog_returns_23() {
         let catalog = build_module_catalog(BaseSystemId::Sayc);
-        assert_eq!(catalog.len(), 15);
+        assert_eq!(catalog.len(), 23);
     }
 
     #[test]
@@ -1534,6 +1538,7 @@ mod tests {
                             strain: BidSuit::Clubs,
                         },
                         alternate_encodings: None,
+                        relay: None,
                     },
                     clauses: vec![
                         BidMeaningClause {
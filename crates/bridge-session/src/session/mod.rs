@@ -4,22 +4,30 @@
 //! Ported from TS `src/session/{session-state,drill-session,config-factory,start-drill,
 //! bidding-controller,play-controller,bid-feedback-builder}.ts`.
 
+pub mod auction_analysis;
 pub mod bid_feedback_builder;
 pub mod bidding_controller;
 pub mod build_viewport;
 pub mod config_factory;
 pub mod flow_tree;
 pub mod format_obs_label;
+pub mod history;
 pub mod learning_formatters;
 pub mod learning_types;
 pub mod learning_viewport;
 pub mod play_controller;
 pub mod practice_focus;
+pub mod replay_viewport;
 pub mod response_table;
+pub mod review_schedule;
 pub mod session_state;
+pub mod snapshot;
 pub mod start_drill;
 pub mod viewport_types;
 
+pub use auction_analysis::{
+    analyze_auction, AnalyzeAuctionInput, AuctionAnalysis, DecisionAnalysis,
+};
 pub use bid_feedback_builder::{assemble_bid_feedback, call_equals, BidFeedbackDTO, BidGrade};
 pub use bidding_controller::{
     initialize_auction, process_bid, run_initial_ai_bids, AiBidEntry, BidProcessResult,
@@ -34,6 +42,9 @@ pub use flow_tree::{
     ModuleFlowTreeViewport,
 };
 pub use format_obs_label::{format_obs_action, format_transition_label};
+pub use history::{
+    undo_last_action, undoable_actions, Checkpoint, UndoError, UndoResult, UndoableAction,
+};
 pub use learning_formatters::{
     call_key, derive_neutral_description, find_explanation_text, format_bid_references,
     format_module_name, map_clauses, module_surfaces,
@@ -51,18 +62,26 @@ pub use learning_viewport::{
     compute_post_fit_phases, derive_entry_condition, derive_phase_order,
 };
 pub use play_controller::{
-    process_play_card, process_single_card, run_initial_ai_plays, AiPlayEntry, PlayCardResult,
-    SingleCardResult,
+    process_claim, process_play_card, process_single_card, run_initial_ai_plays, try_concede,
+    AiPlayEntry, ClaimResult, PlayCardResult, SingleCardResult,
+};
+pub use replay_viewport::build_replay_viewport;
+pub use review_schedule::{DecisionPoint, ReviewCard, ReviewSchedule};
+pub use session_state::{
+    get_current_turn, Claim, DebugLogEntry, PlayState, SeatStrategy, SessionState,
+};
+pub use snapshot::{
+    restore_session, snapshot_session, SessionSnapshot, SnapshotError, SESSION_SNAPSHOT_VERSION,
 };
-pub use session_state::{get_current_turn, DebugLogEntry, PlayState, SeatStrategy, SessionState};
 pub use start_drill::{
     pick_vulnerability, rotate_auction, rotate_deal_constraints, rotate_seat_180, start_drill,
     DrillBundle,
 };
 pub use viewport_types::{
-    AnnotationType, AuctionEntryView, BidAttemptRecord, BidContextView, BidHistoryEntryView,
-    BidRole, BiddingOptionView, BiddingViewport, BuildBiddingViewportInput,
+    AnnotationType, AuctionEntryView, AuctionReplayViewport, BidAttemptRecord, BidContextView,
+    BidHistoryEntryView, BidRole, BiddingOptionView, BiddingViewport, BuildBiddingViewportInput,
     BuildDeclarerPromptViewportInput, BuildExplanationViewportInput, BuildPlayingViewportInput,
-    CallRoleEntry, DeclarerPromptViewport, ExplanationViewport, HandEvaluationView,
-    PlayRecommendation, PlayingViewport, ReviewCondition,
+    BuildReplayViewportInput, CallRoleEntry, DeclarerPromptViewport, ExplanationViewport,
+    HandEvaluationView, PlayRecommendation, PlayingViewport, ReplayMeaningView, ReplayStepView,
+    ReviewCondition,
 };
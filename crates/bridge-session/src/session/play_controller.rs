@@ -5,7 +5,8 @@
 //! EnginePort async boundary). World-class advisor / recommendation tracking
 //! deferred — uses heuristic play chain only.
 
-use bridge_engine::constants::{next_seat, partner_seat};
+use bridge_engine::constants::{next_seat, partner_seat, SEATS};
+use bridge_engine::double_dummy::{fixed_outcome, DoubleDummySolver, PlayPosition};
 use bridge_engine::play::{get_legal_plays, get_trick_winner};
 use bridge_engine::scoring::calculate_score;
 use bridge_engine::types::{Card, Hand, PlayedCard, Seat, Trick};
@@ -17,7 +18,12 @@ use crate::heuristics::play_types::{PlayBeliefs, PlayContext};
 use crate::phase_machine::is_valid_transition;
 use crate::types::GamePhase;
 
-use super::session_state::SessionState;
+use super::history::{record, UndoableAction};
+use super::session_state::{Claim, SessionState};
+
+/// Positions the AI defenders search before conceding; past this the
+/// outcome counts as open and play continues.
+const CONCESSION_NODE_LIMIT: u64 = 50_000;
 
 // ── Result types ───────────────────────────────────────────────────
 
@@ -58,6 +64,25 @@ pub struct PlayCardResult {
     pub legal_plays: Option<Vec<Card>>,
     /// Current player after all processing (None if play complete).
     pub current_player: Option<Seat>,
+    /// Set when the AI defenders conceded the rest of the tricks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concession: Option<Claim>,
+}
+
+/// Result of a claim of the remaining tricks.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimResult {
+    /// Whether the claim stood; play is then complete.
+    pub accepted: bool,
+    /// Tricks the claimer's side takes against any defence, counting the
+    /// trick in progress.
+    pub guaranteed_tricks: u8,
+    /// For a rejected claim, the rest of the play with both sides at their
+    /// best, holding the claimer to `guaranteed_tricks`.
+    pub counter_line: Vec<PlayedCard>,
+    /// Final score if the claim was accepted.
+    pub score: Option<i32>,
 }
 
 /// A single AI card play for animation/replay.
@@ -89,6 +114,8 @@ pub fn process_play_card(state: &mut SessionState, card: Card, seat: Seat) -> Pl
         return empty_play_result();
     }
 
+    record(state, UndoableAction::Card);
+
     // Play the user's card
     add_card_to_trick(state, &card, seat);
 
@@ -106,9 +133,14 @@ pub fn process_play_card(state: &mut SessionState, card: Card, seat: Seat) -> Pl
                 ai_plays: Vec::new(),
                 legal_plays: None,
                 current_player: None,
+                concession: None,
             };
         }
 
+        if try_concede(state) {
+            return build_result(state, true, Vec::new());
+        }
+
         // Trick complete, next player is the winner (set by score_trick)
         if let Some(current) = state.play.current_player {
             if !state.is_user_controlled_play(current) {
@@ -127,6 +159,7 @@ pub fn process_play_card(state: &mut SessionState, card: Card, seat: Seat) -> Pl
             ai_plays: Vec::new(),
             legal_plays: Some(next_legal),
             current_player: state.play.current_player,
+            concession: None,
         };
     }
 
@@ -153,6 +186,7 @@ pub fn process_play_card(state: &mut SessionState, card: Card, seat: Seat) -> Pl
         ai_plays: Vec::new(),
         legal_plays: Some(next_legal),
         current_player: state.play.current_player,
+        concession: None,
     }
 }
 
@@ -187,6 +221,10 @@ pub fn process_single_card(state: &mut SessionState, card: Card, seat: Seat) ->
         return empty_single_result();
     }
 
+    if state.is_user_controlled_play(seat) {
+        record(state, UndoableAction::Card);
+    }
+
     // Play the card
     add_card_to_trick(state, &card, seat);
 
@@ -234,6 +272,66 @@ pub fn process_single_card(state: &mut SessionState, card: Card, seat: Seat) ->
     }
 }
 
+/// Claim `tricks` of the tricks still to play (the one in progress
+/// included) for `seat`'s side. The claim stands if the claimer's side takes
+/// at least that many double-dummy — against every defence, with the claimer
+/// playing on correctly. An accepted claim credits the rest to the other side
+/// and completes play; a rejected one leaves play where it was.
+pub fn process_claim(state: &mut SessionState, seat: Seat, tricks: u8) -> ClaimResult {
+    let rejected = ClaimResult {
+        accepted: false,
+        guaranteed_tricks: 0,
+        counter_line: Vec::new(),
+        score: None,
+    };
+    if !has_active_play(state) || !state.is_user_controlled_play(seat) {
+        return rejected;
+    }
+    let remaining = tricks_remaining(state);
+    if tricks > remaining {
+        return rejected;
+    }
+
+    let position = play_position(state);
+    let mut solver = DoubleDummySolver::new();
+    let Ok(best) = solver.best_tricks(&position) else {
+        return rejected;
+    };
+    let claimer_on_play = state
+        .play
+        .current_player
+        .is_some_and(|player| same_side(player, seat));
+    let guaranteed_tricks = if claimer_on_play {
+        best
+    } else {
+        remaining - best
+    };
+
+    if tricks > guaranteed_tricks {
+        return ClaimResult {
+            guaranteed_tricks,
+            counter_line: solver.optimal_line(&position).unwrap_or_default(),
+            ..rejected
+        };
+    }
+
+    record(state, UndoableAction::Claim);
+    settle_remaining(
+        state,
+        Claim {
+            seat,
+            tricks,
+            conceded: false,
+        },
+    );
+    ClaimResult {
+        accepted: true,
+        guaranteed_tricks,
+        counter_line: Vec::new(),
+        score: state.play.play_score,
+    }
+}
+
 fn empty_single_result() -> SingleCardResult {
     SingleCardResult {
         accepted: false,
@@ -327,6 +425,93 @@ fn score_trick(state: &mut SessionState) {
     }
 }
 
+fn same_side(a: Seat, b: Seat) -> bool {
+    a == b || a == partner_seat(b)
+}
+
+fn tricks_remaining(state: &SessionState) -> u8 {
+    13 - state.play.tricks.len() as u8
+}
+
+/// The cards still to play, for the solver.
+fn play_position(state: &SessionState) -> PlayPosition {
+    let hands = SEATS
+        .into_iter()
+        .map(|seat| (seat, state.get_remaining_cards(seat)))
+        .collect();
+    let leader = match state.play.current_trick.first() {
+        Some(lead) => lead.seat,
+        None => state
+            .play
+            .current_player
+            .expect("play_position requires active play"),
+    };
+    PlayPosition {
+        hands,
+        trump: state.play.trump_suit,
+        leader,
+        current_trick: state
+            .play
+            .current_trick
+            .iter()
+            .map(|p| p.card.clone())
+            .collect(),
+    }
+}
+
+/// The AI defenders concede when the user is declarer and every line of play
+/// from this trick boundary ends with the same number of tricks. Returns
+/// true if they did and play is complete.
+pub fn try_concede(state: &mut SessionState) -> bool {
+    let Some(declarer) = state.contract.as_ref().map(|c| c.declarer) else {
+        return false;
+    };
+    let Some(current) = state.play.current_player else {
+        return false;
+    };
+    let remaining = tricks_remaining(state);
+    if remaining < 2
+        || !state.play.current_trick.is_empty()
+        || !state.is_user_controlled_play(declarer)
+    {
+        return false;
+    }
+    let Some(tricks) = fixed_outcome(&play_position(state), CONCESSION_NODE_LIMIT) else {
+        return false;
+    };
+    let declarer_tricks = if same_side(current, declarer) {
+        tricks
+    } else {
+        remaining - tricks
+    };
+    settle_remaining(
+        state,
+        Claim {
+            seat: declarer,
+            tricks: declarer_tricks,
+            conceded: true,
+        },
+    );
+    true
+}
+
+/// Credit the tricks still to play as `claim` says and complete play.
+fn settle_remaining(state: &mut SessionState, claim: Claim) {
+    let Some(declarer) = state.contract.as_ref().map(|c| c.declarer) else {
+        return;
+    };
+    let remaining = tricks_remaining(state) as u32;
+    let declarer_tricks = if same_side(claim.seat, declarer) {
+        claim.tricks as u32
+    } else {
+        remaining - claim.tricks as u32
+    };
+    state.play.declarer_tricks_won += declarer_tricks;
+    state.play.defender_tricks_won += remaining - declarer_tricks;
+    state.play.claim = Some(claim);
+    complete_play(state);
+}
+
 /// Complete the play: calculate score, transition to EXPLANATION.
 fn complete_play(state: &mut SessionState) {
     let contract = match &state.contract {
@@ -482,6 +667,9 @@ fn run_ai_play_loop(state: &mut SessionState) -> Vec<AiPlayEntry> {
                 complete_play(state);
                 return ai_plays;
             }
+            if try_concede(state) {
+                return ai_plays;
+            }
             // After scoring, current_player is the winner.
             // If winner is user-controlled, loop will break on next iteration.
             continue;
@@ -521,6 +709,7 @@ fn build_result(
         ai_plays,
         legal_plays: None,
         current_player: state.play.current_player,
+        concession: state.play.claim.clone().filter(|claim| claim.conceded),
     }
 }
 
@@ -533,6 +722,7 @@ fn empty_play_result() -> PlayCardResult {
         ai_plays: Vec::new(),
         legal_plays: None,
         current_player: None,
+        concession: None,
     }
 }
 
@@ -803,6 +993,157 @@ mod tests {
         assert_eq!(ctx.legal_plays.len(), 1);
     }
 
+    // ── Claims and concessions ───────────────────────────────────
+
+    #[test]
+    fn safe_claim_completes_play() {
+        // South runs thirteen spades; nobody else can follow.
+        let mut state = make_play_state(Seat::South);
+        state.play.current_player = Some(Seat::South);
+
+        let result = process_claim(&mut state, Seat::South, 13);
+        assert!(result.accepted);
+        assert_eq!(result.guaranteed_tricks, 13);
+        // 3NT with four overtricks, not vulnerable.
+        assert_eq!(result.score, Some(520));
+        assert_eq!(state.play.declarer_tricks_won, 13);
+        assert_eq!(state.phase, GamePhase::Explanation);
+        assert_eq!(
+            state.play.claim,
+            Some(Claim {
+                seat: Seat::South,
+                tricks: 13,
+                conceded: false,
+            })
+        );
+    }
+
+    #[test]
+    fn unsafe_claim_is_rejected_with_a_counter_line() {
+        // West is on lead and cashes thirteen clubs.
+        let mut state = make_play_state(Seat::South);
+        let result = process_claim(&mut state, Seat::South, 1);
+        assert!(!result.accepted);
+        assert_eq!(result.guaranteed_tricks, 0);
+        assert_eq!(result.counter_line.len(), 52);
+        assert_eq!(result.counter_line[0].seat, Seat::West);
+        assert_eq!(result.counter_line[0].card.suit, Suit::Clubs);
+        // Play carries on from where it was.
+        assert_eq!(state.play.current_player, Some(Seat::West));
+        assert!(state.play.claim.is_none());
+        assert_eq!(state.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn claims_need_a_user_seat_and_enough_tricks_left() {
+        let mut state = make_play_state(Seat::South);
+        state.play.current_player = Some(Seat::South);
+        assert!(!process_claim(&mut state, Seat::West, 0).accepted);
+        assert!(!process_claim(&mut state, Seat::South, 14).accepted);
+        assert_eq!(state.phase, GamePhase::Playing);
+    }
+
+    #[test]
+    fn defenders_concede_when_no_line_changes_the_result() {
+        let mut state = make_play_state(Seat::South);
+        state.play.current_player = Some(Seat::South);
+
+        // South leads, West discards, then North (dummy) discards.
+        process_play_card(&mut state, card(Suit::Spades, Rank::Ace), Seat::South);
+        assert_eq!(state.play.current_player, Some(Seat::North));
+        let result = process_play_card(&mut state, card(Suit::Hearts, Rank::Two), Seat::North);
+
+        // East completes the trick; the rest is certain, so play stops.
+        assert!(result.play_complete);
+        assert_eq!(result.ai_plays.len(), 1);
+        assert_eq!(
+            result.concession,
+            Some(Claim {
+                seat: Seat::South,
+                tricks: 12,
+                conceded: true,
+            })
+        );
+        assert_eq!(state.play.tricks.len(), 1);
+        assert_eq!(state.play.declarer_tricks_won, 13);
+        assert_eq!(result.score, Some(520));
+    }
+
+    #[test]
+    fn undo_rolls_back_the_card_and_the_ai_plays_after_it() {
+        use crate::session::history::{undo_last_action, UndoableAction};
+
+        let mut state = make_play_state(Seat::South);
+        state.play.current_player = Some(Seat::South);
+        process_play_card(&mut state, card(Suit::Spades, Rank::Ace), Seat::South);
+        assert_eq!(state.play.current_trick.len(), 2);
+
+        let undone = undo_last_action(&mut state).unwrap();
+        assert_eq!(undone.action, UndoableAction::Card);
+        assert_eq!(undone.phase, GamePhase::Playing);
+        assert!(state.play.current_trick.is_empty());
+        assert_eq!(state.play.current_player, Some(Seat::South));
+        assert_eq!(state.get_remaining_cards(Seat::South).len(), 13);
+    }
+
+    #[test]
+    fn undo_reopens_play_after_a_claim() {
+        use crate::session::history::undo_last_action;
+
+        let mut state = make_play_state(Seat::South);
+        state.play.current_player = Some(Seat::South);
+        assert!(process_claim(&mut state, Seat::South, 13).accepted);
+
+        undo_last_action(&mut state).unwrap();
+        assert_eq!(state.phase, GamePhase::Playing);
+        assert!(state.play.claim.is_none());
+        assert_eq!(state.play.play_score, None);
+        assert_eq!(state.play.declarer_tricks_won, 0);
+    }
+
+    #[test]
+    fn undo_rebuilds_the_posterior_from_the_remaining_tricks() {
+        use crate::heuristics::play_profiles::PlayProfileId;
+        use crate::session::history::{record, undo_last_action, UndoableAction};
+
+        let mut state = make_play_state(Seat::South);
+        state.play_profile_id = PlayProfileId::WorldClass;
+        let contract = state.contract.clone().unwrap();
+        state.initialize_play(&contract);
+        let before = state.posterior.as_ref().unwrap().all_marginal_hcp();
+
+        record(&mut state, UndoableAction::Card);
+        let plays: Vec<PlayedCard> = bridge_engine::SEATS
+            .iter()
+            .map(|&seat| PlayedCard {
+                card: state.deal.hands[&seat].cards[0].clone(),
+                seat,
+            })
+            .collect();
+        state.play.tricks.push(Trick {
+            plays: plays.clone(),
+            trump_suit: None,
+            winner: Some(Seat::West),
+        });
+        state
+            .posterior
+            .as_mut()
+            .unwrap()
+            .update_with_played_cards(&plays);
+        let after_trick = state.posterior.as_ref().unwrap().all_marginal_hcp();
+
+        // Replaying the tricks lands on the same samples.
+        state.rebuild_posterior();
+        assert_eq!(
+            state.posterior.as_ref().unwrap().all_marginal_hcp(),
+            after_trick
+        );
+
+        undo_last_action(&mut state).unwrap();
+        assert!(state.play.tricks.is_empty());
+        assert_eq!(state.posterior.as_ref().unwrap().all_marginal_hcp(), before);
+    }
+
     // ── process_single_card tests ────────────────────────────────
 
     #[test]
@@ -328,11 +328,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -368,11 +370,13 @@ mod tests {
                 min_length: Some(min_length),
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -405,11 +409,13 @@ mod tests {
                 min_length: Some(min_length),
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -442,11 +448,13 @@ mod tests {
                 min_length: Some(min_length),
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -479,11 +487,13 @@ mod tests {
                 min_length: Some(min_length),
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -519,11 +529,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: Some(min_any),
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         // North has 5 spades and 3 hearts → should pick 1S
@@ -628,11 +640,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: Some(min_any),
+                ..Default::default()
             }],
             dealer: Some(Seat::East),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let mut hands = HashMap::new();
@@ -732,11 +746,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: Some(min_any),
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
@@ -766,11 +782,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
 
         let result = derive_initial_auction(
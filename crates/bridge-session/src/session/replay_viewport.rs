@@ -0,0 +1,377 @@
+//! Auction replay viewport — walks a finished (or imported) auction call by call.
+//!
+//! For every call the seat's strategy is re-run on that seat's hand at that
+//! point in the auction. The resulting evaluation gives the active modules,
+//! the meaning the actual call matched, the teaching projection, and what the
+//! strategy would have bid. A fresh inference coordinator is fed the same
+//! calls so each step carries the public belief state just after it.
+
+use bridge_conventions::adapter::strategy_evaluation::StrategyEvaluation;
+use bridge_conventions::pipeline::types::{PipelineCarrier, PipelineResult};
+use bridge_conventions::types::meaning::FactConstraint;
+use bridge_engine::auction::{get_contract, is_legal_call};
+use bridge_engine::hand_evaluator::evaluate_hand_hcp;
+use bridge_engine::types::{Auction, Call, Deal, Seat};
+
+use crate::heuristics::{BidResult, BiddingContext};
+use crate::inference::InferenceCoordinator;
+
+use super::bid_feedback_builder::assemble_bid_feedback;
+use super::build_viewport::{build_auction_entries, format_call};
+use super::session_state::SeatStrategy;
+use super::viewport_types::{
+    AuctionReplayViewport, BuildReplayViewportInput, ReplayMeaningView, ReplayStepView,
+};
+
+/// Build an AuctionReplayViewport from a deal and a complete auction.
+///
+/// All four hands are exposed — like the explanation viewport, this is
+/// review material. Seats without a hand or without an AI strategy still
+/// get a step, just without strategy output.
+pub fn build_replay_viewport(input: BuildReplayViewportInput) -> AuctionReplayViewport {
+    let mut coordinator = InferenceCoordinator::new(input.system_config);
+    let mut steps = Vec::with_capacity(input.auction.entries.len());
+
+    for (index, entry) in input.auction.entries.iter().enumerate() {
+        let auction_before = Auction {
+            entries: input.auction.entries[..index].to_vec(),
+            is_complete: false,
+        };
+        let is_user = entry.seat == input.user_seat;
+
+        let (expected, evaluation) = match input.seat_strategies.get(&entry.seat) {
+            Some(strategy) => evaluate_turn(strategy, input.deal, &auction_before, entry.seat),
+            None => (None, None),
+        };
+        let pipeline_result = evaluation.as_ref().and_then(|e| e.pipeline_result.as_ref());
+        let matched = pipeline_result.and_then(|pr| find_carrier_for_call(pr, &entry.call));
+
+        // Inference sees the actual call's meaning, not the recommended one.
+        let constraints = matched.map(promised_constraints).unwrap_or_default();
+        let label = matched.map(|c| c.proposal().teaching_label.name.to_string());
+        coordinator.process_bid(
+            entry,
+            &auction_before,
+            matched.map(|c| c.proposal().meaning_id.as_str()),
+            label.as_deref(),
+            label.as_deref(),
+            &constraints,
+            Some(input.convention_id),
+        );
+        let belief_state = coordinator.get_public_belief_state();
+        let annotation = belief_state
+            .annotations
+            .last()
+            .map(|a| a.meaning.clone())
+            .filter(|m| !m.is_empty());
+
+        let grade = is_user.then(|| assemble_bid_feedback(&entry.call, expected.as_ref()).grade);
+
+        steps.push(ReplayStepView {
+            index,
+            seat: entry.seat,
+            call: entry.call.clone(),
+            call_display: format_call(&entry.call),
+            is_user,
+            active_modules: pipeline_result.map(active_modules).unwrap_or_default(),
+            matched_meaning: matched.map(meaning_view),
+            teaching: evaluation.and_then(|e| e.teaching_projection),
+            annotation,
+            beliefs_after: belief_state.beliefs.clone(),
+            expected_call: expected.as_ref().map(|r| r.call.clone()),
+            expected_explanation: expected.map(|r| r.explanation),
+            grade,
+        });
+    }
+
+    AuctionReplayViewport {
+        user_seat: input.user_seat,
+        all_hands: input.deal.hands.clone(),
+        dealer: input.deal.dealer,
+        vulnerability: input.deal.vulnerability,
+        auction_entries: build_auction_entries(input.auction, &[]),
+        contract: get_contract(input.auction).ok().flatten(),
+        steps,
+    }
+}
+
+// ── Internal helpers ───────────────────────────────────────────────
+
+/// Run a seat's strategy at one point of the auction.
+///
+/// Mirrors the bidding controller: no suggestion (or an illegal one) means
+/// Pass. The evaluation is only present for convention strategies.
+pub(super) fn evaluate_turn(
+    strategy: &SeatStrategy,
+    deal: &Deal,
+    auction_before: &Auction,
+    seat: Seat,
+) -> (Option<BidResult>, Option<StrategyEvaluation>) {
+    let strategy = match strategy {
+        SeatStrategy::Ai(strategy) => strategy,
+        SeatStrategy::User => return (None, None),
+    };
+    let hand = match deal.hands.get(&seat) {
+        Some(hand) if !hand.cards.is_empty() => hand,
+        _ => return (None, None),
+    };
+
+    let context = BiddingContext {
+        hand: hand.clone(),
+        auction: auction_before.clone(),
+        seat,
+        evaluation: evaluate_hand_hcp(hand),
+        vulnerability: Some(deal.vulnerability),
+        dealer: Some(deal.dealer),
+    };
+    let expected = match strategy.suggest_bid(&context) {
+        Some(result) if is_legal_call(auction_before, &result.call, seat) => result,
+        _ => BidResult {
+            call: Call::Pass,
+            rule_name: None,
+            explanation: "No convention applies — pass".to_string(),
+            ..Default::default()
+        },
+    };
+    let evaluation = strategy
+        .stashed_evaluation()
+        .and_then(|boxed| boxed.downcast_ref::<StrategyEvaluation>().cloned());
+    (Some(expected), evaluation)
+}
+
+/// Find the carrier whose call is the one actually made. Eliminated carriers
+/// are searched last so a wrong call still shows what it would have meant.
+pub(super) fn find_carrier_for_call<'a>(
+    result: &'a PipelineResult,
+    call: &Call,
+) -> Option<&'a PipelineCarrier> {
+    result
+        .selected
+        .iter()
+        .chain(&result.truth_set)
+        .chain(&result.acceptable_set)
+        .chain(&result.eliminated)
+        .find(|c| c.call() == call)
+}
+
+/// Module ids that offered a meaning at this turn, in pipeline order.
+fn active_modules(result: &PipelineResult) -> Vec<String> {
+    let mut modules: Vec<String> = Vec::new();
+    for carrier in result
+        .truth_set
+        .iter()
+        .chain(&result.acceptable_set)
+        .chain(&result.eliminated)
+    {
+        let module_id = &carrier.proposal().module_id;
+        if !modules.contains(module_id) {
+            modules.push(module_id.clone());
+        }
+    }
+    modules
+}
+
+/// Every clause of the matched meaning. Unlike the live bidding path this
+/// ignores `satisfied`: a call promises its whole meaning even when the
+/// bidder's hand does not fit it.
+fn promised_constraints(carrier: &PipelineCarrier) -> Vec<FactConstraint> {
+    carrier
+        .proposal()
+        .clauses
+        .iter()
+        .map(|clause| FactConstraint {
+            fact_id: clause.fact_id.clone(),
+            operator: clause.operator,
+            value: clause.value.clone(),
+            is_public: clause.is_public,
+        })
+        .collect()
+}
+
+pub(super) fn meaning_view(carrier: &PipelineCarrier) -> ReplayMeaningView {
+    let proposal = carrier.proposal();
+    ReplayMeaningView {
+        module_id: proposal.module_id.clone(),
+        meaning_id: proposal.meaning_id.clone(),
+        semantic_class_id: proposal.semantic_class_id.clone(),
+        label: proposal.teaching_label.name.to_string(),
+        summary: proposal.teaching_label.summary.to_string(),
+        disclosure: proposal.disclosure,
+        hand_fits: proposal.all_satisfied,
+        constraints: promised_constraints(carrier),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::heuristics::BiddingStrategy;
+    use crate::session::BidGrade;
+    use bridge_engine::constants::SEATS;
+    use bridge_engine::types::{AuctionEntry, BidSuit, Card, Hand, Rank, Suit, Vulnerability};
+
+    /// Opens 1NT when first to speak, passes otherwise.
+    struct OpensOneNotrump;
+    impl BiddingStrategy for OpensOneNotrump {
+        fn id(&self) -> &str {
+            "opens-1nt"
+        }
+        fn name(&self) -> &str {
+            "Opens 1NT"
+        }
+        fn suggest_bid(&self, ctx: &BiddingContext) -> Option<BidResult> {
+            let call = if ctx.auction.entries.is_empty() {
+                one_notrump()
+            } else {
+                Call::Pass
+            };
+            Some(BidResult {
+                call,
+                rule_name: None,
+                explanation: "Test strategy".to_string(),
+                ..Default::default()
+            })
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn one_notrump() -> Call {
+        Call::Bid {
+            level: 1,
+            strain: BidSuit::NoTrump,
+        }
+    }
+
+    fn make_deal() -> Deal {
+        let mut hands = HashMap::new();
+        for &seat in &SEATS {
+            hands.insert(
+                seat,
+                Hand {
+                    cards: vec![Card {
+                        suit: Suit::Clubs,
+                        rank: Rank::Two,
+                    }],
+                },
+            );
+        }
+        Deal {
+            hands,
+            dealer: Seat::North,
+            vulnerability: Vulnerability::None,
+        }
+    }
+
+    fn make_auction(calls: &[(Seat, Call)]) -> Auction {
+        Auction {
+            entries: calls
+                .iter()
+                .map(|(seat, call)| AuctionEntry {
+                    seat: *seat,
+                    call: call.clone(),
+                })
+                .collect(),
+            is_complete: true,
+        }
+    }
+
+    fn make_strategies() -> HashMap<Seat, SeatStrategy> {
+        let mut strategies = HashMap::new();
+        for &seat in &SEATS {
+            strategies.insert(seat, SeatStrategy::Ai(Box::new(OpensOneNotrump)));
+        }
+        strategies
+    }
+
+    #[test]
+    fn replay_has_one_step_per_call_with_beliefs() {
+        let deal = make_deal();
+        let auction = make_auction(&[
+            (Seat::North, one_notrump()),
+            (Seat::East, Call::Pass),
+            (Seat::South, Call::Pass),
+            (Seat::West, Call::Pass),
+        ]);
+        let strategies = make_strategies();
+
+        let viewport = build_replay_viewport(BuildReplayViewportInput {
+            deal: &deal,
+            user_seat: Seat::South,
+            auction: &auction,
+            convention_id: "test",
+            seat_strategies: &strategies,
+            system_config: None,
+        });
+
+        assert_eq!(viewport.steps.len(), 4);
+        assert_eq!(viewport.all_hands.len(), 4);
+        assert_eq!(
+            viewport.contract.as_ref().map(|c| c.declarer),
+            Some(Seat::North)
+        );
+        assert_eq!(viewport.steps[0].call_display, "1NT");
+        assert_eq!(viewport.steps[0].expected_call, Some(one_notrump()));
+        for step in &viewport.steps {
+            assert_eq!(step.beliefs_after.len(), 4);
+            assert!(step.active_modules.is_empty());
+            assert!(step.matched_meaning.is_none());
+        }
+    }
+
+    #[test]
+    fn only_user_turns_are_graded() {
+        let deal = make_deal();
+        let auction = make_auction(&[
+            (Seat::North, Call::Pass),
+            (Seat::East, Call::Pass),
+            (Seat::South, one_notrump()),
+            (Seat::West, Call::Pass),
+            (Seat::North, Call::Pass),
+            (Seat::East, Call::Pass),
+        ]);
+        let strategies = make_strategies();
+
+        let viewport = build_replay_viewport(BuildReplayViewportInput {
+            deal: &deal,
+            user_seat: Seat::South,
+            auction: &auction,
+            convention_id: "test",
+            seat_strategies: &strategies,
+            system_config: None,
+        });
+
+        let graded: Vec<_> = viewport
+            .steps
+            .iter()
+            .filter_map(|s| s.grade.map(|g| (s.index, g)))
+            .collect();
+        assert_eq!(graded, vec![(2, BidGrade::Incorrect)]);
+        assert!(viewport.steps[2].is_user);
+        assert_eq!(viewport.steps[2].expected_call, Some(Call::Pass));
+    }
+
+    #[test]
+    fn seats_without_hands_have_no_strategy_output() {
+        let mut deal = make_deal();
+        deal.hands.insert(Seat::East, Hand { cards: vec![] });
+        let auction = make_auction(&[(Seat::North, Call::Pass), (Seat::East, Call::Pass)]);
+        let strategies = make_strategies();
+
+        let viewport = build_replay_viewport(BuildReplayViewportInput {
+            deal: &deal,
+            user_seat: Seat::South,
+            auction: &auction,
+            convention_id: "test",
+            seat_strategies: &strategies,
+            system_config: None,
+        });
+
+        assert!(viewport.steps[0].expected_call.is_some());
+        assert!(viewport.steps[1].expected_call.is_none());
+        assert!(viewport.steps[1].teaching.is_none());
+    }
+}
@@ -313,6 +313,7 @@ mod tests {
                     strain: BidSuit::Diamonds,
                 },
                 alternate_encodings: None,
+                relay: None,
             },
             clauses,
             ranking: AuthoredRankingMetadata {
@@ -0,0 +1,288 @@
+//! Spaced-repetition review schedule for convention decision points.
+//!
+//! A decision point is one `(module_id, surface_id)` the user was asked to
+//! bid. Each carries an SM-2 card: ease, interval, repetition count and due
+//! time. Graded calls update the card. Due cards then drive the next drill:
+//! `module_weights` feeds `DrillTuning::module_weights`, and `next_target`
+//! pins the most overdue surface.
+//!
+//! Times are unix seconds supplied by the caller; nothing here reads a clock.
+
+use std::collections::HashMap;
+
+use bridge_conventions::types::rule_types::TargetSelector;
+use serde::{Deserialize, Serialize};
+
+use super::bid_feedback_builder::BidGrade;
+
+/// Ease given to a decision point on its first review.
+pub const DEFAULT_EASE: f64 = 2.5;
+/// SM-2 floor. Below this, intervals stop growing usefully.
+pub const MIN_EASE: f64 = 1.3;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+/// A missed decision comes back within the same practice sitting.
+const RELEARN_DELAY_SECONDS: i64 = 10 * 60;
+
+/// One `(module, surface)` the user was graded on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionPoint {
+    pub module_id: String,
+    pub surface_id: String,
+}
+
+/// Review state for one decision point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewCard {
+    pub decision: DecisionPoint,
+    pub ease: f64,
+    pub interval_days: f64,
+    /// Consecutive passing reviews. Reset by a miss.
+    pub repetitions: u32,
+    /// Misses after the first review.
+    pub lapses: u32,
+    pub due_at: i64,
+    pub last_reviewed_at: i64,
+    pub last_grade: BidGrade,
+}
+
+/// SM-2 response quality (0–5) for a grade. Below 3 is a miss.
+fn quality(grade: BidGrade) -> f64 {
+    match grade {
+        BidGrade::Correct => 5.0,
+        BidGrade::Acceptable => 4.0,
+        BidGrade::NearMiss => 2.0,
+        BidGrade::Incorrect => 0.0,
+    }
+}
+
+impl ReviewCard {
+    /// A card reviewed for the first time at `now`.
+    pub fn first_review(decision: DecisionPoint, grade: BidGrade, now: i64) -> Self {
+        let mut card = Self {
+            decision,
+            ease: DEFAULT_EASE,
+            interval_days: 0.0,
+            repetitions: 0,
+            lapses: 0,
+            due_at: now,
+            last_reviewed_at: now,
+            last_grade: grade,
+        };
+        card.review(grade, now);
+        // A miss on first sight is not a lapse of anything learned.
+        card.lapses = 0;
+        card
+    }
+
+    /// Apply one graded review at `now`.
+    pub fn review(&mut self, grade: BidGrade, now: i64) {
+        let q = quality(grade);
+        self.ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(MIN_EASE);
+
+        if q < 3.0 {
+            self.repetitions = 0;
+            self.lapses += 1;
+            self.interval_days = 0.0;
+            self.due_at = now + RELEARN_DELAY_SECONDS;
+        } else {
+            self.repetitions += 1;
+            self.interval_days = match self.repetitions {
+                1 => 1.0,
+                2 => 6.0,
+                _ => self.interval_days * self.ease,
+            };
+            self.due_at = now + (self.interval_days * SECONDS_PER_DAY) as i64;
+        }
+        self.last_reviewed_at = now;
+        self.last_grade = grade;
+    }
+
+    pub fn is_due(&self, now: i64) -> bool {
+        self.due_at <= now
+    }
+
+    /// How badly this card wants reviewing at `now`; zero when not due.
+    ///
+    /// Grows with time overdue relative to the interval, and with how hard
+    /// the card has proved (low ease).
+    pub fn urgency(&self, now: i64) -> f64 {
+        if !self.is_due(now) {
+            return 0.0;
+        }
+        let overdue_days = (now - self.due_at) as f64 / SECONDS_PER_DAY;
+        let overdue = 1.0 + overdue_days / self.interval_days.max(1.0);
+        overdue * DEFAULT_EASE / self.ease
+    }
+}
+
+/// Every review card one user has.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewSchedule {
+    cards: Vec<ReviewCard>,
+}
+
+impl ReviewSchedule {
+    pub fn new(cards: Vec<ReviewCard>) -> Self {
+        Self { cards }
+    }
+
+    pub fn cards(&self) -> &[ReviewCard] {
+        &self.cards
+    }
+
+    pub fn into_cards(self) -> Vec<ReviewCard> {
+        self.cards
+    }
+
+    pub fn card(&self, decision: &DecisionPoint) -> Option<&ReviewCard> {
+        self.cards.iter().find(|card| &card.decision == decision)
+    }
+
+    /// Record a graded call, creating the card on first sight.
+    pub fn record(&mut self, decision: DecisionPoint, grade: BidGrade, now: i64) -> &ReviewCard {
+        match self.cards.iter().position(|card| card.decision == decision) {
+            Some(index) => {
+                self.cards[index].review(grade, now);
+                &self.cards[index]
+            }
+            None => {
+                self.cards
+                    .push(ReviewCard::first_review(decision, grade, now));
+                self.cards.last().expect("just pushed")
+            }
+        }
+    }
+
+    /// Due cards, most urgent first.
+    pub fn due(&self, now: i64) -> Vec<&ReviewCard> {
+        let mut due: Vec<&ReviewCard> = self.cards.iter().filter(|c| c.is_due(now)).collect();
+        due.sort_by(|a, b| {
+            b.urgency(now)
+                .total_cmp(&a.urgency(now))
+                .then_with(|| a.decision.cmp(&b.decision))
+        });
+        due
+    }
+
+    /// Per-module drill weights for `DrillTuning::module_weights`.
+    ///
+    /// Every reviewed module starts at 1.0 and gains the urgency of its due
+    /// cards. Modules never reviewed are absent and weigh 1.0 by default.
+    pub fn module_weights(&self, now: i64) -> HashMap<String, f64> {
+        let mut weights = HashMap::new();
+        for card in &self.cards {
+            *weights
+                .entry(card.decision.module_id.clone())
+                .or_insert(1.0) += card.urgency(now);
+        }
+        weights
+    }
+
+    /// The most urgent due decision point as a drill target, if any.
+    pub fn next_target(&self, now: i64) -> Option<TargetSelector> {
+        self.due(now).first().map(|card| TargetSelector::Surface {
+            module_id: card.decision.module_id.clone(),
+            surface_id: card.decision.surface_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn point(module_id: &str, surface_id: &str) -> DecisionPoint {
+        DecisionPoint {
+            module_id: module_id.to_string(),
+            surface_id: surface_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn correct_answers_space_reviews_out() {
+        let mut card =
+            ReviewCard::first_review(point("stayman", "stayman:ask-major"), BidGrade::Correct, 0);
+        assert_eq!(card.repetitions, 1);
+        assert_eq!(card.due_at, DAY);
+
+        card.review(BidGrade::Correct, DAY);
+        assert_eq!(card.interval_days, 6.0);
+        assert_eq!(card.due_at, 7 * DAY);
+
+        card.review(BidGrade::Correct, 7 * DAY);
+        assert!(card.interval_days > 6.0 * DEFAULT_EASE);
+        assert!(card.ease > DEFAULT_EASE);
+    }
+
+    #[test]
+    fn a_miss_resets_the_card_and_lowers_ease() {
+        let mut card =
+            ReviewCard::first_review(point("stayman", "stayman:ask-major"), BidGrade::Correct, 0);
+        card.review(BidGrade::Correct, DAY);
+        card.review(BidGrade::Incorrect, 7 * DAY);
+
+        assert_eq!(card.repetitions, 0);
+        assert_eq!(card.lapses, 1);
+        assert_eq!(card.due_at, 7 * DAY + RELEARN_DELAY_SECONDS);
+        assert!(card.ease < DEFAULT_EASE);
+
+        for _ in 0..20 {
+            card.review(BidGrade::Incorrect, 8 * DAY);
+        }
+        assert_eq!(card.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn a_first_miss_is_not_a_lapse() {
+        let card = ReviewCard::first_review(point("smolen", "smolen:3h"), BidGrade::NearMiss, 0);
+        assert_eq!(card.lapses, 0);
+        assert_eq!(card.repetitions, 0);
+        assert!(card.is_due(RELEARN_DELAY_SECONDS));
+    }
+
+    #[test]
+    fn missed_decisions_weigh_their_module_up_and_become_the_target() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record(point("stayman", "stayman:ask-major"), BidGrade::Correct, 0);
+        schedule.record(point("smolen", "smolen:3h"), BidGrade::Incorrect, 0);
+        schedule.record(point("smolen", "smolen:3s"), BidGrade::Correct, 0);
+
+        let now = DAY / 2;
+        let weights = schedule.module_weights(now);
+        assert_eq!(weights["stayman"], 1.0);
+        assert!(weights["smolen"] > 1.0);
+
+        assert_eq!(
+            schedule.next_target(now),
+            Some(TargetSelector::Surface {
+                module_id: "smolen".to_string(),
+                surface_id: "smolen:3h".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn nothing_due_means_no_target() {
+        let mut schedule = ReviewSchedule::default();
+        schedule.record(point("stayman", "stayman:ask-major"), BidGrade::Correct, 0);
+        assert!(schedule.due(DAY / 2).is_empty());
+        assert_eq!(schedule.next_target(DAY / 2), None);
+        assert_eq!(schedule.due(DAY).len(), 1);
+    }
+
+    #[test]
+    fn recording_updates_the_existing_card() {
+        let mut schedule = ReviewSchedule::default();
+        let decision = point("stayman", "stayman:ask-major");
+        schedule.record(decision.clone(), BidGrade::Correct, 0);
+        schedule.record(decision.clone(), BidGrade::Correct, DAY);
+        assert_eq!(schedule.cards().len(), 1);
+        assert_eq!(schedule.card(&decision).unwrap().repetitions, 2);
+    }
+}
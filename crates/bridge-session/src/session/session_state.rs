@@ -22,10 +22,11 @@ use bridge_conventions::adapter::strategy_evaluation::StrategyEvaluation;
 
 use crate::heuristics::play_profiles::{get_profile, PlayProfileId};
 use crate::heuristics::{BidResult, BiddingStrategy};
-use crate::types::{GamePhase, PlayPreference, PracticeFocus, PracticeMode};
+use crate::types::{GamePhase, PlayPreference, PracticeFocus, PracticeMode, UndoPolicy};
 
 use super::bid_feedback_builder::{BidFeedbackDTO, BidGrade};
 use super::build_viewport::format_call;
+use super::history::{discard_play_history, Checkpoint};
 use super::viewport_types::{AnnotationType, BidAttemptRecord, BidHistoryEntryView};
 
 // ── Debug log ─────────────────────────────────────────────────────
@@ -80,7 +81,8 @@ pub enum SeatStrategy {
 // ── PlayState ───────────────────────────────────────────────────────
 
 /// Play-phase mutable state, separated for partial-borrow ergonomics.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PlayState {
     pub tricks: Vec<Trick>,
     pub current_trick: Vec<PlayedCard>,
@@ -90,6 +92,21 @@ pub struct PlayState {
     pub dummy_seat: Option<Seat>,
     pub trump_suit: Option<Suit>,
     pub play_score: Option<i32>,
+    /// Set when play ended on a claim or concession rather than the last card.
+    pub claim: Option<Claim>,
+}
+
+/// The remaining tricks settled without being played out: the user's
+/// accepted claim, or the AI defenders conceding once no line of play could
+/// change the result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Claim {
+    /// Seat that claimed; declarer when the defenders conceded.
+    pub seat: Seat,
+    /// Tricks still to play credited to the claimer's side.
+    pub tricks: u8,
+    pub conceded: bool,
 }
 
 // ── SessionState ────────────────────────────────────────────────────
@@ -98,6 +115,9 @@ pub struct PlayState {
 ///
 /// Owns the deal, auction, inference coordinator, and play state.
 /// No Svelte or UI dependencies — pure domain logic.
+/// Serializable for save/resume; see `session::snapshot`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SessionState {
     // Game state
     pub deal: Deal,
@@ -137,6 +157,11 @@ pub struct SessionState {
     pub posterior: Option<PosteriorEngine>,
     pub play_profile_id: PlayProfileId,
     pub play_seed: u64,
+
+    // Undo
+    pub undo_policy: UndoPolicy,
+    /// Checkpoints before each user action, oldest first.
+    pub history: Vec<Checkpoint>,
 }
 
 impl SessionState {
@@ -190,6 +215,9 @@ impl SessionState {
             posterior: None,
             play_profile_id,
             play_seed,
+
+            undo_policy: UndoPolicy::default(),
+            history: Vec::new(),
         }
     }
 
@@ -333,7 +361,9 @@ impl SessionState {
             dummy_seat: Some(partner_seat(contract.declarer)),
             trump_suit: bid_suit_to_suit(contract.strain),
             play_score: None,
+            claim: None,
         };
+        discard_play_history(self);
         self.initialize_posterior();
     }
 
@@ -381,6 +411,17 @@ impl SessionState {
         }
     }
 
+    /// Rebuild the posterior sampler as it stood after the completed tricks,
+    /// replaying each trick's reveal in order so the samples match.
+    pub(crate) fn rebuild_posterior(&mut self) {
+        self.initialize_posterior();
+        if let Some(engine) = self.posterior.as_mut() {
+            for trick in &self.play.tricks {
+                engine.update_with_played_cards(&trick.plays);
+            }
+        }
+    }
+
     /// Check if a seat is user-controlled during play.
     /// User controls their own seat and dummy (if user is declarer).
     pub fn is_user_controlled_play(&self, seat: Seat) -> bool {
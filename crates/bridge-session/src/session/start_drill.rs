@@ -79,6 +79,8 @@ pub fn rotate_deal_constraints(base: &DealConstraints, new_dealer: Seat) -> Deal
         vulnerability: base.vulnerability,
         max_attempts: base.max_attempts,
         seed: base.seed,
+        // A 180-degree turn leaves each partnership on its own side.
+        partnerships: base.partnerships.clone(),
     }
 }
 
@@ -165,6 +167,9 @@ pub struct StartDrillOptions {
     /// produces). `None` falls back to `NORMAL_DEAL_ATTEMPTS` when a
     /// predicate is supplied.
     pub deal_attempts: Option<u64>,
+    /// Deal to play instead of generating one (an imported hand record).
+    /// Skips rejection sampling entirely; no initial auction is derived.
+    pub preset_deal: Option<Deal>,
 }
 
 impl Default for StartDrillOptions {
@@ -182,6 +187,7 @@ impl Default for StartDrillOptions {
             initial_auction_override: None,
             deal_acceptance_predicate: None,
             deal_attempts: None,
+            preset_deal: None,
         }
     }
 }
@@ -283,7 +289,14 @@ pub fn start_drill(
         (None, false) => 1,
     };
 
-    let mut chosen_deal: Option<Deal> = None;
+    // A preset deal is played as dealt: nothing to sample.
+    let deal_attempts = if options.preset_deal.is_some() {
+        0
+    } else {
+        deal_attempts
+    };
+
+    let mut chosen_deal: Option<Deal> = options.preset_deal.clone();
     let mut chosen_initial_auction: Option<Auction> = None;
 
     for attempt in 0..deal_attempts {
@@ -422,11 +435,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
         let rotated = rotate_deal_constraints(&constraints, Seat::North);
         assert_eq!(rotated.dealer, Some(Seat::North));
@@ -445,6 +460,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     min_length_any: None,
+                    ..Default::default()
                 },
                 SeatConstraint {
                     seat: Seat::North,
@@ -454,12 +470,14 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     min_length_any: None,
+                    ..Default::default()
                 },
             ],
             dealer: Some(Seat::North),
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
         let rotated = rotate_deal_constraints(&constraints, Seat::South);
         assert_eq!(rotated.dealer, Some(Seat::South));
@@ -481,11 +499,13 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 min_length_any: None,
+                ..Default::default()
             }],
             dealer: None,
             vulnerability: None,
             max_attempts: None,
             seed: None,
+            ..Default::default()
         };
         let rotated = rotate_deal_constraints(&constraints, Seat::North);
         // No dealer = noop
@@ -608,6 +628,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: None,
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -653,11 +674,13 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     min_length_any: None,
+                    ..Default::default()
                 }],
                 dealer: Some(Seat::North),
                 vulnerability: None,
                 max_attempts: Some(50_000),
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -687,6 +710,47 @@ mod tests {
         assert_eq!(bundle.resolved_role, PracticeRole::Opener);
     }
 
+    #[test]
+    fn start_drill_plays_a_preset_deal_as_dealt() {
+        let preset = generate_deal(&DealConstraints {
+            seats: vec![],
+            dealer: Some(Seat::West),
+            vulnerability: Some(Vulnerability::Both),
+            max_attempts: None,
+            seed: Some(9),
+            ..Default::default()
+        })
+        .unwrap()
+        .deal;
+        let convention = ConventionConfig {
+            id: "test".to_string(),
+            deal_constraints: DealConstraints {
+                seats: vec![],
+                dealer: Some(Seat::North),
+                vulnerability: None,
+                max_attempts: None,
+                seed: None,
+                ..Default::default()
+            },
+            allowed_dealers: None,
+        };
+        let config = DrillConfig {
+            convention_id: "test".to_string(),
+            user_seat: Seat::South,
+            seat_strategies: HashMap::new(),
+        };
+        let options = StartDrillOptions {
+            seed: Some(3),
+            preset_deal: Some(preset.clone()),
+            deal_acceptance_predicate: Some(std::sync::Arc::new(|_: &Deal, _: Seat| false)),
+            ..Default::default()
+        };
+
+        let bundle = start_drill(&convention, Seat::South, config, &options, &mut || 0.3).unwrap();
+        assert_eq!(bundle.deal, preset);
+        assert!(bundle.initial_auction.is_none());
+    }
+
     #[test]
     fn start_drill_both_role_resolves() {
         let convention = ConventionConfig {
@@ -697,6 +761,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: None,
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -738,6 +803,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: None,
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -778,11 +844,13 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     min_length_any: Some(min_any_n),
+                    ..Default::default()
                 }],
                 dealer: Some(Seat::North),
                 vulnerability: None,
                 max_attempts: Some(50_000),
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -887,11 +955,13 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     min_length_any: None,
+                    ..Default::default()
                 }],
                 dealer: Some(Seat::North),
                 vulnerability: None,
                 max_attempts: Some(50_000),
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -954,6 +1024,7 @@ mod tests {
                 vulnerability: None,
                 max_attempts: None,
                 seed: None,
+                ..Default::default()
             },
             allowed_dealers: None,
         };
@@ -1,14 +1,17 @@
-//! Viewport DTO types — struct/enum definitions for all four viewport builders
-//! (bidding, declarer prompt, playing, explanation).
+//! Viewport DTO types — struct/enum definitions for the viewport builders
+//! (bidding, declarer prompt, playing, explanation, auction replay).
 
 use std::collections::HashMap;
 
+use bridge_conventions::teaching::teaching_types::TeachingProjection;
+use bridge_conventions::types::meaning::{Disclosure, FactConstraint};
 use bridge_engine::types::{
     Call, Card, Contract, DistributionPoints, Hand, PlayedCard, Seat, Suit, SuitLength, Trick,
     Vulnerability,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::inference::types::PublicBeliefs;
 use crate::types::{PracticeMode, PromptMode};
 
 use super::bid_feedback_builder::BidGrade;
@@ -235,6 +238,66 @@ pub struct ExplanationViewport {
     pub play_recommendations: Vec<PlayRecommendation>,
 }
 
+// ── Auction Replay Viewport ───────────────────────────────────────
+
+/// The convention meaning a replayed call was matched to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayMeaningView {
+    pub module_id: String,
+    pub meaning_id: String,
+    pub semantic_class_id: String,
+    pub label: String,
+    pub summary: String,
+    pub disclosure: Disclosure,
+    /// Whether the bidder's hand actually satisfies every clause.
+    pub hand_fits: bool,
+    /// Everything the call promises, public or not.
+    pub constraints: Vec<FactConstraint>,
+}
+
+/// One call of a replayed auction, with the state just after it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayStepView {
+    pub index: usize,
+    pub seat: Seat,
+    pub call: Call,
+    pub call_display: String,
+    pub is_user: bool,
+    /// Modules that offered at least one meaning at this turn.
+    pub active_modules: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_meaning: Option<ReplayMeaningView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teaching: Option<TeachingProjection>,
+    /// Annotation text produced by inference for this call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<String>,
+    pub beliefs_after: HashMap<Seat, PublicBeliefs>,
+    /// What the seat's strategy recommends with this hand at this point.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_call: Option<Call>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_explanation: Option<String>,
+    /// Grade of the actual call — user turns only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<BidGrade>,
+}
+
+/// Auction replay viewport — a finished auction walked through call by call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuctionReplayViewport {
+    pub user_seat: Seat,
+    pub all_hands: HashMap<Seat, Hand>,
+    pub dealer: Seat,
+    pub vulnerability: Vulnerability,
+    pub auction_entries: Vec<AuctionEntryView>,
+    pub contract: Option<Contract>,
+    pub steps: Vec<ReplayStepView>,
+}
+
 // ── Builder Input types ───────────────────────────────────────────
 
 /// Input for building a BiddingViewport.
@@ -297,3 +360,13 @@ pub struct BuildExplanationViewportInput<'a> {
     pub tricks: Vec<Trick>,
     pub play_recommendations: Vec<PlayRecommendation>,
 }
+
+/// Input for building an AuctionReplayViewport.
+pub struct BuildReplayViewportInput<'a> {
+    pub deal: &'a bridge_engine::types::Deal,
+    pub user_seat: Seat,
+    pub auction: &'a bridge_engine::types::Auction,
+    pub convention_id: &'a str,
+    pub seat_strategies: &'a HashMap<Seat, super::session_state::SeatStrategy>,
+    pub system_config: Option<&'a bridge_conventions::types::system_config::SystemConfig>,
+}
@@ -55,6 +55,21 @@ pub enum PlayPreference {
     Skip,
 }
 
+// ── Undo policy ───────────────────────────────────────────────────
+
+/// Which user actions a drill lets the user take back. Drills grade the
+/// first attempt at every bid, so the default keeps bids as first made;
+/// untimed practice can opt into `Allowed`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UndoPolicy {
+    Allowed,
+    /// Cards and claims only; bids stand as first made.
+    #[default]
+    PlayOnly,
+    Forbidden,
+}
+
 // ── Prompt mode ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
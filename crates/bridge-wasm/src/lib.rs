@@ -1,10 +1,12 @@
 use std::sync::Once;
 use wasm_bindgen::prelude::*;
 
+use bridge_conventions::registry::ModuleSource;
 use bridge_engine::types::{Call, Card, Seat};
+use bridge_service::request_types::AuctionAnalysisRequest;
 #[cfg(debug_assertions)]
 use bridge_service::DevServicePort;
-use bridge_service::{ServicePort, ServicePortImpl, SessionConfig};
+use bridge_service::{DrillSnapshot, ServicePort, ServicePortImpl, SessionConfig};
 use bridge_session::dds::{DdsError, McddParams, SolveBoardRequest, SolveBoardResponse};
 use serde::Serialize;
 
@@ -172,6 +174,24 @@ impl WasmServicePort {
             .and_then(to_js)
     }
 
+    pub fn destroy_drill_session(&mut self, handle: &str) -> Result<(), JsError> {
+        self.with_service_mut(|service| service.destroy_drill_session(handle))
+    }
+
+    /// Snapshot as a JSON string: its seeds and RNG position are 64- and
+    /// 128-bit integers that JS numbers cannot hold.
+    pub fn snapshot_drill_session(&self, handle: &str) -> Result<String, JsError> {
+        let snapshot = self.with_service(|service| service.snapshot_drill_session(handle))?;
+        serde_json::to_string(&snapshot).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    pub fn restore_drill_session(&mut self, snapshot_json: &str) -> Result<JsValue, JsError> {
+        let snapshot: DrillSnapshot =
+            serde_json::from_str(snapshot_json).map_err(|e| JsError::new(&e.to_string()))?;
+        self.with_service_mut(|service| service.restore_drill_session(snapshot))
+            .and_then(to_js)
+    }
+
     pub fn start_drill(&mut self, handle: &str) -> Result<JsValue, JsError> {
         self.with_service_mut(|service| service.start_drill(handle))
             .and_then(to_js)
@@ -221,6 +241,18 @@ impl WasmServicePort {
             .and_then(to_js)
     }
 
+    pub fn claim(&mut self, handle: &str, seat: JsValue, tricks: u8) -> Result<JsValue, JsError> {
+        let seat: Seat = from_js(seat)?;
+        self.with_service_mut(|service| service.claim(handle, seat, tricks))
+            .and_then(to_js)
+    }
+
+    /// Take back the user's last bid, card or claim.
+    pub fn undo_last_action(&mut self, handle: &str) -> Result<JsValue, JsError> {
+        self.with_service_mut(|service| service.undo_last_action(handle))
+            .and_then(to_js)
+    }
+
     /// Check if the current profile needs DDS-based play.
     pub fn needs_dds_play(&self, handle: &str) -> Result<bool, JsError> {
         let has_solver = self.dds_solver.is_some();
@@ -339,6 +371,7 @@ impl WasmServicePort {
                 ai_plays: Vec::new(),
                 legal_plays: None,
                 current_player: None,
+                concession: None,
             });
         }
         if user_result.play_complete {
@@ -350,6 +383,7 @@ impl WasmServicePort {
                 ai_plays: Vec::new(),
                 legal_plays: None,
                 current_player: None,
+                concession: None,
             });
         }
 
@@ -365,6 +399,7 @@ impl WasmServicePort {
                 ai_plays,
                 legal_plays: None,
                 current_player: None,
+                concession: None,
             });
         }
 
@@ -383,6 +418,7 @@ impl WasmServicePort {
             ai_plays,
             legal_plays: None,
             current_player,
+            concession: None,
         })
     }
 
@@ -422,6 +458,11 @@ impl WasmServicePort {
             .and_then(to_js)
     }
 
+    pub fn get_auction_replay_viewport(&self, handle: &str) -> Result<JsValue, JsError> {
+        self.with_service(|service| service.get_auction_replay_viewport(handle))
+            .and_then(to_js)
+    }
+
     // ── Inference ─────────────────────────────────────────────────
 
     pub fn get_public_belief_state(&self, handle: &str) -> Result<JsValue, JsError> {
@@ -429,20 +470,23 @@ impl WasmServicePort {
             .and_then(to_js)
     }
 
+    // ── Analysis ──────────────────────────────────────────────────
+
+    pub fn analyze_auction(&self, request: JsValue) -> Result<JsValue, JsError> {
+        let request: AuctionAnalysisRequest = from_js(request)?;
+        self.with_service(|service| service.analyze_auction(request))
+            .and_then(to_js)
+    }
+
     // ── DDS ───────────────────────────────────────────────────────
 
     /// Async DDS table-level solve. Gets PBN from session state internally,
     /// calls the injected JS table solver. No PBN crosses the boundary.
+    /// Par and the par comparison are always computed in Rust.
     pub async fn get_dds_solution(&self, handle: &str) -> Result<JsValue, JsError> {
         let js_fn = match &self.dds_table_solver {
             Some(f) => f,
-            None => {
-                // No table solver — return "not available" stub
-                return to_js(bridge_service::response_types::DDSolutionResult {
-                    solution: None,
-                    error: Some("DDS not available".to_string()),
-                });
-            }
+            None => return to_js(dds_not_available()),
         };
 
         // Get PBN from Rust state (no boundary crossing)
@@ -460,13 +504,43 @@ impl WasmServicePort {
             .map_err(|e| JsError::new(&format!("DDS table solve failed: {:?}", e)))?;
 
         // Wrap the raw DDSolution into DDSolutionResult
-        let solution_json: serde_json::Value = serde_wasm_bindgen::from_value(js_result)
+        let solution: bridge_engine::types::DDSolution = serde_wasm_bindgen::from_value(js_result)
             .map_err(|e| JsError::new(&format!("DDS result deserialization failed: {}", e)))?;
 
-        to_js(bridge_service::response_types::DDSolutionResult {
-            solution: Some(solution_json),
-            error: None,
-        })
+        self.with_service(|service| service.dd_solution_result(handle, solution))
+            .and_then(to_js)
+    }
+
+    // ── Hand records ──────────────────────────────────────────────
+
+    pub fn import_pbn(&self, text: &str) -> Result<JsValue, JsError> {
+        self.with_service(|service| service.import_pbn(text))
+            .and_then(to_js)
+    }
+
+    pub fn export_session_pbn(&self, handle: &str) -> Result<String, JsError> {
+        self.with_service(|service| service.export_session_pbn(handle))
+    }
+
+    pub fn import_lin(&self, text: &str) -> Result<JsValue, JsError> {
+        self.with_service(|service| service.import_lin(text))
+            .and_then(to_js)
+    }
+
+    pub fn export_session_lin(&self, handle: &str) -> Result<String, JsError> {
+        self.with_service(|service| service.export_session_lin(handle))
+    }
+
+    // ── Board references ──────────────────────────────────────────
+
+    pub fn session_board_reference(&self, handle: &str) -> Result<JsValue, JsError> {
+        self.with_service(|service| service.session_board_reference(handle))
+            .and_then(to_js)
+    }
+
+    pub fn deal_from_board_id(&self, board_id: &str) -> Result<JsValue, JsError> {
+        self.with_service(|service| service.deal_from_board_id(board_id))
+            .and_then(to_js)
     }
 
     // ── Catalog ───────────────────────────────────────────────────
@@ -529,6 +603,13 @@ impl WasmServicePort {
         let result = self.inner.validate_module(&json).map_err(service_error)?;
         to_js(result)
     }
+
+    /// Install runtime convention modules. Process-wide: affects every
+    /// session, not just this port's.
+    pub fn load_modules(&self, sources: JsValue) -> Result<JsValue, JsError> {
+        let sources: Vec<ModuleSource> = from_js(sources)?;
+        to_js(bridge_service::load_modules(&sources))
+    }
 }
 
 // ── DevServicePort methods (debug builds only) ────────────────────
@@ -557,6 +638,16 @@ impl WasmServicePort {
     }
 }
 
+/// Table solve result without a JS solver. The native solver would run on
+/// the UI thread for seconds, so the browser never falls back to it.
+fn dds_not_available() -> bridge_service::response_types::DDSolutionResult {
+    bridge_service::response_types::DDSolutionResult {
+        solution: None,
+        error: Some("DDS not available".to_string()),
+        par_comparison: None,
+    }
+}
+
 // ── Serde wire-shape tests ────────────────────────────────────────
 //
 // Lock down the `ServiceErrorPayload` JSON shape that crosses to TS via
@@ -580,4 +671,13 @@ mod tests {
         assert_eq!(v["kind"], "dealGenerationExhausted");
         assert_eq!(v["witnessSummary"], "stayman/stayman:invite");
     }
+
+    #[test]
+    fn dds_solution_without_a_table_solver_skips_the_native_solver() {
+        let started = std::time::Instant::now();
+        let result = dds_not_available();
+        assert!(result.solution.is_none());
+        assert_eq!(result.error.as_deref(), Some("DDS not available"));
+        assert!(started.elapsed() < std::time::Duration::from_millis(50));
+    }
 }
@@ -24,6 +24,9 @@ const CONVENTION_DISPLAY_NAMES: Record<string, string> = {
   "strong-2c-bundle": "Strong 2♣",
   "negative-doubles-bundle": "Negative Doubles",
   "nmf-bundle": "New Minor Forcing",
+  "lebensohl-bundle": "Lebensohl",
+  "drury-bundle": "Drury",
+  "fourth-suit-forcing-bundle": "Fourth Suit Forcing",
 };
 
 const SYSTEM_DISPLAY_NAMES: Record<string, string> = {
@@ -9,6 +9,7 @@ import type { UserModule } from "../../service/session-types";
 /** Maps system module IDs to their display category. */
 export const MODULE_CATEGORIES: Record<string, string> = {
   "natural-bids": "Opening Bids",
+  "notrump-openings": "Opening Bids",
   "strong-2c": "Opening Bids",
   "stayman": "Notrump Responses",
   "stayman-garbage": "Notrump Responses",
@@ -16,13 +17,20 @@ export const MODULE_CATEGORIES: Record<string, string> = {
   "jacoby-4way": "Notrump Responses",
   "smolen": "Notrump Responses",
   "bergen": "Major Raises",
+  "drury": "Major Raises",
   "weak-twos": "Weak Bids",
+  "preempts": "Weak Bids",
+  "suit-responses": "Constructive",
+  "opener-rebids": "Constructive",
   "dont": "Competitive",
   "michaels-unusual": "Competitive",
   "blackwood": "Slam",
   "negative-doubles": "Competitive",
   "natural-competitive": "Competitive",
+  "lebensohl": "Competitive",
   "new-minor-forcing": "Responder Rebids",
+  "fourth-suit-forcing": "Responder Rebids",
+  "responder-rebids": "Responder Rebids",
 };
 
 /** Maps ModuleCategory enum values to display names. */
@@ -32,6 +40,7 @@ export const CATEGORY_DISPLAY: Record<string, string> = {
   "major-raises": "Major Raises",
   "weak-bids": "Weak Bids",
   "competitive": "Competitive",
+  "constructive": "Constructive",
   "slam": "Slam",
   "custom": "Custom",
   "responder-rebids": "Responder Rebids",
@@ -120,12 +120,65 @@ export interface SeatConstraint {
   readonly maxLength?: Partial<Record<Suit, number>>;
   /** OR constraint: at least ONE listed suit meets its minimum length. */
   readonly minLengthAny?: Partial<Record<Suit, number>>;
+  /** Controls, counting an ace as 2 and a king as 1. */
+  readonly minControls?: number;
+  readonly maxControls?: number;
+  /** Losing-trick count. */
+  readonly minLosers?: number;
+  readonly maxLosers?: number;
+  readonly keyCards?: KeyCardRange;
+  /** Every listed suit must hold enough of its top honors. */
+  readonly suitQuality?: readonly SuitQuality[];
+  /** OR constraint: the hand matches at least ONE pattern. */
+  readonly shapes?: readonly ShapePattern[];
+  /** Longest the shortest suit may be: 1 = singleton or void, 0 = void. */
+  readonly maxShortest?: number;
   /** Escape hatch for exotic constraints. Runs last, after all other checks pass. */
   readonly customCheck?: (hand: Hand) => boolean;
 }
 
+/** Roman keycards held for a trump suit: the four aces and the trump king. */
+export interface KeyCardRange {
+  readonly trump: Suit;
+  readonly min?: number;
+  readonly max?: number;
+  /** Whether the trump queen must be held (true) or missing (false). */
+  readonly queen?: boolean;
+}
+
+/** At least `minHonors` of the top `ofTop` cards in `suit`. */
+export interface SuitQuality {
+  readonly suit: Suit;
+  readonly minHonors: number;
+  readonly ofTop: number;
+}
+
+/** Hand pattern, lengths as [Spades, Hearts, Diamonds, Clubs]. */
+export type ShapePattern =
+  | { readonly type: "exact"; readonly lengths: readonly [number, number, number, number] }
+  | { readonly type: "anyOrder"; readonly lengths: readonly [number, number, number, number] };
+
+export type Partnership = "NS" | "EW";
+
+/** Requirements on the two hands of a partnership taken together. */
+export interface PartnershipConstraint {
+  readonly partnership: Partnership;
+  readonly minHcp?: number;
+  readonly maxHcp?: number;
+  /** Combined length per suit, e.g. `{ H: 8 }` for an eight-card heart fit. */
+  readonly minFit?: Partial<Record<Suit, number>>;
+  readonly maxFit?: Partial<Record<Suit, number>>;
+  /** At least one listed suit reaches its combined length. */
+  readonly minFitAny?: Partial<Record<Suit, number>>;
+  readonly minAces?: number;
+  readonly maxAces?: number;
+  readonly minKings?: number;
+  readonly maxKings?: number;
+}
+
 export interface DealConstraints {
   readonly seats: readonly SeatConstraint[];
+  readonly partnerships?: readonly PartnershipConstraint[];
   readonly vulnerability?: Vulnerability;
   readonly dealer?: Seat;
   readonly maxAttempts?: number;
@@ -174,3 +227,24 @@ export interface Trick {
   readonly trumpSuit?: Suit;
   readonly winner?: Seat;
 }
+
+/** Alert and explanation attached to one call of a recorded auction. */
+export interface CallAnnotation {
+  readonly callIndex: number;
+  readonly alert: boolean;
+  readonly explanation?: string;
+}
+
+/** One board as exchanged with other bridge software (PBN, LIN). Mirrors Rust `HandRecord`. */
+export interface HandRecord {
+  readonly deal: Deal;
+  readonly board?: number;
+  readonly auction?: Auction;
+  readonly annotations?: readonly CallAnnotation[];
+  readonly contract?: Contract;
+  readonly play?: readonly Trick[];
+  /** Tricks taken by declarer. */
+  readonly result?: number;
+  /** Tags without a dedicated field, as `[name, value]` pairs in file order. */
+  readonly tags?: readonly (readonly [string, string])[];
+}
@@ -3,7 +3,7 @@
 // ── 1. Service Port & Implementation ─────────────────────────────────
 export type { DevServicePort } from "./port";
 export { BridgeService } from "./wasm-service";
-export type { DrillHandle, SessionConfig } from "./request-types";
+export type { DrillHandle, SessionConfig, AuctionAnalysisRequest, ModuleSource } from "./request-types";
 
 // ── 2. Viewports & Response Types (service-owned) ────────────────────
 export {
@@ -21,10 +21,17 @@ export type {
   ConventionCardPanelView,
   BiddingViewport,
   ViewportBidFeedback,
+  DecisionPoint,
   TeachingDetail,
   DeclarerPromptViewport,
   PlayingViewport,
   ExplanationViewport,
+  AuctionReplayViewport,
+  ReplayStepView,
+  ReplayMeaningView,
+  AuctionAnalysis,
+  DecisionAnalysis,
+  DecisionFeedback,
   ServiceDerivedRanges,
   PlayRecommendation,
   HandEvaluationView,
@@ -48,6 +55,9 @@ export type {
   ConfigurableParameter,
   ValidationResult,
   ValidationError,
+  ModuleIssue,
+  ModuleLoadError,
+  ModuleLoadReport,
 } from "./response-types";
 
 // ── 3. Game Vocabulary (engine primitives) ───────────────────────────
@@ -72,6 +82,7 @@ export {
   PracticeRole,
   PlayPreference,
   PromptMode,
+  UndoPolicy,
   DEFAULT_DRILL_TUNING,
   DEFAULT_DRILL_SETTINGS,
   PLAY_PROFILES,
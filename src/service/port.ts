@@ -11,18 +11,24 @@ import type { PlayProfileId, UserModuleContent } from "./session-types";
 import type {
   DrillHandle,
   SessionConfig,
+  AuctionAnalysisRequest,
+  ModuleSource,
 } from "./request-types";
 import type {
   BiddingViewport,
   DeclarerPromptViewport,
   PlayingViewport,
   ExplanationViewport,
+  AuctionReplayViewport,
+  AuctionAnalysis,
   ModuleCatalogEntry,
   ModuleLearningViewport,
   DrillStartResult,
   BidSubmitResult,
   PlayEntryResult,
   PlayCardResult,
+  ClaimResult,
+  UndoResult,
   DDSolutionResult,
   ConventionInfo,
   ModuleFlowTreeViewport,
@@ -30,6 +36,7 @@ import type {
   ServicePublicBeliefState,
   ModuleConfigSchemaView,
   ValidationResult,
+  ModuleLoadReport,
 } from "./response-types";
 import type {
   ServiceDebugLogEntry,
@@ -43,6 +50,12 @@ interface ServicePort {
 
   // ── Session lifecycle ───────────────────────────────────────────
   createDrillSession(config: SessionConfig): Promise<DrillHandle>;
+  /** Drop a session; its handle is invalid afterwards. */
+  destroyDrillSession(handle: DrillHandle): Promise<void>;
+  /** Save the whole session as an opaque, versioned JSON string. */
+  snapshotDrillSession(handle: DrillHandle): Promise<string>;
+  /** Resume a saved session under a new handle. */
+  restoreDrillSession(snapshot: string): Promise<DrillHandle>;
 
   // ── Drill lifecycle ─────────────────────────────────────────────
   startDrill(handle: DrillHandle): Promise<DrillStartResult>;
@@ -59,6 +72,10 @@ interface ServicePort {
 
   // ── Play ────────────────────────────────────────────────────────
   playCard(handle: DrillHandle, card: Card, seat: Seat): Promise<PlayCardResult>;
+  /** Claim `tricks` of the remaining tricks; rejected claims carry a counter-line. */
+  claim(handle: DrillHandle, seat: Seat, tricks: number): Promise<ClaimResult>;
+  /** Take back the last bid, card or claim, with the AI actions that followed it. */
+  undoLastAction(handle: DrillHandle): Promise<UndoResult>;
   skipToReview(handle: DrillHandle): Promise<void>;
   updatePlayProfile(handle: DrillHandle, profileId: PlayProfileId): Promise<void>;
 
@@ -67,6 +84,8 @@ interface ServicePort {
   getDeclarerPromptViewport(handle: DrillHandle): Promise<DeclarerPromptViewport | null>;
   getPlayingViewport(handle: DrillHandle): Promise<PlayingViewport | null>;
   getExplanationViewport(handle: DrillHandle): Promise<ExplanationViewport | null>;
+  /** Call-by-call walkthrough of the finished auction. Null while bidding. */
+  getAuctionReplayViewport(handle: DrillHandle): Promise<AuctionReplayViewport | null>;
 
   // ── Inference ─────────────────────────────────────────────────────
   /** Get the current public belief state from the session's inference coordinator.
@@ -76,6 +95,10 @@ interface ServicePort {
   // ── DDS analysis ────────────────────────────────────────────────
   getDDSSolution(handle: DrillHandle): Promise<DDSolutionResult>;
 
+  // ── Auction analysis ────────────────────────────────────────────
+  /** Grade an externally played auction's North–South calls — no session needed. */
+  analyzeAuction(request: AuctionAnalysisRequest): Promise<AuctionAnalysis>;
+
   // ── Convention catalog ──────────────────────────────────────────
   listConventions(): Promise<ConventionInfo[]>;
 
@@ -97,6 +120,8 @@ interface ServicePort {
   getModuleConfigSchema(moduleId: string, userModulesJson?: string): Promise<ModuleConfigSchemaView>;
   /** Validate a user module's content. */
   validateModule(moduleJson: string): Promise<ValidationResult>;
+  /** Load convention modules from JSON, replacing those from any earlier call. */
+  loadModules(sources: readonly ModuleSource[]): Promise<ModuleLoadReport>;
 }
 
 /** Extends ServicePort with dev/debug methods.
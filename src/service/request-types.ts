@@ -6,8 +6,8 @@
  * (auth, pagination, etc.) it can be promoted to a requests/ folder.
  */
 
-import type { Seat, Vulnerability } from "../engine/types";
-import type { OpponentMode, PlayPreference, PlayProfileId, PracticeMode, PracticeRole, SystemConfig, VulnerabilityDistribution } from "./session-types";
+import type { Auction, Deal, Seat, Vulnerability } from "../engine/types";
+import type { OpponentMode, PlayPreference, PlayProfileId, PracticeMode, PracticeRole, SystemConfig, UndoPolicy, VulnerabilityDistribution } from "./session-types";
 
 // ── Drill Handle ───────────────────────────────────────────────────
 
@@ -63,4 +63,34 @@ export interface SessionConfig {
   readonly playProfileId?: PlayProfileId;
   /** Probability weights over the four vulnerability states; the deal generator samples from this. */
   readonly vulnerabilityDistribution?: VulnerabilityDistribution;
+  /** Preset deal (e.g. from an imported hand record), drilled as dealt instead of generating one. */
+  readonly deal?: Deal;
+  /** Which actions the user may take back. Absent allows cards and claims but not graded bids. */
+  readonly undoPolicy?: UndoPolicy;
+  /** Relative weight per module when choosing the surface to drill, usually from the review schedule. Unlisted modules weigh 1. */
+  readonly moduleWeights?: Readonly<Record<string, number>>;
+}
+
+// ── Runtime Modules ────────────────────────────────────────────────
+
+/** One ConventionModule JSON document to load. `name` labels it in the load report. */
+export interface ModuleSource {
+  readonly name: string;
+  readonly json: string;
+}
+
+// ── Auction Analysis ───────────────────────────────────────────────
+
+/**
+ * An auction played outside the app, graded against the convention model.
+ * North–South are the analyzed partnership.
+ */
+export interface AuctionAnalysisRequest {
+  readonly conventionId: string;
+  readonly systemConfig: SystemConfig;
+  readonly baseModuleIds: string[];
+  /** All four hands, dealer and vulnerability as played. */
+  readonly deal: Deal;
+  /** The auction as it went at the table, starting from the dealer. */
+  readonly auction: Auction;
 }
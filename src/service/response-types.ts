@@ -345,6 +345,38 @@ export interface PlayCardResult {
   readonly legalPlays: readonly Card[] | null;
   /** Current player after all processing. */
   readonly currentPlayer: Seat | null;
+  /** Set when the AI defenders conceded the rest of the tricks. */
+  readonly concession?: Claim;
+}
+
+/** Remaining tricks settled by a claim or concession instead of played out. */
+export interface Claim {
+  /** Seat that claimed; declarer when the defenders conceded. */
+  readonly seat: Seat;
+  /** Tricks still to play credited to the claimer's side. */
+  readonly tricks: number;
+  readonly conceded: boolean;
+}
+
+/** Result of claiming the remaining tricks. */
+export interface ClaimResult {
+  /** Whether the claim stood; play is then complete. */
+  readonly accepted: boolean;
+  /** Tricks the claimer's side takes against any defence, trick in progress included. */
+  readonly guaranteedTricks: number;
+  /** For a rejected claim, the rest of the play with both sides at their best. */
+  readonly counterLine: readonly PlayedCard[];
+  /** Final score if the claim was accepted. */
+  readonly score: number | null;
+}
+
+/** Result of taking back the user's last action. */
+export interface UndoResult {
+  readonly action: "bid" | "card" | "claim";
+  /** Phase after the rollback. */
+  readonly phase: GamePhase;
+  /** Earlier actions that can still be undone. */
+  readonly remaining: number;
 }
 
 /** A single AI play entry for animation (analogous to AiBidEntry). */
@@ -360,6 +392,29 @@ export interface AiPlayEntry {
 export interface DDSolutionResult {
   readonly solution: DDSolution | null;
   readonly error: string | null;
+  /** Final contract vs par (absent when passed out or no par). */
+  readonly parComparison?: ParComparison;
+}
+
+/** Double-dummy score of the final contract next to par (both NS perspective). */
+export interface ParComparison {
+  readonly contract: Contract;
+  readonly contractScore: number;
+  readonly parScore: number;
+  /** contractScore - parScore: positive means NS beat par. */
+  readonly delta: number;
+  /** `delta` converted to IMPs. */
+  readonly imps: number;
+}
+
+/** Stable identity of a session's board. Mirrors Rust `BoardReference`. */
+export interface BoardReference {
+  /** Dealer, vulnerability digit and deal number, e.g. `N0-…` (24 hex digits). */
+  readonly boardId: string;
+  /** Canonical auction text, e.g. `N:1NT P 2C P`. */
+  readonly auctionKey: string;
+  /** 64-bit FNV-1a hash of `auctionKey` as 16 hex digits. */
+  readonly auctionHash: string;
 }
 
 /** Convention info for catalog listing. */
@@ -620,6 +675,14 @@ export interface ViewportBidFeedback {
   // ── Control ───────────────────────────────────────────────────
   /** True when the player must retry (near-miss or incorrect). */
   readonly requiresRetry: boolean;
+  /** The module surface this call was graded against — the review-schedule key. */
+  readonly decisionPoint?: DecisionPoint;
+}
+
+/** One convention decision: a module surface the player was asked to bid. */
+export interface DecisionPoint {
+  readonly moduleId: string;
+  readonly surfaceId: string;
 }
 
 /** A condition node in the teaching explanation. */
@@ -849,6 +912,81 @@ export interface ExplanationViewport extends AuctionContextBase {
   readonly playRecommendations: readonly PlayRecommendation[];
 }
 
+// ── Auction Replay Viewport ─────────────────────────────────────────
+
+/** Convention meaning a replayed call was matched to. */
+export interface ReplayMeaningView {
+  readonly moduleId: string;
+  readonly meaningId: string;
+  readonly semanticClassId: string;
+  readonly label: string;
+  readonly summary: string;
+  readonly disclosure: "alert" | "announcement" | "natural" | "standard";
+  /** Whether the bidder's hand satisfies every clause. */
+  readonly handFits: boolean;
+  /** Everything the call promises, public or not. */
+  readonly constraints: readonly ServiceFactConstraint[];
+}
+
+/** One call of a replayed auction, with the state just after it. */
+export interface ReplayStepView {
+  readonly index: number;
+  readonly seat: Seat;
+  readonly call: Call;
+  readonly callDisplay: string;
+  readonly isUser: boolean;
+  readonly activeModules: readonly string[];
+  readonly matchedMeaning?: ReplayMeaningView;
+  readonly teaching?: TeachingProjection;
+  readonly annotation?: string;
+  readonly beliefsAfter: Record<Seat, ServicePublicBeliefs>;
+  /** What the seat's strategy recommends with this hand at this point. */
+  readonly expectedCall?: Call;
+  readonly expectedExplanation?: string;
+  /** Grade of the actual call — user turns only. */
+  readonly grade?: ViewportBidGrade;
+}
+
+/** Finished auction walked through call by call. All hands are visible. */
+export interface AuctionReplayViewport extends AuctionContextBase {
+  readonly userSeat: Seat;
+  readonly allHands: Record<Seat, Hand>;
+  readonly auctionEntries: readonly AuctionEntryView[];
+  readonly contract: Contract | null;
+  readonly steps: readonly ReplayStepView[];
+}
+
+// ── Auction Analysis ────────────────────────────────────────────────
+
+/** Grade of one analyzed call against the model's expected call. */
+export interface DecisionFeedback {
+  readonly grade: ViewportBidGrade;
+  readonly userCall: Call;
+  readonly expectedCall: Call | null;
+  readonly explanation: string;
+}
+
+/** One North–South decision of an analyzed auction. */
+export interface DecisionAnalysis {
+  /** Index into the analyzed auction's entries. */
+  readonly index: number;
+  readonly seat: Seat;
+  readonly call: Call;
+  readonly feedback: DecisionFeedback;
+  /** What the actual call means in the model. */
+  readonly actualMeaning?: ReplayMeaningView;
+  /** What the model's expected call would have meant. */
+  readonly expectedMeaning?: ReplayMeaningView;
+  readonly teaching?: TeachingProjection;
+}
+
+/** Externally played auction graded against the convention model. */
+export interface AuctionAnalysis {
+  readonly decisions: readonly DecisionAnalysis[];
+  /** Auction index of the first near-miss or incorrect decision. */
+  readonly firstDivergence: number | null;
+}
+
 // ── Convention Card ──────────────────────────────────────────────────
 
 /** Convention card summary — mirrors the physical card at the table. */
@@ -993,3 +1131,37 @@ export interface ValidationResult {
   readonly valid: boolean;
   readonly errors: readonly ValidationError[];
 }
+
+/** One problem found while validating a module, located by field path. */
+export interface ModuleIssue {
+  readonly field: string;
+  readonly message: string;
+}
+
+/** Why one module source was not loaded. */
+export type ModuleLoadError =
+  | { readonly kind: "io"; readonly source: string; readonly message: string }
+  | { readonly kind: "parse"; readonly source: string; readonly message: string }
+  | {
+      readonly kind: "invalid";
+      readonly source: string;
+      readonly moduleId: string;
+      readonly issues: readonly ModuleIssue[];
+    }
+  | {
+      readonly kind: "duplicateId";
+      readonly source: string;
+      readonly moduleId: string;
+      readonly definedIn: string;
+    };
+
+/** Outcome of loading convention modules at runtime. */
+export interface ModuleLoadReport {
+  /** Module IDs loaded, in source order. */
+  readonly loaded: readonly string[];
+  /** Built-in module IDs now replaced by a loaded module. */
+  readonly replaced: readonly string[];
+  /** Modules whose source failed this time; the previously loaded version is still in use. */
+  readonly kept: readonly string[];
+  readonly errors: readonly ModuleLoadError[];
+}
@@ -11,6 +11,11 @@
 import type { BaseModuleInfo, ConventionInfo, ModuleCatalogEntry } from "./response-types";
 
 const BASE_MODULE_IDS: readonly string[] = [
+  "suit-responses",
+  "opener-rebids",
+  "responder-rebids",
+  "notrump-openings",
+  "preempts",
   "natural-bids",
   "natural-competitive",
   "stayman",
@@ -51,6 +51,7 @@ export type SystemSelectionId = BaseSystemId | `custom:${string}`;
 
 /** Default base module IDs merged into every spec. */
 export const DEFAULT_BASE_MODULE_IDS: readonly string[] = [
+  "suit-responses", "opener-rebids", "responder-rebids", "notrump-openings", "preempts",
   "natural-bids", "natural-competitive", "stayman", "jacoby-transfers", "blackwood",
 ] as const;
 
@@ -313,6 +314,14 @@ export enum PlayPreference {
   Skip = "skip",
 }
 
+/** Which user actions a drill lets the user take back. */
+export enum UndoPolicy {
+  Allowed = "allowed",
+  /** Cards and claims only; bids stand as first made. */
+  PlayOnly = "play-only",
+  Forbidden = "forbidden",
+}
+
 export enum PromptMode {
   SouthDeclarer = "south-declarer",
   DeclarerSwap = "declarer-swap",
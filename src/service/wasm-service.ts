@@ -12,19 +12,24 @@
 
 /* eslint-disable @typescript-eslint/require-await -- async wraps sync WASM calls to match ServicePort interface */
 
-import type { Call, Card, Seat } from "../engine/types";
+import type { Call, Card, Deal, HandRecord, Seat } from "../engine/types";
 import type { DevServicePort } from "./port";
-import type { DrillHandle, SessionConfig } from "./request-types";
+import type { AuctionAnalysisRequest, DrillHandle, ModuleSource, SessionConfig } from "./request-types";
 import type {
   BiddingViewport,
   DeclarerPromptViewport,
   PlayingViewport,
   ExplanationViewport,
+  AuctionReplayViewport,
+  AuctionAnalysis,
   DrillStartResult,
   BidSubmitResult,
   PlayEntryResult,
   PlayCardResult,
+  ClaimResult,
+  UndoResult,
   DDSolutionResult,
+  BoardReference,
   ConventionInfo,
   ModuleCatalogEntry,
   ModuleLearningViewport,
@@ -33,6 +38,7 @@ import type {
   ServiceInferenceSnapshot,
   ModuleConfigSchemaView,
   ValidationResult,
+  ModuleLoadReport,
 } from "./response-types";
 import type { ServiceDebugLogEntry } from "./debug-types";
 import type { PlayProfileId, UserModuleContent } from "./session-types";
@@ -49,6 +55,9 @@ interface WasmServicePortBindings {
   set_dds_table_solver(solver: (pbn: string) => Promise<unknown>): void;
   needs_dds_play(handle: string): boolean;
   create_drill_session(config: SessionConfig): string;
+  destroy_drill_session(handle: string): void;
+  snapshot_drill_session(handle: string): string;
+  restore_drill_session(snapshot: string): string;
   start_drill(handle: string): DrillStartResult;
   submit_bid(handle: string, call: Call): BidSubmitResult;
   enter_play(handle: string, seatOverride: Seat | undefined): PlayEntryResult;
@@ -57,14 +66,24 @@ interface WasmServicePortBindings {
   restart_play(handle: string): PlayEntryResult;
   play_card(handle: string, card: Card, seat: Seat): PlayCardResult;
   play_card_dds(handle: string, card: Card, seat: Seat): Promise<PlayCardResult>;
+  claim(handle: string, seat: Seat, tricks: number): ClaimResult;
+  undo_last_action(handle: string): UndoResult;
   skip_to_review(handle: string): void;
   update_play_profile(handle: string, profileId: string): void;
   get_bidding_viewport(handle: string): BiddingViewport | null;
   get_declarer_prompt_viewport(handle: string): DeclarerPromptViewport | null;
   get_playing_viewport(handle: string): PlayingViewport | null;
   get_explanation_viewport(handle: string): ExplanationViewport | null;
+  get_auction_replay_viewport(handle: string): AuctionReplayViewport | null;
   get_public_belief_state(handle: string): ServicePublicBeliefState;
   get_dds_solution(handle: string): Promise<DDSolutionResult>;
+  analyze_auction(request: AuctionAnalysisRequest): AuctionAnalysis;
+  import_pbn(text: string): HandRecord[];
+  export_session_pbn(handle: string): string;
+  import_lin(text: string): HandRecord[];
+  export_session_lin(handle: string): string;
+  session_board_reference(handle: string): BoardReference;
+  deal_from_board_id(board_id: string): Deal;
   list_conventions(): ConventionInfo[];
   list_modules(): ModuleCatalogEntry[];
   get_module_learning_viewport(moduleId: string): ModuleLearningViewport | null;
@@ -72,6 +91,7 @@ interface WasmServicePortBindings {
   fork_module(source_module_id: string): unknown;
   get_module_config_schema(module_id: string, user_modules_json: string | null): ModuleConfigSchemaView;
   validate_module(module_json: string): ValidationResult;
+  load_modules(sources: readonly ModuleSource[]): ModuleLoadReport;
   // Dev methods (available in debug builds only)
   get_expected_bid?(handle: string): Call | { call: Call } | null;
   get_debug_log?(handle: string): readonly ServiceDebugLogEntry[];
@@ -182,6 +202,18 @@ export class BridgeService implements DevServicePort {
     }
   }
 
+  async destroyDrillSession(handle: DrillHandle): Promise<void> {
+    getPort().destroy_drill_session(handle);
+  }
+
+  async snapshotDrillSession(handle: DrillHandle): Promise<string> {
+    return getPort().snapshot_drill_session(handle);
+  }
+
+  async restoreDrillSession(snapshot: string): Promise<DrillHandle> {
+    return getPort().restore_drill_session(snapshot);
+  }
+
   async startDrill(handle: DrillHandle): Promise<DrillStartResult> {
     try {
       return getPort().start_drill(handle);
@@ -227,6 +259,14 @@ export class BridgeService implements DevServicePort {
     return port.play_card(handle, card, seat);
   }
 
+  async claim(handle: DrillHandle, seat: Seat, tricks: number): Promise<ClaimResult> {
+    return getPort().claim(handle, seat, tricks);
+  }
+
+  async undoLastAction(handle: DrillHandle): Promise<UndoResult> {
+    return getPort().undo_last_action(handle);
+  }
+
   async skipToReview(handle: DrillHandle): Promise<void> {
     getPort().skip_to_review(handle);
   }
@@ -252,6 +292,10 @@ export class BridgeService implements DevServicePort {
     return getPort().get_explanation_viewport(handle);
   }
 
+  async getAuctionReplayViewport(handle: DrillHandle): Promise<AuctionReplayViewport | null> {
+    return getPort().get_auction_replay_viewport(handle);
+  }
+
   // ── Inference ───────────────────────────────────────────────────
   async getPublicBeliefState(handle: DrillHandle): Promise<ServicePublicBeliefState> {
     return getPort().get_public_belief_state(handle);
@@ -262,6 +306,41 @@ export class BridgeService implements DevServicePort {
     return getPort().get_dds_solution(handle);
   }
 
+  // ── Auction analysis ────────────────────────────────────────────
+  async analyzeAuction(request: AuctionAnalysisRequest): Promise<AuctionAnalysis> {
+    return getPort().analyze_auction(request);
+  }
+
+  // ── Hand records ────────────────────────────────────────────────
+  /** Parse a PBN file; pass a record's `deal` as `SessionConfig.deal` to drill it. */
+  async importPbn(text: string): Promise<HandRecord[]> {
+    return getPort().import_pbn(text);
+  }
+
+  async exportSessionPbn(handle: DrillHandle): Promise<string> {
+    return getPort().export_session_pbn(handle);
+  }
+
+  /** Parse a BBO LIN string; one record per board. */
+  async importLin(text: string): Promise<HandRecord[]> {
+    return getPort().import_lin(text);
+  }
+
+  async exportSessionLin(handle: DrillHandle): Promise<string> {
+    return getPort().export_session_lin(handle);
+  }
+
+  // ── Board references ────────────────────────────────────────────
+  /** Board id and auction hash for saved drills and review links. */
+  async sessionBoardReference(handle: DrillHandle): Promise<BoardReference> {
+    return getPort().session_board_reference(handle);
+  }
+
+  /** Restore a board id's deal; pass it as `SessionConfig.deal` to drill it. */
+  async dealFromBoardId(boardId: string): Promise<Deal> {
+    return getPort().deal_from_board_id(boardId);
+  }
+
   // ── Catalog ─────────────────────────────────────────────────────
   async listConventions(): Promise<ConventionInfo[]> {
     return getPort().list_conventions();
@@ -294,6 +373,10 @@ export class BridgeService implements DevServicePort {
     return getPort().validate_module(moduleJson);
   }
 
+  async loadModules(sources: readonly ModuleSource[]): Promise<ModuleLoadReport> {
+    return getPort().load_modules(sources);
+  }
+
   // ── DevServicePort ──────────────────────────────────────────────
   async getExpectedBid(handle: DrillHandle): Promise<{ call: Call } | null> {
     const raw = getPort().get_expected_bid?.(handle);
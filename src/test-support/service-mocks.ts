@@ -22,6 +22,9 @@ export function createMockService(
     init: vi.fn().mockResolvedValue(undefined),
     // Session lifecycle
     createDrillSession: vi.fn().mockResolvedValue("session-1"),
+    destroyDrillSession: vi.fn().mockResolvedValue(undefined),
+    snapshotDrillSession: vi.fn().mockResolvedValue("{}"),
+    restoreDrillSession: vi.fn().mockResolvedValue("session-2"),
     startDrill: vi.fn().mockResolvedValue(makeDrillStartResult()),
     // Bidding
     submitBid: vi.fn().mockResolvedValue(makeBidSubmitResult()),
@@ -32,6 +35,17 @@ export function createMockService(
     restartPlay: vi.fn().mockResolvedValue(makePlayEntryResult()),
     // Play
     playCard: vi.fn().mockResolvedValue(makePlayCardResult()),
+    claim: vi.fn().mockResolvedValue({
+      accepted: true,
+      guaranteedTricks: 0,
+      counterLine: [],
+      score: null,
+    }),
+    undoLastAction: vi.fn().mockResolvedValue({
+      action: "bid",
+      phase: "BIDDING",
+      remaining: 0,
+    }),
     skipToReview: vi.fn().mockResolvedValue(undefined),
     updatePlayProfile: vi.fn().mockResolvedValue(undefined),
     // Query
@@ -39,10 +53,13 @@ export function createMockService(
     getDeclarerPromptViewport: vi.fn().mockResolvedValue(null),
     getPlayingViewport: vi.fn().mockResolvedValue(null),
     getExplanationViewport: vi.fn().mockResolvedValue(null),
+    getAuctionReplayViewport: vi.fn().mockResolvedValue(null),
     // Inference
     getPublicBeliefState: vi.fn().mockResolvedValue({ beliefs: {}, annotations: [] }),
     // DDS
     getDDSSolution: vi.fn().mockRejectedValue(new Error("not available")),
+    // Analysis
+    analyzeAuction: vi.fn().mockResolvedValue({ decisions: [], firstDivergence: null }),
     // Catalog
     listConventions: vi.fn().mockResolvedValue([]),
     // Learning
@@ -54,6 +71,7 @@ export function createMockService(
     // Module config schema
     getModuleConfigSchema: vi.fn().mockResolvedValue({ moduleId: "", displayName: "", category: "custom", ownership: "system", forkedFrom: null, surfaces: [] }),
     validateModule: vi.fn().mockResolvedValue({ valid: true, errors: [] }),
+    loadModules: vi.fn().mockResolvedValue({ loaded: [], replaced: [], kept: [], errors: [] }),
     // DevServicePort
     getExpectedBid: vi.fn().mockResolvedValue(null),
     getDebugLog: vi.fn().mockResolvedValue([]),